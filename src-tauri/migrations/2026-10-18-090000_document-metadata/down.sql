@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `documents` DROP COLUMN `deleted_at`;
+ALTER TABLE `documents` DROP COLUMN `modified_at`;
+ALTER TABLE `documents` DROP COLUMN `created_at`;
+ALTER TABLE `documents` DROP COLUMN `tags`;
+ALTER TABLE `documents` DROP COLUMN `description`;
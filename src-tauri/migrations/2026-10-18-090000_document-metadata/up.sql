@@ -0,0 +1,11 @@
+-- Metadata and trash of stored documents
+ALTER TABLE `documents` ADD COLUMN `description` TEXT;
+ALTER TABLE `documents` ADD COLUMN `tags` TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE `documents` ADD COLUMN `created_at` BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE `documents` ADD COLUMN `modified_at` BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE `documents` ADD COLUMN `deleted_at` BIGINT;
+
+-- Existing documents count as created and modified now
+UPDATE `documents`
+SET `created_at` = CAST(strftime('%s', 'now') AS BIGINT),
+	`modified_at` = CAST(strftime('%s', 'now') AS BIGINT);
@@ -0,0 +1,11 @@
+-- Full-text index of stored documents, keyed by the ID of the document.
+-- The index is filled by the application, which also indexes the documents
+-- stored before this migration on start.
+CREATE VIRTUAL TABLE `documents_search` USING fts5(
+	`title`,
+	`species`,
+	`proteins`,
+	`reactions`,
+	`notes`,
+	tokenize = 'unicode61 remove_diacritics 2'
+);
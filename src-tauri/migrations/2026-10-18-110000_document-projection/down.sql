@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS `document_measurement_species`;
+DROP TABLE IF EXISTS `document_measurements`;
+DROP TABLE IF EXISTS `document_parameter_species`;
+DROP TABLE IF EXISTS `document_parameters`;
+DROP TABLE IF EXISTS `document_reaction_species`;
+DROP TABLE IF EXISTS `document_reactions`;
+DROP TABLE IF EXISTS `document_species`;
+DROP TABLE IF EXISTS `projection_state`;
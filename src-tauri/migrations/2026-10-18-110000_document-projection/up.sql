@@ -0,0 +1,87 @@
+-- Normalised projection of stored documents, which allows queries across
+-- documents. The tables are derived from the content of the documents and
+-- maintained by the application whenever a document is saved, as long as the
+-- projection is enabled.
+CREATE TABLE `projection_state` (
+	`id` INTEGER NOT NULL PRIMARY KEY CHECK (`id` = 1),
+	`enabled` BOOL NOT NULL DEFAULT 0
+);
+INSERT INTO `projection_state` (`id`, `enabled`) VALUES (1, 0);
+
+CREATE TABLE `document_species` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`document_id` INTEGER NOT NULL,
+	`species_id` VARCHAR NOT NULL,
+	`kind` VARCHAR NOT NULL,
+	`name` VARCHAR NOT NULL,
+	`inchikey` VARCHAR,
+	`sequence` TEXT,
+	`ecnumber` VARCHAR,
+	`organism` VARCHAR
+);
+CREATE INDEX `document_species_document` ON `document_species` (`document_id`);
+CREATE INDEX `document_species_name` ON `document_species` (`name` COLLATE NOCASE);
+
+CREATE TABLE `document_reactions` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`document_id` INTEGER NOT NULL,
+	`reaction_id` VARCHAR NOT NULL,
+	`name` VARCHAR NOT NULL,
+	`reversible` BOOL NOT NULL,
+	`kinetic_law` TEXT
+);
+CREATE INDEX `document_reactions_document` ON `document_reactions` (`document_id`);
+
+CREATE TABLE `document_reaction_species` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`document_id` INTEGER NOT NULL,
+	`reaction_id` VARCHAR NOT NULL,
+	`species_id` VARCHAR NOT NULL,
+	`role` VARCHAR NOT NULL
+);
+CREATE INDEX `document_reaction_species_document` ON `document_reaction_species` (`document_id`);
+
+CREATE TABLE `document_parameters` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`document_id` INTEGER NOT NULL,
+	`parameter_id` VARCHAR NOT NULL,
+	`name` VARCHAR NOT NULL,
+	`symbol` VARCHAR NOT NULL,
+	`value` DOUBLE,
+	`unit` VARCHAR,
+	`stderr` DOUBLE,
+	`lower_bound` DOUBLE,
+	`upper_bound` DOUBLE
+);
+CREATE INDEX `document_parameters_document` ON `document_parameters` (`document_id`);
+CREATE INDEX `document_parameters_name` ON `document_parameters` (`name` COLLATE NOCASE);
+
+CREATE TABLE `document_parameter_species` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`document_id` INTEGER NOT NULL,
+	`parameter_id` VARCHAR NOT NULL,
+	`species_id` VARCHAR NOT NULL
+);
+CREATE INDEX `document_parameter_species_document` ON `document_parameter_species` (`document_id`);
+
+CREATE TABLE `document_measurements` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`document_id` INTEGER NOT NULL,
+	`measurement_id` VARCHAR NOT NULL,
+	`name` VARCHAR NOT NULL,
+	`ph` DOUBLE,
+	`temperature` DOUBLE,
+	`temperature_unit` VARCHAR,
+	`temperature_kelvin` DOUBLE
+);
+CREATE INDEX `document_measurements_document` ON `document_measurements` (`document_id`);
+
+CREATE TABLE `document_measurement_species` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`document_id` INTEGER NOT NULL,
+	`measurement_id` VARCHAR NOT NULL,
+	`species_id` VARCHAR NOT NULL,
+	`initial` DOUBLE,
+	`data_unit` VARCHAR
+);
+CREATE INDEX `document_measurement_species_document` ON `document_measurement_species` (`document_id`);
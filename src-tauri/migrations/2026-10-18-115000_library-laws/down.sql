@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS `laws_parameters`;
+DROP TABLE IF EXISTS `laws_assignables`;
+DROP TABLE IF EXISTS `laws_species`;
+DROP TABLE IF EXISTS `laws`;
@@ -0,0 +1,31 @@
+-- Kinetic laws saved for reuse, together with the species, assignables and
+-- parameters they involve. The tables were declared by the schema, but not
+-- created so far, so library archives could not hold any laws.
+CREATE TABLE IF NOT EXISTS `laws` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`name` VARCHAR NOT NULL,
+	`equation` TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS `laws_species` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`law_id` INTEGER NOT NULL,
+	`species_name` VARCHAR NOT NULL,
+	`role` VARCHAR NOT NULL
+);
+CREATE INDEX IF NOT EXISTS `laws_species_law` ON `laws_species` (`law_id`);
+
+CREATE TABLE IF NOT EXISTS `laws_assignables` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`law_id` INTEGER NOT NULL,
+	`assignable_name` VARCHAR NOT NULL,
+	`assignable_type` VARCHAR NOT NULL
+);
+CREATE INDEX IF NOT EXISTS `laws_assignables_law` ON `laws_assignables` (`law_id`);
+
+CREATE TABLE IF NOT EXISTS `laws_parameters` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`law_id` INTEGER NOT NULL,
+	`parameter_name` VARCHAR NOT NULL
+);
+CREATE INDEX IF NOT EXISTS `laws_parameters_law` ON `laws_parameters` (`law_id`);
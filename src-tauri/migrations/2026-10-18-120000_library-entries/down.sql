@@ -0,0 +1,28 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER IF EXISTS `laws_name_update`;
+DROP TRIGGER IF EXISTS `laws_name_insert`;
+DROP INDEX IF EXISTS `laws_name`;
+DROP TRIGGER IF EXISTS `creators_mail_update`;
+DROP TRIGGER IF EXISTS `creators_mail_insert`;
+DROP INDEX IF EXISTS `creators_mail`;
+DROP TRIGGER IF EXISTS `proteins_sequence_update`;
+DROP TRIGGER IF EXISTS `proteins_sequence_insert`;
+DROP INDEX IF EXISTS `proteins_sequence`;
+
+CREATE TABLE `small_molecules_old` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`name` VARCHAR NOT NULL UNIQUE,
+	`canonical_smiles` TEXT,
+	`inchi` TEXT,
+	`inchikey` VARCHAR,
+	`references` TEXT
+);
+
+INSERT INTO `small_molecules_old` (`id`, `name`, `canonical_smiles`, `inchi`, `inchikey`, `references`)
+SELECT `id`, `name`, `canonical_smiles`, `inchi`, `inchikey`, (
+	SELECT nullif(group_concat(`value`, ','), '') FROM json_each(`small_molecules`.`references`)
+)
+FROM `small_molecules`;
+
+DROP TABLE `small_molecules`;
+ALTER TABLE `small_molecules_old` RENAME TO `small_molecules`;
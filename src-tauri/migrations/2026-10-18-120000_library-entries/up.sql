@@ -0,0 +1,132 @@
+-- References of small molecules are stored as JSON array of strings instead
+-- of a comma separated string, so references containing commas survive.
+-- Items were joined without escaping, so a part following a URL continues
+-- the URL, unless it contains a colon and thus looks like a reference of its
+-- own, e.g. "https://..." or "PMID:123". The same rule is applied by
+-- `StringArray::split_legacy` when reading legacy archives.
+CREATE TABLE `small_molecules_new` (
+	`id` INTEGER NOT NULL PRIMARY KEY,
+	`name` VARCHAR NOT NULL UNIQUE,
+	`canonical_smiles` TEXT,
+	`inchi` TEXT,
+	`inchikey` VARCHAR,
+	`references` TEXT NOT NULL DEFAULT '[]'
+);
+
+INSERT INTO `small_molecules_new` (`id`, `name`, `canonical_smiles`, `inchi`, `inchikey`, `references`)
+SELECT `id`, `name`, `canonical_smiles`, `inchi`, `inchikey`, (
+	WITH RECURSIVE
+	`joined` (`text`) AS (
+		SELECT coalesce(`small_molecules`.`references`, '') || ','
+	),
+	`split` (`items`, `current`, `next`, `rest`) AS (
+		SELECT json_array(), '', substr(`text`, 1, instr(`text`, ',') - 1), substr(`text`, instr(`text`, ',') + 1)
+		FROM `joined`
+		UNION ALL
+		SELECT
+			CASE
+				WHEN instr(`current`, '://') > 0 AND trim(`next`) <> '' AND instr(`next`, ':') = 0 THEN `items`
+				WHEN trim(`current`) = '' THEN `items`
+				ELSE json_insert(`items`, '$[#]', trim(`current`))
+			END,
+			CASE
+				WHEN instr(`current`, '://') > 0 AND trim(`next`) <> '' AND instr(`next`, ':') = 0 THEN `current` || ',' || `next`
+				ELSE `next`
+			END,
+			CASE WHEN `rest` = '' THEN NULL ELSE substr(`rest`, 1, instr(`rest`, ',') - 1) END,
+			substr(`rest`, instr(`rest`, ',') + 1)
+		FROM `split`
+		WHERE `next` IS NOT NULL
+	)
+	SELECT CASE
+		WHEN trim(`current`) = '' THEN `items`
+		ELSE json_insert(`items`, '$[#]', trim(`current`))
+	END
+	FROM `split`
+	WHERE `next` IS NULL
+)
+FROM `small_molecules`;
+
+DROP TABLE `small_molecules`;
+ALTER TABLE `small_molecules_new` RENAME TO `small_molecules`;
+
+-- Besides their name, entries are unique by the key identifying what they
+-- describe. Existing entries sharing a key are kept as they are, so the keys
+-- are enforced by triggers on new writes instead of unique indexes, which
+-- could not be created over them. An entry may keep its key when updated, but
+-- cannot take a key held by another entry. Small molecules and proteins are
+-- saved by upserting them by name, so inserts of an entry of the same name are
+-- checked like updates of it. The triggers raise the message of a unique
+-- index, so violations are reported as conflicts.
+CREATE INDEX `small_molecules_inchikey` ON `small_molecules` (`inchikey`);
+
+CREATE TRIGGER `small_molecules_inchikey_insert`
+BEFORE INSERT ON `small_molecules`
+WHEN NEW.`inchikey` <> ''
+	AND EXISTS (SELECT 1 FROM `small_molecules` WHERE `inchikey` = NEW.`inchikey` AND `name` IS NOT NEW.`name`)
+	AND NOT EXISTS (SELECT 1 FROM `small_molecules` WHERE `inchikey` = NEW.`inchikey` AND `name` = NEW.`name`)
+BEGIN
+	SELECT RAISE(ABORT, 'UNIQUE constraint failed: small_molecules.inchikey');
+END;
+
+CREATE TRIGGER `small_molecules_inchikey_update`
+BEFORE UPDATE OF `inchikey` ON `small_molecules`
+WHEN NEW.`inchikey` <> '' AND NEW.`inchikey` IS NOT OLD.`inchikey`
+	AND EXISTS (SELECT 1 FROM `small_molecules` WHERE `inchikey` = NEW.`inchikey` AND `id` <> NEW.`id`)
+BEGIN
+	SELECT RAISE(ABORT, 'UNIQUE constraint failed: small_molecules.inchikey');
+END;
+
+CREATE INDEX `proteins_sequence` ON `proteins` (`sequence`);
+
+CREATE TRIGGER `proteins_sequence_insert`
+BEFORE INSERT ON `proteins`
+WHEN NEW.`sequence` <> ''
+	AND EXISTS (SELECT 1 FROM `proteins` WHERE `sequence` = NEW.`sequence` AND `name` IS NOT NEW.`name`)
+	AND NOT EXISTS (SELECT 1 FROM `proteins` WHERE `sequence` = NEW.`sequence` AND `name` = NEW.`name`)
+BEGIN
+	SELECT RAISE(ABORT, 'UNIQUE constraint failed: proteins.sequence');
+END;
+
+CREATE TRIGGER `proteins_sequence_update`
+BEFORE UPDATE OF `sequence` ON `proteins`
+WHEN NEW.`sequence` <> '' AND NEW.`sequence` IS NOT OLD.`sequence`
+	AND EXISTS (SELECT 1 FROM `proteins` WHERE `sequence` = NEW.`sequence` AND `id` <> NEW.`id`)
+BEGIN
+	SELECT RAISE(ABORT, 'UNIQUE constraint failed: proteins.sequence');
+END;
+
+CREATE INDEX `creators_mail` ON `creators` (`mail`);
+
+CREATE TRIGGER `creators_mail_insert`
+BEFORE INSERT ON `creators`
+WHEN NEW.`mail` <> ''
+	AND EXISTS (SELECT 1 FROM `creators` WHERE `mail` = NEW.`mail`)
+BEGIN
+	SELECT RAISE(ABORT, 'UNIQUE constraint failed: creators.mail');
+END;
+
+CREATE TRIGGER `creators_mail_update`
+BEFORE UPDATE OF `mail` ON `creators`
+WHEN NEW.`mail` <> '' AND NEW.`mail` IS NOT OLD.`mail`
+	AND EXISTS (SELECT 1 FROM `creators` WHERE `mail` = NEW.`mail` AND `id` <> NEW.`id`)
+BEGIN
+	SELECT RAISE(ABORT, 'UNIQUE constraint failed: creators.mail');
+END;
+
+CREATE INDEX `laws_name` ON `laws` (`name`);
+
+CREATE TRIGGER `laws_name_insert`
+BEFORE INSERT ON `laws`
+WHEN EXISTS (SELECT 1 FROM `laws` WHERE `name` = NEW.`name`)
+BEGIN
+	SELECT RAISE(ABORT, 'UNIQUE constraint failed: laws.name');
+END;
+
+CREATE TRIGGER `laws_name_update`
+BEFORE UPDATE OF `name` ON `laws`
+WHEN NEW.`name` IS NOT OLD.`name`
+	AND EXISTS (SELECT 1 FROM `laws` WHERE `name` = NEW.`name` AND `id` <> NEW.`id`)
+BEGIN
+	SELECT RAISE(ABORT, 'UNIQUE constraint failed: laws.name');
+END;
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use tauri::{AppHandle, State};
+
+use crate::backup::{self, BackupEntry, Backups};
+use crate::error::SuiteError;
+use crate::io::dataio::detach_open_documents;
+use crate::io::{projection, search};
+use crate::states::Workspace;
+
+/// Takes a backup of the database
+///
+/// Only the configured number of most recent backups is kept, so the oldest
+/// backups may be removed.
+///
+/// # Arguments
+/// * `backups` - The backups of the database
+/// * `app` - The Tauri application handle used to access the settings
+///
+/// # Returns
+/// Result containing the new backup
+#[tauri::command]
+#[specta::specta]
+pub fn create_backup(
+    backups: State<Arc<Backups>>,
+    app: AppHandle,
+) -> Result<BackupEntry, SuiteError> {
+    let settings = backup::load_settings(&app)?;
+    backups.create(settings.keep)
+}
+
+/// Lists the backups of the database
+///
+/// # Arguments
+/// * `backups` - The backups of the database
+///
+/// # Returns
+/// Result containing the backups, the most recent first
+#[tauri::command]
+#[specta::specta]
+pub fn list_backups(backups: State<Arc<Backups>>) -> Result<Vec<BackupEntry>, SuiteError> {
+    backups.list()
+}
+
+/// Replaces the content of the database with a backup
+///
+/// The current content is backed up first. Open documents are detached from
+/// the database, since their entries may differ or be missing in the backup,
+/// so the next save creates a new entry. The search index and the projection
+/// are rebuilt from the restored documents.
+///
+/// # Arguments
+/// * `file` - File name of the backup within the backup directory
+/// * `backups` - The backups of the database
+/// * `workspace` - The open documents of the application
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing the backup of the replaced content
+#[tauri::command]
+#[specta::specta]
+pub fn restore_backup(
+    file: &str,
+    backups: State<Arc<Backups>>,
+    workspace: State<Arc<Workspace>>,
+    app_handle: AppHandle,
+) -> Result<BackupEntry, SuiteError> {
+    let previous = backups.restore(file)?;
+
+    let ids: Vec<i32> = workspace
+        .documents()
+        .iter()
+        .filter_map(|state| *state.id.lock().unwrap())
+        .collect();
+    detach_open_documents(&workspace, &ids, &app_handle);
+
+    search::rebuild_index()?;
+    projection::rebuild_projection()?;
+
+    Ok(previous)
+}
+
+/// Removes a backup of the database
+///
+/// # Arguments
+/// * `file` - File name of the backup within the backup directory
+/// * `backups` - The backups of the database
+///
+/// # Returns
+/// Result indicating whether the backup could be removed
+#[tauri::command]
+#[specta::specta]
+pub fn delete_backup(file: &str, backups: State<Arc<Backups>>) -> Result<(), SuiteError> {
+    backups.delete(file)
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use tauri::State;
+
+use crate::document::{
+    self,
+    diff::{DiffOptions, DocumentDiff},
+};
+use crate::error::SuiteError;
+use crate::io::source::DocumentSource;
+use crate::states::Workspace;
+
+/// Compares two documents entity by entity
+///
+/// Tauri adapter of [`document::diff::diff_documents`], resolving both
+/// documents from their sources first.
+#[tauri::command]
+#[specta::specta]
+pub fn diff_documents(
+    before: DocumentSource,
+    after: DocumentSource,
+    options: Option<DiffOptions>,
+    workspace: State<Arc<Workspace>>,
+) -> Result<DocumentDiff, SuiteError> {
+    let before = before.resolve(&workspace)?;
+    let after = after.resolve(&workspace)?;
+    document::diff::diff_documents(&before, &after, &options.unwrap_or_default())
+}
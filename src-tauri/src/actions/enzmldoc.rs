@@ -1,52 +1,28 @@
 use diesel::RunQueryDsl;
 use enzymeml::prelude::{EnzymeMLDocument, EnzymeMLDocumentBuilder};
-use std::error::Error;
-use std::sync::{Arc, MutexGuard};
-use tauri::{AppHandle, Emitter, State};
+use std::sync::Arc;
+use tauri::{AppHandle, State};
 
-use crate::db::establish_db_connection;
+use crate::db::establish_connection;
+use crate::document::{self, enzmldoc::Stats};
+use crate::error::SuiteError;
 use crate::models::NewDocument;
 use crate::schema::documents;
-use crate::states::EnzymeMLState;
-use crate::{update_event, update_report};
-
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
-pub struct Stats {
-    pub small_molecules: usize,
-    pub proteins: usize,
-    pub reactions: usize,
-    pub vessels: usize,
-    pub measurements: usize,
-}
+use crate::states::Workspace;
 
 /// Sets the title of the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-/// * `title` - The new title for the document
-/// * `app_handle` - Handle to the Tauri application for event emission
-///
-/// # Returns
-/// Result indicating success or failure
+/// Tauri adapter of [`document::enzmldoc::set_title`].
 #[tauri::command]
+#[specta::specta]
 pub fn set_title(
-    state: State<Arc<EnzymeMLState>>,
+    workspace: State<Arc<Workspace>>,
     title: &str,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<(), String> {
-    let mut state_title = state.title.lock().unwrap();
-    let mut state_doc = state.doc.lock().unwrap();
-
-    state_doc.name = title.to_string();
-    *state_title = title.to_string();
-
-    app_handle
-        .emit("update_document", ())
-        .map_err(|e| e.to_string())?;
-
-    update_report!(state, app_handle, &state_doc);
-
-    Ok(())
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    document::enzmldoc::set_title(&state, title, &app_handle)
 }
 
 /// Creates a new EnzymeML document and saves it to the database
@@ -56,9 +32,12 @@ pub fn set_title(
 ///
 /// # Returns
 /// Result containing the database ID of the created document or an error
-pub fn create_new_document(title: &str) -> Result<i32, Box<dyn Error>> {
-    let mut connection = establish_db_connection();
-    let doc = EnzymeMLDocumentBuilder::default().name(title).build()?;
+pub fn create_new_document(title: &str) -> Result<i32, SuiteError> {
+    let mut connection = establish_connection()?;
+    let doc = EnzymeMLDocumentBuilder::default()
+        .name(title)
+        .build()
+        .map_err(|e| SuiteError::validation(e.to_string()))?;
 
     let content = serde_json::to_string_pretty(&doc)?;
     let new_document = NewDocument {
@@ -76,170 +55,81 @@ pub fn create_new_document(title: &str) -> Result<i32, Box<dyn Error>> {
 
 /// Creates a new EnzymeML document and replaces the current document in state
 ///
-/// This function replaces the current EnzymeML document in the application state
-/// with the provided document. It emits an update event to notify the frontend
-/// of the document change.
-///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state containing the document data
-/// * `enzmldoc` - The new EnzymeML document to set as the current document
-/// * `app_handle` - Handle to the Tauri application for event emission to the frontend
-///
-/// # Returns
-/// Result indicating success or failure
+/// Tauri adapter of [`document::enzmldoc::create_document`].
 #[tauri::command]
+#[specta::specta]
 pub fn create_document(
-    state: State<Arc<EnzymeMLState>>,
+    workspace: State<Arc<Workspace>>,
     enzmldoc: EnzymeMLDocument,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<(), String> {
-    let mut state_doc = state.doc.lock().unwrap();
-    *state_doc = enzmldoc;
-
-    update_event!(app_handle, "update_document");
-    update_report!(state, app_handle, &state_doc);
-
-    Ok(())
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    document::enzmldoc::create_document(&state, enzmldoc, &app_handle)
 }
 
 /// Retrieves all species IDs from the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-///
-/// # Returns
-/// Vector of all species IDs (small molecules, proteins, and complexes)
+/// Tauri adapter of [`document::enzmldoc::get_all_species_ids`].
 #[tauri::command]
-pub fn get_all_species_ids(state: State<Arc<EnzymeMLState>>) -> Vec<String> {
-    let doc = state.doc.lock().unwrap();
-    extract_species_ids(&doc)
-}
-
-/// Extracts species IDs from an EnzymeML document
-///
-/// # Arguments
-/// * `doc` - Reference to the locked EnzymeML document
-///
-/// # Returns
-/// Vector of all species IDs (small molecules, proteins, and complexes)
-pub fn extract_species_ids(doc: &MutexGuard<EnzymeMLDocument>) -> Vec<String> {
-    doc.small_molecules
-        .iter()
-        .map(|s| s.id.clone())
-        .chain(doc.proteins.iter().map(|s| s.id.clone()))
-        .chain(doc.complexes.iter().map(|s| s.id.clone()))
-        .collect()
+#[specta::specta]
+pub fn get_all_species_ids(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<Vec<String>, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    Ok(document::enzmldoc::get_all_species_ids(&state))
 }
 
 /// Retrieves all species with their IDs and names from the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-///
-/// # Returns
-/// Vector of tuples containing the ID and name of each species
+/// Tauri adapter of [`document::enzmldoc::get_all_species`].
 #[tauri::command]
-pub fn get_all_species(state: State<Arc<EnzymeMLState>>) -> Vec<(String, String)> {
-    let doc = state.doc.lock().unwrap();
-    get_all_species_ids_and_names(doc)
+#[specta::specta]
+pub fn get_all_species(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<Vec<(String, String)>, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    Ok(document::enzmldoc::get_all_species(&state))
 }
 
 /// Retrieves the name of a specific species by its ID
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-/// * `species_id` - The ID of the species to look up
-///
-/// # Returns
-/// Result containing the species name or an error if not found
+/// Tauri adapter of [`document::enzmldoc::get_species_name`].
 #[tauri::command]
+#[specta::specta]
 pub fn get_species_name(
-    state: State<Arc<EnzymeMLState>>,
+    workspace: State<Arc<Workspace>>,
     species_id: &str,
-) -> Result<String, String> {
-    let state_doc = state.doc.lock().unwrap();
-
-    // Combine all species into one vector
-    let all_species = get_all_species_ids_and_names(state_doc);
-
-    // Find the species with the given ID
-    let species = all_species.iter().find(|(id, _)| id == species_id);
-
-    match species {
-        Some((_, name)) => Ok(name.clone()),
-        None => Err(format!("Species with ID {} not found", species_id)),
-    }
-}
-
-/// Helper function to extract all species IDs and names from an EnzymeML document
-///
-/// # Arguments
-/// * `state_doc` - Reference to the locked EnzymeML document
-///
-/// # Returns
-/// Vector of tuples containing the ID and name of each species
-fn get_all_species_ids_and_names(state_doc: MutexGuard<EnzymeMLDocument>) -> Vec<(String, String)> {
-    let all_species = state_doc
-        .small_molecules
-        .iter()
-        .map(|s| (s.id.clone(), s.name.clone()))
-        .chain(
-            state_doc
-                .proteins
-                .iter()
-                .map(|s| (s.id.clone(), s.name.clone())),
-        )
-        .chain(
-            state_doc
-                .complexes
-                .iter()
-                .map(|s| (s.id.clone(), s.name.clone())),
-        )
-        .collect::<Vec<(String, String)>>();
-    all_species
+    handle: Option<String>,
+) -> Result<String, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    document::enzmldoc::get_species_name(&state, species_id)
 }
 
 /// Retrieves all non-constant species IDs from the EnzymeML document
-/// Non-constant species are those that can change concentration during simulation
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-///
-/// # Returns
-/// Vector of non-constant species IDs (small molecules and proteins only)
+/// Tauri adapter of [`document::enzmldoc::get_all_non_constant_species_ids`].
 #[tauri::command]
-pub fn get_all_non_constant_species_ids(state: State<Arc<EnzymeMLState>>) -> Vec<String> {
-    let state_doc = state.doc.lock().unwrap();
-    state_doc
-        .small_molecules
-        .iter()
-        .filter(|s| !s.constant)
-        .map(|s| s.id.clone())
-        .chain(
-            state_doc
-                .proteins
-                .iter()
-                .filter(|s| !s.constant)
-                .map(|s| s.id.clone()),
-        )
-        .collect()
+#[specta::specta]
+pub fn get_all_non_constant_species_ids(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<Vec<String>, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    Ok(document::enzmldoc::get_all_non_constant_species_ids(&state))
 }
 
 /// Retrieves statistics about the current EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-///
-/// # Returns
-/// Stats struct containing counts of all entity types in the document
+/// Tauri adapter of [`document::enzmldoc::get_stats`].
 #[tauri::command]
-pub fn get_stats(state: State<Arc<EnzymeMLState>>) -> Stats {
-    let state_doc = state.doc.lock().unwrap();
-    Stats {
-        small_molecules: state_doc.small_molecules.len(),
-        proteins: state_doc.proteins.len(),
-        reactions: state_doc.reactions.len(),
-        vessels: state_doc.vessels.len(),
-        measurements: state_doc.measurements.len(),
-    }
+#[specta::specta]
+pub fn get_stats(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<Stats, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    Ok(document::enzmldoc::get_stats(&state))
 }
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+use crate::document::{self, external::MergeReport};
+use crate::error::SuiteError;
+use crate::states::Workspace;
+
+/// Replaces the document with the current content of its origin file
+///
+/// Tauri adapter of [`document::external::reload`].
+#[tauri::command]
+#[specta::specta]
+pub fn reload_from_file(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    document::external::reload(&state, &app_handle)
+}
+
+/// Keeps the document as it is and dismisses the changes of its origin file
+///
+/// Tauri adapter of [`document::external::keep_local`].
+#[tauri::command]
+#[specta::specta]
+pub fn keep_local_changes(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    document::external::keep_local(&state, &app_handle)
+}
+
+/// Merges the changes of the origin file into the document
+///
+/// Tauri adapter of [`document::external::merge`].
+#[tauri::command]
+#[specta::specta]
+pub fn merge_with_file(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<MergeReport, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    document::external::merge(&state, &app_handle)
+}
@@ -4,6 +4,9 @@ pub const SMALL_MOLECULE_PREFIX: &str = "s";
 /// Prefix used for generating unique identifiers for proteins
 pub const PROTEIN_PREFIX: &str = "p";
 
+/// Prefix used for generating unique identifiers for complexes
+pub const COMPLEX_PREFIX: &str = "c";
+
 /// Prefix used for generating unique identifiers for reactions
 pub const REACTION_PREFIX: &str = "r";
 
@@ -12,3 +15,6 @@ pub const MEASUREMENT_PREFIX: &str = "m";
 
 /// Prefix used for generating unique identifiers for vessels
 pub const VESSEL_PREFIX: &str = "v";
+
+/// Prefix used for generating unique identifiers for parameters
+pub const PARAMETER_PREFIX: &str = "q";
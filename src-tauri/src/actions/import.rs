@@ -0,0 +1,28 @@
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+use crate::document::{
+    self,
+    import::{ImportOptions, ImportReport},
+};
+use crate::error::SuiteError;
+use crate::io::source::DocumentSource;
+use crate::states::Workspace;
+
+/// Imports the entities of another document into the document
+///
+/// Tauri adapter of [`document::import::import_document`], resolving the
+/// imported document from its source first.
+#[tauri::command]
+#[specta::specta]
+pub fn import_document(
+    source: DocumentSource,
+    options: Option<ImportOptions>,
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<ImportReport, SuiteError> {
+    let incoming = source.resolve(&workspace)?;
+    let state = workspace.document(handle.as_deref())?;
+    document::import::import_document(&state, &incoming, &options.unwrap_or_default(), &app_handle)
+}
@@ -1,18 +1,23 @@
 use std::{collections::HashMap, net::TcpListener, path::PathBuf, sync::Arc};
 
+use notebookx::NotebookFormat;
 use python_launcher::all_executables;
 use regex::Regex;
 use specta;
 use std::fs::create_dir_all;
 use std::process::Command;
-use tauri::{AppHandle, Emitter, State};
+use tauri::{AppHandle, State};
 use tauri_plugin_dialog::DialogExt;
 use tauri_plugin_opener::OpenerExt;
 use tauri_plugin_shell::{process::CommandEvent, ShellExt};
 use tauri_plugin_store::StoreExt;
+use tauri_specta::Event;
 
 use crate::actions::utils::get_config_store_path;
-use crate::states::{EnzymeMLState, JupyterSessionInfo, JupyterState, PythonInstallation};
+use crate::error::SuiteError;
+use crate::states::{
+    EnzymeMLState, JupyterSessionInfo, JupyterState, PythonInstallation, Workspace,
+};
 
 const PYTHON_VERSION_REGEX: &str = r"Python (\d+\.\d+\.\d+(?:\.\w+)?)";
 const PYTHON_ENVS_KEY: &str = "custom_python_envs";
@@ -40,6 +45,11 @@ macro_rules! jupyter_template {
     };
 }
 
+/// Event signalling that a Jupyter Lab session has been started or stopped
+#[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type, tauri_specta::Event, Clone)]
+#[tauri_specta(event_name = "jupyter_update")]
+pub struct JupyterUpdate;
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type, tauri_specta::Event, Clone)]
 pub struct JupyterInstallOutput {
     status: JupyterInstallStatus,
@@ -53,7 +63,9 @@ pub enum JupyterInstallStatus {
     Output,
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type, Clone)]
+#[derive(
+    Debug, serde::Serialize, serde::Deserialize, specta::Type, schemars::JsonSchema, Clone,
+)]
 pub struct JupyterTemplate {
     pub id: String,
     pub name: String,
@@ -108,13 +120,13 @@ lazy_static::lazy_static! {
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(Vec<PythonInstallation>)` with all detected Python installations
-/// - `Err(String)` if the operation fails
+/// - `Err(SuiteError)` if the operation fails
 #[tauri::command]
 #[specta::specta]
 pub fn detect_python_installations(
     app: AppHandle,
     jupyter_state: State<'_, Arc<JupyterState>>,
-) -> Result<Vec<PythonInstallation>, String> {
+) -> Result<Vec<PythonInstallation>, SuiteError> {
     let mut installations = Vec::new();
     let pattern = Regex::new(PYTHON_VERSION_REGEX).unwrap();
 
@@ -168,12 +180,12 @@ pub fn detect_python_installations(
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(Vec<PythonInstallation>)` with all detected Python installations
-/// - `Err(String)` if the operation fails
+/// - `Err(SuiteError)` if the operation fails
 #[tauri::command]
 #[specta::specta]
 pub fn list_detected_pythons(
     jupyter_state: State<'_, Arc<JupyterState>>,
-) -> Result<Vec<PythonInstallation>, String> {
+) -> Result<Vec<PythonInstallation>, SuiteError> {
     Ok(jupyter_state.detected_pythons.lock().unwrap().clone())
 }
 
@@ -185,12 +197,12 @@ pub fn list_detected_pythons(
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(Option<String>)` with the selected Python path if set
-/// - `Err(String)` if the operation fails
+/// - `Err(SuiteError)` if the operation fails
 #[tauri::command]
 #[specta::specta]
 pub fn get_selected_python(
     jupyter_state: State<'_, Arc<JupyterState>>,
-) -> Result<Option<String>, String> {
+) -> Result<Option<String>, SuiteError> {
     Ok(jupyter_state.selected_python_path.lock().unwrap().clone())
 }
 
@@ -203,20 +215,17 @@ pub fn get_selected_python(
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(())` if the path is set successfully
-/// - `Err(String)` if the operation fails
+/// - `Err(SuiteError)` if the operation fails
 #[tauri::command]
 #[specta::specta]
 pub fn set_selected_python(
     jupyter_state: State<'_, Arc<JupyterState>>,
     path: String,
-) -> Result<(), String> {
+) -> Result<(), SuiteError> {
     // Verify the path exists in detected pythons
     let detected = jupyter_state.detected_pythons.lock().unwrap();
     if !detected.iter().any(|p| p.path == path) {
-        return Err(format!(
-            "Python path not found in detected installations: {}",
-            path
-        ));
+        return Err(SuiteError::not_found("Python installation", path));
     }
     drop(detected);
 
@@ -241,7 +250,7 @@ pub fn set_selected_python(
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(())` if the Python environment is added and persisted successfully
-/// - `Err(String)` if the operation fails, containing the error message
+/// - `Err(SuiteError)` if the operation fails, containing the error message
 ///
 /// # Errors
 /// Returns an error if:
@@ -254,22 +263,22 @@ pub fn set_selected_python(
 pub async fn add_python_env(
     app: AppHandle,
     jupyter_state: State<'_, Arc<JupyterState>>,
-) -> Result<(), String> {
+) -> Result<(), SuiteError> {
     // Let the user point to the python env
     let file_path = app
         .dialog()
         .file()
         .set_title("Pick a Python installation")
         .blocking_pick_file()
-        .ok_or_else(|| "No file has been selected.".to_string())?;
+        .ok_or_else(|| SuiteError::cancelled("No file has been selected."))?;
 
     let path = file_path
         .as_path()
-        .ok_or_else(|| "Could not convert 'FilePath' into path.".to_string())?;
+        .ok_or_else(|| SuiteError::validation("Could not convert 'FilePath' into path."))?;
 
     // Check if the given path is in fact a valid Python installation
     if path.is_dir() {
-        return Err("The provided path is a directory, but a Python executable file is required. Please specify the full path to the Python executable (e.g., /path/to/python or /path/to/python.exe)".to_string());
+        return Err(SuiteError::validation("The provided path is a directory, but a Python executable file is required. Please specify the full path to the Python executable (e.g., /path/to/python or /path/to/python.exe)"));
     }
 
     let check = app.shell().command(path).arg("--version").output().await;
@@ -277,7 +286,7 @@ pub async fn add_python_env(
     match check {
         Ok(output) => {
             if !output.status.success() {
-                return Err("No valid Python version detected. Are you sure the binary provided is a Python installation?".to_string());
+                return Err(SuiteError::validation("No valid Python version detected. Are you sure the binary provided is a Python installation?"));
             }
 
             // Parse version from output
@@ -288,10 +297,10 @@ pub async fn add_python_env(
             {
                 caps[1].to_string()
             } else {
-                return Err(format!(
+                return Err(SuiteError::parse(format!(
                     "Failed to parse Python version from output: {}",
                     version_output
-                ));
+                )));
             };
 
             let path_str = path.to_string_lossy().to_string();
@@ -319,7 +328,10 @@ pub async fn add_python_env(
             *jupyter_state.selected_python_path.lock().unwrap() = Some(path_str);
         }
         Err(e) => {
-            return Err(format!("Failed to verify Python installation: {}", e));
+            return Err(SuiteError::internal(format!(
+                "Failed to verify Python installation: {}",
+                e
+            )));
         }
     }
 
@@ -340,7 +352,7 @@ pub async fn add_python_env(
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(Vec<PythonInstallation>)` with all custom Python environments from the store
-/// - `Err(String)` if the operation fails, containing a descriptive error message
+/// - `Err(SuiteError)` if the operation fails, containing a descriptive error message
 ///
 /// # Errors
 /// Returns an error if:
@@ -349,24 +361,26 @@ pub async fn add_python_env(
 /// - The store cannot be saved when initializing an empty array
 #[tauri::command]
 #[specta::specta]
-pub async fn list_custom_python_envs(app: AppHandle) -> Result<Vec<PythonInstallation>, String> {
+pub async fn list_custom_python_envs(
+    app: AppHandle,
+) -> Result<Vec<PythonInstallation>, SuiteError> {
+    let store_path = get_config_store_path()?;
     let store = app
-        .store(get_config_store_path()?)
-        .map_err(|e| format!("Could not open store: {}", e))?;
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Could not open store: {}", e)))?;
 
     match store.get(PYTHON_ENVS_KEY) {
         Some(custom_pythons) => {
             // Parse the stored custom Python environments
-            serde_json::from_value::<Vec<PythonInstallation>>(custom_pythons.clone())
-                .map_err(|e| format!("Failed to parse custom Python environments: {}", e))
+            serde_json::from_value::<Vec<PythonInstallation>>(custom_pythons.clone()).map_err(|e| {
+                SuiteError::parse(format!("Failed to parse custom Python environments: {}", e))
+            })
         }
         None => {
             // Key doesn't exist, initialize it with an empty array
             let empty_vec: Vec<PythonInstallation> = Vec::new();
             store.set(PYTHON_ENVS_KEY, serde_json::to_value(&empty_vec).unwrap());
-            store
-                .save()
-                .map_err(|e| format!("Failed to save store: {}", e))?;
+            store.save().map_err(|e| SuiteError::io(&store_path, e))?;
             Ok(empty_vec)
         }
     }
@@ -415,11 +429,11 @@ fn load_custom_python_envs(app: &AppHandle, installations: &mut Vec<PythonInstal
 fn save_custom_python_env(
     app: &AppHandle,
     installation: &PythonInstallation,
-) -> Result<(), String> {
+) -> Result<(), SuiteError> {
     let store_path = get_config_store_path()?;
     let store = app
-        .store(store_path)
-        .map_err(|e| format!("Failed to access store: {}", e))?;
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
 
     // Get existing custom Python environments or create new vec
     let mut custom_envs: Vec<PythonInstallation> = store
@@ -434,9 +448,7 @@ fn save_custom_python_env(
     if !already_exists {
         custom_envs.push(installation.clone());
         store.set(PYTHON_ENVS_KEY, serde_json::to_value(&custom_envs).unwrap());
-        store
-            .save()
-            .map_err(|e| format!("Failed to save store: {}", e))?;
+        store.save().map_err(|e| SuiteError::io(&store_path, e))?;
     }
 
     Ok(())
@@ -498,13 +510,13 @@ fn determine_python_source(path: &str) -> (String, u8) {
 /// A `Result` containing:
 /// - `Ok(true)` if JupyterLab is installed and accessible
 /// - `Ok(false)` if JupyterLab is not found or command fails
-/// - `Err(String)` if the shell command cannot be executed
+/// - `Err(SuiteError)` if the shell command cannot be executed
 #[tauri::command]
 #[specta::specta]
 pub async fn is_jupyter_lab_installed(
     app_handle: tauri::AppHandle,
     jupyter_state: State<'_, Arc<JupyterState>>,
-) -> Result<bool, String> {
+) -> Result<bool, SuiteError> {
     let selected_python = jupyter_state.selected_python_path.lock().unwrap().clone();
 
     if selected_python.is_none() {
@@ -544,7 +556,7 @@ pub async fn is_jupyter_lab_installed(
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(())` if JupyterLab is installed successfully
-/// - `Err(String)` if the installation fails, containing the error message
+/// - `Err(SuiteError)` if the installation fails, containing the error message
 ///
 /// # Errors
 /// Returns an error if:
@@ -558,28 +570,24 @@ pub async fn is_jupyter_lab_installed(
 pub async fn install_jupyter_lab(
     app_handle: tauri::AppHandle,
     jupyter_state: State<'_, Arc<JupyterState>>,
-) -> Result<(), String> {
+) -> Result<(), SuiteError> {
     let selected_python = jupyter_state.selected_python_path.lock().unwrap().clone();
 
     if selected_python.is_none() {
-        return Err(
-            "No Python installation selected. Please select a Python installation first."
-                .to_string(),
-        );
+        return Err(SuiteError::validation(
+            "No Python installation selected. Please select a Python installation first.",
+        ));
     }
 
     let python_path = selected_python.unwrap();
 
     // Emit initial status
-    app_handle
-        .emit(
-            "jupyter-install",
-            JupyterInstallOutput {
-                status: JupyterInstallStatus::Output,
-                output: format!("Starting JupyterLab installation with {}...", python_path),
-            },
-        )
-        .ok();
+    JupyterInstallOutput {
+        status: JupyterInstallStatus::Output,
+        output: format!("Starting JupyterLab installation with {}...", python_path),
+    }
+    .emit(&app_handle)
+    .ok();
 
     let (mut rx, _child) = app_handle
         .shell()
@@ -594,7 +602,7 @@ pub async fn install_jupyter_lab(
         .arg("--user")
         .envs(setup_jupyter_env(&python_path))
         .spawn()
-        .map_err(|e| format!("spawn failed: {e}"))?;
+        .map_err(|e| SuiteError::internal(format!("spawn failed: {e}")))?;
 
     // Handle command output
 
@@ -602,53 +610,38 @@ pub async fn install_jupyter_lab(
         match event {
             CommandEvent::Stdout(line) | CommandEvent::Stderr(line) => {
                 let line = String::from_utf8_lossy(&line);
-                app_handle
-                    .emit(
-                        "jupyter-install",
-                        JupyterInstallOutput {
-                            status: JupyterInstallStatus::Output,
-                            output: line.to_string(),
-                        },
-                    )
-                    .ok();
+                JupyterInstallOutput {
+                    status: JupyterInstallStatus::Output,
+                    output: line.to_string(),
+                }
+                .emit(&app_handle)
+                .ok();
             }
             CommandEvent::Terminated(payload) => {
                 if payload.code == Some(0) {
-                    app_handle
-                        .emit(
-                            "jupyter-install",
-                            JupyterInstallOutput {
-                                status: JupyterInstallStatus::Success,
-                                output: "JupyterLab installed successfully".to_string(),
-                            },
-                        )
-                        .ok();
+                    JupyterInstallOutput {
+                        status: JupyterInstallStatus::Success,
+                        output: "JupyterLab installed successfully".to_string(),
+                    }
+                    .emit(&app_handle)
+                    .ok();
                 } else {
-                    app_handle
-                        .emit(
-                            "jupyter-install",
-                            JupyterInstallOutput {
-                                status: JupyterInstallStatus::Error,
-                                output: format!(
-                                    "Installation failed with exit code: {:?}",
-                                    payload.code
-                                ),
-                            },
-                        )
-                        .ok();
+                    JupyterInstallOutput {
+                        status: JupyterInstallStatus::Error,
+                        output: format!("Installation failed with exit code: {:?}", payload.code),
+                    }
+                    .emit(&app_handle)
+                    .ok();
                 }
                 break;
             }
             CommandEvent::Error(error) => {
-                app_handle
-                    .emit(
-                        "jupyter-install",
-                        JupyterInstallOutput {
-                            status: JupyterInstallStatus::Error,
-                            output: error.to_string(),
-                        },
-                    )
-                    .ok();
+                JupyterInstallOutput {
+                    status: JupyterInstallStatus::Error,
+                    output: error.to_string(),
+                }
+                .emit(&app_handle)
+                .ok();
             }
             _ => {}
         }
@@ -669,12 +662,12 @@ pub async fn install_jupyter_lab(
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(Vec<JupyterSessionInfo>)` with details of all active sessions
-/// - `Err(String)` if the operation fails
+/// - `Err(SuiteError)` if the operation fails
 #[tauri::command]
 #[specta::specta]
 pub fn get_jupyter_sessions(
     jupyter_state: State<'_, Arc<JupyterState>>,
-) -> Result<Vec<JupyterSessionInfo>, String> {
+) -> Result<Vec<JupyterSessionInfo>, SuiteError> {
     Ok(jupyter_state.get_sessions())
 }
 
@@ -685,12 +678,13 @@ pub fn get_jupyter_sessions(
 ///
 /// # Arguments
 /// * `app_handle` - The Tauri application handle for opening the project folder
-/// * `enzmldoc_state` - The shared EnzymeML document state containing document metadata
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 ///
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(())` if the project folder is opened successfully
-/// - `Err(String)` if the operation fails, containing the error message
+/// - `Err(SuiteError)` if the operation fails, containing the error message
 ///
 /// # Errors
 /// Returns an error if:
@@ -701,19 +695,19 @@ pub fn get_jupyter_sessions(
 #[specta::specta]
 pub fn open_project_folder(
     app_handle: tauri::AppHandle,
-    enzmldoc_state: State<'_, Arc<EnzymeMLState>>,
-) -> Result<(), String> {
-    let project_path = get_project_path(&enzmldoc_state);
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<(), SuiteError> {
+    let project_path = get_project_path(&*workspace.document(handle.as_deref())?);
 
     if !project_path.exists() {
-        create_dir_all(&project_path)
-            .map_err(|e| format!("failed to create project folder: {e}"))?;
+        create_dir_all(&project_path).map_err(|e| SuiteError::io(&project_path, e))?;
     }
 
     app_handle
         .opener()
         .open_path(project_path.to_str().unwrap(), None::<&str>)
-        .map_err(|e| format!("failed to open project folder: {e}"))?;
+        .map_err(|e| SuiteError::internal(format!("failed to open project folder: {e}")))?;
     Ok(())
 }
 
@@ -732,7 +726,7 @@ pub fn open_project_folder(
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(())` if the session is terminated successfully
-/// - `Err(String)` if the operation fails, containing the error message
+/// - `Err(SuiteError)` if the operation fails, containing the error message
 ///
 /// # Errors
 /// Returns an error if:
@@ -746,15 +740,15 @@ pub fn kill_jupyter(
     app_handle: tauri::AppHandle,
     jupyter_state: State<'_, Arc<JupyterState>>,
     id: String,
-) -> Result<(), String> {
+) -> Result<(), SuiteError> {
     jupyter_state
         .kill_child(&id)
-        .map_err(|e| format!("failed to kill child: {e}"))?;
+        .map_err(|e| SuiteError::internal(format!("failed to kill child: {e}")))?;
 
     // Emit update signal after successfully killing the session
-    app_handle
-        .emit("jupyter_update", ())
-        .map_err(|e| format!("failed to emit jupyter_update event: {e}"))?;
+    JupyterUpdate
+        .emit(&app_handle)
+        .map_err(|e| SuiteError::internal(format!("failed to emit jupyter_update event: {e}")))?;
 
     Ok(())
 }
@@ -773,12 +767,13 @@ pub fn kill_jupyter(
 /// * `app_handle` - Handle to the Tauri application for shell command execution
 /// * `template` - Optional template name to create in the project directory
 /// * `jupyter_state` - The shared Jupyter state for managing server information
-/// * `enzmldoc_state` - The shared EnzymeML document state containing document metadata
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 ///
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(())` if the Jupyter Lab server starts successfully
-/// - `Err(String)` if the operation fails, containing the error message
+/// - `Err(SuiteError)` if the operation fails, containing the error message
 ///
 /// # Errors
 /// Returns an error if:
@@ -793,8 +788,11 @@ pub async fn start_jupyter(
     app_handle: tauri::AppHandle,
     template: Option<String>,
     jupyter_state: State<'_, Arc<JupyterState>>,
-    enzmldoc_state: State<'_, Arc<EnzymeMLState>>,
-) -> Result<(), String> {
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<(), SuiteError> {
+    let enzmldoc_state = workspace.document(handle.as_deref())?;
+
     // Get the name of the enzymeml document and sanitize it for filesystem use
     // Replace spaces with underscores and convert to lowercase for consistency
     let name = enzmldoc_state
@@ -809,7 +807,7 @@ pub async fn start_jupyter(
     // Path: ~/enzymeml-suite/projects/{document_name}/
     let jupyter_dir = get_project_path(&enzmldoc_state);
     if !jupyter_dir.exists() {
-        create_dir_all(&jupyter_dir).unwrap();
+        create_dir_all(&jupyter_dir).map_err(|e| SuiteError::io(&jupyter_dir, e))?;
     }
 
     // Create a template file in the Jupyter directory if a template is specified
@@ -821,10 +819,9 @@ pub async fn start_jupyter(
     // Get the selected Python path
     let selected_python = jupyter_state.selected_python_path.lock().unwrap().clone();
     if selected_python.is_none() {
-        return Err(
-            "No Python installation selected. Please select a Python installation first."
-                .to_string(),
-        );
+        return Err(SuiteError::validation(
+            "No Python installation selected. Please select a Python installation first.",
+        ));
     }
     let python_path = selected_python.unwrap();
 
@@ -843,7 +840,7 @@ pub async fn start_jupyter(
         .current_dir(&jupyter_dir)
         .envs(setup_jupyter_env(&python_path))
         .spawn()
-        .map_err(|e| format!("spawn failed: {e}"))?;
+        .map_err(|e| SuiteError::internal(format!("spawn failed: {e}")))?;
 
     // Process the command output streams
     // This loop handles both stdout and stderr from the Jupyter process
@@ -868,11 +865,16 @@ pub async fn start_jupyter(
                 if payload.code == Some(0) {
                     return Ok(());
                 } else {
-                    return Err(format!("failed to spawn jupyter lab: {:?}", payload.code));
+                    return Err(SuiteError::internal(format!(
+                        "failed to spawn jupyter lab: {:?}",
+                        payload.code
+                    )));
                 }
             }
             CommandEvent::Error(error) => {
-                return Err(format!("failed to spawn jupyter lab: {error}"));
+                return Err(SuiteError::internal(format!(
+                    "failed to spawn jupyter lab: {error}"
+                )));
             }
             _ => {}
         }
@@ -890,13 +892,44 @@ pub async fn start_jupyter(
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(Vec<JupyterTemplate>)` with all available template metadata
-/// - `Err(String)` if the operation fails
+/// - `Err(SuiteError)` if the operation fails
 #[tauri::command]
 #[specta::specta]
-pub fn get_jupyter_template_metadata() -> Result<Vec<JupyterTemplate>, String> {
+pub fn get_jupyter_template_metadata() -> Result<Vec<JupyterTemplate>, SuiteError> {
     Ok(JUPYTER_TEMPLATE_METADATA.to_vec())
 }
 
+/// Converts a Jupyter template to a percent-formatted script
+///
+/// Looks up the template by the ID from its metadata, parses the embedded
+/// notebook and serializes it in percent format, which is easier to consume
+/// for external tools and editors than the raw notebook JSON.
+///
+/// # Arguments
+/// * `template_id` - The ID of the template to convert
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)` with the template as percent-formatted script
+/// - `Err(String)` if the template does not exist or cannot be converted
+pub fn template_as_script(template_id: &str) -> Result<String, String> {
+    let metadata = JUPYTER_TEMPLATE_METADATA
+        .iter()
+        .find(|t| t.id == template_id)
+        .ok_or(format!("Template {template_id} not found"))?;
+    let template = JUPYTER_TEMPLATES
+        .get(metadata.template_path.as_str())
+        .ok_or(format!("Template {} not found", metadata.name))?;
+
+    let notebook = NotebookFormat::Ipynb
+        .parse(template)
+        .map_err(|e| format!("Failed to parse notebook: {e}"))?;
+
+    NotebookFormat::Percent
+        .serialize(&notebook)
+        .map_err(|e| format!("Failed to serialize notebook: {e}"))
+}
+
 /// Adds a template to the current project directory
 ///
 /// This function writes a specified Jupyter template to the current project's
@@ -905,19 +938,21 @@ pub fn get_jupyter_template_metadata() -> Result<Vec<JupyterTemplate>, String> {
 ///
 /// # Arguments
 /// * `template_name` - The name of the template to add to the project
-/// * `state` - The shared EnzymeML state containing project information
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 ///
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(())` if the template is added successfully
-/// - `Err(String)` if the operation fails, containing the error message
+/// - `Err(SuiteError)` if the operation fails, containing the error message
 #[tauri::command]
 #[specta::specta]
 pub async fn add_template_to_project(
     template_name: &str,
-    state: State<'_, Arc<EnzymeMLState>>,
-) -> Result<(), String> {
-    write_template(template_name, &state)?;
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<(), SuiteError> {
+    write_template(template_name, &*workspace.document(handle.as_deref())?)?;
     Ok(())
 }
 
@@ -934,30 +969,27 @@ pub async fn add_template_to_project(
 /// # Returns
 /// A `Result` containing:
 /// - `Ok(())` if the template is written successfully
-/// - `Err(String)` if the operation fails
-fn write_template(
-    template_name: &str,
-    state: &State<'_, Arc<EnzymeMLState>>,
-) -> Result<(), String> {
+/// - `Err(SuiteError)` if the operation fails
+fn write_template(template_name: &str, state: &EnzymeMLState) -> Result<(), SuiteError> {
     let template_content = JUPYTER_TEMPLATES
         .get(template_name)
-        .ok_or(format!("Failed to get template content: {template_name}"))?;
+        .ok_or_else(|| SuiteError::not_found("Template", template_name))?;
 
     let template_metadata = JUPYTER_TEMPLATE_METADATA
         .iter()
         .find(|t| t.template_path == template_name)
-        .ok_or(format!("Failed to get template metadata: {template_name}"))?;
+        .ok_or_else(|| SuiteError::not_found("Template", template_name))?;
 
     let project_path = get_project_path(state);
 
     if !project_path.exists() {
-        create_dir_all(&project_path).map_err(|e| format!("Failed to create project path: {e}"))?;
+        create_dir_all(&project_path).map_err(|e| SuiteError::io(&project_path, e))?;
     }
 
     let template_file_path = project_path.join(format!("{}.ipynb", template_metadata.name));
     if !template_file_path.exists() {
         std::fs::write(&template_file_path, template_content)
-            .map_err(|e| format!("Failed to write template file: {e}"))?;
+            .map_err(|e| SuiteError::io(&template_file_path, e))?;
     }
 
     Ok(())
@@ -1041,7 +1073,7 @@ fn is_port_occupied(port: u16) -> bool {
 ///
 /// # Returns
 /// A `PathBuf` representing the project directory path
-fn get_project_path(state: &State<'_, Arc<EnzymeMLState>>) -> PathBuf {
+fn get_project_path(state: &EnzymeMLState) -> PathBuf {
     let name = state.title.lock().unwrap().replace(" ", "_").to_lowercase();
     let id = state.id.lock().unwrap();
 
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use tauri::{AppHandle, State};
+
+use crate::db::{self, DatabaseStatus};
+use crate::error::SuiteError;
+use crate::io::dataio::detach_open_documents;
+use crate::io::search;
+use crate::libraries::{self, Library, LibrarySettings};
+use crate::states::Workspace;
+
+/// Retrieves the status of the library the application currently works with
+///
+/// # Returns
+/// Result containing the name and path of the library and whether it could be
+/// opened, or an error if no library has been opened yet
+#[tauri::command]
+#[specta::specta]
+pub fn get_database_status() -> Result<DatabaseStatus, SuiteError> {
+    db::status().ok_or_else(|| SuiteError::database("The database has not been initialized"))
+}
+
+/// Lists the registered libraries
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing the registered libraries and the active one
+#[tauri::command]
+#[specta::specta]
+pub fn list_libraries(app: AppHandle) -> Result<LibrarySettings, SuiteError> {
+    libraries::load_settings(&app)
+}
+
+/// Registers a library
+///
+/// The database file is created when switching to the library, if it
+/// doesn't exist by then.
+///
+/// # Arguments
+/// * `name` - Unique name of the library
+/// * `path` - Path to the database file of the library
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing the updated library settings
+#[tauri::command]
+#[specta::specta]
+pub fn add_library(
+    name: String,
+    path: String,
+    app: AppHandle,
+) -> Result<LibrarySettings, SuiteError> {
+    let mut settings = libraries::load_settings(&app)?;
+    settings.libraries.push(Library {
+        name: name.trim().to_string(),
+        path: path.trim().to_string(),
+    });
+
+    settings.validate()?;
+    libraries::save_settings(&app, &settings)?;
+
+    Ok(settings)
+}
+
+/// Unregisters a library
+///
+/// The database file of the library is kept. The active library cannot be
+/// unregistered.
+///
+/// # Arguments
+/// * `name` - Name of the library
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing the updated library settings
+#[tauri::command]
+#[specta::specta]
+pub fn remove_library(name: &str, app: AppHandle) -> Result<LibrarySettings, SuiteError> {
+    let mut settings = libraries::load_settings(&app)?;
+
+    if settings.get(name).is_none() {
+        return Err(SuiteError::not_found("Library", name));
+    }
+
+    if settings.active == name || db::active_library().is_ok_and(|library| library.name == name) {
+        return Err(SuiteError::conflict(format!(
+            "The library '{}' is in use. Switch to another library first.",
+            name
+        )));
+    }
+
+    settings.libraries.retain(|library| library.name != name);
+    libraries::save_settings(&app, &settings)?;
+
+    Ok(settings)
+}
+
+/// Switches to another registered library
+///
+/// The library is opened and migrated to the current schema, and becomes the
+/// library opened when starting. If it cannot be opened, the application keeps
+/// working with the current library. Open documents are detached from the
+/// previous library, so the next save creates an entry in the new one.
+///
+/// # Arguments
+/// * `name` - Name of the library
+/// * `workspace` - The open documents of the application
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing the status of the opened library
+#[tauri::command]
+#[specta::specta]
+pub fn switch_library(
+    name: &str,
+    workspace: State<Arc<Workspace>>,
+    app_handle: AppHandle,
+) -> Result<DatabaseStatus, SuiteError> {
+    let mut settings = libraries::load_settings(&app_handle)?;
+    let library = settings
+        .get(name)
+        .cloned()
+        .ok_or_else(|| SuiteError::not_found("Library", name))?;
+
+    db::open(library)?;
+    settings.active = name.to_string();
+    libraries::save_settings(&app_handle, &settings)?;
+
+    let ids: Vec<i32> = workspace
+        .documents()
+        .iter()
+        .filter_map(|state| *state.id.lock().unwrap())
+        .collect();
+    detach_open_documents(&workspace, &ids, &app_handle);
+
+    search::sync_index()?;
+
+    get_database_status()
+}
@@ -13,7 +13,7 @@
 /// # Returns
 ///
 /// * `Ok(s.clone())` - If an item with the given ID is found, it returns a clone of the item.
-/// * `Err(format!("{} not found", $id))` - If no item with the given ID is found, it returns an error message.
+/// * `Err(SuiteError::NotFound)` - If no item with the given ID is found.
 #[macro_export]
 macro_rules! get_object {
     ($state:expr, $($path:ident).+, $id:expr, $id_prop:ident) => {{
@@ -21,9 +21,10 @@ macro_rules! get_object {
         let collection = &state.$($path).+;
         match collection.iter().find(|s| s.$id_prop == $id) {
             Some(s) => Ok(s.clone()),
-            None => {
-                Err(format!("{:?} not found", $id))
-            }
+            None => Err($crate::error::SuiteError::not_found(
+                $crate::error::entity_of(stringify!($($path).+)),
+                &$id,
+            )),
         }
     }};
 }
@@ -71,21 +72,24 @@ macro_rules! delete_object {
 ///
 /// # Returns
 ///
-/// Returns the ID of the updated item.
-///
-/// # Panics
-///
-/// This macro will panic if the item with the given ID is not found in the collection.
+/// * `Ok(id)` - The ID of the updated item.
+/// * `Err(SuiteError::NotFound)` - If no item with the ID of the new data is found.
 #[macro_export]
 macro_rules! update_object {
     ($state:expr, $($path:ident).+, $data:expr, $id_prop:ident) => {{
         let mut state = $state.lock().unwrap();
-        let index = state.$($path).+.iter().position(|s| s.$id_prop == $data.$id_prop)
-            .expect("Item not found");
-        state.$($path).+[index] = $data;
+        let index = state.$($path).+.iter().position(|s| s.$id_prop == $data.$id_prop);
 
-        let id = state.$($path).+[index].$id_prop.clone();
-        id
+        match index {
+            Some(index) => {
+                state.$($path).+[index] = $data;
+                Ok(state.$($path).+[index].$id_prop.clone())
+            }
+            None => Err($crate::error::SuiteError::not_found(
+                $crate::error::entity_of(stringify!($($path).+)),
+                &$data.$id_prop,
+            )),
+        }
     }};
 }
 
@@ -179,43 +183,62 @@ macro_rules! add_objects {
 
 /// Macro to emit an update event to all listeners.
 ///
-/// This macro takes an app handle and an event name, and emits the event to all listeners.
+/// This macro takes an event sink and an event, and emits the event to all listeners.
 /// It provides a convenient way to notify the frontend about state changes.
 ///
+/// # Usage Patterns
+///
+/// 1. Change of the document, advancing its revision:
+///    `update_event!(state, events, EntityKind::SmallMolecule, ChangeOp::Create, [&id])`
+///
+/// 2. Any other event:
+///    `update_event!(events, SuiteEvent::Visualisation)`
+///
 /// # Arguments
 ///
-/// * `$app_handle` - The Tauri app handle used to emit events.
-/// * `$event` - The name of the event to emit.
+/// * `$state` - The state holding the changed document.
+/// * `$events` - The event sink used to emit events, such as the Tauri app handle.
+/// * `$kind` - The kind of the affected entities.
+/// * `$op` - The operation that changed the entities.
+/// * `$ids` - The IDs of the affected entities.
+/// * `$event` - The event to emit.
 ///
 /// # Panics
 ///
 /// This macro will panic if it fails to emit the event.
 #[macro_export]
 macro_rules! update_event {
-    ($app_handle:expr, $event:expr) => {
-        $app_handle.emit($event, ()).expect("Failed to emit event");
+    ($state:expr, $events:expr, $kind:expr, $op:expr, $ids:expr) => {
+        $crate::events::EventSink::emit_event(
+            &$events,
+            $crate::events::SuiteEvent::changed(&$state, $kind, $op, $ids),
+        )
+        .expect("Failed to emit event");
+    };
+    ($events:expr, $event:expr) => {
+        $crate::events::EventSink::emit_event(&$events, $event).expect("Failed to emit event");
     };
     () => {};
 }
 
 /// Macro to update the validation report and emit an update event.
 ///
-/// This macro takes a state and an app handle, updates the validation report
-/// in the state, and then emits an "update_report" event to notify the frontend
-/// about the report changes.
+/// This macro takes a state and an event sink, updates the validation report
+/// in the state, and then emits an "update_report" event carrying a summary of
+/// the report to notify the frontend about the report changes.
 ///
 /// # Usage Patterns
 ///
 /// 1. Without document reference (locks internally):
-///    `update_report!(state, app_handle)`
+///    `update_report!(state, events)`
 ///
 /// 2. With document reference (avoids deadlock when already locked):
-///    `update_report!(state, app_handle, &doc)`
+///    `update_report!(state, events, &doc)`
 ///
 /// # Arguments
 ///
 /// * `$state` - The state containing the validation report, wrapped in a `Mutex`.
-/// * `$app_handle` - The Tauri app handle used to emit events.
+/// * `$events` - The event sink used to emit events, such as the Tauri app handle.
 /// * `$doc` - (Optional) A reference to the EnzymeML document when already locked.
 ///
 /// # Panics
@@ -225,17 +248,21 @@ macro_rules! update_event {
 #[macro_export]
 macro_rules! update_report {
     // Pattern with document reference (avoids deadlock)
-    ($state:expr, $app_handle:expr, $doc:expr) => {
+    ($state:expr, $events:expr, $doc:expr) => {
         $state.update_report_with_doc($doc);
-        $app_handle
-            .emit("update_report", ())
-            .expect("Failed to emit event");
+        $crate::events::EventSink::emit_event(
+            &$events,
+            $crate::events::SuiteEvent::report(&$state),
+        )
+        .expect("Failed to emit event");
     };
     // Pattern without document reference (locks internally)
-    ($state:expr, $app_handle:expr) => {
+    ($state:expr, $events:expr) => {
         $state.update_report();
-        $app_handle
-            .emit("update_report", ())
-            .expect("Failed to emit event");
+        $crate::events::EventSink::emit_event(
+            &$events,
+            $crate::events::SuiteEvent::report(&$state),
+        )
+        .expect("Failed to emit event");
     };
 }
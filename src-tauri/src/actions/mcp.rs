@@ -2,13 +2,25 @@
 //!
 //! This module provides Tauri commands for registering the EnzymeML MCP server
 //! with various AI clients that support the Model Context Protocol.
+//!
+//! Client configurations are edited in place: only the `EnzymeMLSuite` entry is
+//! added or removed, all other servers and settings of the client are kept. Before
+//! a configuration file is overwritten, a timestamped backup is written next to it.
 
-use crate::mcp::get_mcp_path;
+use crate::api::auth::{self, API_TOKEN_ENV, API_URL_ENV};
+use crate::error::SuiteError;
+use crate::mcp::{self, get_mcp_path};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use specta;
 use std::collections::HashMap;
-use std::path::PathBuf;
-use tauri::{AppHandle, Emitter};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+/// Name of the EnzymeML MCP server entry in the client configurations
+const SERVER_NAME: &str = "EnzymeMLSuite";
 
 /// Event payload for MCP installation progress updates
 #[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type, tauri_specta::Event, Clone)]
@@ -40,6 +52,73 @@ pub enum McpRegisterStatus {
     Output,
 }
 
+/// Change to apply to the MCP configuration of a client
+#[derive(Debug, Serialize, Deserialize, specta::Type, Clone, Copy, PartialEq)]
+pub enum McpConfigAction {
+    /// Add or update the EnzymeML MCP server entry
+    Register,
+    /// Remove the EnzymeML MCP server entry
+    Unregister,
+}
+
+/// Result of a dry-run of a change to the MCP configuration of a client
+#[derive(Debug, Serialize, Deserialize, specta::Type, Clone)]
+pub struct McpConfigPreview {
+    /// Path of the client configuration file
+    pub path: String,
+    /// Current content of the configuration file, if it exists
+    pub current: Option<String>,
+    /// Content of the configuration file after applying the change
+    pub updated: String,
+    /// Whether applying the change modifies the configuration
+    pub changed: bool,
+}
+
+/// Health of the installed MCP server binary and its client registrations
+///
+/// Registrations launch the stdio bridge of this application, so only they
+/// decide whether a repair is needed. The standalone binary is reported for
+/// information.
+#[derive(Debug, Serialize, Deserialize, specta::Type, Clone)]
+pub struct McpStatus {
+    /// Path where the MCP server binary is installed
+    pub installed_path: String,
+    /// Whether the MCP server binary is installed
+    pub installed: bool,
+    /// Version reported by the installed binary, if any
+    pub installed_version: Option<String>,
+    /// Version reported by the binary bundled with the application, if any
+    pub bundled_version: Option<String>,
+    /// Whether the installed binary is identical to the bundled one
+    pub hash_matches: bool,
+    /// Registration state of every supported client
+    pub clients: Vec<McpClientStatus>,
+    /// Whether a registration points to a wrong command or exposes the API token
+    pub needs_repair: bool,
+}
+
+/// Registration state of the EnzymeML MCP server in a single client
+#[derive(Debug, Serialize, Deserialize, specta::Type, Clone)]
+pub struct McpClientStatus {
+    /// The client the state belongs to
+    pub client_type: ClientType,
+    /// Path of the client configuration file
+    pub config_path: String,
+    /// Whether the EnzymeML MCP server is registered with the client
+    pub registered: bool,
+    /// Command of the registered server entry, if any
+    pub command: Option<String>,
+    /// Whether the registered command exists on disk
+    pub command_exists: bool,
+    /// Whether the registered command is the stdio bridge of this application
+    pub command_matches: bool,
+    /// Whether the registration holds the API token in plain text, as written
+    /// by earlier versions
+    pub exposes_token: bool,
+    /// Error raised while locating or reading the client configuration, if any
+    pub error: Option<String>,
+}
+
 /// Checks if the MCP server binary is installed on the system
 ///
 /// This command checks whether the MCP server binary exists at the expected
@@ -48,10 +127,10 @@ pub enum McpRegisterStatus {
 /// # Returns
 /// * `Ok(true)` if the binary is installed
 /// * `Ok(false)` if the binary is not installed
-/// * `Err(String)` if there was an error checking the installation
+/// * `Err(SuiteError)` if there was an error checking the installation
 #[tauri::command]
 #[specta::specta]
-pub fn is_mcp_binary_installed() -> Result<bool, String> {
+pub fn is_mcp_binary_installed() -> Result<bool, SuiteError> {
     let mcp_path = get_mcp_path();
     Ok(mcp_path.exists())
 }
@@ -62,49 +141,43 @@ pub fn is_mcp_binary_installed() -> Result<bool, String> {
 /// in the specified AI client's MCP configuration file.
 ///
 /// # Arguments
-/// * `client_type` - The type of AI client to check (Claude Desktop or Cursor)
+/// * `client_type` - The type of AI client to check
 ///
 /// # Returns
 /// * `Ok(true)` if the EnzymeML server is already registered
 /// * `Ok(false)` if the EnzymeML server is not registered or config doesn't exist
-/// * `Err(String)` if there was an error reading the configuration
+/// * `Err(SuiteError)` if there was an error reading the configuration
 #[tauri::command]
 #[specta::specta]
-pub fn is_mcp_registered(client_type: ClientType) -> Result<bool, String> {
-    let path = client_type.get_path();
+pub fn is_mcp_registered(client_type: ClientType) -> Result<bool, SuiteError> {
+    let content = read_config(&client_type.get_path().map_err(SuiteError::internal)?)?;
 
     // If config file doesn't exist, EnzymeML is not registered
-    if !path.exists() {
+    let Some(content) = content else {
         return Ok(false);
-    }
-
-    // Read and parse existing config
-    let content = std::fs::read_to_string(&path)
-        .map_err(|e| format!("Failed to read configuration: {}", e))?;
-
-    let registration: MCPRegistration = serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse configuration: {}", e))?;
+    };
 
-    // Check if EnzymeMLSuite is registered
-    Ok(registration.mcp_servers.contains_key("EnzymeMLSuite"))
+    let config = ClientConfig::parse(&client_type, Some(&content))?;
+    Ok(config.contains_server())
 }
 
 /// Registers the EnzymeML MCP server with the specified AI client
 ///
 /// This command registers the EnzymeML MCP server binary with AI clients like
-/// Claude Desktop or Cursor, enabling them to interact with EnzymeML documents
-/// and data through the Model Context Protocol.
+/// Claude Desktop, Cursor or VS Code, enabling them to interact with EnzymeML
+/// documents and data through the Model Context Protocol.
 ///
 /// The function reads the client's existing MCP configuration, adds the EnzymeML
 /// server to it, and writes the updated configuration back to the client's
-/// configuration file.
+/// configuration file. Other servers and settings of the client are kept and the
+/// previous configuration is backed up next to the file.
 ///
 /// # Arguments
-/// * `client_type` - The type of AI client to register with (Claude Desktop or Cursor)
+/// * `client_type` - The type of AI client to register with
 ///
 /// # Returns
 /// * `Ok(())` if the registration was successful
-/// * `Err(String)` if there was an error during registration
+/// * `Err(SuiteError)` if there was an error during registration
 ///
 /// # Errors
 /// * Returns error if the client configuration directory cannot be created
@@ -112,132 +185,441 @@ pub fn is_mcp_registered(client_type: ClientType) -> Result<bool, String> {
 /// * Returns error if the updated configuration cannot be written
 #[tauri::command]
 #[specta::specta]
-pub fn register_mcp(app: AppHandle, client_type: ClientType) -> Result<(), String> {
-    let path = client_type.get_path();
-    let mcp_path = get_mcp_path();
-
+pub fn register_mcp(app: AppHandle, client_type: ClientType) -> Result<(), SuiteError> {
     // For Claude Desktop, require its config directory to already exist
     // (i.e., Claude has been launched at least once).
     if matches!(&client_type, ClientType::ClaudeDesktop) {
+        let path = client_type
+            .get_path()
+            .inspect_err(|e| emit_register_output(&app, McpRegisterStatus::Error, e))
+            .map_err(SuiteError::internal)?;
         if let Some(parent) = path.parent() {
             if !parent.exists() {
                 let error_msg = "Claude Desktop config directory not found. Please ensure Claude Desktop is installed and has been run at least once to initialize its config.".to_string();
-                app.emit(
-                    "mcp-register-output",
-                    McpRegisterOutput {
-                        status: McpRegisterStatus::Error,
-                        output: error_msg.clone(),
-                    },
-                )
-                .ok();
-                return Err(error_msg);
+                emit_register_output(&app, McpRegisterStatus::Error, &error_msg);
+                return Err(SuiteError::validation(error_msg));
             }
         }
     }
 
+    let result = apply_config_change(&app, &client_type, McpConfigAction::Register);
+    let backup = result
+        .inspect_err(|e| emit_register_output(&app, McpRegisterStatus::Error, e.message()))?;
+
+    let success_msg = format!(
+        "MCP server registered successfully with {}{}",
+        format_client_name(&client_type),
+        format_backup(&backup)
+    );
+    emit_register_output(&app, McpRegisterStatus::Success, &success_msg);
+
+    Ok(())
+}
+
+/// Removes the EnzymeML MCP server from the specified AI client
+///
+/// Only the `EnzymeMLSuite` entry is removed from the client's configuration,
+/// all other servers and settings are kept. The previous configuration is
+/// backed up next to the file. Unregistering a client without registration
+/// succeeds without modifying any file.
+///
+/// # Arguments
+/// * `client_type` - The type of AI client to unregister from
+///
+/// # Returns
+/// * `Ok(())` if the server is no longer registered with the client
+/// * `Err(SuiteError)` if the configuration cannot be read, parsed or written
+#[tauri::command]
+#[specta::specta]
+pub fn unregister_mcp(app: AppHandle, client_type: ClientType) -> Result<(), SuiteError> {
+    let result = apply_config_change(&app, &client_type, McpConfigAction::Unregister);
+    let backup = result
+        .inspect_err(|e| emit_register_output(&app, McpRegisterStatus::Error, e.message()))?;
+
+    let success_msg = format!(
+        "MCP server unregistered from {}{}",
+        format_client_name(&client_type),
+        format_backup(&backup)
+    );
+    emit_register_output(&app, McpRegisterStatus::Success, &success_msg);
+
+    Ok(())
+}
+
+/// Shows the configuration of a client after registering or unregistering
+///
+/// Performs a dry-run of `register_mcp` or `unregister_mcp` without writing
+/// anything, so the user can review the resulting configuration first.
+///
+/// # Arguments
+/// * `client_type` - The type of AI client whose configuration to preview
+/// * `action` - Whether to preview registering or unregistering
+///
+/// # Returns
+/// * `Ok(McpConfigPreview)` with the current and the resulting configuration
+/// * `Err(SuiteError)` if the existing configuration cannot be read or parsed
+#[tauri::command]
+#[specta::specta]
+pub fn preview_mcp_config(
+    app: AppHandle,
+    client_type: ClientType,
+    action: McpConfigAction,
+) -> Result<McpConfigPreview, SuiteError> {
+    plan_config_change(&app, &client_type, action)
+}
+
+/// Reports the installed and bundled MCP server versions and all client registrations
+///
+/// The installed binary is compared to the binary bundled with the application
+/// by hash, so an outdated install is detected even if both report the same
+/// version. For every client with a registration, the registered command is
+/// checked to exist and to point to the stdio bridge of this application.
+/// Registrations do not use the installed binary, so a repair is only needed
+/// for registrations pointing elsewhere or exposing the API token.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access resource paths
+///
+/// # Returns
+/// * `Ok(McpStatus)` with the state of the binary and all clients
+/// * `Err(SuiteError)` if the resource path cannot be resolved or a binary cannot be read
+#[tauri::command]
+#[specta::specta]
+pub fn get_mcp_status(app: AppHandle) -> Result<McpStatus, SuiteError> {
+    let resource_path = resolve_resource_path(&app)?;
+    let mcp_path = get_mcp_path();
+
+    let clients: Vec<McpClientStatus> = ClientType::ALL.iter().map(client_status).collect();
+    let hash_matches = mcp::is_up_to_date(&resource_path).map_err(SuiteError::internal)?;
+    let broken_registration = clients
+        .iter()
+        .any(|client| client.registered && (!client.command_matches || client.exposes_token));
+
+    Ok(McpStatus {
+        installed_path: mcp_path.display().to_string(),
+        installed: mcp_path.exists(),
+        installed_version: mcp::binary_version(&mcp_path),
+        bundled_version: mcp::binary_version(&mcp::bundled_path(&resource_path)),
+        hash_matches,
+        needs_repair: broken_registration,
+        clients,
+    })
+}
+
+/// Rewrites all existing registrations of the EnzymeML MCP server
+///
+/// Clients without a registration are left untouched. Registrations are
+/// rewritten in place, so they point to the stdio bridge and carry the
+/// current API settings afterwards. A plain text API token written by earlier
+/// versions is removed.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to read the API settings
+///
+/// # Returns
+/// * `Ok(McpStatus)` with the state after the repair
+/// * `Err(SuiteError)` if a configuration cannot be rewritten
+#[tauri::command]
+#[specta::specta]
+pub fn repair_mcp(app: AppHandle) -> Result<McpStatus, SuiteError> {
+    for client_type in ClientType::ALL.iter() {
+        if !is_mcp_registered(client_type.clone()).unwrap_or(false) {
+            continue;
+        }
+
+        let result = apply_config_change(&app, client_type, McpConfigAction::Register);
+        let backup = result
+            .inspect_err(|e| emit_register_output(&app, McpRegisterStatus::Error, e.message()))?;
+
+        emit_register_output(
+            &app,
+            McpRegisterStatus::Output,
+            &format!(
+                "Updated registration with {}{}",
+                format_client_name(client_type),
+                format_backup(&backup)
+            ),
+        );
+    }
+
+    emit_register_output(
+        &app,
+        McpRegisterStatus::Success,
+        "MCP server repaired successfully",
+    );
+
+    get_mcp_status(app)
+}
+
+/// Collects the registration state of a single client
+///
+/// # Arguments
+/// * `client_type` - The client to inspect
+fn client_status(client_type: &ClientType) -> McpClientStatus {
+    match client_type.get_path() {
+        Ok(config_path) => client_status_at(client_type, &config_path),
+        Err(e) => McpClientStatus {
+            client_type: client_type.clone(),
+            config_path: String::new(),
+            registered: false,
+            command: None,
+            command_exists: false,
+            command_matches: false,
+            exposes_token: false,
+            error: Some(e),
+        },
+    }
+}
+
+/// Collects the registration state of a client from a configuration file
+///
+/// # Arguments
+/// * `client_type` - The client the configuration belongs to
+/// * `config_path` - Path of the configuration file
+///
+/// # Returns
+/// The registration state, holding the error if the file cannot be read or parsed
+pub fn client_status_at(client_type: &ClientType, config_path: &Path) -> McpClientStatus {
+    let mut status = McpClientStatus {
+        client_type: client_type.clone(),
+        config_path: config_path.display().to_string(),
+        registered: false,
+        command: None,
+        command_exists: false,
+        command_matches: false,
+        exposes_token: false,
+        error: None,
+    };
+
+    let config = read_config(config_path).and_then(|content| match content {
+        Some(content) => ClientConfig::parse(client_type, Some(&content)).map(Some),
+        None => Ok(None),
+    });
+
+    match config {
+        Ok(Some(config)) => {
+            status.registered = config.contains_server();
+            status.command = config.server_command();
+            status.exposes_token = config.server_env_contains(API_TOKEN_ENV);
+        }
+        Ok(None) => {}
+        Err(e) => status.error = Some(e.to_string()),
+    }
+
+    if let Some(command) = &status.command {
+        let command = PathBuf::from(command);
+        status.command_exists = command.exists();
+        status.command_matches =
+            mcp::stdio::bridge_command().is_ok_and(|bridge| is_same_path(&command, &bridge));
+    }
+
+    status
+}
+
+/// Checks whether two paths point to the same file
+///
+/// Paths are compared after resolving symlinks and relative components,
+/// falling back to a plain comparison if a path does not exist.
+fn is_same_path(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Resolves the resource directory holding the bundled MCP server binaries
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access resource paths
+fn resolve_resource_path(app: &AppHandle) -> Result<PathBuf, SuiteError> {
+    app.path()
+        .resolve("mcp", tauri::path::BaseDirectory::Resource)
+        .map_err(|e| SuiteError::internal(format!("Failed to resolve resource path: {}", e)))
+}
+
+/// Computes the configuration of a client after applying a change
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to read the API settings
+/// * `client_type` - The type of AI client to change
+/// * `action` - The change to apply
+///
+/// # Returns
+/// The current and the resulting configuration or an error
+fn plan_config_change(
+    app: &AppHandle,
+    client_type: &ClientType,
+    action: McpConfigAction,
+) -> Result<McpConfigPreview, SuiteError> {
+    let path = client_type.get_path().map_err(SuiteError::internal)?;
+    let current = read_config(&path)?;
+
+    let original = ClientConfig::parse(client_type, current.as_deref())?;
+    let mut config = ClientConfig::parse(client_type, current.as_deref())?;
+
+    match action {
+        McpConfigAction::Register => config.insert_server(client_type, &registration_tool(app)?)?,
+        McpConfigAction::Unregister => config.remove_server(),
+    }
+
+    let updated = config.render()?;
+    let changed =
+        (current.is_none() && action == McpConfigAction::Register) || original.render()? != updated;
+
+    if changed && config.has_comments() {
+        return Err(SuiteError::validation(format!(
+            "The configuration at {} contains comments, which would be lost by rewriting it. \
+             Please edit the EnzymeML entry manually.",
+            path.display()
+        )));
+    }
+
+    Ok(McpConfigPreview {
+        path: path.display().to_string(),
+        current,
+        updated,
+        changed,
+    })
+}
+
+/// Applies a change to the configuration of a client
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to read the API settings
+/// * `client_type` - The type of AI client to change
+/// * `action` - The change to apply
+///
+/// # Returns
+/// The path of the backup of the previous configuration, if one has been
+/// written, or an error
+fn apply_config_change(
+    app: &AppHandle,
+    client_type: &ClientType,
+    action: McpConfigAction,
+) -> Result<Option<PathBuf>, SuiteError> {
+    let preview = plan_config_change(app, client_type, action)?;
+
+    if !preview.changed {
+        return Ok(None);
+    }
+
+    let path = PathBuf::from(&preview.path);
+
     // Create parent directory if it doesn't exist (for non-Claude clients)
     if let Some(parent) = path.parent() {
         if !parent.exists() {
-            std::fs::create_dir_all(parent).map_err(|e| {
-                let error_msg = format!("Failed to create directory: {}", e);
-                app.emit(
-                    "mcp-register-output",
-                    McpRegisterOutput {
-                        status: McpRegisterStatus::Error,
-                        output: error_msg.clone(),
-                    },
-                )
-                .ok();
-                error_msg
-            })?;
+            std::fs::create_dir_all(parent).map_err(|e| SuiteError::io(parent, e))?;
         }
     }
 
-    // Read existing config or create empty one
-    let mut registration: MCPRegistration = if path.exists() {
-        let content = std::fs::read_to_string(&path).map_err(|e| {
-            let error_msg = format!("Failed to read configuration: {}", e);
-            app.emit(
-                "mcp-register-output",
-                McpRegisterOutput {
-                    status: McpRegisterStatus::Error,
-                    output: error_msg.clone(),
-                },
-            )
-            .ok();
-            error_msg
-        })?;
-        serde_json::from_str(&content).map_err(|e| {
-            let error_msg = format!("Failed to parse configuration: {}", e);
-            app.emit(
-                "mcp-register-output",
-                McpRegisterOutput {
-                    status: McpRegisterStatus::Error,
-                    output: error_msg.clone(),
-                },
-            )
-            .ok();
-            error_msg
-        })?
-    } else {
-        MCPRegistration {
-            mcp_servers: HashMap::new(),
+    let backup = backup_config(&path)?;
+
+    std::fs::write(&path, preview.updated).map_err(|e| SuiteError::io(&path, e))?;
+
+    Ok(backup)
+}
+
+/// Builds the server entry launching the stdio bridge to the running application
+///
+/// The location of the local REST API is passed to the bridge via an
+/// environment variable. The API token is left out, since client
+/// configurations are often synced or shared. The bridge reads it from the
+/// config store instead, which also keeps registrations valid after the token
+/// has been regenerated.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to read the API settings
+fn registration_tool(app: &AppHandle) -> Result<MCPTool, SuiteError> {
+    let api_settings = auth::load_settings(app)?;
+    let command = mcp::stdio::bridge_command().map_err(SuiteError::internal)?;
+
+    Ok(MCPTool {
+        command: command.display().to_string(),
+        args: vec![mcp::stdio::STDIO_FLAG.to_string()],
+        env: HashMap::from([(API_URL_ENV.to_string(), api_settings.base_url())]),
+    })
+}
+
+/// Reads a client configuration file
+///
+/// # Arguments
+/// * `path` - Path of the configuration file
+///
+/// # Returns
+/// The content of the file, `None` if it does not exist, or an error
+fn read_config(path: &Path) -> Result<Option<String>, SuiteError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    std::fs::read_to_string(path)
+        .map(Some)
+        .map_err(|e| SuiteError::io(path, e))
+}
+
+/// Copies a client configuration file to a timestamped backup next to it
+///
+/// Existing backups are never replaced. Backups taken within the same second
+/// are told apart by a counter, e.g. `mcp.json.1700000000-1.bak`.
+///
+/// # Arguments
+/// * `path` - Path of the configuration file
+///
+/// # Returns
+/// The path of the backup, `None` if there is no file to back up, or an error
+fn backup_config(path: &Path) -> Result<Option<PathBuf>, SuiteError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    for counter in 0u32.. {
+        let backup = match counter {
+            0 => path.with_file_name(format!("{}.{}.bak", file_name, timestamp)),
+            _ => path.with_file_name(format!("{}.{}-{}.bak", file_name, timestamp, counter)),
+        };
+
+        // Reserve the name first, so a backup taken concurrently is not overwritten
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&backup)
+        {
+            Ok(_) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(SuiteError::io(&backup, e)),
         }
-    };
 
-    // Add the new tool
-    registration.mcp_servers.insert(
-        "EnzymeMLSuite".to_string(),
-        MCPTool {
-            command: mcp_path.display().to_string(),
-            args: vec![],
-            env: HashMap::new(),
-        },
-    );
+        return match std::fs::copy(path, &backup) {
+            Ok(_) => Ok(Some(backup)),
+            Err(e) => {
+                let _ = std::fs::remove_file(&backup);
+                Err(SuiteError::io(&backup, e))
+            }
+        };
+    }
 
-    // Write the updated registration
-    std::fs::write(
-        &path,
-        serde_json::to_string_pretty(&registration).map_err(|e| {
-            let error_msg = format!("Failed to write configuration: {}", e);
-            app.emit(
-                "mcp-register-output",
-                McpRegisterOutput {
-                    status: McpRegisterStatus::Error,
-                    output: error_msg.clone(),
-                },
-            )
-            .ok();
-            error_msg
-        })?,
-    )
-    .map_err(|e| {
-        let error_msg = format!("Failed to write file: {}", e);
-        app.emit(
-            "mcp-register-output",
-            McpRegisterOutput {
-                status: McpRegisterStatus::Error,
-                output: error_msg.clone(),
-            },
-        )
-        .ok();
-        error_msg
-    })?;
+    Err(SuiteError::internal(
+        "No free name for the backup of the configuration",
+    ))
+}
 
-    let success_msg = format!(
-        "MCP server registered successfully with {}",
-        format_client_name(&client_type)
-    );
-    app.emit(
-        "mcp-register-output",
-        McpRegisterOutput {
-            status: McpRegisterStatus::Success,
-            output: success_msg.clone(),
-        },
-    )
+/// Emits a registration progress event to the frontend
+fn emit_register_output(app: &AppHandle, status: McpRegisterStatus, output: &str) {
+    McpRegisterOutput {
+        status,
+        output: output.to_string(),
+    }
+    .emit(app)
     .ok();
-
-    Ok(())
 }
 
 /// Helper function to format client type name for display
@@ -245,20 +627,214 @@ fn format_client_name(client_type: &ClientType) -> String {
     match client_type {
         ClientType::ClaudeDesktop => "Claude Desktop",
         ClientType::Cursor => "Cursor",
+        ClientType::VSCode => "VS Code",
+        ClientType::Windsurf => "Windsurf",
+        ClientType::Zed => "Zed",
+        ClientType::Continue => "Continue",
+        ClientType::Codex => "Codex",
     }
     .to_string()
 }
 
-/// Represents the structure of an MCP client configuration file
+/// Helper function to mention the backup of a configuration in a message
+fn format_backup(backup: &Option<PathBuf>) -> String {
+    match backup {
+        Some(path) => format!(" (previous configuration saved to {})", path.display()),
+        None => String::new(),
+    }
+}
+
+/// Parsed MCP configuration file of a client
 ///
-/// This struct defines the JSON structure used by MCP clients to store
-/// their server registrations. The `mcpServers` field contains a map
-/// of server names to their configuration details.
-#[derive(Serialize, Deserialize)]
-pub(crate) struct MCPRegistration {
-    /// Map of MCP server names to their configuration
-    #[serde(rename = "mcpServers")]
-    pub(crate) mcp_servers: HashMap<String, MCPTool>,
+/// JSON and TOML configurations are kept as generic documents, so that all
+/// entries unrelated to the EnzymeML MCP server survive a rewrite. JSON
+/// configurations may contain comments and trailing commas (JSONC), as used by
+/// VS Code and Zed, but comments cannot be written back.
+enum ClientConfig {
+    /// JSON configuration along with the key holding the servers and whether it contains comments
+    Json(Value, &'static str, bool),
+    /// TOML configuration along with the key holding the servers
+    Toml(toml_edit::DocumentMut, &'static str),
+}
+
+impl ClientConfig {
+    /// Parses the configuration of a client
+    ///
+    /// # Arguments
+    /// * `client_type` - The client the configuration belongs to
+    /// * `content` - The content of the configuration file, if it exists
+    fn parse(client_type: &ClientType, content: Option<&str>) -> Result<Self, SuiteError> {
+        let content = content.filter(|c| !c.trim().is_empty());
+        let key = client_type.servers_key();
+
+        match client_type.config_format() {
+            ConfigFormat::Json => {
+                let Some(content) = content else {
+                    return Ok(ClientConfig::Json(json!({}), key, false));
+                };
+
+                let options = jsonc_parser::CollectOptions {
+                    comments: jsonc_parser::CommentCollectionStrategy::Separate,
+                    tokens: false,
+                };
+                let parsed = jsonc_parser::parse_to_ast(content, &options, &Default::default())
+                    .map_err(|e| {
+                        SuiteError::parse(format!("Failed to parse configuration: {}", e))
+                    })?;
+                let has_comments = parsed.comments.is_some_and(|comments| !comments.is_empty());
+                let value = parsed.value.map(Value::from).unwrap_or_else(|| json!({}));
+
+                Ok(ClientConfig::Json(value, key, has_comments))
+            }
+            ConfigFormat::Toml => {
+                let document = content
+                    .unwrap_or_default()
+                    .parse::<toml_edit::DocumentMut>()
+                    .map_err(|e| {
+                        SuiteError::parse(format!("Failed to parse configuration: {}", e))
+                    })?;
+                Ok(ClientConfig::Toml(document, key))
+            }
+        }
+    }
+
+    /// Checks whether the EnzymeML MCP server is registered
+    fn contains_server(&self) -> bool {
+        match self {
+            ClientConfig::Json(value, key, _) => value
+                .get(key)
+                .and_then(|servers| servers.get(SERVER_NAME))
+                .is_some(),
+            ClientConfig::Toml(document, key) => document
+                .get(key)
+                .and_then(|servers| servers.as_table_like())
+                .is_some_and(|servers| servers.contains_key(SERVER_NAME)),
+        }
+    }
+
+    /// Returns the command of the EnzymeML MCP server entry, if registered
+    ///
+    /// Zed nests the command in a `command.path` object in older
+    /// configurations, which is supported as well.
+    fn server_command(&self) -> Option<String> {
+        match self {
+            ClientConfig::Json(value, key, _) => {
+                let command = value.get(key)?.get(SERVER_NAME)?.get("command")?;
+                command
+                    .as_str()
+                    .or_else(|| command.get("path").and_then(Value::as_str))
+                    .map(str::to_string)
+            }
+            ClientConfig::Toml(document, key) => document
+                .get(key)?
+                .as_table_like()?
+                .get(SERVER_NAME)?
+                .as_table_like()?
+                .get("command")?
+                .as_str()
+                .map(str::to_string),
+        }
+    }
+
+    /// Checks whether the EnzymeML MCP server entry sets an environment variable
+    ///
+    /// # Arguments
+    /// * `variable` - Name of the environment variable
+    fn server_env_contains(&self, variable: &str) -> bool {
+        match self {
+            ClientConfig::Json(value, key, _) => value
+                .get(key)
+                .and_then(|servers| servers.get(SERVER_NAME))
+                .and_then(|server| server.get("env"))
+                .is_some_and(|env| env.get(variable).is_some()),
+            ClientConfig::Toml(document, key) => document
+                .get(key)
+                .and_then(|servers| servers.as_table_like())
+                .and_then(|servers| servers.get(SERVER_NAME))
+                .and_then(|server| server.as_table_like())
+                .and_then(|server| server.get("env"))
+                .and_then(|env| env.as_table_like())
+                .is_some_and(|env| env.contains_key(variable)),
+        }
+    }
+
+    /// Adds or replaces the EnzymeML MCP server entry
+    ///
+    /// # Arguments
+    /// * `client_type` - The client the configuration belongs to
+    /// * `tool` - The server entry to register
+    fn insert_server(
+        &mut self,
+        client_type: &ClientType,
+        tool: &MCPTool,
+    ) -> Result<(), SuiteError> {
+        match self {
+            ClientConfig::Json(value, key, _) => {
+                let root = value.as_object_mut().ok_or_else(|| {
+                    SuiteError::parse("Failed to parse configuration: expected a JSON object")
+                })?;
+                let servers = root
+                    .entry(key.to_string())
+                    .or_insert_with(|| json!({}))
+                    .as_object_mut()
+                    .ok_or_else(|| {
+                        SuiteError::parse(format!(
+                            "Failed to parse configuration: '{}' is not an object",
+                            key
+                        ))
+                    })?;
+
+                servers.insert(SERVER_NAME.to_string(), tool.to_json(client_type));
+            }
+            ClientConfig::Toml(document, key) => {
+                let servers = document
+                    .entry(key)
+                    .or_insert(toml_edit::table())
+                    .as_table_mut()
+                    .ok_or_else(|| {
+                        SuiteError::parse(format!(
+                            "Failed to parse configuration: '{}' is not a table",
+                            key
+                        ))
+                    })?;
+                servers.set_implicit(true);
+
+                servers.insert(SERVER_NAME, toml_edit::Item::Table(tool.to_toml()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the EnzymeML MCP server entry, if present
+    fn remove_server(&mut self) {
+        match self {
+            ClientConfig::Json(value, key, _) => {
+                if let Some(servers) = value.get_mut(*key).and_then(|s| s.as_object_mut()) {
+                    servers.remove(SERVER_NAME);
+                }
+            }
+            ClientConfig::Toml(document, key) => {
+                if let Some(servers) = document.get_mut(key).and_then(|s| s.as_table_like_mut()) {
+                    servers.remove(SERVER_NAME);
+                }
+            }
+        }
+    }
+
+    /// Checks whether rewriting the configuration would drop comments
+    fn has_comments(&self) -> bool {
+        matches!(self, ClientConfig::Json(_, _, true))
+    }
+
+    /// Serializes the configuration for writing it back to the file
+    fn render(&self) -> Result<String, SuiteError> {
+        match self {
+            ClientConfig::Json(value, _, _) => serde_json::to_string_pretty(value)
+                .map_err(|e| SuiteError::internal(format!("Failed to write configuration: {}", e))),
+            ClientConfig::Toml(document, _) => Ok(document.to_string()),
+        }
+    }
 }
 
 /// Configuration details for an individual MCP server
@@ -278,6 +854,54 @@ pub(crate) struct MCPTool {
     env: HashMap<String, String>,
 }
 
+impl MCPTool {
+    /// Converts the server into the JSON entry expected by the client
+    ///
+    /// VS Code requires the transport type and Zed the source of the server
+    /// next to the command, all other clients use the plain entry.
+    ///
+    /// # Arguments
+    /// * `client_type` - The client the entry is written for
+    fn to_json(&self, client_type: &ClientType) -> Value {
+        let mut entry = json!(self);
+
+        match client_type {
+            ClientType::VSCode => entry["type"] = json!("stdio"),
+            ClientType::Zed => entry["source"] = json!("custom"),
+            _ => {}
+        }
+
+        entry
+    }
+
+    /// Converts the server into a TOML table
+    fn to_toml(&self) -> toml_edit::Table {
+        let mut env = toml_edit::InlineTable::new();
+        for (key, value) in self.env.iter() {
+            env.insert(key.as_str(), toml_edit::Value::from(value.as_str()));
+        }
+
+        let mut table = toml_edit::Table::new();
+        table["command"] = toml_edit::value(self.command.as_str());
+        table["args"] = toml_edit::value(
+            self.args
+                .iter()
+                .map(|arg| arg.as_str())
+                .collect::<toml_edit::Array>(),
+        );
+        table["env"] = toml_edit::value(env);
+
+        table
+    }
+}
+
+/// File formats of MCP client configurations
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ConfigFormat {
+    Json,
+    Toml,
+}
+
 /// Supported AI client types for MCP server registration
 ///
 /// This enum represents the different AI clients that support the Model Context
@@ -288,9 +912,30 @@ pub enum ClientType {
     ClaudeDesktop,
     /// Cursor AI code editor
     Cursor,
+    /// Visual Studio Code
+    VSCode,
+    /// Windsurf AI code editor
+    Windsurf,
+    /// Zed code editor
+    Zed,
+    /// Continue coding assistant
+    Continue,
+    /// OpenAI Codex CLI
+    Codex,
 }
 
 impl ClientType {
+    /// All supported client types
+    pub const ALL: [ClientType; 7] = [
+        ClientType::ClaudeDesktop,
+        ClientType::Cursor,
+        ClientType::VSCode,
+        ClientType::Windsurf,
+        ClientType::Zed,
+        ClientType::Continue,
+        ClientType::Codex,
+    ];
+
     /// Returns the configuration file path for the specified client type
     ///
     /// Each AI client stores its MCP configuration in a different location
@@ -298,61 +943,92 @@ impl ClientType {
     /// path for the client's configuration file.
     ///
     /// # Returns
-    /// * `PathBuf` - The path to the client's MCP configuration file
+    /// Result containing the path to the client's MCP configuration file or an
+    /// error message if the home directory cannot be determined
     ///
     /// # Platform-specific behavior
-    /// * **macOS**: Uses `~/Library/Application Support/` for Claude, `~/.cursor/` for Cursor
-    /// * **Windows**: Uses `%APPDATA%/Claude/` for Claude, `%USERPROFILE%/.cursor/` for Cursor  
-    /// * **Linux**: Uses `~/.config/Claude/` for Claude, `~/.cursor/` for Cursor
-    pub fn get_path(&self) -> PathBuf {
+    /// * **macOS**: Uses `~/Library/Application Support/` for Claude and VS Code, `~/.config/zed/` for Zed
+    /// * **Windows**: Uses `%APPDATA%/Claude/` for Claude, `%APPDATA%/Code/User/` for VS Code, `%APPDATA%/Zed/` for Zed
+    /// * **Linux**: Uses `~/.config/Claude/` for Claude, `~/.config/Code/User/` for VS Code, `~/.config/zed/` for Zed
+    /// * Cursor, Windsurf, Continue and Codex use a directory in the home directory on all platforms
+    pub fn get_path(&self) -> Result<PathBuf, String> {
+        let home_dir = dirs::home_dir().ok_or_else(|| {
+            format!(
+                "Failed to determine the home directory holding the configuration of {}",
+                format_client_name(self)
+            )
+        })?;
+
+        match self {
+            ClientType::Cursor => return Ok(home_dir.join(".cursor/mcp.json")),
+            ClientType::Windsurf => return Ok(home_dir.join(".codeium/windsurf/mcp_config.json")),
+            ClientType::Continue => {
+                return Ok(home_dir.join(".continue/mcpServers/enzymeml-suite.json"))
+            }
+            ClientType::Codex => return Ok(home_dir.join(".codex/config.toml")),
+            ClientType::VSCode => {
+                return Ok(dirs::config_dir()
+                    .unwrap_or_else(|| home_dir.join(".config"))
+                    .join("Code/User/mcp.json"))
+            }
+            ClientType::ClaudeDesktop | ClientType::Zed => {}
+        }
+
         #[cfg(target_os = "macos")]
         {
-            match self {
-                ClientType::ClaudeDesktop => dirs::home_dir()
-                    .unwrap()
-                    .join("Library/Application Support/Claude/claude_desktop_config.json"),
-                ClientType::Cursor => dirs::home_dir().unwrap().join(".cursor/mcp.json"),
-            }
+            Ok(match self {
+                ClientType::Zed => home_dir.join(".config/zed/settings.json"),
+                _ => home_dir.join("Library/Application Support/Claude/claude_desktop_config.json"),
+            })
         }
 
         #[cfg(target_os = "windows")]
         {
-            match self {
-                ClientType::ClaudeDesktop => {
+            Ok(match self {
+                ClientType::Zed => home_dir.join("AppData/Roaming/Zed/settings.json"),
+                _ => {
                     // Try %USERPROFILE%\AppData\Claude\claude_desktop_config.json first
-                    let primary_path = dirs::home_dir()
-                        .unwrap()
-                        .join("AppData/Claude/claude_desktop_config.json");
+                    let primary_path = home_dir.join("AppData/Claude/claude_desktop_config.json");
 
                     if primary_path.exists() {
                         primary_path
                     } else {
                         // Fallback to %USERPROFILE%\AppData\Roaming\Claude\claude_desktop_config.json
-                        dirs::home_dir()
-                            .unwrap()
-                            .join("AppData/Roaming/Claude/claude_desktop_config.json")
+                        home_dir.join("AppData/Roaming/Claude/claude_desktop_config.json")
                     }
                 }
-                ClientType::Cursor => {
-                    // %USERPROFILE%\.cursor\mcp.json
-                    dirs::home_dir().unwrap().join(".cursor/mcp.json")
-                }
-            }
+            })
         }
 
         #[cfg(target_os = "linux")]
         {
-            match self {
-                ClientType::ClaudeDesktop => {
-                    // $XDG_CONFIG_HOME/Claude/claude_desktop_config.json
-                    // fallback: ~/.config/Claude/claude_desktop_config.json
-                    std::env::var_os("XDG_CONFIG_HOME")
-                        .map(PathBuf::from)
-                        .unwrap_or_else(|| dirs::home_dir().unwrap().join(".config"))
-                        .join("Claude/claude_desktop_config.json")
-                }
-                ClientType::Cursor => dirs::home_dir().unwrap().join(".cursor/mcp.json"),
-            }
+            // $XDG_CONFIG_HOME/<client>/..., fallback: ~/.config/<client>/...
+            let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| home_dir.join(".config"));
+
+            Ok(match self {
+                ClientType::Zed => config_dir.join("zed/settings.json"),
+                _ => config_dir.join("Claude/claude_desktop_config.json"),
+            })
+        }
+    }
+
+    /// Returns the file format of the client's MCP configuration
+    fn config_format(&self) -> ConfigFormat {
+        match self {
+            ClientType::Codex => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+
+    /// Returns the key under which the client expects its MCP servers
+    fn servers_key(&self) -> &'static str {
+        match self {
+            ClientType::VSCode => "servers",
+            ClientType::Zed => "context_servers",
+            ClientType::Codex => "mcp_servers",
+            _ => "mcpServers",
         }
     }
 }
@@ -1,131 +1,97 @@
 use std::sync::Arc;
 
-use enzymeml::prelude::{Parameter, ParameterBuilder};
-use tauri::{AppHandle, Emitter, State};
+use enzymeml::prelude::Parameter;
+use tauri::{AppHandle, State};
 
-use crate::actions::utils::generate_id;
-use crate::states::EnzymeMLState;
-use crate::{create_object, delete_object, get_object, update_event, update_object, update_report};
+use crate::document::parameters;
+use crate::error::SuiteError;
+use crate::states::Workspace;
 
 /// Retrieves all parameters from the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-///
-/// # Returns
-/// Vector of tuples containing the ID and name of each parameter
+/// Tauri adapter of [`parameters::list_parameters`].
 #[tauri::command]
-pub fn list_parameters(state: State<Arc<EnzymeMLState>>) -> Vec<(String, String)> {
-    // Extract the guarded state values
-    let state_doc = state.doc.lock().unwrap();
-
-    state_doc
-        .parameters
-        .iter()
-        .map(|s| (s.id.clone(), s.name.clone()))
-        .collect()
+#[specta::specta]
+pub fn list_parameters(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<Vec<(String, String)>, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    Ok(parameters::list_parameters(&state))
 }
 
 /// Creates a new parameter in the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-/// * `app_handle` - Handle to the Tauri application for event emission
-///
-/// # Returns
-/// Result containing the ID of the created parameter or an error
+/// Tauri adapter of [`parameters::create_parameter`].
 #[tauri::command]
+#[specta::specta]
 pub fn create_parameter(
-    state: State<Arc<EnzymeMLState>>,
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<String, String> {
-    let mut builder = ParameterBuilder::default();
-    builder.name("New Parameter".to_string());
-    builder.symbol("".to_string());
-
-    let id = create_object!(state.doc, parameters, builder, "q", id);
-
-    // Notify the frontend
-    update_event!(app_handle, "update_parameters");
-    update_report!(state, app_handle);
-
-    Ok(id)
+) -> Result<String, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    parameters::create_parameter(&state, &app_handle)
 }
 
 /// Retrieves a specific parameter by its ID
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-/// * `id` - The ID of the parameter to retrieve
-///
-/// # Returns
-/// Result containing the parameter or an error if not found
+/// Tauri adapter of [`parameters::get_parameter`].
 #[tauri::command]
-pub fn get_parameter(state: State<Arc<EnzymeMLState>>, id: &str) -> Result<Parameter, String> {
-    get_object!(state.doc, parameters, id, id)
+#[specta::specta]
+pub fn get_parameter(
+    workspace: State<Arc<Workspace>>,
+    id: &str,
+    handle: Option<String>,
+) -> Result<Parameter, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    parameters::get_parameter(&state, id)
 }
 
 /// Updates an existing parameter in the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-/// * `data` - The updated parameter data
-/// * `app_handle` - Handle to the Tauri application for event emission
-///
-/// # Returns
-/// Result indicating success or failure
+/// Tauri adapter of [`parameters::update_parameter`].
 #[tauri::command]
+#[specta::specta]
 pub fn update_parameter(
-    state: State<Arc<EnzymeMLState>>,
+    workspace: State<Arc<Workspace>>,
     data: Parameter,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<(), String> {
-    // Check if the parameter exists
-    let id: String = update_object!(state.doc, parameters, data, id);
-
-    update_event!(app_handle, "update_parameters");
-    update_event!(app_handle, &id);
-    update_report!(state, app_handle);
-
-    Ok(())
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    parameters::update_parameter(&state, data, &app_handle)
 }
 
+/// Updates a single value of a parameter in the EnzymeML document
+///
+/// Tauri adapter of [`parameters::partial_update_parameter`].
 #[tauri::command]
+#[specta::specta]
 pub fn partial_update_parameter(
-    state: State<Arc<EnzymeMLState>>,
+    workspace: State<Arc<Workspace>>,
     pid: &str,
     key: &str,
     value: f64,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<(), String> {
-    println!("Partial update parameter: {} {}", pid, key);
-    let mut doc = state.doc.lock().unwrap();
-    let parameter = doc.parameters.iter_mut().find(|p| p.id == pid).unwrap();
-    match key {
-        "value" => parameter.value = Some(value),
-        "initial_value" => parameter.initial_value = Some(value),
-        "upper_bound" => parameter.upper_bound = Some(value),
-        "lower_bound" => parameter.lower_bound = Some(value),
-        _ => return Err(format!("Invalid key: {}", key)),
-    }
-
-    update_event!(app_handle, "update_parameters");
-    update_event!(app_handle, &pid);
-    update_report!(state, app_handle, &doc);
-
-    Ok(())
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    parameters::partial_update_parameter(&state, pid, key, value, &app_handle)
 }
 
 /// Deletes a parameter from the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-/// * `id` - The ID of the parameter to delete
-/// * `app_handle` - Handle to the Tauri application for event emission
+/// Tauri adapter of [`parameters::delete_parameter`].
 #[tauri::command]
-pub fn delete_parameter(state: State<Arc<EnzymeMLState>>, id: &str, app_handle: AppHandle) {
-    delete_object!(state.doc, parameters, id, id);
-
-    update_event!(app_handle, "update_parameters");
-    update_report!(state, app_handle);
+#[specta::specta]
+pub fn delete_parameter(
+    workspace: State<Arc<Workspace>>,
+    id: &str,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    parameters::delete_parameter(&state, id, &app_handle);
+    Ok(())
 }
@@ -1,182 +1,108 @@
-use enzymeml::prelude::{Protein, ProteinBuilder};
+use enzymeml::prelude::Protein;
 use std::sync::Arc;
-use tauri::{AppHandle, Emitter, State};
+use tauri::{AppHandle, State};
 
-use crate::actions::identifiers::PROTEIN_PREFIX;
-use crate::actions::utils::generate_id;
-use crate::states::EnzymeMLState;
-use crate::{
-    add_objects, create_object, delete_object, get_object, update_event, update_object,
-    update_report,
-};
+use crate::document::proteins;
+use crate::error::SuiteError;
+use crate::states::Workspace;
 
 /// Adds a small molecule to the EnzymeML document
 ///
-/// This function adds a protein to the document's proteins collection.
-/// It emits an update event to notify the frontend of the changes.
-///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state containing the document data
-/// * `object` - The protein object to add
+/// Tauri adapter of [`proteins::add_protein`].
 #[tauri::command]
+#[specta::specta]
 pub fn add_protein(
-    state: State<Arc<EnzymeMLState>>,
-    mut object: Protein,
+    workspace: State<Arc<Workspace>>,
+    object: Protein,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> String {
-    let mut state_guard = state.doc.lock().unwrap();
-    let id = generate_id(
-        &state_guard.proteins.iter().map(|s| s.id.clone()).collect(),
-        PROTEIN_PREFIX,
-    );
-    object.id = id.clone();
-    state_guard.proteins.push(object.clone());
-    drop(state_guard);
-
-    update_event!(app_handle, "update_proteins");
-    update_report!(state, app_handle);
-
-    id
+) -> Result<String, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    Ok(proteins::add_protein(&state, object, &app_handle))
 }
 
 /// Adds multiple proteins to the EnzymeML document
 ///
-/// This function adds multiple proteins to the document's proteins collection.
-/// It emits an update event to notify the frontend of the changes.
-///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state containing the document data
-/// * `data` - The vector of protein objects to add
+/// Tauri adapter of [`proteins::add_proteins`].
 #[tauri::command]
+#[specta::specta]
 pub fn add_proteins(
-    state: State<Arc<EnzymeMLState>>,
-    mut data: Vec<Protein>,
+    workspace: State<Arc<Workspace>>,
+    data: Vec<Protein>,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Vec<String> {
-    let mut existing_ids: Vec<String> = state
-        .doc
-        .lock()
-        .unwrap()
-        .proteins
-        .iter()
-        .map(|s| s.id.clone())
-        .collect();
-
-    let mut ids = Vec::with_capacity(data.len());
-    let objects: Vec<Protein> = data
-        .iter_mut()
-        .map(|o| {
-            let id = generate_id(&existing_ids, PROTEIN_PREFIX);
-            ids.push(id.clone());
-            existing_ids.push(id.clone());
-            o.id = id;
-            o.clone()
-        })
-        .collect();
-
-    add_objects!(state.doc, proteins, objects);
-    update_event!(app_handle, "update_proteins");
-    update_report!(state, app_handle);
-
-    ids
+) -> Result<Vec<String>, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    Ok(proteins::add_proteins(&state, data, &app_handle))
 }
 
 /// Creates a new protein in the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-/// * `app_handle` - Handle to the Tauri application for event emission
-///
-/// # Returns
-/// The ID of the created protein
+/// Tauri adapter of [`proteins::create_protein`].
 #[tauri::command]
-pub fn create_protein(state: State<Arc<EnzymeMLState>>, app_handle: AppHandle) -> String {
-    let mut builder = ProteinBuilder::default();
-    builder.name("New Protein".to_string());
-    builder.constant(true);
-
-    let id = create_object!(state.doc, proteins, builder, PROTEIN_PREFIX, id);
-
-    update_event!(app_handle, "update_proteins");
-    update_report!(state, app_handle);
-
-    id
+#[specta::specta]
+pub fn create_protein(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<String, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    Ok(proteins::create_protein(&state, &app_handle))
 }
 
 /// Updates an existing protein in the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-/// * `data` - The updated protein data
-/// * `app_handle` - Handle to the Tauri application for event emission
-///
-/// # Returns
-/// Result indicating success or failure
+/// Tauri adapter of [`proteins::update_protein`].
 #[tauri::command]
+#[specta::specta]
 pub fn update_protein(
-    state: State<Arc<EnzymeMLState>>,
+    workspace: State<Arc<Workspace>>,
     data: Protein,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<(), String> {
-    let id = update_object!(state.doc, proteins, data, id);
-
-    update_event!(app_handle, &id);
-
-    Ok(())
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    proteins::update_protein(&state, data, &app_handle)
 }
 
 /// Retrieves all proteins from the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-///
-/// # Returns
-/// Vector of tuples containing the ID and name of each protein
+/// Tauri adapter of [`proteins::list_proteins`].
 #[tauri::command]
-pub fn list_proteins(state: State<Arc<EnzymeMLState>>) -> Vec<(String, String)> {
-    // Extract the guarded state values
-    let state_doc = state.doc.lock().unwrap();
-
-    state_doc
-        .proteins
-        .iter()
-        .map(|s| (s.id.clone(), s.name.clone()))
-        .collect()
+#[specta::specta]
+pub fn list_proteins(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<Vec<(String, String)>, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    Ok(proteins::list_proteins(&state))
 }
 
 /// Retrieves a specific protein by its ID
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-/// * `id` - The ID of the protein to retrieve
-///
-/// # Returns
-/// Result containing the protein or an error if not found
+/// Tauri adapter of [`proteins::get_protein`].
 #[tauri::command]
-pub fn get_protein(state: State<Arc<EnzymeMLState>>, id: &str) -> Result<Protein, String> {
-    get_object!(state.doc, proteins, id, id)
+#[specta::specta]
+pub fn get_protein(
+    workspace: State<Arc<Workspace>>,
+    id: &str,
+    handle: Option<String>,
+) -> Result<Protein, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    proteins::get_protein(&state, id)
 }
 
 /// Deletes a protein from the EnzymeML document
 ///
-/// # Arguments
-/// * `state` - The shared EnzymeML document state
-/// * `id` - The ID of the protein to delete
-/// * `app_handle` - Handle to the Tauri application for event emission
-///
-/// # Returns
-/// Result indicating success or failure
+/// Tauri adapter of [`proteins::delete_protein`].
 #[tauri::command]
+#[specta::specta]
 pub fn delete_protein(
-    state: State<Arc<EnzymeMLState>>,
+    workspace: State<Arc<Workspace>>,
     id: &str,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<(), String> {
-    // Signature: State, Path, ID, ID property
-    delete_object!(state.doc, proteins, id, id);
-
-    update_event!(app_handle, "update_proteins");
-    update_report!(state, app_handle);
-
-    Ok(())
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    proteins::delete_protein(&state, id, &app_handle)
 }
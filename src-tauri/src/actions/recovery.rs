@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use tauri::{AppHandle, State};
+
+use crate::actions::workspace::notify_workspace_change;
+use crate::error::SuiteError;
+use crate::recovery::{Recovery, RecoveryEntry};
+use crate::states::Workspace;
+
+/// Lists the recovery snapshots left over by previous sessions
+///
+/// Leftover snapshots hold the unsaved changes of documents that were open
+/// when the application crashed or was killed. The frontend offers to restore
+/// them on startup.
+///
+/// # Arguments
+/// * `recovery` - The recovery snapshots of this session
+///
+/// # Returns
+/// Result containing the leftover snapshots, the most recent first
+#[tauri::command]
+#[specta::specta]
+pub fn list_recovery_snapshots(
+    recovery: State<Arc<Recovery>>,
+) -> Result<Vec<RecoveryEntry>, SuiteError> {
+    recovery.leftovers()
+}
+
+/// Restores a leftover recovery snapshot as new open document
+///
+/// The restored document becomes the active one and keeps its unsaved state
+/// until it is saved. The snapshot is removed from the recovery directory.
+///
+/// # Arguments
+/// * `file` - File name of the snapshot within the recovery directory
+/// * `recovery` - The recovery snapshots of this session
+/// * `workspace` - The open documents of the application
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing the handle of the restored document
+#[tauri::command]
+#[specta::specta]
+pub fn restore_recovery_snapshot(
+    file: &str,
+    recovery: State<Arc<Recovery>>,
+    workspace: State<Arc<Workspace>>,
+    app_handle: AppHandle,
+) -> Result<String, SuiteError> {
+    let state = workspace.open(recovery.restore(file)?);
+    notify_workspace_change(&state, &app_handle)?;
+
+    Ok(state.handle.clone())
+}
+
+/// Discards a leftover recovery snapshot without restoring it
+///
+/// # Arguments
+/// * `file` - File name of the snapshot within the recovery directory
+/// * `recovery` - The recovery snapshots of this session
+///
+/// # Returns
+/// Result indicating whether the snapshot could be removed
+#[tauri::command]
+#[specta::specta]
+pub fn discard_recovery_snapshot(
+    file: &str,
+    recovery: State<Arc<Recovery>>,
+) -> Result<(), SuiteError> {
+    recovery.discard(file)
+}
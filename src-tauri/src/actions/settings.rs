@@ -1,8 +1,14 @@
-use tauri::{AppHandle, Emitter, Manager};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
 use tauri_plugin_store::StoreExt;
+use tauri_specta::Event;
 
 use crate::actions::mcp::{McpInstallOutput, McpInstallStatus};
+use crate::api::auth::{self, ApiSettings};
+use crate::backup::{self, BackupSettings};
+use crate::error::SuiteError;
 use crate::mcp;
+use crate::states::ApiState;
 
 /// Installs the MCP server binary to the user's configuration directory
 ///
@@ -17,24 +23,20 @@ use crate::mcp;
 /// Result containing either a success message with the installation path or an error message
 #[tauri::command]
 #[specta::specta]
-pub fn install_mcp_server(app: AppHandle) -> Result<String, String> {
+pub fn install_mcp_server(app: AppHandle) -> Result<String, SuiteError> {
     // Emit initial status
-    app.emit(
-        "mcp-install-output",
-        McpInstallOutput {
-            status: McpInstallStatus::Output,
-            output: "Starting MCP server installation...".to_string(),
-        },
-    )
+    McpInstallOutput {
+        status: McpInstallStatus::Output,
+        output: "Starting MCP server installation...".to_string(),
+    }
+    .emit(&app)
     .ok();
 
-    app.emit(
-        "mcp-install-output",
-        McpInstallOutput {
-            status: McpInstallStatus::Output,
-            output: "Resolving resource path...".to_string(),
-        },
-    )
+    McpInstallOutput {
+        status: McpInstallStatus::Output,
+        output: "Resolving resource path...".to_string(),
+    }
+    .emit(&app)
     .ok();
 
     let resource_path = app
@@ -42,46 +44,38 @@ pub fn install_mcp_server(app: AppHandle) -> Result<String, String> {
         .resolve("mcp", tauri::path::BaseDirectory::Resource)
         .map_err(|e| {
             let error_msg = format!("Failed to resolve resource path: {}", e);
-            app.emit(
-                "mcp-install-output",
-                McpInstallOutput {
-                    status: McpInstallStatus::Error,
-                    output: error_msg.clone(),
-                },
-            )
+            McpInstallOutput {
+                status: McpInstallStatus::Error,
+                output: error_msg.clone(),
+            }
+            .emit(&app)
             .ok();
-            error_msg
+            SuiteError::internal(error_msg)
         })?;
 
-    app.emit(
-        "mcp-install-output",
-        McpInstallOutput {
-            status: McpInstallStatus::Output,
-            output: format!("Resource path: {}", resource_path.display()),
-        },
-    )
+    McpInstallOutput {
+        status: McpInstallStatus::Output,
+        output: format!("Resource path: {}", resource_path.display()),
+    }
+    .emit(&app)
     .ok();
 
-    app.emit(
-        "mcp-install-output",
-        McpInstallOutput {
-            status: McpInstallStatus::Output,
-            output: "Copying MCP server binary...".to_string(),
-        },
-    )
+    McpInstallOutput {
+        status: McpInstallStatus::Output,
+        output: "Copying MCP server binary...".to_string(),
+    }
+    .emit(&app)
     .ok();
 
     mcp::install(resource_path).map_err(|e| {
         let error_msg = format!("Failed to install MCP server: {}", e);
-        app.emit(
-            "mcp-install-output",
-            McpInstallOutput {
-                status: McpInstallStatus::Error,
-                output: error_msg.clone(),
-            },
-        )
+        McpInstallOutput {
+            status: McpInstallStatus::Error,
+            output: error_msg.clone(),
+        }
+        .emit(&app)
         .ok();
-        error_msg
+        SuiteError::internal(error_msg)
     })?;
 
     let mcp_path = mcp::get_mcp_path();
@@ -90,13 +84,11 @@ pub fn install_mcp_server(app: AppHandle) -> Result<String, String> {
         mcp_path.display()
     );
 
-    app.emit(
-        "mcp-install-output",
-        McpInstallOutput {
-            status: McpInstallStatus::Success,
-            output: success_msg.clone(),
-        },
-    )
+    McpInstallOutput {
+        status: McpInstallStatus::Success,
+        output: success_msg.clone(),
+    }
+    .emit(&app)
     .ok();
 
     Ok(success_msg)
@@ -114,13 +106,12 @@ pub fn install_mcp_server(app: AppHandle) -> Result<String, String> {
 /// Result containing either the token string (or empty string if not set) or an error message
 #[tauri::command]
 #[specta::specta]
-pub fn get_openai_token(app: AppHandle) -> Result<String, String> {
-    let store_path = crate::actions::utils::get_config_store_path()
-        .map_err(|e| format!("Failed to get config store path: {}", e))?;
+pub fn get_openai_token(app: AppHandle) -> Result<String, SuiteError> {
+    let store_path = crate::actions::utils::get_config_store_path()?;
 
     let store = app
-        .store(store_path)
-        .map_err(|e| format!("Failed to access store: {}", e))?;
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
 
     let token = store
         .get("openai_token")
@@ -144,19 +135,123 @@ pub fn get_openai_token(app: AppHandle) -> Result<String, String> {
 /// Result containing either a success message or an error message
 #[tauri::command]
 #[specta::specta]
-pub fn set_openai_token(app: AppHandle, token: String) -> Result<String, String> {
-    let store_path = crate::actions::utils::get_config_store_path()
-        .map_err(|e| format!("Failed to get config store path: {}", e))?;
+pub fn set_openai_token(app: AppHandle, token: String) -> Result<String, SuiteError> {
+    let store_path = crate::actions::utils::get_config_store_path()?;
 
     let store = app
-        .store(store_path)
-        .map_err(|e| format!("Failed to access store: {}", e))?;
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
 
     store.set("openai_token", serde_json::json!(token));
 
-    store
-        .save()
-        .map_err(|e| format!("Failed to persist store: {}", e))?;
+    store.save().map_err(|e| SuiteError::io(&store_path, e))?;
 
     Ok("Token saved successfully".to_string())
 }
+
+/// Retrieves the settings of the local REST API
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing either the API settings or an error message
+#[tauri::command]
+#[specta::specta]
+pub fn get_api_settings(app: AppHandle) -> Result<ApiSettings, SuiteError> {
+    auth::load_settings(&app)
+}
+
+/// Saves the settings of the local REST API
+///
+/// Enabling or disabling write access takes effect immediately, whereas a
+/// changed host or port is applied after restarting the application.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+/// * `api_state` - The shared state of the running REST API
+/// * `settings` - The new API settings
+///
+/// # Returns
+/// Result containing either a success message or an error message
+#[tauri::command]
+#[specta::specta]
+pub fn set_api_settings(
+    app: AppHandle,
+    api_state: State<Arc<ApiState>>,
+    settings: ApiSettings,
+) -> Result<String, SuiteError> {
+    auth::save_settings(&app, &settings)?;
+    *api_state.allow_writes.lock().unwrap() = settings.allow_writes;
+
+    Ok("API settings saved successfully".to_string())
+}
+
+/// Retrieves the token clients have to provide to access the local REST API
+///
+/// # Arguments
+/// * `api_state` - The shared state of the running REST API
+///
+/// # Returns
+/// The API token of this install
+#[tauri::command]
+#[specta::specta]
+pub fn get_api_token(api_state: State<Arc<ApiState>>) -> String {
+    api_state.token.lock().unwrap().clone()
+}
+
+/// Generates a new token for the local REST API
+///
+/// The new token is persisted and applied immediately, so clients using the
+/// previous token lose access to the API. Registered MCP clients read the
+/// token from the config store whenever they launch the stdio bridge and
+/// thus keep their access.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+/// * `api_state` - The shared state of the running REST API
+///
+/// # Returns
+/// Result containing either the new token or an error message
+#[tauri::command]
+#[specta::specta]
+pub fn regenerate_api_token(
+    app: AppHandle,
+    api_state: State<Arc<ApiState>>,
+) -> Result<String, SuiteError> {
+    let token = auth::regenerate_token(&app)?;
+    *api_state.token.lock().unwrap() = token.clone();
+
+    Ok(token)
+}
+
+/// Retrieves the schedule of the backups of the database
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing either the backup settings or an error message
+#[tauri::command]
+#[specta::specta]
+pub fn get_backup_settings(app: AppHandle) -> Result<BackupSettings, SuiteError> {
+    backup::load_settings(&app)
+}
+
+/// Saves the schedule of the backups of the database
+///
+/// The new schedule applies from the next check of the schedule on.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+/// * `settings` - The new backup settings
+///
+/// # Returns
+/// Result containing either a success message or an error message
+#[tauri::command]
+#[specta::specta]
+pub fn set_backup_settings(app: AppHandle, settings: BackupSettings) -> Result<String, SuiteError> {
+    backup::save_settings(&app, &settings)?;
+
+    Ok("Backup settings saved successfully".to_string())
+}
@@ -1,9 +1,16 @@
 use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::SuiteError;
 
 /// Default SUITE directory name
 const SUITE_DIR: &str = "enzymeml-suite";
 /// Config store filename
 const CONFIG_STORE: &str = "config.json";
+/// Directory name of the recovery snapshots within the SUITE directory
+const RECOVERY_DIR: &str = "recovery";
+/// Directory name of the database backups within the SUITE directory
+const BACKUP_DIR: &str = "backups";
 
 /// Gets the path to the EnzymeML Suite configuration store
 ///
@@ -15,13 +22,45 @@ const CONFIG_STORE: &str = "config.json";
 ///
 /// A `Result` containing:
 /// - `Ok(PathBuf)` with the full path to the config store
-/// - `Err(String)` if the home directory cannot be determined
-pub fn get_config_store_path() -> Result<PathBuf, String> {
+/// - `Err(SuiteError)` if the home directory cannot be determined
+pub fn get_config_store_path() -> Result<PathBuf, SuiteError> {
     dirs::home_dir()
-        .ok_or_else(|| "Failed to get home directory".to_string())
+        .ok_or_else(|| SuiteError::internal("Failed to get home directory"))
         .map(|home| home.join(SUITE_DIR).join(CONFIG_STORE))
 }
 
+/// Gets the path to the directory holding the recovery snapshots
+///
+/// Unsaved documents are periodically written to this directory, so they can
+/// be restored after a crash. The path is: `~/enzymeml-suite/recovery`
+///
+/// # Returns
+///
+/// A `Result` containing:
+/// - `Ok(PathBuf)` with the full path to the recovery directory
+/// - `Err(SuiteError)` if the home directory cannot be determined
+pub fn get_recovery_dir() -> Result<PathBuf, SuiteError> {
+    dirs::home_dir()
+        .ok_or_else(|| SuiteError::internal("Failed to get home directory"))
+        .map(|home| home.join(SUITE_DIR).join(RECOVERY_DIR))
+}
+
+/// Gets the path to the directory holding the backups of the database
+///
+/// Backups are taken periodically and on request, see [`crate::backup`], and
+/// kept in a subdirectory per library. The path is: `~/enzymeml-suite/backups`
+///
+/// # Returns
+///
+/// A `Result` containing:
+/// - `Ok(PathBuf)` with the full path to the backup directory
+/// - `Err(SuiteError)` if the home directory cannot be determined
+pub fn get_backup_dir() -> Result<PathBuf, SuiteError> {
+    dirs::home_dir()
+        .ok_or_else(|| SuiteError::internal("Failed to get home directory"))
+        .map(|home| home.join(SUITE_DIR).join(BACKUP_DIR))
+}
+
 /// Generates a unique identifier with a given prefix.
 ///
 /// This function takes a vector of existing IDs and a prefix string, and generates
@@ -45,3 +84,11 @@ pub fn generate_id(ids: &Vec<String>, prefix: &str) -> String {
     }
     id
 }
+
+/// Returns the current time in seconds since the Unix epoch
+pub fn seconds_since_epoch() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
@@ -8,7 +8,8 @@ use serde::{Deserialize, Serialize};
 use specta::Type;
 use tauri::State;
 
-use crate::states::EnzymeMLState;
+use crate::error::SuiteError;
+use crate::states::Workspace;
 
 /// Severity levels for validation issues
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -78,13 +79,18 @@ impl From<EnzymeMLReport> for Report {
 /// other validation issues that may need attention.
 ///
 /// # Arguments
-/// * `state` - The shared EnzymeML document state containing the validation report
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 ///
 /// # Returns
 /// Result containing either the validation Report object or an error message
 #[tauri::command]
 #[specta::specta]
-pub fn get_validation_report(state: State<Arc<EnzymeMLState>>) -> Result<Report, String> {
+pub fn get_validation_report(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<Report, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
     let enzymeml_report = state.validation_report.lock().unwrap().clone();
     Ok(enzymeml_report.into())
 }
@@ -100,21 +106,24 @@ pub fn get_validation_report(state: State<Arc<EnzymeMLState>>) -> Result<Report,
 /// identifier.
 ///
 /// # Arguments
-/// * `state` - The shared EnzymeML document state containing the validation report
+/// * `workspace` - The open documents of the application
 /// * `identifier` - The specific identifier string to filter validation results by
+/// * `handle` - Handle of the document, `None` for the active document
 ///
 /// # Returns
-/// A vector of ValidationResult objects that match the specified identifier
+/// Result containing the ValidationResult objects that match the specified identifier
 #[tauri::command]
 #[specta::specta]
 pub fn get_validation_report_by_identifier(
-    state: State<Arc<EnzymeMLState>>,
+    workspace: State<Arc<Workspace>>,
     identifier: &str,
-) -> Vec<ValidationResult> {
+    handle: Option<String>,
+) -> Result<Vec<ValidationResult>, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
     let state_report = state.validation_report.lock().unwrap().clone();
-    state_report
+    Ok(state_report
         .filter_results(identifier)
         .into_iter()
         .map(|r| r.into())
-        .collect()
+        .collect())
 }
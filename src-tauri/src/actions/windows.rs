@@ -1,3 +1,5 @@
+use crate::error::SuiteError;
+
 // use tauri::Manager;
 
 /// Opens a new visualization window for the EnzymeML application
@@ -13,7 +15,8 @@
 /// # Returns
 /// Result indicating success with empty tuple or failure with error message string
 #[tauri::command]
-pub async fn open_visualisation(app: tauri::AppHandle) -> Result<(), String> {
+#[specta::specta]
+pub async fn open_visualisation(app: tauri::AppHandle) -> Result<(), SuiteError> {
     let file_path = "viswindow/index.html";
     let _settings_window = tauri::WebviewWindowBuilder::new(
         &app,
@@ -27,7 +30,7 @@ pub async fn open_visualisation(app: tauri::AppHandle) -> Result<(), String> {
     .resizable(true)
     .inner_size(800_f64, 600_f64)
     .build()
-    .map_err(|e| e.to_string())?;
+    .map_err(|e| SuiteError::internal(e.to_string()))?;
 
     // #[cfg(debug_assertions)] // only include this code on debug builds
     // {
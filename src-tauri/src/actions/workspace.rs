@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use tauri::{AppHandle, State};
+
+use crate::error::SuiteError;
+use crate::events::{EventSink, SuiteEvent};
+use crate::io::dataio::load_into_state;
+use crate::states::{EnzymeMLState, OpenDocument, Workspace};
+
+/// Lists the documents open in the workspace
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+///
+/// # Returns
+/// The open documents in the order they have been opened
+#[tauri::command]
+#[specta::specta]
+pub fn list_open_documents(workspace: State<Arc<Workspace>>) -> Vec<OpenDocument> {
+    workspace.list()
+}
+
+/// Opens a new, empty document and makes it the active one
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing the handle of the new document
+#[tauri::command]
+#[specta::specta]
+pub fn open_new_document(
+    workspace: State<Arc<Workspace>>,
+    app_handle: AppHandle,
+) -> Result<String, SuiteError> {
+    let state = workspace.open(EnzymeMLState::default());
+    notify_workspace_change(&state, &app_handle)?;
+
+    Ok(state.handle.clone())
+}
+
+/// Opens a stored document next to the already open ones
+///
+/// If the stored document is open already, it is made the active one instead
+/// of being opened twice.
+///
+/// # Arguments
+/// * `id` - The database ID of the document to open
+/// * `workspace` - The open documents of the application
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing the handle of the opened document
+#[tauri::command]
+#[specta::specta]
+pub fn open_document(
+    id: i32,
+    workspace: State<Arc<Workspace>>,
+    app_handle: AppHandle,
+) -> Result<String, SuiteError> {
+    let state = match workspace.find_by_id(id).into_iter().next() {
+        Some(state) => workspace.switch(&state.handle)?,
+        None => {
+            let state = EnzymeMLState::default();
+            load_into_state(&state, id)?;
+            state.update_report();
+            workspace.open(state)
+        }
+    };
+
+    notify_workspace_change(&state, &app_handle)?;
+
+    Ok(state.handle.clone())
+}
+
+/// Makes an open document the active one
+///
+/// # Arguments
+/// * `handle` - Handle of the document to activate
+/// * `workspace` - The open documents of the application
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result indicating whether a document with the given handle is open
+#[tauri::command]
+#[specta::specta]
+pub fn switch_document(
+    handle: &str,
+    workspace: State<Arc<Workspace>>,
+    app_handle: AppHandle,
+) -> Result<(), SuiteError> {
+    let state = workspace.switch(handle)?;
+    notify_workspace_change(&state, &app_handle)
+}
+
+/// Closes an open document without saving it
+///
+/// Closing the active document activates its neighbour, closing the last
+/// document opens a new, empty one.
+///
+/// # Arguments
+/// * `handle` - Handle of the document to close
+/// * `workspace` - The open documents of the application
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing the handle of the active document after closing
+#[tauri::command]
+#[specta::specta]
+pub fn close_document(
+    handle: &str,
+    workspace: State<Arc<Workspace>>,
+    app_handle: AppHandle,
+) -> Result<String, SuiteError> {
+    let state = workspace.close(handle)?;
+    notify_workspace_change(&state, &app_handle)?;
+
+    Ok(state.handle.clone())
+}
+
+/// Notifies the frontend that the open or active documents changed
+///
+/// The validation report of the active document is emitted along with the
+/// workspace event, so the report shown matches the document shown.
+///
+/// # Arguments
+/// * `active` - The active document after the change
+/// * `events` - Sink notified about the change
+///
+/// # Returns
+/// Result indicating whether the events could be delivered
+pub(crate) fn notify_workspace_change(
+    active: &EnzymeMLState,
+    events: &impl EventSink,
+) -> Result<(), SuiteError> {
+    events
+        .emit_event(SuiteEvent::Workspace)
+        .and_then(|_| events.emit_event(SuiteEvent::report(active)))
+        .map_err(SuiteError::internal)
+}
@@ -1,32 +1,57 @@
 use enzymeml::prelude::EnzymeMLDocument;
-use notebookx::NotebookFormat;
 use rocket::http::{ContentType, Status};
 use rocket::serde::json::Json;
-use rocket::{get, put, routes, Build, Rocket, State};
+use rocket::{catch, catchers, delete, get, post, put, routes, Build, Request, Rocket, State};
 use rocket_cors::AllowedOrigins;
+use schemars::JsonSchema;
 use serde::{Serialize, Serializer};
 use serde_json::Value;
 use std::sync::Arc;
-use tauri::{AppHandle, Emitter, Manager};
 
-use crate::actions::jupyter::{JupyterTemplate, JUPYTER_TEMPLATES, JUPYTER_TEMPLATE_METADATA};
+use crate::actions::jupyter::{template_as_script, JupyterTemplate, JUPYTER_TEMPLATE_METADATA};
+use crate::document::diff::{diff_documents, DiffOptions};
 use crate::docutils::deserialize_doc;
-use crate::io::dataio::{retrieve_all_documents, retrieve_document_by_id};
-use crate::states::EnzymeMLState;
+use crate::error::SuiteError;
+use crate::events::{ChangeOp, EventSink, SuiteEvent};
+use crate::io::dataio::{
+    delete_document, edit_document, insert_document, load_into_state, retrieve_all_documents,
+    retrieve_document_by_id, save_state,
+};
+use crate::io::source::DocumentSource;
+use crate::mcp::server::{handle_message, McpContext};
+use crate::states::{ApiState, EnzymeMLState, Workspace};
+
+use self::auth::{ApiSettings, Authenticated, WriteAccess};
+
+/// Authentication and bind configuration of the REST API
+pub mod auth;
+/// OpenAPI description of the REST API
+mod openapi;
+
+/// Version of the REST API, bumped whenever routes or the response envelope change
+pub(crate) const API_VERSION: &str = "1.5.0";
 
 /// Creates and configures a Rocket web server instance
 ///
-/// Sets up a Rocket server with CORS enabled for localhost connections, configured
-/// to run on port 13452. The server is initialized with application state and routes
-/// for document management operations.
+/// Sets up a Rocket server with CORS enabled for localhost connections, bound to
+/// the host and port from the API settings. The server is initialized with application
+/// state and routes for document management operations. All routes except for the
+/// health, version and OpenAPI endpoints require the API token.
 ///
 /// # Arguments
-/// * `state` - Shared EnzymeML document state
-/// * `app_handle` - Handle to the Tauri application
+/// * `workspace` - Shared open documents of the application
+/// * `events` - Sink notified about document changes, such as the Tauri application
+/// * `settings` - Host, port and write access of the API
+/// * `api_state` - Shared token and write access state of the API
 ///
 /// # Returns
 /// A configured Rocket build instance ready to launch
-pub fn create_rocket(state: Arc<EnzymeMLState>, app_handle: Arc<AppHandle>) -> Rocket<Build> {
+pub fn create_rocket(
+    workspace: Arc<Workspace>,
+    events: Arc<dyn EventSink>,
+    settings: ApiSettings,
+    api_state: Arc<ApiState>,
+) -> Rocket<Build> {
     // Configure CORS
     let allowed_origins = AllowedOrigins::some_regex(&["http://localhost:.*"]);
     let cors = rocket_cors::CorsOptions::default()
@@ -35,43 +60,155 @@ pub fn create_rocket(state: Arc<EnzymeMLState>, app_handle: Arc<AppHandle>) -> R
         .expect("Error creating CORS");
 
     let figment = rocket::Config::figment()
-        .merge(("port", 13452))
-        .merge(("address", "127.0.0.1"));
+        .merge(("port", settings.port))
+        .merge(("address", settings.host.clone()));
 
     rocket::custom(figment)
         .attach(cors)
-        .manage(state)
-        .manage(app_handle)
+        .manage(workspace)
+        .manage(events)
+        .manage(api_state)
+        .manage(settings)
         .mount(
             "/",
             routes![
                 get_docs,
                 get_current_doc,
+                get_workspace,
                 get_doc_by_id,
                 update_document,
+                create_doc,
+                update_doc_by_id,
+                delete_doc_by_id,
+                load_doc_by_id,
+                post_diff,
                 get_jupyter_templates,
-                get_jupyter_template
+                get_jupyter_template,
+                post_mcp,
+                get_mcp,
+                get_openapi,
+                get_health,
+                get_version
             ],
         )
+        .register(
+            "/",
+            catchers![unauthorized, forbidden, not_found, unprocessable_entity],
+        )
 }
 
 /// Standard API response structure
 ///
 /// Provides a consistent response format for all API endpoints, including
-/// status code, optional data payload, and optional error messages.
-#[derive(serde::Serialize)]
+/// status code, optional data payload, optional messages and the typed error
+/// of failed requests.
+#[derive(serde::Serialize, JsonSchema)]
 struct APIResponse {
+    /// HTTP status code of the response
+    #[schemars(with = "u16")]
     status: Status,
+    /// Payload of the response, if any
     #[serde(skip_serializing_if = "Option::is_none")]
     data: Option<ResponseTypes>,
+    /// Human readable status or error message, if any
     #[serde(skip_serializing_if = "Option::is_none")]
     message: Option<String>,
+    /// Typed error of a failed request, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<SuiteError>,
+}
+
+impl APIResponse {
+    /// Creates an error response with the given status and message
+    ///
+    /// # Arguments
+    /// * `status` - The HTTP status of the response
+    /// * `message` - Human readable description of the error
+    fn error(status: Status, message: impl Into<String>) -> Self {
+        APIResponse {
+            status,
+            data: None,
+            message: Some(message.into()),
+            error: None,
+        }
+    }
+
+    /// Creates an error response from a typed error
+    ///
+    /// # Arguments
+    /// * `status` - The HTTP status of the response
+    /// * `err` - The error that made the request fail
+    fn from_error(status: Status, err: &SuiteError) -> Self {
+        APIResponse {
+            status,
+            data: None,
+            message: Some(err.to_string()),
+            error: Some(err.clone()),
+        }
+    }
+
+    /// Serializes the response into the tuple returned by the route handlers
+    fn respond(self) -> (Status, (ContentType, String)) {
+        (
+            self.status,
+            (ContentType::JSON, serde_json::to_string(&self).unwrap()),
+        )
+    }
+}
+
+/// Request body for creating or updating stored documents
+///
+/// Both fields are optional. When creating a document without content, the
+/// currently loaded document is saved instead. When updating, only the
+/// provided fields are changed.
+#[derive(serde::Deserialize, JsonSchema)]
+struct DocumentRequest {
+    /// Title of the stored document
+    #[serde(default)]
+    title: Option<String>,
+    /// Content of the stored document
+    #[serde(default)]
+    content: Option<EnzymeMLDocument>,
+}
+
+/// Overview entry of a stored document
+#[derive(serde::Serialize, JsonSchema)]
+struct DocumentEntry {
+    /// Database ID of the document
+    id: i32,
+    /// Title of the document
+    title: String,
+}
+
+/// Request body for comparing two documents
+#[derive(serde::Deserialize, JsonSchema)]
+struct DiffRequest {
+    /// Source of the document to compare against
+    before: DocumentSource,
+    /// Source of the document to compare
+    after: DocumentSource,
+    /// Options of the comparison
+    #[serde(default)]
+    options: DiffOptions,
+}
+
+/// Version information of the running application and API
+#[derive(serde::Serialize, JsonSchema)]
+struct VersionInfo {
+    /// Name of the application
+    name: String,
+    /// Version of the EnzymeML Suite
+    version: String,
+    /// Version of the REST API
+    api_version: String,
 }
 
 /// Enumeration of possible response data types
 ///
 /// Allows the API to return different types of data while maintaining
 /// a consistent response structure across all endpoints.
+#[derive(JsonSchema)]
+#[schemars(untagged)]
 enum ResponseTypes {
     Document(EnzymeMLDocResponse),
     Generic(Value),
@@ -93,10 +230,14 @@ impl Serialize for ResponseTypes {
 ///
 /// Contains the document title, serialized content, and optional database ID
 /// for transmission to API clients.
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, JsonSchema)]
 struct EnzymeMLDocResponse {
+    /// Title of the document
     title: String,
+    /// The serialized EnzymeML document
+    #[schemars(with = "EnzymeMLDocument")]
     content: Value,
+    /// Database ID of the document, if it has been saved
     id: Option<i32>,
 }
 
@@ -127,25 +268,24 @@ impl EnzymeMLDocResponse {
 /// Returns a list of all available EnzymeML documents with their IDs and titles.
 /// This endpoint provides an overview of all stored documents without their full content.
 #[get("/docs")]
-fn get_docs() -> (Status, (ContentType, String)) {
+fn get_docs(_auth: Authenticated) -> (Status, (ContentType, String)) {
     let entries = retrieve_all_documents();
 
     match entries {
         Ok(entries) => {
             let data = entries
-                .iter()
-                .map(|doc| {
-                    serde_json::json!({
-                        "id": doc.id,
-                        "title": doc.title,
-                    })
+                .into_iter()
+                .map(|doc| DocumentEntry {
+                    id: doc.id,
+                    title: doc.title,
                 })
-                .collect::<Vec<Value>>();
+                .collect::<Vec<DocumentEntry>>();
 
             let response = APIResponse {
                 status: Status::Ok,
                 data: ResponseTypes::Generic(serde_json::to_value(data).unwrap()).into(),
                 message: None,
+                error: None,
             };
 
             (
@@ -153,27 +293,29 @@ fn get_docs() -> (Status, (ContentType, String)) {
                 (ContentType::JSON, serde_json::to_string(&response).unwrap()),
             )
         }
-        Err(e) => {
-            let response = APIResponse {
-                status: Status::InternalServerError,
-                data: None,
-                message: Some(e.to_string()),
-            };
-
-            (
-                Status::InternalServerError,
-                (ContentType::JSON, serde_json::to_string(&response).unwrap()),
-            )
-        }
+        Err(e) => error_response(&e),
     }
 }
 
 /// Retrieves the currently loaded document from application state
 ///
-/// Returns the EnzymeML document that is currently loaded in the application's
-/// memory state, including its title, content, and database ID if available.
-#[get("/docs/:current")]
-fn get_current_doc(state: &State<Arc<EnzymeMLState>>) -> (Status, (ContentType, String)) {
+/// Returns the active document of the workspace or the open document with the
+/// given handle, including its title, content, and database ID if available.
+///
+/// # Arguments
+/// * `handle` - Handle of an open document, the active document if omitted
+/// * `workspace` - The open documents of the application
+#[get("/docs/:current?<handle>")]
+fn get_current_doc(
+    handle: Option<&str>,
+    workspace: &State<Arc<Workspace>>,
+    _auth: Authenticated,
+) -> (Status, (ContentType, String)) {
+    let state = match workspace.document(handle) {
+        Ok(state) => state,
+        Err(e) => return error_response(&e),
+    };
+
     let state_doc = state.doc.lock().unwrap();
     let state_title = state.title.lock().unwrap();
     let state_id = state.id.lock().unwrap();
@@ -183,6 +325,7 @@ fn get_current_doc(state: &State<Arc<EnzymeMLState>>) -> (Status, (ContentType,
         status: Status::Ok,
         data: ResponseTypes::Document(data).into(),
         message: None,
+        error: None,
     };
 
     (
@@ -191,6 +334,29 @@ fn get_current_doc(state: &State<Arc<EnzymeMLState>>) -> (Status, (ContentType,
     )
 }
 
+/// Lists the documents open in the application
+///
+/// Returns the handle, title and database ID of every open document along with
+/// whether it is the active one. The handles address the open documents in the
+/// `/docs/:current` routes.
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+#[get("/workspace")]
+fn get_workspace(
+    workspace: &State<Arc<Workspace>>,
+    _auth: Authenticated,
+) -> (Status, (ContentType, String)) {
+    let response = APIResponse {
+        status: Status::Ok,
+        data: ResponseTypes::Generic(serde_json::to_value(workspace.list()).unwrap()).into(),
+        message: None,
+        error: None,
+    };
+
+    response.respond()
+}
+
 /// Retrieves a specific document by its database ID
 ///
 /// Fetches and deserializes a specific EnzymeML document from the database
@@ -199,63 +365,313 @@ fn get_current_doc(state: &State<Arc<EnzymeMLState>>) -> (Status, (ContentType,
 /// # Arguments
 /// * `id` - The database ID of the document to retrieve
 #[get("/docs/<id>")]
-fn get_doc_by_id(id: i32) -> (Status, (ContentType, String)) {
-    let doc = retrieve_document_by_id(id);
+fn get_doc_by_id(id: i32, _auth: Authenticated) -> (Status, (ContentType, String)) {
+    let doc = match retrieve_document_by_id(id) {
+        Ok(doc) => doc,
+        Err(e) => return error_response(&e),
+    };
 
-    match doc {
-        Ok(doc) => {
-            let enzmldoc = deserialize_doc(doc.content.as_str()).unwrap();
+    match deserialize_doc(doc.content.as_str()) {
+        Ok(enzmldoc) => {
             let data = EnzymeMLDocResponse::new(doc.title, &enzmldoc, Some(doc.id));
             let response = APIResponse {
                 status: Status::Ok,
                 data: ResponseTypes::Document(data).into(),
                 message: None,
+                error: None,
             };
 
-            (
-                Status::Ok,
-                (ContentType::JSON, serde_json::to_string(&response).unwrap()),
-            )
+            response.respond()
+        }
+        Err(e) => error_response(&SuiteError::from(e)),
+    }
+}
+
+/// Creates a new stored document or saves the currently loaded one
+///
+/// If the request contains a document, it is inserted as a new entry using the
+/// given title or the document name. Without content, the active or the given
+/// open document is saved in the same way as the `save` command, optionally
+/// renaming it first.
+///
+/// # Arguments
+/// * `request` - The title and content of the document to store
+/// * `handle` - Handle of an open document, the active document if omitted
+/// * `workspace` - The open documents of the application
+/// * `events` - Sink notified about document changes
+#[post("/docs?<handle>", format = "application/json", data = "<request>")]
+fn create_doc(
+    request: Json<DocumentRequest>,
+    handle: Option<&str>,
+    workspace: &State<Arc<Workspace>>,
+    events: &State<Arc<dyn EventSink>>,
+    _write: WriteAccess,
+) -> (Status, (ContentType, String)) {
+    let request = request.into_inner();
+    let state = match workspace.document(handle) {
+        Ok(state) => state,
+        Err(e) => return error_response(&e),
+    };
+
+    let saves_state = request.content.is_none();
+    let result = match request.content {
+        Some(content) => {
+            let title = request.title.unwrap_or_else(|| content.name.clone());
+            insert_document(&title, &content)
+        }
+        None => {
+            if let Some(title) = request.title {
+                state.doc.lock().unwrap().name = title.clone();
+                *state.title.lock().unwrap() = title;
+            }
+
+            save_state(&state)
+        }
+    };
+
+    let id = match result {
+        Ok(id) => id,
+        Err(e) => return error_response(&e),
+    };
+
+    let event = if saves_state {
+        SuiteEvent::persisted(&state, ChangeOp::Create)
+    } else {
+        SuiteEvent::document(&state, ChangeOp::Create)
+    };
+
+    if let Some(value) = signal_change_to_frontend(events.inner().as_ref(), &state, event) {
+        return value;
+    }
+
+    let response = APIResponse {
+        status: Status::Created,
+        data: ResponseTypes::Generic(serde_json::json!({ "id": id })).into(),
+        message: "Saved document.".to_string().into(),
+        error: None,
+    };
+
+    response.respond()
+}
+
+/// Updates the title and/or content of a stored document
+///
+/// The title and content are stored together or not at all. If the updated
+/// entry is open in the application, the open documents take over the new
+/// title. They take over the new content as well, unless they have unsaved
+/// changes, which are kept and replace the stored content when saved.
+///
+/// # Arguments
+/// * `id` - The database ID of the document to update
+/// * `request` - The new title and/or content of the document
+/// * `workspace` - The open documents of the application
+/// * `events` - Sink notified about document changes
+#[put("/docs/<id>", format = "application/json", data = "<request>")]
+fn update_doc_by_id(
+    id: i32,
+    request: Json<DocumentRequest>,
+    workspace: &State<Arc<Workspace>>,
+    events: &State<Arc<dyn EventSink>>,
+    _write: WriteAccess,
+) -> (Status, (ContentType, String)) {
+    let request = request.into_inner();
+
+    if let Err(e) = edit_document(id, request.title.as_deref(), request.content.as_ref()) {
+        return error_response(&e);
+    }
+
+    let open_documents = match (&request.title, &request.content) {
+        (None, None) => Vec::new(),
+        _ => workspace.find_by_id(id),
+    };
+    for state in open_documents {
+        // Unsaved changes of open documents are kept over the stored content
+        let reload = request.content.is_some() && !state.is_dirty();
+        if let Some(content) = request.content.as_ref().filter(|_| reload) {
+            *state.doc.lock().unwrap() = content.clone();
+        }
+        if let Some(title) = &request.title {
+            *state.title.lock().unwrap() = title.clone();
+        }
+
+        let event = if reload {
+            SuiteEvent::persisted(&state, ChangeOp::Update)
+        } else {
+            SuiteEvent::document(&state, ChangeOp::Update)
+        };
+        if let Some(value) = signal_change_to_frontend(events.inner().as_ref(), &state, event) {
+            return value;
+        }
+    }
+
+    let response = APIResponse {
+        status: Status::Ok,
+        data: ResponseTypes::Generic(serde_json::json!({ "id": id })).into(),
+        message: "Updated document.".to_string().into(),
+        error: None,
+    };
+
+    response.respond()
+}
+
+/// Deletes a stored document
+///
+/// If the deleted entry is open in the application, the document stays open
+/// but is detached from the database, so the next save creates a new entry.
+///
+/// # Arguments
+/// * `id` - The database ID of the document to delete
+/// * `workspace` - The open documents of the application
+/// * `events` - Sink notified about document changes
+#[delete("/docs/<id>")]
+fn delete_doc_by_id(
+    id: i32,
+    workspace: &State<Arc<Workspace>>,
+    events: &State<Arc<dyn EventSink>>,
+    _write: WriteAccess,
+) -> (Status, (ContentType, String)) {
+    if let Err(e) = delete_document(id) {
+        return error_response(&e);
+    }
+
+    for state in workspace.find_by_id(id) {
+        *state.id.lock().unwrap() = None;
+
+        if let Some(value) = signal_change_to_frontend(
+            events.inner().as_ref(),
+            &state,
+            SuiteEvent::document(&state, ChangeOp::Delete),
+        ) {
+            return value;
         }
-        Err(e) => {
+    }
+
+    let response = APIResponse {
+        status: Status::Ok,
+        data: None,
+        message: "Deleted document.".to_string().into(),
+        error: None,
+    };
+
+    response.respond()
+}
+
+/// Loads a stored document into the application
+///
+/// Replaces the active or the given open document with the stored one in the
+/// same way as the `load` command and signals the change to the frontend.
+///
+/// # Arguments
+/// * `id` - The database ID of the document to load
+/// * `handle` - Handle of an open document, the active document if omitted
+/// * `workspace` - The open documents of the application
+/// * `events` - Sink notified about document changes
+#[post("/docs/<id>/load?<handle>")]
+fn load_doc_by_id(
+    id: i32,
+    handle: Option<&str>,
+    workspace: &State<Arc<Workspace>>,
+    events: &State<Arc<dyn EventSink>>,
+    _write: WriteAccess,
+) -> (Status, (ContentType, String)) {
+    let state = match workspace.document(handle) {
+        Ok(state) => state,
+        Err(e) => return error_response(&e),
+    };
+
+    if let Err(e) = load_into_state(&state, id) {
+        return error_response(&e);
+    }
+
+    let event = SuiteEvent::persisted(&state, ChangeOp::Bulk);
+    if let Some(value) = signal_change_to_frontend(events.inner().as_ref(), &state, event) {
+        return value;
+    }
+
+    let response = APIResponse {
+        status: Status::Ok,
+        data: None,
+        message: "Loaded document.".to_string().into(),
+        error: None,
+    };
+
+    response.respond()
+}
+
+/// Compares two documents entity by entity
+///
+/// Both documents are given as source, referring to an open document, a
+/// stored document or an inline document. File sources are rejected. Returns
+/// the added, removed and modified entities along with their changed fields.
+///
+/// # Arguments
+/// * `request` - The sources of the documents and the options of the comparison
+/// * `workspace` - The open documents of the application
+#[post("/diff", format = "application/json", data = "<request>")]
+fn post_diff(
+    request: Json<DiffRequest>,
+    workspace: &State<Arc<Workspace>>,
+    _auth: Authenticated,
+) -> (Status, (ContentType, String)) {
+    let request = request.into_inner();
+    let diff = request.before.resolve_remote(workspace).and_then(|before| {
+        let after = request.after.resolve_remote(workspace)?;
+        diff_documents(&before, &after, &request.options)
+    });
+
+    match diff {
+        Ok(diff) => {
             let response = APIResponse {
-                status: Status::NotFound,
-                data: None,
-                message: Some(e.to_string()),
+                status: Status::Ok,
+                data: ResponseTypes::Generic(serde_json::to_value(diff).unwrap()).into(),
+                message: None,
+                error: None,
             };
 
-            (
-                Status::NotFound,
-                (ContentType::JSON, serde_json::to_string(&response).unwrap()),
-            )
+            response.respond()
         }
+        Err(e) => error_response(&e),
     }
 }
 
 /// Updates the currently loaded document in application state
 ///
-/// Replaces the current document in memory with the provided EnzymeML document
-/// and signals the change to the frontend application via Tauri events.
+/// Replaces the active or the given open document in memory with the provided
+/// EnzymeML document and signals the change to the frontend application via
+/// Tauri events.
 ///
 /// # Arguments
 /// * `enzmldoc` - The new EnzymeML document data in JSON format
-/// * `state` - The shared application state to update
-/// * `app_handle` - Handle for emitting events to the frontend
-#[put("/docs/:current", format = "application/json", data = "<enzmldoc>")]
+/// * `handle` - Handle of an open document, the active document if omitted
+/// * `workspace` - The open documents of the application
+/// * `events` - Sink notified about document changes
+#[put(
+    "/docs/:current?<handle>",
+    format = "application/json",
+    data = "<enzmldoc>"
+)]
 pub fn update_document(
     enzmldoc: Json<EnzymeMLDocument>,
-    state: &State<Arc<EnzymeMLState>>,
-    app_handle: &State<Arc<AppHandle>>,
+    handle: Option<&str>,
+    workspace: &State<Arc<Workspace>>,
+    events: &State<Arc<dyn EventSink>>,
+    _write: WriteAccess,
 ) -> (Status, (ContentType, String)) {
+    let state = match workspace.document(handle) {
+        Ok(state) => state,
+        Err(e) => return error_response(&e),
+    };
+
     let mut state_doc = state.doc.lock().unwrap();
-    let app_handle = app_handle.app_handle();
     let enzmldoc = enzmldoc.into_inner();
 
     // Perform the update
     *state_doc = enzmldoc;
 
     // Communicate the change to the Tauri app
-    if let Some(value) = signal_change_to_frontend(app_handle) {
+    drop(state_doc);
+    let event = SuiteEvent::document(&state, ChangeOp::Bulk);
+    if let Some(value) = signal_change_to_frontend(events.inner().as_ref(), &state, event) {
         return value;
     }
 
@@ -264,6 +680,7 @@ pub fn update_document(
         status: Status::Ok,
         data: None,
         message: "Updated document.".to_string().into(),
+        error: None,
     };
 
     (
@@ -272,33 +689,190 @@ pub fn update_document(
     )
 }
 
+/// Handles messages sent to the MCP server via streamable HTTP
+///
+/// Every request carries a single JSON-RPC message or a batch of messages,
+/// which are processed against the active document. Requests are
+/// answered with a plain JSON response, notifications are acknowledged with
+/// `202 Accepted` and an empty body.
+///
+/// # Arguments
+/// * `message` - The JSON-RPC message or batch of messages
+/// * `workspace` - The open documents of the application
+/// * `events` - Sink notified about document changes
+/// * `api_state` - Shared token and write access state of the API
+#[post("/mcp", format = "application/json", data = "<message>")]
+fn post_mcp(
+    message: Json<Value>,
+    workspace: &State<Arc<Workspace>>,
+    events: &State<Arc<dyn EventSink>>,
+    api_state: &State<Arc<ApiState>>,
+    _auth: Authenticated,
+) -> (Status, (ContentType, String)) {
+    let state = match workspace.active() {
+        Ok(state) => state,
+        Err(e) => return error_response(&e),
+    };
+    let context = McpContext {
+        state: &state,
+        events: events.inner().as_ref(),
+        allow_writes: *api_state.allow_writes.lock().unwrap(),
+    };
+
+    match handle_message(&context, message.into_inner()) {
+        Some(response) => (Status::Ok, (ContentType::JSON, response.to_string())),
+        None => (Status::Accepted, (ContentType::JSON, String::new())),
+    }
+}
+
+/// Rejects attempts to open a server-sent event stream on the MCP endpoint
+///
+/// The MCP server never initiates messages on its own, so clients are told
+/// via `405 Method Not Allowed` that no stream is offered.
+#[get("/mcp")]
+fn get_mcp(_auth: Authenticated) -> Status {
+    Status::MethodNotAllowed
+}
+
+/// Serves the OpenAPI 3 description of this API
+///
+/// The document is generated from the request and response types used by the
+/// route handlers, so clients can be generated from it instead of being written
+/// by hand against the response envelope.
+#[get("/openapi.json")]
+fn get_openapi(settings: &State<ApiSettings>) -> Json<Value> {
+    Json(openapi::document(settings))
+}
+
+/// Reports whether the API is up and running
+///
+/// Does not touch the database or the application state and can thus be used
+/// by clients to wait for the server to become available.
+#[get("/health")]
+fn get_health() -> (Status, (ContentType, String)) {
+    let response = APIResponse {
+        status: Status::Ok,
+        data: None,
+        message: "OK".to_string().into(),
+        error: None,
+    };
+
+    response.respond()
+}
+
+/// Reports the version of the application and the API
+#[get("/version")]
+fn get_version() -> (Status, (ContentType, String)) {
+    let data = VersionInfo {
+        name: env!("CARGO_PKG_NAME").to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        api_version: API_VERSION.to_string(),
+    };
+
+    let response = APIResponse {
+        status: Status::Ok,
+        data: ResponseTypes::Generic(serde_json::to_value(data).unwrap()).into(),
+        message: None,
+        error: None,
+    };
+
+    response.respond()
+}
+
 /// Signals document changes to the frontend application
 ///
-/// Emits an update event to notify the Tauri frontend that the document
-/// has been modified. Returns an error response if the event emission fails.
+/// Refreshes the validation report and emits update events to notify the Tauri
+/// frontend that the document has been modified. Returns an error response if
+/// the event emission fails.
 ///
 /// # Arguments
-/// * `app_handle` - Handle to the Tauri application for event emission
+/// * `events` - Sink notified about the changes
+/// * `state` - The shared application state holding the changed document
+/// * `event` - Event describing the change of the document
 ///
 /// # Returns
 /// Optional error response if event emission fails, None on success
-fn signal_change_to_frontend(app_handle: &AppHandle) -> Option<(Status, (ContentType, String))> {
-    match app_handle.emit("update_document", true) {
-        Ok(_) => {}
-        Err(e) => {
-            let response = APIResponse {
-                status: Status::InternalServerError,
-                data: None,
-                message: Some(e.to_string()),
-            };
+fn signal_change_to_frontend(
+    events: &dyn EventSink,
+    state: &EnzymeMLState,
+    event: SuiteEvent,
+) -> Option<(Status, (ContentType, String))> {
+    state.update_report();
+
+    let result = events
+        .emit_event(event)
+        .and_then(|_| events.emit_event(SuiteEvent::report(state)));
+
+    match result {
+        Ok(_) => None,
+        Err(e) => Some(error_response(&SuiteError::internal(e))),
+    }
+}
 
-            return Some((
-                Status::InternalServerError,
-                (ContentType::JSON, serde_json::to_string(&response).unwrap()),
-            ));
+/// Maps an error raised while handling a request to an error response
+///
+/// Missing entries are reported as `404 Not Found`, invalid or unparsable
+/// input as `422 Unprocessable Entity`, conflicts as `409 Conflict` and
+/// everything else as `500 Internal Server Error`. The typed error is part of
+/// the response, so clients can react to its `kind`.
+///
+/// # Arguments
+/// * `err` - The error to map
+///
+/// # Returns
+/// The serialized error response
+fn error_response(err: &SuiteError) -> (Status, (ContentType, String)) {
+    let status = match err {
+        SuiteError::NotFound { .. } => Status::NotFound,
+        SuiteError::Validation { .. } | SuiteError::Parse { .. } => Status::UnprocessableEntity,
+        SuiteError::Conflict { .. } => Status::Conflict,
+        SuiteError::Cancelled { .. } => Status::BadRequest,
+        SuiteError::Io { .. } | SuiteError::Database { .. } | SuiteError::Internal { .. } => {
+            Status::InternalServerError
         }
-    }
-    None
+    };
+
+    APIResponse::from_error(status, err).respond()
+}
+
+/// Catches requests without a valid API token
+#[catch(401)]
+fn unauthorized(_req: &Request) -> (ContentType, String) {
+    let response = APIResponse::error(
+        Status::Unauthorized,
+        "A valid API token has to be provided as bearer token in the Authorization header",
+    );
+    (ContentType::JSON, serde_json::to_string(&response).unwrap())
+}
+
+/// Catches requests to write routes while write access is disabled
+#[catch(403)]
+fn forbidden(_req: &Request) -> (ContentType, String) {
+    let response = APIResponse::error(
+        Status::Forbidden,
+        "Write access to the API is disabled in the settings",
+    );
+    (ContentType::JSON, serde_json::to_string(&response).unwrap())
+}
+
+/// Catches requests to unknown routes or resources
+#[catch(404)]
+fn not_found(req: &Request) -> (ContentType, String) {
+    let response = APIResponse::error(
+        Status::NotFound,
+        format!("No resource found at '{}'", req.uri()),
+    );
+    (ContentType::JSON, serde_json::to_string(&response).unwrap())
+}
+
+/// Catches requests whose body could not be parsed
+#[catch(422)]
+fn unprocessable_entity(_req: &Request) -> (ContentType, String) {
+    let response = APIResponse::error(
+        Status::UnprocessableEntity,
+        "The request body is not a valid EnzymeML document request",
+    );
+    (ContentType::JSON, serde_json::to_string(&response).unwrap())
 }
 
 /// Retrieves all available Jupyter notebook templates
@@ -310,7 +884,7 @@ fn signal_change_to_frontend(app_handle: &AppHandle) -> Option<(Status, (Content
 /// # Returns
 /// JSON response containing a vector of JupyterTemplate metadata
 #[get("/jupyter/templates")]
-fn get_jupyter_templates() -> Json<Vec<JupyterTemplate>> {
+fn get_jupyter_templates(_auth: Authenticated) -> Json<Vec<JupyterTemplate>> {
     let metadata = JUPYTER_TEMPLATE_METADATA.to_vec();
     Json(metadata)
 }
@@ -334,22 +908,11 @@ fn get_jupyter_templates() -> Json<Vec<JupyterTemplate>> {
 /// * Failed to parse the notebook format
 /// * Failed to serialize to percent format
 #[get("/jupyter/templates/<template_id>")]
-fn get_jupyter_template(template_id: &str) -> Result<(Status, (ContentType, String)), String> {
-    let metadata = JUPYTER_TEMPLATE_METADATA
-        .iter()
-        .find(|t| t.id == template_id)
-        .ok_or(format!("Template {template_id} not found"))?;
-    let template = JUPYTER_TEMPLATES
-        .get(metadata.template_path.as_str())
-        .ok_or(format!("Template {} not found", metadata.name))?;
-
-    let notebook = NotebookFormat::Ipynb
-        .parse(template)
-        .map_err(|e| format!("Failed to parse notebook: {e}"))?;
-
-    let script = NotebookFormat::Percent
-        .serialize(&notebook)
-        .map_err(|e| format!("Failed to serialize notebook: {e}"))?;
+fn get_jupyter_template(
+    template_id: &str,
+    _auth: Authenticated,
+) -> Result<(Status, (ContentType, String)), String> {
+    let script = template_as_script(template_id)?;
 
     Ok((Status::Ok, (ContentType::Text, script)))
 }
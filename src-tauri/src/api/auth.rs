@@ -0,0 +1,322 @@
+//! Authentication and bind configuration of the local REST API
+//!
+//! Every install generates a random API token which is persisted in the config
+//! store next to the other user settings. Clients have to send it as bearer token
+//! in the `Authorization` header. Routes that modify documents additionally
+//! require write access, which can be disabled entirely in the settings.
+
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use rocket::http::Status;
+use rocket::request::{FromRequest, Outcome};
+use rocket::Request;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::actions::utils::get_config_store_path;
+use crate::error::SuiteError;
+use crate::states::ApiState;
+
+/// Default address the REST API binds to
+pub const DEFAULT_HOST: &str = "127.0.0.1";
+/// Default port the REST API listens on
+pub const DEFAULT_PORT: u16 = 13452;
+
+/// Environment variable holding the base URL of the REST API for external clients
+pub const API_URL_ENV: &str = "ENZYMEML_SUITE_API_URL";
+/// Environment variable holding the API token for external clients
+pub const API_TOKEN_ENV: &str = "ENZYMEML_SUITE_API_TOKEN";
+
+/// Config store key of the API token
+const API_TOKEN_KEY: &str = "api_token";
+/// Config store key of the API settings
+const API_SETTINGS_KEY: &str = "api_settings";
+/// Number of characters of a generated API token
+const TOKEN_LENGTH: usize = 48;
+
+/// User configurable settings of the REST API
+///
+/// Changes to `host` and `port` take effect after restarting the application,
+/// whereas `allow_writes` is applied immediately.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ApiSettings {
+    /// Address the REST API binds to
+    pub host: String,
+    /// Port the REST API listens on
+    pub port: u16,
+    /// Whether routes that modify documents are enabled
+    pub allow_writes: bool,
+}
+
+impl Default for ApiSettings {
+    fn default() -> Self {
+        ApiSettings {
+            host: DEFAULT_HOST.to_string(),
+            port: DEFAULT_PORT,
+            allow_writes: true,
+        }
+    }
+}
+
+impl ApiSettings {
+    /// Checks that the host is a valid IP address and the port is not zero
+    ///
+    /// # Returns
+    /// Result indicating whether the settings are valid
+    pub fn validate(&self) -> Result<(), SuiteError> {
+        self.host.parse::<IpAddr>().map_err(|_| {
+            SuiteError::validation(format!("Invalid API host address: {}", self.host))
+        })?;
+
+        if self.port == 0 {
+            return Err(SuiteError::validation("The API port must not be 0"));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the base URL under which the REST API is reachable
+    pub fn base_url(&self) -> String {
+        format!("http://{}:{}", self.host, self.port)
+    }
+}
+
+/// Loads the API settings from the config store
+///
+/// Falls back to the defaults if no settings have been stored yet or the
+/// stored settings are invalid.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing the API settings or an error
+pub fn load_settings(app: &AppHandle) -> Result<ApiSettings, SuiteError> {
+    let store_path = get_config_store_path()?;
+    let store = app
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
+
+    let settings = store
+        .get(API_SETTINGS_KEY)
+        .and_then(|v| serde_json::from_value::<ApiSettings>(v).ok())
+        .filter(|settings| settings.validate().is_ok())
+        .unwrap_or_default();
+
+    Ok(settings)
+}
+
+/// Validates and persists the API settings in the config store
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+/// * `settings` - The settings to persist
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn save_settings(app: &AppHandle, settings: &ApiSettings) -> Result<(), SuiteError> {
+    settings.validate()?;
+
+    let store_path = get_config_store_path()?;
+    let store = app
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
+
+    store.set(API_SETTINGS_KEY, serde_json::to_value(settings)?);
+    store.save().map_err(|e| SuiteError::io(&store_path, e))
+}
+
+/// Loads the API token from the config store, generating one on first use
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing the API token or an error
+pub fn load_or_create_token(app: &AppHandle) -> Result<String, SuiteError> {
+    let store_path = get_config_store_path()?;
+    let store = app
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
+
+    let token = store
+        .get(API_TOKEN_KEY)
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|token| !token.is_empty());
+
+    match token {
+        Some(token) => Ok(token),
+        None => regenerate_token(app),
+    }
+}
+
+/// Generates a new API token and persists it in the config store
+///
+/// Clients holding the previous token lose access to the API.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing the new API token or an error
+pub fn regenerate_token(app: &AppHandle) -> Result<String, SuiteError> {
+    let store_path = get_config_store_path()?;
+    let store = app
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
+
+    let token = generate_token();
+
+    store.set(API_TOKEN_KEY, serde_json::json!(token));
+    store.save().map_err(|e| SuiteError::io(&store_path, e))?;
+
+    Ok(token)
+}
+
+/// Generates a random API token
+pub fn generate_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(TOKEN_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// Reads the API settings and token from the config store file
+///
+/// Used by processes that do not run the Tauri application and thus have no
+/// access to the store plugin. Falls back to the default settings if no
+/// settings have been stored yet.
+///
+/// # Returns
+/// Result containing the API settings and the stored token, if any, or an error
+/// if the config store cannot be read
+pub fn load_stored_credentials() -> Result<(ApiSettings, Option<String>), SuiteError> {
+    let store_path = get_config_store_path()?;
+
+    if !store_path.exists() {
+        return Ok((ApiSettings::default(), None));
+    }
+
+    let content =
+        std::fs::read_to_string(&store_path).map_err(|e| SuiteError::io(&store_path, e))?;
+    let store: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| SuiteError::parse(format!("Failed to parse config store: {}", e)))?;
+
+    let settings = store
+        .get(API_SETTINGS_KEY)
+        .and_then(|v| serde_json::from_value::<ApiSettings>(v.clone()).ok())
+        .filter(|settings| settings.validate().is_ok())
+        .unwrap_or_default();
+    let token = store
+        .get(API_TOKEN_KEY)
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|token| !token.is_empty());
+
+    Ok((settings, token))
+}
+
+/// Resolves the base URL and token of the REST API outside of the application
+///
+/// Used by processes that do not run the Tauri application, such as the MCP
+/// stdio bridge. The environment variables take precedence, otherwise the
+/// values are read from the config store file written by the application.
+///
+/// # Returns
+/// Result containing the base URL and the API token or an error
+pub fn resolve_client_credentials() -> Result<(String, String), SuiteError> {
+    let url = std::env::var(API_URL_ENV).ok();
+    let token = std::env::var(API_TOKEN_ENV).ok();
+
+    if let (Some(url), Some(token)) = (&url, &token) {
+        return Ok((url.clone(), token.clone()));
+    }
+
+    let (settings, stored_token) = load_stored_credentials()?;
+    let token = token.or(stored_token).ok_or_else(|| {
+        SuiteError::internal("No API token found. Please start the EnzymeML Suite at least once.")
+    })?;
+
+    Ok((url.unwrap_or_else(|| settings.base_url()), token))
+}
+
+/// Request guard for routes that require a valid API token
+pub struct Authenticated;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Authenticated {
+    type Error = &'static str;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let Some(api_state) = req.rocket().state::<Arc<ApiState>>() else {
+            return Outcome::Error((Status::InternalServerError, "API state is not managed"));
+        };
+
+        let provided = req
+            .headers()
+            .get_one("Authorization")
+            .and_then(|value| value.strip_prefix("Bearer "));
+
+        match provided {
+            Some(token) if tokens_match(token, &api_state.token.lock().unwrap()) => {
+                Outcome::Success(Authenticated)
+            }
+            Some(_) => Outcome::Error((Status::Unauthorized, "Invalid API token")),
+            None => Outcome::Error((Status::Unauthorized, "Missing API token")),
+        }
+    }
+}
+
+/// Request guard for routes that modify documents
+///
+/// Requires a valid API token and fails with `403 Forbidden` if write access
+/// has been disabled in the settings.
+pub struct WriteAccess;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for WriteAccess {
+    type Error = &'static str;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match Authenticated::from_request(req).await {
+            Outcome::Success(_) => {}
+            Outcome::Error(e) => return Outcome::Error(e),
+            Outcome::Forward(status) => return Outcome::Forward(status),
+        }
+
+        let allow_writes = req
+            .rocket()
+            .state::<Arc<ApiState>>()
+            .map(|api_state| *api_state.allow_writes.lock().unwrap())
+            .unwrap_or(false);
+
+        if allow_writes {
+            Outcome::Success(WriteAccess)
+        } else {
+            Outcome::Error((Status::Forbidden, "Write access to the API is disabled"))
+        }
+    }
+}
+
+/// Compares two tokens in constant time with respect to their content
+///
+/// # Arguments
+/// * `provided` - The token sent by the client
+/// * `expected` - The token of this install
+///
+/// # Returns
+/// True if both tokens are equal, false otherwise
+fn tokens_match(provided: &str, expected: &str) -> bool {
+    if provided.len() != expected.len() {
+        return false;
+    }
+
+    provided
+        .bytes()
+        .zip(expected.bytes())
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
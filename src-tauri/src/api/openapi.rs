@@ -0,0 +1,484 @@
+//! OpenAPI description of the local REST API
+//!
+//! The schemas of all request and response bodies are generated from the types
+//! used by the route handlers via `schemars`, so the description cannot drift
+//! from the actual envelope. The paths are listed explicitly below and have to
+//! be extended whenever a route is added to `create_rocket`.
+//!
+//! All operations require the API token as bearer token, except for those
+//! explicitly marked as public.
+
+use enzymeml::prelude::EnzymeMLDocument;
+use schemars::generate::SchemaSettings;
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+use serde_json::{json, Value};
+
+use super::auth::ApiSettings;
+use super::{
+    APIResponse, DiffRequest, DocumentEntry, DocumentRequest, EnzymeMLDocResponse, VersionInfo,
+    API_VERSION,
+};
+use crate::actions::jupyter::JupyterTemplate;
+use crate::document::diff::DocumentDiff;
+use crate::states::OpenDocument;
+
+/// Reference to the schema of the response envelope
+const ENVELOPE_REF: &str = "#/components/schemas/APIResponse";
+
+/// Builds the OpenAPI 3 document describing all routes of the REST API
+///
+/// # Arguments
+/// * `settings` - The settings the API has been started with
+///
+/// # Returns
+/// The OpenAPI document as a JSON value
+pub(super) fn document(settings: &ApiSettings) -> Value {
+    let mut generator = SchemaSettings::openapi3().into_generator();
+
+    let envelope = generator.subschema_for::<APIResponse>();
+    let doc_response = enveloped::<EnzymeMLDocResponse>(&mut generator, &envelope);
+    let entries_response = enveloped::<Vec<DocumentEntry>>(&mut generator, &envelope);
+    let id_response = with_data(
+        &envelope,
+        json!({
+            "type": "object",
+            "properties": { "id": { "type": "integer", "format": "int32" } },
+            "required": ["id"]
+        }),
+    );
+    let version_response = enveloped::<VersionInfo>(&mut generator, &envelope);
+    let workspace_response = enveloped::<Vec<OpenDocument>>(&mut generator, &envelope);
+    let diff_response = enveloped::<DocumentDiff>(&mut generator, &envelope);
+    let diff_request = generator.subschema_for::<DiffRequest>();
+    let request = generator.subschema_for::<DocumentRequest>();
+    let document = generator.subschema_for::<EnzymeMLDocument>();
+    let templates = generator.subschema_for::<Vec<JupyterTemplate>>();
+
+    let envelope = json!(envelope);
+    let id_param = path_param("id", "Database ID of the stored document", "integer");
+    let template_param = path_param("template_id", "Identifier of the template", "string");
+    let handle_param = query_param(
+        "handle",
+        "Handle of an open document, the active document if omitted",
+        "string",
+    );
+
+    let paths = json!({
+        "/docs": {
+            "get": operation(
+                "listDocuments",
+                "List all stored documents",
+                "Documents",
+                json!([]),
+                None,
+                json!({
+                    "200": json_response("Titles and IDs of all stored documents", &entries_response),
+                    "500": json_response("The database could not be read", &envelope),
+                }),
+            ),
+            "post": writes(operation(
+                "createDocument",
+                "Store a new document or save an open one",
+                "Documents",
+                json!([handle_param]),
+                Some(json_body(&request)),
+                json!({
+                    "201": json_response("ID of the stored document", &id_response),
+                    "404": json_response("No open document has the given handle", &envelope),
+                    "422": json_response("The request body is invalid", &envelope),
+                    "500": json_response("The document could not be stored", &envelope),
+                }),
+            )),
+        },
+        "/docs/:current": {
+            "get": operation(
+                "getCurrentDocument",
+                "Get the active or a specific open document",
+                "Current document",
+                json!([handle_param]),
+                None,
+                json!({
+                    "200": json_response("The open document", &doc_response),
+                    "404": json_response("No open document has the given handle", &envelope),
+                }),
+            ),
+            "put": writes(operation(
+                "updateCurrentDocument",
+                "Replace the active or a specific open document",
+                "Current document",
+                json!([handle_param]),
+                Some(json_body(&document)),
+                json!({
+                    "200": json_response("The document has been replaced", &envelope),
+                    "404": json_response("No open document has the given handle", &envelope),
+                    "422": json_response("The request body is not a valid EnzymeML document", &envelope),
+                }),
+            )),
+        },
+        "/workspace": {
+            "get": operation(
+                "listOpenDocuments",
+                "List the documents open in the application",
+                "Current document",
+                json!([]),
+                None,
+                json!({
+                    "200": json_response("Handles, titles and IDs of all open documents", &workspace_response),
+                }),
+            ),
+        },
+        "/docs/{id}": {
+            "get": operation(
+                "getDocument",
+                "Get a stored document",
+                "Documents",
+                json!([id_param]),
+                None,
+                json!({
+                    "200": json_response("The stored document", &doc_response),
+                    "404": json_response("No document with the given ID exists", &envelope),
+                    "422": json_response("The stored document cannot be parsed", &envelope),
+                }),
+            ),
+            "put": writes(operation(
+                "updateDocument",
+                "Rename and/or replace the content of a stored document",
+                "Documents",
+                json!([id_param]),
+                Some(json_body(&request)),
+                json!({
+                    "200": json_response("ID of the updated document", &id_response),
+                    "404": json_response("No document with the given ID exists", &envelope),
+                    "422": json_response("The request body is invalid", &envelope),
+                }),
+            )),
+            "delete": writes(operation(
+                "deleteDocument",
+                "Delete a stored document",
+                "Documents",
+                json!([id_param]),
+                None,
+                json!({
+                    "200": json_response("The document has been deleted", &envelope),
+                    "404": json_response("No document with the given ID exists", &envelope),
+                }),
+            )),
+        },
+        "/docs/{id}/load": {
+            "post": writes(operation(
+                "loadDocument",
+                "Load a stored document into the active or a specific open document",
+                "Documents",
+                json!([id_param, handle_param]),
+                None,
+                json!({
+                    "200": json_response("The document has been loaded", &envelope),
+                    "404": json_response("No document with the given ID or no open document with the given handle exists", &envelope),
+                    "422": json_response("The stored document cannot be parsed", &envelope),
+                }),
+            )),
+        },
+        "/diff": {
+            "post": operation(
+                "diffDocuments",
+                "Compare two open, stored or inline documents",
+                "Documents",
+                json!([]),
+                Some(json_body(&diff_request)),
+                json!({
+                    "200": json_response("Added, removed and modified entities", &diff_response),
+                    "404": json_response("A referenced document does not exist", &envelope),
+                    "422": json_response("The request body or a referenced document is invalid", &envelope),
+                }),
+            ),
+        },
+        "/jupyter/templates": {
+            "get": operation(
+                "listJupyterTemplates",
+                "List all available Jupyter notebook templates",
+                "Jupyter",
+                json!([]),
+                None,
+                json!({
+                    "200": json_response("Metadata of all templates", &json!(templates)),
+                }),
+            ),
+        },
+        "/jupyter/templates/{template_id}": {
+            "get": operation(
+                "getJupyterTemplate",
+                "Get a Jupyter notebook template as percent-formatted script",
+                "Jupyter",
+                json!([template_param]),
+                None,
+                json!({
+                    "200": {
+                        "description": "The template as percent-formatted script",
+                        "content": { "text/plain": { "schema": { "type": "string" } } }
+                    },
+                    "404": { "description": "No template with the given ID exists" },
+                }),
+            ),
+        },
+        "/mcp": {
+            "post": operation(
+                "postMcpMessage",
+                "Send a JSON-RPC message to the MCP server",
+                "MCP",
+                json!([]),
+                Some(json!({
+                    "required": true,
+                    "content": { "application/json": { "schema": {
+                        "description": "A JSON-RPC 2.0 message or a batch of messages as defined by the Model Context Protocol",
+                        "oneOf": [{ "type": "object" }, { "type": "array", "items": { "type": "object" } }]
+                    } } },
+                })),
+                json!({
+                    "200": {
+                        "description": "The JSON-RPC response",
+                        "content": { "application/json": { "schema": { "type": "object" } } }
+                    },
+                    "202": { "description": "The notification has been accepted" },
+                }),
+            ),
+            "get": operation(
+                "getMcpStream",
+                "Open a server-sent event stream, which is not offered by this server",
+                "MCP",
+                json!([]),
+                None,
+                json!({
+                    "405": { "description": "The server does not offer a stream" },
+                }),
+            ),
+        },
+        "/health": {
+            "get": public(operation(
+                "getHealth",
+                "Check whether the API is running",
+                "Meta",
+                json!([]),
+                None,
+                json!({
+                    "200": json_response("The API is running", &envelope),
+                }),
+            )),
+        },
+        "/version": {
+            "get": public(operation(
+                "getVersion",
+                "Get the version of the application and the API",
+                "Meta",
+                json!([]),
+                None,
+                json!({
+                    "200": json_response("Version information", &version_response),
+                }),
+            )),
+        },
+        "/openapi.json": {
+            "get": public(operation(
+                "getOpenApi",
+                "Get this OpenAPI description",
+                "Meta",
+                json!([]),
+                None,
+                json!({
+                    "200": {
+                        "description": "The OpenAPI description",
+                        "content": { "application/json": { "schema": { "type": "object" } } }
+                    },
+                }),
+            )),
+        },
+    });
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "EnzymeML Suite API",
+            "description": "Local REST API to access and modify the documents of the EnzymeML Suite.",
+            "version": API_VERSION,
+        },
+        "servers": [{ "url": settings.base_url() }],
+        "security": [{ "apiToken": [] }],
+        "paths": paths,
+        "components": {
+            "schemas": generator.take_definitions(true),
+            "securitySchemes": {
+                "apiToken": {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "description": "Per-install API token shown in the settings of the EnzymeML Suite",
+                },
+            },
+        },
+    })
+}
+
+/// Creates the schema of a response envelope carrying data of type `T`
+///
+/// # Arguments
+/// * `generator` - The generator collecting the component schemas
+/// * `envelope` - Reference to the schema of the response envelope
+///
+/// # Returns
+/// The schema of the envelope with its `data` property narrowed to `T`
+fn enveloped<T: JsonSchema>(generator: &mut SchemaGenerator, envelope: &Schema) -> Value {
+    let data = generator.subschema_for::<T>();
+    with_data(envelope, json!(data))
+}
+
+/// Narrows the `data` property of the response envelope to the given schema
+///
+/// # Arguments
+/// * `envelope` - Reference to the schema of the response envelope
+/// * `data` - The schema of the `data` property
+///
+/// # Returns
+/// The combined schema
+fn with_data(envelope: &Schema, data: Value) -> Value {
+    json!({
+        "allOf": [
+            envelope,
+            { "type": "object", "properties": { "data": data } }
+        ]
+    })
+}
+
+/// Creates an OpenAPI operation object
+///
+/// # Arguments
+/// * `id` - Unique operation ID used by client generators
+/// * `summary` - Short description of the operation
+/// * `tag` - Tag used to group the operation
+/// * `parameters` - Path and query parameters of the operation
+/// * `body` - Optional request body of the operation
+/// * `responses` - Possible responses keyed by status code
+///
+/// # Returns
+/// The operation object
+fn operation(
+    id: &str,
+    summary: &str,
+    tag: &str,
+    parameters: Value,
+    body: Option<Value>,
+    responses: Value,
+) -> Value {
+    let mut operation = json!({
+        "operationId": id,
+        "summary": summary,
+        "tags": [tag],
+        "parameters": parameters,
+        "responses": responses,
+    });
+
+    if let Some(body) = body {
+        operation["requestBody"] = body;
+    }
+
+    operation["responses"]["401"] = json_response(
+        "The API token is missing or invalid",
+        &json!({ "$ref": ENVELOPE_REF }),
+    );
+
+    operation
+}
+
+/// Marks an operation as modifying documents
+///
+/// # Arguments
+/// * `operation` - The operation object to mark
+///
+/// # Returns
+/// The operation object including the response for disabled write access
+fn writes(mut operation: Value) -> Value {
+    operation["responses"]["403"] = json_response(
+        "Write access to the API is disabled",
+        &json!({ "$ref": ENVELOPE_REF }),
+    );
+    operation
+}
+
+/// Marks an operation as accessible without the API token
+///
+/// # Arguments
+/// * `operation` - The operation object to mark
+///
+/// # Returns
+/// The operation object without security requirements
+fn public(mut operation: Value) -> Value {
+    operation["security"] = json!([]);
+    if let Some(responses) = operation["responses"].as_object_mut() {
+        responses.remove("401");
+    }
+    operation
+}
+
+/// Creates a required path parameter object
+///
+/// # Arguments
+/// * `name` - Name of the parameter as used in the path template
+/// * `description` - Description of the parameter
+/// * `type_name` - JSON schema type of the parameter
+///
+/// # Returns
+/// The parameter object
+fn path_param(name: &str, description: &str, type_name: &str) -> Value {
+    json!({
+        "name": name,
+        "in": "path",
+        "required": true,
+        "description": description,
+        "schema": { "type": type_name },
+    })
+}
+
+/// Creates an optional query parameter object
+///
+/// # Arguments
+/// * `name` - Name of the parameter in the query string
+/// * `description` - Description of the parameter
+/// * `type_name` - JSON schema type of the parameter
+///
+/// # Returns
+/// The parameter object
+fn query_param(name: &str, description: &str, type_name: &str) -> Value {
+    json!({
+        "name": name,
+        "in": "query",
+        "required": false,
+        "description": description,
+        "schema": { "type": type_name },
+    })
+}
+
+/// Creates a required JSON request body object
+///
+/// # Arguments
+/// * `schema` - The schema of the request body
+///
+/// # Returns
+/// The request body object
+fn json_body(schema: &Schema) -> Value {
+    json!({
+        "required": true,
+        "content": { "application/json": { "schema": schema } },
+    })
+}
+
+/// Creates a JSON response object
+///
+/// # Arguments
+/// * `description` - Description of the response
+/// * `schema` - The schema of the response body
+///
+/// # Returns
+/// The response object
+fn json_response(description: &str, schema: &Value) -> Value {
+    json!({
+        "description": description,
+        "content": { "application/json": { "schema": schema } },
+    })
+}
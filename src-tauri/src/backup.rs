@@ -0,0 +1,478 @@
+//! Rotating backups of the database
+//!
+//! Backups are consistent copies of the database taken with the online backup
+//! API of SQLite, so they can be written while the application keeps using the
+//! database. While the application is running, a backup is taken whenever the
+//! most recent one is older than the configured interval, and only the
+//! configured number of most recent backups is kept. Each library has its own
+//! subdirectory in the backup directory, so backups are only rotated and
+//! restored within the library they were taken of.
+
+use std::ffi::{c_int, CStr, CString};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use libsqlite3_sys as ffi;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::actions::utils::{get_config_store_path, seconds_since_epoch};
+use crate::db::{self, get_db_path};
+use crate::error::SuiteError;
+use crate::libraries::Library;
+
+/// Interval in which the schedule of the backups is checked
+pub const BACKUP_CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Config store key of the backup settings
+const BACKUP_SETTINGS_KEY: &str = "backup_settings";
+/// Prefix of the backup files
+const BACKUP_PREFIX: &str = "db-";
+/// Extension of the backup files
+const BACKUP_EXTENSION: &str = "sqlite";
+/// Number of pages copied per step of a backup
+const BACKUP_PAGES: c_int = 256;
+/// Delay before retrying a step of a backup while the database is locked
+const BACKUP_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// User configurable schedule of the backups
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct BackupSettings {
+    /// Whether backups are taken automatically
+    pub enabled: bool,
+    /// Hours between two automatic backups
+    pub interval_hours: u32,
+    /// Number of most recent backups that are kept
+    pub keep: u32,
+}
+
+impl Default for BackupSettings {
+    fn default() -> Self {
+        BackupSettings {
+            enabled: true,
+            interval_hours: 24,
+            keep: 7,
+        }
+    }
+}
+
+impl BackupSettings {
+    /// Checks that the interval and the number of kept backups are not zero
+    ///
+    /// # Returns
+    /// Result indicating whether the settings are valid
+    pub fn validate(&self) -> Result<(), SuiteError> {
+        if self.interval_hours == 0 {
+            return Err(SuiteError::validation(
+                "The backup interval must be at least one hour",
+            ));
+        }
+
+        if self.keep == 0 {
+            return Err(SuiteError::validation("At least one backup has to be kept"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Backup of the database
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct BackupEntry {
+    /// File name of the backup within the backup directory
+    pub file: String,
+    /// Time the backup was taken, in seconds since the Unix epoch
+    pub created_at: u64,
+    /// Size of the backup in bytes
+    pub size: u64,
+}
+
+/// Writer of the backups of the database
+#[derive(Debug)]
+pub struct Backups {
+    /// Directory holding the backups of all libraries
+    root: PathBuf,
+}
+
+impl Backups {
+    /// Creates the writer of the backups
+    ///
+    /// # Arguments
+    /// * `root` - Directory holding the backups of all libraries
+    pub fn new(root: PathBuf) -> Self {
+        Backups { root }
+    }
+
+    /// Takes a backup if the most recent one is older than the interval
+    ///
+    /// # Arguments
+    /// * `settings` - The schedule of the backups
+    ///
+    /// # Returns
+    /// Result containing the new backup or `None` if none was due
+    pub fn run_if_due(&self, settings: &BackupSettings) -> Result<Option<BackupEntry>, SuiteError> {
+        if !settings.enabled {
+            return Ok(None);
+        }
+
+        let interval = u64::from(settings.interval_hours) * 60 * 60;
+        let latest = self.list()?.first().map(|entry| entry.created_at);
+        if latest.is_some_and(|latest| seconds_since_epoch() < latest + interval) {
+            return Ok(None);
+        }
+
+        self.create(settings.keep).map(Some)
+    }
+
+    /// Takes a backup of the database and removes the oldest backups
+    ///
+    /// # Arguments
+    /// * `keep` - Number of most recent backups that are kept
+    ///
+    /// # Returns
+    /// Result containing the new backup
+    pub fn create(&self, keep: u32) -> Result<BackupEntry, SuiteError> {
+        let entry = self.write()?;
+        self.rotate(keep)?;
+
+        Ok(entry)
+    }
+
+    /// Lists the backups of the database
+    ///
+    /// # Returns
+    /// Result containing the backups, the most recent first
+    pub fn list(&self) -> Result<Vec<BackupEntry>, SuiteError> {
+        let dir = self.dir()?;
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&dir).map_err(|e| SuiteError::io(&dir, e))? {
+            let entry = entry.map_err(|e| SuiteError::io(&dir, e))?;
+            let Some(file) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            let Some(created_at) = backup_time(&file) else {
+                continue;
+            };
+
+            let size = entry.metadata().map(|meta| meta.len()).unwrap_or_default();
+            entries.push(BackupEntry {
+                file,
+                created_at,
+                size,
+            });
+        }
+
+        entries.sort_by_key(|entry| std::cmp::Reverse(backup_order(&entry.file)));
+        Ok(entries)
+    }
+
+    /// Replaces the content of the database with a backup
+    ///
+    /// A backup of the current content is taken first, so the restore can be
+    /// undone. It is not counted when removing the oldest backups until the
+    /// next backup is taken. The restored content is migrated to the current
+    /// schema.
+    ///
+    /// # Arguments
+    /// * `file` - File name of the backup within the backup directory
+    ///
+    /// # Returns
+    /// Result containing the backup of the replaced content
+    pub fn restore(&self, file: &str) -> Result<BackupEntry, SuiteError> {
+        let path = self.backup_path(file)?;
+        let previous = self.write()?;
+
+        copy_database(&path, Path::new(&get_db_path()?))?;
+        db::migrate()?;
+
+        Ok(previous)
+    }
+
+    /// Removes a backup
+    ///
+    /// # Arguments
+    /// * `file` - File name of the backup within the backup directory
+    pub fn delete(&self, file: &str) -> Result<(), SuiteError> {
+        let path = self.backup_path(file)?;
+        fs::remove_file(&path).map_err(|e| SuiteError::io(&path, e))
+    }
+
+    /// Takes a backup of the database without removing older backups
+    fn write(&self) -> Result<BackupEntry, SuiteError> {
+        let dir = self.dir()?;
+        fs::create_dir_all(&dir).map_err(|e| SuiteError::io(&dir, e))?;
+
+        let created_at = seconds_since_epoch();
+        let (file, path) = reserve_backup(&dir, created_at)?;
+        let partial = path.with_extension("partial");
+
+        let copied = get_db_path().and_then(|db_path| copy_database(Path::new(&db_path), &partial));
+        if let Err(e) = copied {
+            let _ = fs::remove_file(&partial);
+            let _ = fs::remove_file(&path);
+            return Err(e);
+        }
+        fs::rename(&partial, &path).map_err(|e| SuiteError::io(&path, e))?;
+
+        let size = fs::metadata(&path)
+            .map_err(|e| SuiteError::io(&path, e))?
+            .len();
+        Ok(BackupEntry {
+            file,
+            created_at,
+            size,
+        })
+    }
+
+    /// Removes all but the most recent backups
+    fn rotate(&self, keep: u32) -> Result<(), SuiteError> {
+        let dir = self.dir()?;
+        for entry in self.list()?.iter().skip(keep as usize) {
+            let path = dir.join(&entry.file);
+            fs::remove_file(&path).map_err(|e| SuiteError::io(&path, e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the path of a backup
+    ///
+    /// Only plain file names of backups are accepted, so callers cannot
+    /// address other files.
+    fn backup_path(&self, file: &str) -> Result<PathBuf, SuiteError> {
+        let is_plain = Path::new(file).file_name().and_then(|name| name.to_str()) == Some(file);
+        if !is_plain || backup_time(file).is_none() {
+            return Err(SuiteError::validation(format!(
+                "'{}' is not a backup of the database",
+                file
+            )));
+        }
+
+        let path = self.dir()?.join(file);
+        if !path.exists() {
+            return Err(SuiteError::not_found("Backup", file));
+        }
+
+        Ok(path)
+    }
+
+    /// Gets the directory holding the backups of the current library
+    fn dir(&self) -> Result<PathBuf, SuiteError> {
+        Ok(self.root.join(library_dir(&db::active_library()?)))
+    }
+}
+
+/// Loads the backup settings from the config store
+///
+/// Falls back to the defaults if no settings have been stored yet or the
+/// stored settings are invalid.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing the backup settings or an error
+pub fn load_settings(app: &AppHandle) -> Result<BackupSettings, SuiteError> {
+    let store_path = get_config_store_path()?;
+    let store = app
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
+
+    let settings = store
+        .get(BACKUP_SETTINGS_KEY)
+        .and_then(|v| serde_json::from_value::<BackupSettings>(v).ok())
+        .filter(|settings| settings.validate().is_ok())
+        .unwrap_or_default();
+
+    Ok(settings)
+}
+
+/// Validates and persists the backup settings in the config store
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+/// * `settings` - The settings to persist
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn save_settings(app: &AppHandle, settings: &BackupSettings) -> Result<(), SuiteError> {
+    settings.validate()?;
+
+    let store_path = get_config_store_path()?;
+    let store = app
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
+
+    store.set(BACKUP_SETTINGS_KEY, serde_json::to_value(settings)?);
+    store.save().map_err(|e| SuiteError::io(&store_path, e))
+}
+
+/// Copies a database with the online backup API of SQLite
+///
+/// The copy is consistent even if the source is written meanwhile. An
+/// existing destination is overwritten.
+///
+/// # Arguments
+/// * `source` - Path of the database to copy
+/// * `destination` - Path of the copy
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn copy_database(source: &Path, destination: &Path) -> Result<(), SuiteError> {
+    let source = Connection::open(source, ffi::SQLITE_OPEN_READONLY)?;
+    let destination = Connection::open(
+        destination,
+        ffi::SQLITE_OPEN_READWRITE | ffi::SQLITE_OPEN_CREATE,
+    )?;
+    let main = c"main";
+
+    // SAFETY: both handles are open for the lifetime of the backup, which is
+    // finished before they are closed.
+    unsafe {
+        let backup =
+            ffi::sqlite3_backup_init(destination.0, main.as_ptr(), source.0, main.as_ptr());
+        if backup.is_null() {
+            return Err(destination.error());
+        }
+
+        loop {
+            match ffi::sqlite3_backup_step(backup, BACKUP_PAGES) {
+                ffi::SQLITE_OK => {}
+                ffi::SQLITE_BUSY | ffi::SQLITE_LOCKED => thread::sleep(BACKUP_RETRY_DELAY),
+                _ => break,
+            }
+        }
+
+        if ffi::sqlite3_backup_finish(backup) != ffi::SQLITE_OK {
+            return Err(destination.error());
+        }
+    }
+
+    Ok(())
+}
+
+/// Reserves the file of a new backup without replacing an existing one
+///
+/// Backups are named after the second they are taken in. Backups taken within
+/// the same second are told apart by a counter, e.g. `db-1700000000-1.sqlite`.
+///
+/// # Arguments
+/// * `dir` - Directory holding the backups of the library
+/// * `created_at` - Time the backup is taken, in seconds since the Unix epoch
+///
+/// # Returns
+/// Result containing the file name and path of the empty backup file
+fn reserve_backup(dir: &Path, created_at: u64) -> Result<(String, PathBuf), SuiteError> {
+    for counter in 0u32.. {
+        let file = match counter {
+            0 => format!("{}{}.{}", BACKUP_PREFIX, created_at, BACKUP_EXTENSION),
+            _ => format!(
+                "{}{}-{}.{}",
+                BACKUP_PREFIX, created_at, counter, BACKUP_EXTENSION
+            ),
+        };
+        let path = dir.join(&file);
+
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(_) => return Ok((file, path)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(SuiteError::io(&path, e)),
+        }
+    }
+
+    Err(SuiteError::internal("No free name for the backup"))
+}
+
+/// Returns the time a backup was taken from its file name
+fn backup_time(file: &str) -> Option<u64> {
+    backup_order(file).map(|(created_at, _)| created_at)
+}
+
+/// Returns the time and counter of a backup from its file name
+///
+/// Backups are ordered by these, as backups taken within the same second only
+/// differ in their counter.
+fn backup_order(file: &str) -> Option<(u64, u32)> {
+    let stem = file
+        .strip_prefix(BACKUP_PREFIX)?
+        .strip_suffix(BACKUP_EXTENSION)?
+        .strip_suffix('.')?;
+
+    match stem.split_once('-') {
+        Some((created_at, counter)) => Some((created_at.parse().ok()?, counter.parse().ok()?)),
+        None => Some((stem.parse().ok()?, 0)),
+    }
+}
+
+/// Returns the name of the directory holding the backups of a library
+///
+/// Characters that are not safe in file names are replaced by underscores.
+fn library_dir(library: &Library) -> String {
+    library
+        .name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Raw connection to an SQLite database, closed when dropped
+struct Connection(*mut ffi::sqlite3);
+
+impl Connection {
+    /// Opens a database
+    ///
+    /// # Arguments
+    /// * `path` - Path of the database
+    /// * `flags` - Flags of `sqlite3_open_v2`
+    fn open(path: &Path, flags: c_int) -> Result<Self, SuiteError> {
+        let name =
+            CString::new(path.to_string_lossy().as_bytes()).map_err(|e| SuiteError::io(path, e))?;
+
+        let mut handle = std::ptr::null_mut();
+        // SAFETY: `name` is a valid C string and `handle` receives the new connection.
+        let result =
+            unsafe { ffi::sqlite3_open_v2(name.as_ptr(), &mut handle, flags, std::ptr::null()) };
+
+        // A handle is returned even if opening fails and has to be closed
+        let connection = Connection(handle);
+        if result != ffi::SQLITE_OK {
+            return Err(SuiteError::io(path, connection.error().to_string()));
+        }
+
+        Ok(connection)
+    }
+
+    /// Returns the most recent error of the connection
+    fn error(&self) -> SuiteError {
+        // SAFETY: the handle is valid until the connection is dropped and the
+        // message is copied before the next call on the handle.
+        let message = unsafe { CStr::from_ptr(ffi::sqlite3_errmsg(self.0)) };
+        SuiteError::database(message.to_string_lossy())
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // SAFETY: the handle is not used after it has been closed.
+        unsafe {
+            ffi::sqlite3_close(self.0);
+        }
+    }
+}
@@ -0,0 +1,423 @@
+//! Headless command line interface of the EnzymeML Suite
+//!
+//! Exposes the document logic of the desktop application to batch jobs and
+//! scripts. All commands operate on files or on the document database shared
+//! with the application, and `serve` starts the local REST API without a window.
+//! Started with `--mcp-stdio`, it bridges MCP clients to the running application.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use clap::{Parser, Subcommand};
+use enzymeml::prelude::EnzymeMLDocument;
+use enzymeml::sbml::{sbml_writer, EnzymeMLVersion};
+use enzymeml::validation::consistency::check_consistency;
+
+use enzymeml_suite::actions::validation::Report;
+use enzymeml_suite::api::auth::{self, API_TOKEN_ENV};
+use enzymeml_suite::api::create_rocket;
+use enzymeml_suite::backup::copy_database;
+use enzymeml_suite::db::{self, get_db_path};
+use enzymeml_suite::document::equations::derive_equations;
+use enzymeml_suite::docutils::serialize_doc;
+use enzymeml_suite::events::NoopSink;
+use enzymeml_suite::io::dataio::{
+    import_measurements, read_document, retrieve_all_documents, retrieve_document_by_id,
+};
+use enzymeml_suite::io::library::{export_archive, import_archive, ConflictStrategy};
+use enzymeml_suite::mcp::stdio;
+use enzymeml_suite::states::{ApiState, EnzymeMLState, Workspace};
+
+/// Command line interface of the EnzymeML Suite
+#[derive(Parser)]
+#[command(name = "enzymeml-suite-cli", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Commands of the command line interface
+#[derive(Subcommand)]
+enum Command {
+    /// Checks an EnzymeML document for consistency
+    Validate {
+        /// Path of the EnzymeML JSON document
+        document: PathBuf,
+        /// Print the validation report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Converts an EnzymeML document to JSON, an Excel measurement table or SBML
+    Convert {
+        /// Path of the EnzymeML JSON document
+        input: PathBuf,
+        /// Output path, the format is derived from the extension (.json, .xlsx or .omex for
+        /// an SBML COMBINE archive)
+        output: PathBuf,
+    },
+    /// Adds the measurements of an Excel file to an EnzymeML document
+    Import {
+        /// Path of the EnzymeML JSON document
+        document: PathBuf,
+        /// Path of the Excel file holding the measurements
+        measurements: PathBuf,
+        /// Where to write the resulting document, defaults to overwriting the input
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Derives the ODE equations of a document from the kinetic laws of its reactions
+    Derive {
+        /// Path of the EnzymeML JSON document
+        document: PathBuf,
+        /// Where to write the resulting document, defaults to overwriting the input
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Lists the documents stored in the database
+    List {
+        /// Print the documents as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Exports a document stored in the database as JSON
+    Export {
+        /// Database ID of the document
+        id: i32,
+        /// Where to write the document, defaults to stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Exports the whole library of the database to a portable archive
+    ExportLibrary {
+        /// Where to write the archive
+        output: PathBuf,
+    },
+    /// Imports a library archive into the database
+    ImportLibrary {
+        /// Path of the archive
+        archive: PathBuf,
+        /// Overwrite existing entries that differ from the archived ones
+        #[arg(long, conflicts_with = "keep_both")]
+        replace: bool,
+        /// Add archived entries that differ from existing ones under a new name
+        #[arg(long)]
+        keep_both: bool,
+    },
+    /// Copies the database to a file, while the application may keep using it
+    Backup {
+        /// Where to write the copy
+        output: PathBuf,
+    },
+    /// Starts the REST API without the desktop application
+    Serve {
+        /// Address to bind to, defaults to the address in the settings
+        #[arg(long)]
+        host: Option<String>,
+        /// Port to listen on, defaults to the port in the settings
+        #[arg(long)]
+        port: Option<u16>,
+        /// Reject all requests that modify documents
+        #[arg(long)]
+        read_only: bool,
+        /// EnzymeML JSON document to serve as current document
+        #[arg(long)]
+        document: Option<PathBuf>,
+    },
+}
+
+#[tokio::main]
+async fn main() {
+    // Act as MCP stdio bridge, which the GUI binary cannot do on Windows
+    if std::env::args().any(|arg| arg == stdio::STDIO_FLAG) {
+        if let Err(e) = stdio::run().await {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Validate { document, json } => validate(&document, json),
+        Command::Convert { input, output } => convert(&input, &output),
+        Command::Import {
+            document,
+            measurements,
+            output,
+        } => import(&document, &measurements, output.as_deref()),
+        Command::Derive { document, output } => derive(&document, output.as_deref()),
+        Command::List { json } => list(json),
+        Command::Export { id, output } => export(id, output.as_deref()),
+        Command::ExportLibrary { output } => export_library(&output),
+        Command::ImportLibrary {
+            archive,
+            replace,
+            keep_both,
+        } => import_library(&archive, replace, keep_both),
+        Command::Backup { output } => backup(&output),
+        Command::Serve {
+            host,
+            port,
+            read_only,
+            document,
+        } => serve(host, port, read_only, document.as_deref()).await,
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Prints the validation report of a document
+///
+/// Exits with a non-zero code if the document is not valid, so the command can
+/// be used as check in batch jobs.
+///
+/// # Arguments
+/// * `path` - Path of the EnzymeML JSON document
+/// * `json` - Whether to print the report as JSON
+fn validate(path: &Path, json: bool) -> Result<(), String> {
+    let doc = read_document(path)?;
+    let report: Report = check_consistency(&doc).into();
+
+    if json {
+        let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        for result in report.errors.iter() {
+            println!(
+                "{:?} at {}: {}",
+                result.severity, result.location, result.message
+            );
+        }
+    }
+
+    if report.is_valid {
+        if !json {
+            println!("{} is valid", path.display());
+        }
+        Ok(())
+    } else {
+        Err(format!("{} is not valid", path.display()))
+    }
+}
+
+/// Converts a document to the format given by the extension of the output path
+///
+/// SBML is written as EnzymeML v2 COMBINE archive, which holds the SBML model
+/// along with the measurement data.
+///
+/// # Arguments
+/// * `input` - Path of the EnzymeML JSON document
+/// * `output` - Path of the converted document
+fn convert(input: &Path, output: &Path) -> Result<(), String> {
+    let doc = read_document(input)?;
+
+    let extension = output
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase());
+
+    match extension.as_deref() {
+        Some("json") => write_document(&doc, output),
+        Some("xlsx") => doc
+            .to_excel(&output.to_path_buf(), false, true)
+            .map_err(|e| format!("Failed to export to Excel: {}", e)),
+        Some("omex") => sbml_writer::to_omex(&doc, EnzymeMLVersion::V2)
+            .map_err(|e| format!("Failed to export to SBML: {}", e))?
+            .save(output)
+            .map_err(|e| format!("Failed to write {}: {}", output.display(), e)),
+        _ => Err(format!(
+            "Unsupported output format: {} (only .json, .xlsx and .omex are supported)",
+            output.display()
+        )),
+    }
+}
+
+/// Adds the measurements of an Excel file to a document
+///
+/// # Arguments
+/// * `path` - Path of the EnzymeML JSON document
+/// * `measurements` - Path of the Excel file holding the measurements
+/// * `output` - Where to write the resulting document, if not to the input
+fn import(path: &Path, measurements: &Path, output: Option<&Path>) -> Result<(), String> {
+    let mut doc = read_document(path)?;
+    let count = import_measurements(&mut doc, measurements)?;
+
+    write_document(&doc, output.unwrap_or(path))?;
+    eprintln!("Imported {} measurements", count);
+
+    Ok(())
+}
+
+/// Derives the ODE equations of a document from its reactions
+///
+/// # Arguments
+/// * `path` - Path of the EnzymeML JSON document
+/// * `output` - Where to write the resulting document, if not to the input
+fn derive(path: &Path, output: Option<&Path>) -> Result<(), String> {
+    let state = EnzymeMLState::with_document(read_document(path)?);
+    derive_equations(&state)?;
+
+    let doc = state.doc.lock().unwrap();
+    write_document(&doc, output.unwrap_or(path))
+}
+
+/// Prints the IDs and titles of all stored documents
+///
+/// # Arguments
+/// * `json` - Whether to print the documents as JSON
+fn list(json: bool) -> Result<(), String> {
+    db::init()?;
+    let entries = retrieve_all_documents()?;
+
+    if json {
+        let entries = entries
+            .iter()
+            .map(|entry| serde_json::json!({ "id": entry.id, "title": entry.title }))
+            .collect::<Vec<_>>();
+        let json = serde_json::to_string_pretty(&entries).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        for entry in entries.iter() {
+            println!("{}\t{}", entry.id, entry.title);
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a stored document to a file or stdout
+///
+/// # Arguments
+/// * `id` - Database ID of the document
+/// * `output` - Where to write the document, stdout if not given
+fn export(id: i32, output: Option<&Path>) -> Result<(), String> {
+    db::init()?;
+    let entry = retrieve_document_by_id(id)?;
+
+    match output {
+        Some(path) => std::fs::write(path, entry.content)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e)),
+        None => {
+            println!("{}", entry.content);
+            Ok(())
+        }
+    }
+}
+
+/// Writes the whole library to an archive
+///
+/// # Arguments
+/// * `output` - Where to write the archive
+fn export_library(output: &Path) -> Result<(), String> {
+    db::init()?;
+    let archive = export_archive(output)?;
+
+    eprintln!(
+        "Exported {} documents, {} small molecules, {} proteins, {} vessels, {} creators and {} laws",
+        archive.documents.len(),
+        archive.small_molecules.len(),
+        archive.proteins.len(),
+        archive.vessels.len(),
+        archive.creators.len(),
+        archive.laws.len()
+    );
+    Ok(())
+}
+
+/// Imports an archive into the library and prints the outcome
+///
+/// Conflicting entries are skipped unless `replace` or `keep_both` is set.
+///
+/// # Arguments
+/// * `archive` - Path of the archive
+/// * `replace` - Whether conflicting entries overwrite existing ones
+/// * `keep_both` - Whether conflicting entries are added under a new name
+fn import_library(archive: &Path, replace: bool, keep_both: bool) -> Result<(), String> {
+    db::init()?;
+
+    let strategy = match (replace, keep_both) {
+        (true, _) => ConflictStrategy::Replace,
+        (_, true) => ConflictStrategy::KeepBoth,
+        _ => ConflictStrategy::Skip,
+    };
+    let report = import_archive(archive, strategy)?;
+
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    println!("{}", json);
+    Ok(())
+}
+
+/// Copies the database with the online backup API of SQLite
+///
+/// # Arguments
+/// * `output` - Where to write the copy
+fn backup(output: &Path) -> Result<(), String> {
+    db::init()?;
+    copy_database(Path::new(&get_db_path()?), output)?;
+    Ok(())
+}
+
+/// Serves the REST API until the process is terminated
+///
+/// Host, port and token default to the values stored by the desktop
+/// application. The token can be overridden via the environment. If neither
+/// is available, a token is generated for this run and printed to stderr.
+///
+/// # Arguments
+/// * `host` - Address to bind to, if not the stored one
+/// * `port` - Port to listen on, if not the stored one
+/// * `read_only` - Whether to reject requests that modify documents
+/// * `document` - Document to serve as current document, if any
+async fn serve(
+    host: Option<String>,
+    port: Option<u16>,
+    read_only: bool,
+    document: Option<&Path>,
+) -> Result<(), String> {
+    db::init()?;
+
+    let (mut settings, stored_token) = auth::load_stored_credentials()?;
+    settings.host = host.unwrap_or(settings.host);
+    settings.port = port.unwrap_or(settings.port);
+    settings.allow_writes = settings.allow_writes && !read_only;
+    settings.validate()?;
+
+    let token = match std::env::var(API_TOKEN_ENV).ok().or(stored_token) {
+        Some(token) => token,
+        None => {
+            let token = auth::generate_token();
+            eprintln!("Generated API token for this session: {}", token);
+            token
+        }
+    };
+
+    let state = match document {
+        Some(path) => EnzymeMLState::with_document(read_document(path)?),
+        None => EnzymeMLState::default(),
+    };
+
+    let api_state = Arc::new(ApiState::new(token, settings.allow_writes));
+    eprintln!("Serving the EnzymeML Suite API at {}", settings.base_url());
+
+    let workspace = Arc::new(Workspace::new(state));
+    create_rocket(workspace, Arc::new(NoopSink), settings, api_state)
+        .launch()
+        .await
+        .map_err(|e| format!("Failed to launch the API: {}", e))?;
+
+    Ok(())
+}
+
+/// Writes a document as pretty printed JSON
+///
+/// # Arguments
+/// * `doc` - The EnzymeML document to write
+/// * `path` - Path of the written file
+fn write_document(doc: &EnzymeMLDocument, path: &Path) -> Result<(), String> {
+    let json = serialize_doc(doc)?;
+    std::fs::write(path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
@@ -0,0 +1,277 @@
+//! TypeScript bindings of the Tauri commands and events
+//!
+//! The bindings are generated by `tauri-specta` from the signatures of the
+//! commands and the event types, so the frontend cannot drift from the
+//! backend. The same builder provides the invoke handler and mounts the
+//! events of the application, so every command is registered exactly once.
+//! Debug builds regenerate the bindings on start, and the `bindings`
+//! integration test fails if the checked in file is out of date.
+
+use std::path::Path;
+
+use specta_typescript::{BigIntExportBehavior, Typescript};
+use tauri::Wry;
+use tauri_specta::{collect_commands, collect_events, Builder};
+
+use crate::actions::{
+    self, diff, enzmldoc, equations, external, import, jupyter, measurements, parameters, proteins,
+    reactions, recovery, settings, smallmols, units, validation, vessels, windows, workspace,
+};
+use crate::{events, io};
+
+/// Directory of the generated bindings, relative to the crate root
+pub const BINDINGS_DIR: &str = "../src/commands";
+
+/// File of the generated bindings within [`BINDINGS_DIR`]
+pub const BINDINGS_FILE: &str = "bindings.ts";
+
+/// Returns the builder holding all commands and typed events of the application
+///
+/// Typed events can only be emitted once they are mounted on the application,
+/// which is done with this builder during setup.
+pub fn builder() -> Builder<Wry> {
+    Builder::<Wry>::new()
+        .commands(collect_commands![
+            // Data IO
+            io::dataio::save,
+            io::dataio::load,
+            io::dataio::list_all_entries,
+            io::dataio::list_documents,
+            io::dataio::rename_stored_document,
+            io::dataio::duplicate_stored_document,
+            io::dataio::trash_stored_document,
+            io::dataio::restore_stored_document,
+            io::dataio::delete_stored_document,
+            io::dataio::empty_document_trash,
+            io::dataio::tag_stored_document,
+            io::dataio::describe_stored_document,
+            io::search::search_documents,
+            io::search::rebuild_search_index,
+            io::projection::get_projection_enabled,
+            io::projection::set_projection_enabled,
+            io::projection::rebuild_document_projection,
+            io::projection::query_parameters,
+            io::projection::query_measurements,
+            io::library::export_library,
+            io::library::import_library,
+            io::dataio::new_document,
+            io::dataio::export_to_json,
+            io::dataio::save_to_file,
+            io::dataio::save_to_file_as,
+            io::dataio::get_state,
+            io::dataio::export_measurements,
+            io::dataio::import_excel_meas,
+            io::dataio::load_json,
+            io::dataio::load_json_from_path,
+            io::dataio::import_excel_from_path,
+            io::dataio::handle_file_drop,
+            io::recent::list_recent_files,
+            io::recent::clear_recent_files,
+            // Database
+            io::dbops::save_mol_to_db,
+            io::dbops::filter_small_mols,
+            io::dbops::get_all_small_mols,
+            io::dbops::get_small_mol_by_id,
+            io::dbops::update_small_mol_in_db,
+            io::dbops::delete_small_mol_from_db,
+            io::dbops::save_protein_to_db,
+            io::dbops::filter_proteins,
+            io::dbops::get_protein_by_id,
+            io::dbops::get_all_proteins,
+            io::dbops::add_protein_from_db,
+            io::dbops::update_protein_in_db,
+            io::dbops::delete_protein_from_db,
+            io::dbops::save_vessel_to_db,
+            io::dbops::filter_vessels,
+            io::dbops::get_vessel_by_id,
+            io::dbops::get_all_vessels,
+            io::dbops::add_vessel_from_db,
+            io::dbops::update_vessel_in_db,
+            io::dbops::delete_vessel_from_db,
+            io::dbops::save_creators_to_db,
+            io::dbops::filter_creators,
+            io::dbops::get_creator_by_id,
+            io::dbops::get_all_creators,
+            io::dbops::add_creator_from_db,
+            io::dbops::update_creator_in_db,
+            io::dbops::delete_creator_from_db,
+            io::dbops::get_law_by_id,
+            io::dbops::get_all_laws,
+            io::dbops::update_law_in_db,
+            io::dbops::delete_law_from_db,
+            // Workspace
+            workspace::list_open_documents,
+            workspace::open_new_document,
+            workspace::open_document,
+            workspace::switch_document,
+            workspace::close_document,
+            // Recovery
+            recovery::list_recovery_snapshots,
+            recovery::restore_recovery_snapshot,
+            recovery::discard_recovery_snapshot,
+            // Backups of the database
+            actions::backup::create_backup,
+            actions::backup::list_backups,
+            actions::backup::restore_backup,
+            actions::backup::delete_backup,
+            // Libraries
+            actions::libraries::get_database_status,
+            actions::libraries::list_libraries,
+            actions::libraries::add_library,
+            actions::libraries::remove_library,
+            actions::libraries::switch_library,
+            // External changes of document files
+            external::reload_from_file,
+            external::keep_local_changes,
+            external::merge_with_file,
+            // Comparison of documents
+            diff::diff_documents,
+            // Import of other documents
+            import::import_document,
+            // EnzymeML Document
+            enzmldoc::get_all_species_ids,
+            enzmldoc::get_all_non_constant_species_ids,
+            enzmldoc::get_species_name,
+            enzmldoc::set_title,
+            enzmldoc::get_all_species,
+            enzmldoc::create_document,
+            enzmldoc::get_stats,
+            // Units
+            units::get_unit,
+            units::get_unit_group,
+            units::get_unit_groups,
+            // Small Molecules
+            smallmols::create_small_mol,
+            smallmols::get_small_mol,
+            smallmols::update_small_mol,
+            smallmols::delete_small_mol,
+            smallmols::list_small_mols,
+            smallmols::list_small_mol_smiles,
+            smallmols::add_small_mol,
+            smallmols::add_small_mols,
+            // Vessels
+            vessels::create_vessel,
+            vessels::get_vessel,
+            vessels::update_vessel,
+            vessels::delete_vessel,
+            vessels::list_vessels,
+            vessels::add_vessel,
+            vessels::add_vessels,
+            // Proteins
+            proteins::create_protein,
+            proteins::get_protein,
+            proteins::update_protein,
+            proteins::delete_protein,
+            proteins::list_proteins,
+            proteins::add_protein,
+            proteins::add_proteins,
+            // Reactions
+            reactions::create_reaction,
+            reactions::get_reaction,
+            reactions::update_reaction,
+            reactions::delete_reaction,
+            reactions::list_reactions,
+            reactions::add_reaction,
+            reactions::add_reactions,
+            // Equations
+            equations::update_equation,
+            equations::get_equation,
+            equations::delete_equation,
+            equations::create_equation,
+            equations::list_equations,
+            equations::derive_from_reactions,
+            // Parameters
+            parameters::list_parameters,
+            parameters::create_parameter,
+            parameters::get_parameter,
+            parameters::update_parameter,
+            parameters::partial_update_parameter,
+            parameters::delete_parameter,
+            // Measurements
+            measurements::create_measurement,
+            measurements::get_measurement,
+            measurements::get_datapoints,
+            measurements::update_measurement,
+            measurements::delete_measurement,
+            measurements::list_measurements,
+            measurements::add_measurement,
+            measurements::add_measurements,
+            // Windows
+            windows::open_visualisation,
+            // Settings
+            settings::install_mcp_server,
+            settings::get_openai_token,
+            settings::set_openai_token,
+            settings::get_api_settings,
+            settings::set_api_settings,
+            settings::get_api_token,
+            settings::regenerate_api_token,
+            settings::get_backup_settings,
+            settings::set_backup_settings,
+            // Jupyter commands
+            jupyter::get_jupyter_sessions,
+            jupyter::kill_jupyter,
+            jupyter::start_jupyter,
+            jupyter::detect_python_installations,
+            jupyter::list_detected_pythons,
+            jupyter::get_selected_python,
+            jupyter::set_selected_python,
+            jupyter::add_python_env,
+            jupyter::list_custom_python_envs,
+            jupyter::install_jupyter_lab,
+            jupyter::is_jupyter_lab_installed,
+            jupyter::get_jupyter_template_metadata,
+            jupyter::add_template_to_project,
+            jupyter::open_project_folder,
+            // Validation commands
+            validation::get_validation_report,
+            validation::get_validation_report_by_identifier,
+            // MCP commands
+            actions::mcp::register_mcp,
+            actions::mcp::unregister_mcp,
+            actions::mcp::preview_mcp_config,
+            actions::mcp::is_mcp_registered,
+            actions::mcp::is_mcp_binary_installed,
+            actions::mcp::get_mcp_status,
+            actions::mcp::repair_mcp,
+        ])
+        .events(collect_events![
+            events::UpdateDocument,
+            events::UpdateReport,
+            events::UpdateSmallMols,
+            events::UpdateProteins,
+            events::UpdateVessels,
+            events::UpdateReactions,
+            events::UpdateMeasurements,
+            events::UpdateParameters,
+            events::UpdateEquations,
+            events::UpdateVis,
+            events::DocumentChanged,
+            events::UpdateWorkspace,
+            events::FileChanged,
+            events::NavigateTo,
+            jupyter::JupyterInstallOutput,
+            jupyter::JupyterInstallStatus,
+            jupyter::JupyterUpdate,
+            actions::mcp::McpInstallOutput,
+            actions::mcp::McpInstallStatus,
+            actions::mcp::McpRegisterOutput,
+            actions::mcp::McpRegisterStatus,
+        ])
+}
+
+/// Exports the bindings into a directory
+///
+/// # Arguments
+/// * `dir` - Directory to write the file to, usually [`BINDINGS_DIR`]
+///
+/// # Returns
+/// Result indicating whether the file could be written
+pub fn export(dir: &Path) -> Result<(), String> {
+    let language = Typescript::default().bigint(BigIntExportBehavior::Number);
+    let path = dir.join(BINDINGS_FILE);
+
+    builder()
+        .export(language, &path)
+        .map_err(|e| format!("Failed to export {}: {}", path.display(), e))
+}
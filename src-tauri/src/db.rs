@@ -3,121 +3,323 @@
 //! This module handles all database operations including initialization,
 //! connection management, and migrations. It uses SQLite as the database
 //! backend and diesel for ORM functionality.
+//!
+//! The application works with one library, i.e. database, at a time, see
+//! [`crate::libraries`]. Connections to it are handed out by a pool, which is
+//! replaced when switching to another library. If the database cannot be
+//! opened, the application keeps running and database operations fail with a
+//! [`SuiteError::Database`] describing the cause.
 
 use std::fs;
 use std::path::Path;
+use std::sync::RwLock;
+use std::time::Duration;
 
 use diesel::prelude::*;
+use diesel::r2d2::{self, ConnectionManager, CustomizeConnection, Pool, PooledConnection};
 use diesel::sqlite::SqliteConnection;
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+use crate::error::SuiteError;
+use crate::libraries::{self, Library};
 
 const MIGRATIONS: EmbeddedMigrations = embed_migrations!();
 const DEBUG_DB_PATH: &str = "./db.sqlite";
+/// Maximum number of connections kept by the pool
+const POOL_SIZE: u32 = 8;
+/// Time to wait for a connection before the database is considered unavailable
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+/// Time in milliseconds a statement waits for locks held by other connections
+///
+/// Libraries on network drives may be used by several instances at once.
+const BUSY_TIMEOUT_MS: u32 = 5000;
+
+/// Escape character of the patterns built by [`contains_pattern`]
+pub const LIKE_ESCAPE: char = '\\';
+
+/// Pool of connections to the database
+pub type DbPool = Pool<ConnectionManager<SqliteConnection>>;
+/// Connection to the database borrowed from the pool
+pub type DbConnection = PooledConnection<ConnectionManager<SqliteConnection>>;
+
+/// Library the application currently works with
+struct Database {
+    /// The library
+    library: Library,
+    /// Pool of connections to the library or the reason it is unavailable
+    pool: Result<DbPool, SuiteError>,
+}
+
+lazy_static! {
+    static ref DATABASE: RwLock<Option<Database>> = RwLock::new(None);
+}
 
-/// Initializes the database by creating the database file if it doesn't exist
-/// and running any pending migrations.
-///
-/// This function should be called when the application starts to ensure
-/// the database is properly set up.
-///
-/// # Panics
-/// * If the database file cannot be created
-/// * If the database directory cannot be created
-/// * If migrations fail to run
-pub fn init() {
-    println!("Initializing database");
-    if !db_file_exists() {
-        println!("Database file does not exist, creating it");
-        create_db_file();
+/// Status of the library the application currently works with
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct DatabaseStatus {
+    /// Name of the library
+    pub library: String,
+    /// Path to the database file of the library
+    pub path: String,
+    /// Whether the database could be opened
+    pub available: bool,
+    /// Reason the database is unavailable
+    pub error: Option<String>,
+}
+
+/// Applies the connection settings whenever the pool opens a connection
+#[derive(Debug)]
+struct ConnectionOptions;
+
+impl CustomizeConnection<SqliteConnection, r2d2::Error> for ConnectionOptions {
+    fn on_acquire(&self, connection: &mut SqliteConnection) -> Result<(), r2d2::Error> {
+        diesel::sql_query(format!("PRAGMA busy_timeout = {}", BUSY_TIMEOUT_MS))
+            .execute(connection)
+            .map(|_| ())
+            .map_err(r2d2::Error::QueryError)
     }
-    run_migrations();
 }
 
-/// Establishes a connection to the SQLite database.
+/// Opens the active library when the application starts
+///
+/// The active library is taken from the environment variable
+/// [`libraries::DB_PATH_ENV`] or else from the config store. The database file
+/// is created if it doesn't exist and any pending migrations are run.
+///
+/// If the library cannot be resolved or the database cannot be opened, the
+/// failure is remembered, so [`establish_connection`] and [`status`] report
+/// it, and the error is returned.
 ///
 /// # Returns
-/// * `SqliteConnection` - A connection to the database
+/// Result indicating whether the database could be opened
+pub fn init() -> Result<(), SuiteError> {
+    eprintln!("Initializing database");
+    let (library, pool) = match libraries::resolve_library() {
+        Ok(library) => {
+            let pool = connect(&library.path);
+            (library, pool)
+        }
+        // The personal library is reported as unavailable, without a path
+        Err(e) => (
+            Library {
+                name: libraries::DEFAULT_LIBRARY.to_string(),
+                path: String::new(),
+            },
+            Err(e),
+        ),
+    };
+    let result = pool.as_ref().map(|_| ()).map_err(Clone::clone);
+
+    *DATABASE.write().unwrap() = Some(Database { library, pool });
+    result
+}
+
+/// Switches to another library
 ///
-/// # Panics
-/// * If the connection cannot be established
-pub fn establish_db_connection() -> SqliteConnection {
-    let db_path = get_db_path();
+/// The database file is created if it doesn't exist and any pending
+/// migrations are run. If the library cannot be opened, the application keeps
+/// working with the current library.
+///
+/// # Arguments
+/// * `library` - The library to switch to
+///
+/// # Returns
+/// Result indicating whether the library could be opened
+pub fn open(library: Library) -> Result<(), SuiteError> {
+    let pool = connect(&library.path)?;
+    *DATABASE.write().unwrap() = Some(Database {
+        library,
+        pool: Ok(pool),
+    });
 
-    SqliteConnection::establish(&db_path)
-        .unwrap_or_else(|_| panic!("Error connecting to {}", db_path))
+    Ok(())
 }
 
-/// Runs any pending database migrations.
+/// Runs any pending migrations on the current library
 ///
-/// This function applies any new migrations that haven't been run yet
-/// to keep the database schema up to date.
+/// Used after the content of the database has been replaced, e.g. by a
+/// backup written with an older schema.
 ///
-/// # Panics
-/// * If the database connection cannot be established
-/// * If migrations fail to execute
-fn run_migrations() {
-    let mut connection = establish_connection();
-    connection.run_pending_migrations(MIGRATIONS).unwrap();
+/// # Returns
+/// Result indicating whether the migrations could be run
+pub fn migrate() -> Result<(), SuiteError> {
+    let mut connection = establish_connection()?;
+    run_migrations(&mut connection)
 }
 
-/// Establishes a connection to the SQLite database with the sqlite:// prefix.
+/// Establishes a connection to the current library
 ///
 /// # Returns
-/// * `SqliteConnection` - A connection to the database
+/// Result containing a connection borrowed from the pool or an error if the
+/// database is unavailable
+pub fn establish_connection() -> Result<DbConnection, SuiteError> {
+    let pool = {
+        let database = DATABASE.read().unwrap();
+        let database = database
+            .as_ref()
+            .ok_or_else(|| SuiteError::database("The database has not been initialized"))?;
+
+        database.pool.clone()?
+    };
+
+    pool.get()
+        .map_err(|e| SuiteError::database(format!("Failed to connect to the database: {}", e)))
+}
+
+/// Gets the library the application currently works with
 ///
-/// # Panics
-/// * If the connection cannot be established
-pub fn establish_connection() -> SqliteConnection {
-    let db_path = format!("sqlite://{}", get_db_path());
+/// # Returns
+/// Result containing the library or an error if no library has been opened
+pub fn active_library() -> Result<Library, SuiteError> {
+    DATABASE
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|database| database.library.clone())
+        .ok_or_else(|| SuiteError::database("The database has not been initialized"))
+}
 
-    SqliteConnection::establish(&db_path)
-        .unwrap_or_else(|_| panic!("Error connecting to {}", db_path))
+/// Gets the path to the database file of the current library
+///
+/// # Returns
+/// Result containing the path or an error if no library has been opened
+pub fn get_db_path() -> Result<String, SuiteError> {
+    active_library().map(|library| library.path)
 }
 
-/// Creates the SQLite database file and its parent directory if they don't exist.
+/// Gets the status of the library the application currently works with
 ///
-/// This function ensures that the necessary directory structure exists
-/// and creates an empty database file.
+/// # Returns
+/// The status, or `None` if no library has been opened
+pub fn status() -> Option<DatabaseStatus> {
+    DATABASE.read().unwrap().as_ref().map(|database| {
+        let error = database
+            .pool
+            .as_ref()
+            .err()
+            .map(|e| e.message().to_string());
+        DatabaseStatus {
+            library: database.library.name.clone(),
+            path: database.library.path.clone(),
+            available: error.is_none(),
+            error,
+        }
+    })
+}
+
+/// Builds a `LIKE` pattern matching any value containing a text
 ///
-/// # Panics
-/// * If the parent directory cannot be created
-/// * If the database file cannot be created
-fn create_db_file() {
-    let db_path = get_db_path();
-    let db_dir = Path::new(&db_path).parent().unwrap();
+/// The wildcards `%` and `_` and the escape character of the text are
+/// escaped, so they match literally. The pattern has to be used along with
+/// `.escape(LIKE_ESCAPE)`.
+///
+/// # Arguments
+/// * `text` - The text to search for
+///
+/// # Returns
+/// The pattern matching the text anywhere in a value
+pub fn contains_pattern(text: &str) -> String {
+    let mut pattern = String::with_capacity(text.len() + 2);
+    pattern.push('%');
+    for c in text.chars() {
+        if matches!(c, '%' | '_') || c == LIKE_ESCAPE {
+            pattern.push(LIKE_ESCAPE);
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
 
-    if !db_dir.exists() {
-        fs::create_dir_all(db_dir).unwrap();
+/// Creates the pool of connections to a database and migrates it
+///
+/// # Arguments
+/// * `path` - Path to the database file
+///
+/// # Returns
+/// Result containing the pool or an error if the database cannot be opened
+fn connect(path: &str) -> Result<DbPool, SuiteError> {
+    if !Path::new(path).exists() {
+        eprintln!("Database file does not exist, creating it");
+        create_db_file(path)?;
     }
 
-    fs::File::create(db_path).unwrap();
+    let pool = Pool::builder()
+        .max_size(POOL_SIZE)
+        .connection_timeout(CONNECTION_TIMEOUT)
+        .connection_customizer(Box::new(ConnectionOptions))
+        .build(ConnectionManager::<SqliteConnection>::new(path))
+        .map_err(|e| {
+            SuiteError::database(format!("Failed to open the database at {}: {}", path, e))
+        })?;
+
+    let mut connection = pool.get().map_err(|e| {
+        SuiteError::database(format!("Failed to open the database at {}: {}", path, e))
+    })?;
+    run_migrations(&mut connection)?;
+
+    Ok(pool)
 }
 
-/// Checks if the database file exists.
+/// Runs any pending database migrations.
 ///
-/// # Returns
-/// * `bool` - true if the database file exists, false otherwise
-fn db_file_exists() -> bool {
-    let db_path = get_db_path();
-    Path::new(&db_path).exists()
+/// This function applies any new migrations that haven't been run yet
+/// to keep the database schema up to date.
+///
+/// # Arguments
+/// * `connection` - Connection to the database
+fn run_migrations(connection: &mut SqliteConnection) -> Result<(), SuiteError> {
+    connection
+        .run_pending_migrations(MIGRATIONS)
+        .map(|_| ())
+        .map_err(|e| SuiteError::database(format!("Failed to migrate the database: {}", e)))
 }
 
-/// Gets the path to the SQLite database file.
+/// Creates the SQLite database file and its parent directory if they don't exist.
 ///
-/// In debug mode, uses a local db.sqlite file in the current directory.
-/// In release mode, uses the user's configuration directory.
+/// This function ensures that the necessary directory structure exists
+/// and creates an empty database file.
 ///
-/// # Returns
-/// * `String` - The full path to the database file
+/// # Arguments
+/// * `path` - Path to the database file
+fn create_db_file(path: &str) -> Result<(), SuiteError> {
+    let path = Path::new(path);
+
+    if let Some(db_dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(db_dir).map_err(|e| SuiteError::io(db_dir, e))?;
+    }
+
+    fs::File::create(path)
+        .map(|_| ())
+        .map_err(|e| SuiteError::io(path, e))
+}
+
+/// Gets the default path to the SQLite database file.
 ///
-/// # Panics
-/// * If the home directory cannot be determined (release mode only)
-/// * If the home directory path cannot be converted to a string (release mode only)
-fn get_db_path() -> String {
+/// This is the location of the personal library. In debug mode, uses a local
+/// db.sqlite file in the current directory. In release mode, uses the user's
+/// configuration directory.
+///
+/// # Returns
+/// Result containing the full path to the database file or an error if the
+/// home directory cannot be determined or its path is not valid Unicode
+/// (release mode only)
+pub fn default_db_path() -> Result<String, SuiteError> {
     if cfg!(debug_assertions) {
-        DEBUG_DB_PATH.to_string()
-    } else {
-        let home_dir = dirs::home_dir().unwrap();
-        home_dir.to_str().unwrap().to_string() + "/.config/enzymeml/db.sqlite"
+        return Ok(DEBUG_DB_PATH.to_string());
     }
+
+    let home_dir = dirs::home_dir().ok_or_else(|| {
+        SuiteError::database("Failed to determine the home directory for the personal library")
+    })?;
+    let home_dir = home_dir.to_str().ok_or_else(|| {
+        SuiteError::database(format!(
+            "The home directory {} is not a valid Unicode path",
+            home_dir.display()
+        ))
+    })?;
+
+    Ok(home_dir.to_string() + "/.config/enzymeml/db.sqlite")
 }
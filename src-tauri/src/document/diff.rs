@@ -0,0 +1,355 @@
+use enzymeml::prelude::EnzymeMLDocument;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::docutils::{entity_key, into_object, is_keyed, take_items};
+use crate::error::SuiteError;
+
+/// Collections of a document whose entities are compared one by one
+const COLLECTIONS: [(&str, DiffEntity); 8] = [
+    ("small_molecules", DiffEntity::SmallMolecule),
+    ("proteins", DiffEntity::Protein),
+    ("complexes", DiffEntity::Complex),
+    ("vessels", DiffEntity::Vessel),
+    ("reactions", DiffEntity::Reaction),
+    ("equations", DiffEntity::Equation),
+    ("parameters", DiffEntity::Parameter),
+    ("measurements", DiffEntity::Measurement),
+];
+
+/// Options of the comparison of two documents
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type, JsonSchema)]
+pub struct DiffOptions {
+    /// Whether entities without a counterpart of the same ID are matched by name
+    #[serde(default)]
+    pub match_by_name: bool,
+    /// Absolute difference up to which numbers are considered equal, must not be negative
+    #[serde(default)]
+    pub tolerance: f64,
+}
+
+/// Kind of the compared entities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffEntity {
+    SmallMolecule,
+    Protein,
+    Complex,
+    Vessel,
+    Reaction,
+    Equation,
+    Parameter,
+    Measurement,
+}
+
+/// How an entity differs between the compared documents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffStatus {
+    /// The entity only exists in the new document
+    Added,
+    /// The entity only exists in the old document
+    Removed,
+    /// The entity exists in both documents with different fields
+    Modified,
+}
+
+/// Changed field of a document or an entity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type, JsonSchema)]
+pub struct FieldChange {
+    /// JSON pointer of the field, with nested entities addressed by their ID
+    pub path: String,
+    /// Value in the old document as JSON, `None` if the field is missing
+    pub old: Option<String>,
+    /// Value in the new document as JSON, `None` if the field is missing
+    pub new: Option<String>,
+}
+
+/// Difference of an entity between the compared documents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type, JsonSchema)]
+pub struct EntityDiff {
+    /// Kind of the entity
+    pub kind: DiffEntity,
+    /// ID of the entity in the old document, or in the new one if it has been added
+    pub id: String,
+    /// ID of the entity in the new document, if it has been matched by name
+    pub new_id: Option<String>,
+    /// Name of the entity, if it has one
+    pub name: Option<String>,
+    /// How the entity differs
+    pub status: DiffStatus,
+    /// Changed fields of a modified entity
+    pub changes: Vec<FieldChange>,
+}
+
+/// Structural difference between two documents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type, JsonSchema)]
+pub struct DocumentDiff {
+    /// Changed fields of the document itself, such as its name or creators
+    pub changes: Vec<FieldChange>,
+    /// Added, removed and modified entities
+    pub entities: Vec<EntityDiff>,
+}
+
+impl DocumentDiff {
+    /// Returns whether the compared documents are equal
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.entities.is_empty()
+    }
+}
+
+/// Compares two documents entity by entity
+///
+/// Species, vessels, reactions, equations, parameters and measurements are
+/// matched by their ID, equations by the ID of their species. Optionally,
+/// entities left without a counterpart are matched by name, which tells
+/// renamed IDs apart from removed and added entities. Numbers, including the
+/// data arrays of measurements, are compared with the given tolerance.
+///
+/// # Arguments
+/// * `old` - The document to compare against
+/// * `new` - The document to compare
+/// * `options` - Options of the comparison
+///
+/// # Returns
+/// Result containing the differences of the documents or a `Validation`
+/// error if the tolerance is negative or not finite
+pub fn diff_documents(
+    old: &EnzymeMLDocument,
+    new: &EnzymeMLDocument,
+    options: &DiffOptions,
+) -> Result<DocumentDiff, SuiteError> {
+    if !options.tolerance.is_finite() || options.tolerance < 0.0 {
+        return Err(SuiteError::validation(format!(
+            "The tolerance must be a finite, non-negative number, got {}",
+            options.tolerance
+        )));
+    }
+
+    let mut old = into_object(serde_json::to_value(old)?);
+    let mut new = into_object(serde_json::to_value(new)?);
+    let mut entities = Vec::new();
+
+    for (collection, kind) in COLLECTIONS {
+        let old_items = take_items(&mut old, collection);
+        let new_items = take_items(&mut new, collection);
+        entities.extend(diff_collection(kind, &old_items, &new_items, options));
+    }
+
+    let mut changes = Vec::new();
+    diff_values(
+        Some(&Value::Object(old)),
+        Some(&Value::Object(new)),
+        "",
+        options.tolerance,
+        &mut changes,
+    );
+
+    Ok(DocumentDiff { changes, entities })
+}
+
+/// Compares the entities of a collection
+fn diff_collection(
+    kind: DiffEntity,
+    old: &[Value],
+    new: &[Value],
+    options: &DiffOptions,
+) -> Vec<EntityDiff> {
+    let mut matched_new = vec![false; new.len()];
+    let mut pairs: Vec<(usize, Option<usize>)> = Vec::new();
+
+    // Match by ID first, then the remaining entities by name
+    for (i, item) in old.iter().enumerate() {
+        let counterpart = (0..new.len()).find(|&j| {
+            !matched_new[j] && entity_key(item).is_some() && entity_key(&new[j]) == entity_key(item)
+        });
+        if let Some(j) = counterpart {
+            matched_new[j] = true;
+        }
+        pairs.push((i, counterpart));
+    }
+
+    if options.match_by_name {
+        for (i, counterpart) in pairs.iter_mut() {
+            if counterpart.is_some() {
+                continue;
+            }
+            let Some(name) = entity_name(&old[*i]) else {
+                continue;
+            };
+            *counterpart =
+                (0..new.len()).find(|&j| !matched_new[j] && entity_name(&new[j]) == Some(name));
+            if let Some(j) = counterpart {
+                matched_new[*j] = true;
+            }
+        }
+    }
+
+    let mut diffs = Vec::new();
+    for (i, counterpart) in pairs {
+        let item = &old[i];
+        let id = entity_key(item).unwrap_or_default().to_string();
+        let name = entity_name(item).map(str::to_string);
+
+        match counterpart {
+            Some(j) => {
+                let mut changes = Vec::new();
+                diff_values(
+                    Some(item),
+                    Some(&new[j]),
+                    "",
+                    options.tolerance,
+                    &mut changes,
+                );
+
+                let new_id = entity_key(&new[j])
+                    .filter(|new_id| *new_id != id)
+                    .map(str::to_string);
+
+                if !changes.is_empty() {
+                    diffs.push(EntityDiff {
+                        kind,
+                        id,
+                        new_id,
+                        name,
+                        status: DiffStatus::Modified,
+                        changes,
+                    });
+                }
+            }
+            None => diffs.push(EntityDiff {
+                kind,
+                id,
+                new_id: None,
+                name,
+                status: DiffStatus::Removed,
+                changes: Vec::new(),
+            }),
+        }
+    }
+
+    for (j, item) in new.iter().enumerate() {
+        if !matched_new[j] {
+            diffs.push(EntityDiff {
+                kind,
+                id: entity_key(item).unwrap_or_default().to_string(),
+                new_id: None,
+                name: entity_name(item).map(str::to_string),
+                status: DiffStatus::Added,
+                changes: Vec::new(),
+            });
+        }
+    }
+
+    diffs
+}
+
+/// Compares two values and records the changed fields
+fn diff_values(
+    old: Option<&Value>,
+    new: Option<&Value>,
+    path: &str,
+    tolerance: f64,
+    changes: &mut Vec<FieldChange>,
+) {
+    let (old, new) = match (old, new) {
+        (Some(old), Some(new)) => (old, new),
+        (None, None) => return,
+        _ => {
+            changes.push(field_change(path, old, new));
+            return;
+        }
+    };
+
+    if values_equal(old, new, tolerance) {
+        return;
+    }
+
+    match (old, new) {
+        (Value::Object(old_fields), Value::Object(new_fields)) => {
+            let mut keys: Vec<&String> = old_fields.keys().collect();
+            keys.extend(
+                new_fields
+                    .keys()
+                    .filter(|key| !old_fields.contains_key(*key)),
+            );
+
+            for key in keys {
+                diff_values(
+                    old_fields.get(key),
+                    new_fields.get(key),
+                    &format!("{}/{}", path, key),
+                    tolerance,
+                    changes,
+                );
+            }
+        }
+        (Value::Array(old_items), Value::Array(new_items))
+            if is_keyed(old_items) && is_keyed(new_items) =>
+        {
+            let mut keys: Vec<&str> = old_items.iter().filter_map(entity_key).collect();
+            keys.extend(
+                new_items
+                    .iter()
+                    .filter_map(entity_key)
+                    .filter(|key| position_of(old_items, key).is_none()),
+            );
+
+            for key in keys {
+                diff_values(
+                    position_of(old_items, key).map(|i| &old_items[i]),
+                    position_of(new_items, key).map(|i| &new_items[i]),
+                    &format!("{}/{}", path, key),
+                    tolerance,
+                    changes,
+                );
+            }
+        }
+        _ => changes.push(field_change(path, Some(old), Some(new))),
+    }
+}
+
+/// Returns whether two values are equal, comparing numbers with a tolerance
+fn values_equal(old: &Value, new: &Value, tolerance: f64) -> bool {
+    match (old, new) {
+        (Value::Number(old), Value::Number(new)) => match (old.as_f64(), new.as_f64()) {
+            (Some(old), Some(new)) => (old - new).abs() <= tolerance,
+            _ => old == new,
+        },
+        (Value::Array(old), Value::Array(new)) => {
+            old.len() == new.len()
+                && old
+                    .iter()
+                    .zip(new)
+                    .all(|(old, new)| values_equal(old, new, tolerance))
+        }
+        (Value::Object(old), Value::Object(new)) => {
+            old.len() == new.len()
+                && old.iter().all(|(key, old)| {
+                    new.get(key)
+                        .is_some_and(|new| values_equal(old, new, tolerance))
+                })
+        }
+        _ => old == new,
+    }
+}
+
+/// Returns the position of the entity with the given ID within a collection
+fn position_of(items: &[Value], key: &str) -> Option<usize> {
+    items.iter().position(|item| entity_key(item) == Some(key))
+}
+
+/// Returns the name of a serialized entity
+fn entity_name(item: &Value) -> Option<&str> {
+    item.get("name").and_then(Value::as_str)
+}
+
+/// Creates the change of a field
+fn field_change(path: &str, old: Option<&Value>, new: Option<&Value>) -> FieldChange {
+    FieldChange {
+        path: path.to_string(),
+        old: old.map(Value::to_string),
+        new: new.map(Value::to_string),
+    }
+}
@@ -0,0 +1,244 @@
+use enzymeml::prelude::{Creator, EnzymeMLDocument};
+
+use crate::error::SuiteError;
+use crate::events::{ChangeOp, EventSink, SuiteEvent};
+use crate::states::EnzymeMLState;
+use crate::{update_event, update_report};
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, specta::Type)]
+pub struct Stats {
+    pub small_molecules: usize,
+    pub proteins: usize,
+    pub reactions: usize,
+    pub vessels: usize,
+    pub measurements: usize,
+}
+
+/// Sets the title of the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `title` - The new title for the document
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn set_title(
+    state: &EnzymeMLState,
+    title: &str,
+    events: &dyn EventSink,
+) -> Result<(), SuiteError> {
+    let mut state_doc = state.doc.lock().unwrap();
+    let mut state_title = state.title.lock().unwrap();
+
+    state_doc.name = title.to_string();
+    *state_title = title.to_string();
+
+    events
+        .emit_event(SuiteEvent::document(state, ChangeOp::Update))
+        .map_err(SuiteError::internal)?;
+
+    update_report!(state, events, &state_doc);
+
+    Ok(())
+}
+
+/// Adds a creator to the EnzymeML document
+///
+/// Creators are identified by their mail address, or by their name if they
+/// have none, so a creator who is already listed is not added again.
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `creator` - The creator to add
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result containing whether the creator has been added
+pub fn add_creator(
+    state: &EnzymeMLState,
+    creator: Creator,
+    events: &dyn EventSink,
+) -> Result<bool, SuiteError> {
+    let mut state_doc = state.doc.lock().unwrap();
+
+    if state_doc.creators.iter().any(|known| {
+        if known.mail.is_empty() || creator.mail.is_empty() {
+            known.given_name == creator.given_name && known.family_name == creator.family_name
+        } else {
+            known.mail == creator.mail
+        }
+    }) {
+        return Ok(false);
+    }
+
+    state_doc.creators.push(creator);
+    events
+        .emit_event(SuiteEvent::document(state, ChangeOp::Update))
+        .map_err(SuiteError::internal)?;
+
+    update_report!(state, events, &state_doc);
+
+    Ok(true)
+}
+
+/// Creates a new EnzymeML document and replaces the current document in state
+///
+/// This function replaces the current EnzymeML document in the application state
+/// with the provided document. It emits an update event to notify the frontend
+/// of the document change.
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state containing the document data
+/// * `enzmldoc` - The new EnzymeML document to set as the current document
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn create_document(
+    state: &EnzymeMLState,
+    enzmldoc: EnzymeMLDocument,
+    events: &dyn EventSink,
+) -> Result<(), SuiteError> {
+    let mut state_doc = state.doc.lock().unwrap();
+    *state_doc = enzmldoc;
+
+    update_event!(events, SuiteEvent::document(state, ChangeOp::Create));
+    update_report!(state, events, &state_doc);
+
+    Ok(())
+}
+
+/// Retrieves all species IDs from the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+///
+/// # Returns
+/// Vector of all species IDs (small molecules, proteins, and complexes)
+pub fn get_all_species_ids(state: &EnzymeMLState) -> Vec<String> {
+    let doc = state.doc.lock().unwrap();
+    extract_species_ids(&doc)
+}
+
+/// Extracts species IDs from an EnzymeML document
+///
+/// # Arguments
+/// * `doc` - Reference to the EnzymeML document
+///
+/// # Returns
+/// Vector of all species IDs (small molecules, proteins, and complexes)
+pub fn extract_species_ids(doc: &EnzymeMLDocument) -> Vec<String> {
+    doc.small_molecules
+        .iter()
+        .map(|s| s.id.clone())
+        .chain(doc.proteins.iter().map(|s| s.id.clone()))
+        .chain(doc.complexes.iter().map(|s| s.id.clone()))
+        .collect()
+}
+
+/// Retrieves all species with their IDs and names from the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+///
+/// # Returns
+/// Vector of tuples containing the ID and name of each species
+pub fn get_all_species(state: &EnzymeMLState) -> Vec<(String, String)> {
+    let doc = state.doc.lock().unwrap();
+    get_all_species_ids_and_names(&doc)
+}
+
+/// Retrieves the name of a specific species by its ID
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `species_id` - The ID of the species to look up
+///
+/// # Returns
+/// Result containing the species name or an error if not found
+pub fn get_species_name(state: &EnzymeMLState, species_id: &str) -> Result<String, SuiteError> {
+    let state_doc = state.doc.lock().unwrap();
+
+    // Combine all species into one vector
+    let all_species = get_all_species_ids_and_names(&state_doc);
+
+    // Find the species with the given ID
+    let species = all_species.iter().find(|(id, _)| id == species_id);
+
+    match species {
+        Some((_, name)) => Ok(name.clone()),
+        None => Err(SuiteError::not_found("Species", species_id)),
+    }
+}
+
+/// Helper function to extract all species IDs and names from an EnzymeML document
+///
+/// # Arguments
+/// * `state_doc` - Reference to the EnzymeML document
+///
+/// # Returns
+/// Vector of tuples containing the ID and name of each species
+pub fn get_all_species_ids_and_names(state_doc: &EnzymeMLDocument) -> Vec<(String, String)> {
+    let all_species = state_doc
+        .small_molecules
+        .iter()
+        .map(|s| (s.id.clone(), s.name.clone()))
+        .chain(
+            state_doc
+                .proteins
+                .iter()
+                .map(|s| (s.id.clone(), s.name.clone())),
+        )
+        .chain(
+            state_doc
+                .complexes
+                .iter()
+                .map(|s| (s.id.clone(), s.name.clone())),
+        )
+        .collect::<Vec<(String, String)>>();
+    all_species
+}
+
+/// Retrieves all non-constant species IDs from the EnzymeML document
+/// Non-constant species are those that can change concentration during simulation
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+///
+/// # Returns
+/// Vector of non-constant species IDs (small molecules and proteins only)
+pub fn get_all_non_constant_species_ids(state: &EnzymeMLState) -> Vec<String> {
+    let state_doc = state.doc.lock().unwrap();
+    state_doc
+        .small_molecules
+        .iter()
+        .filter(|s| !s.constant)
+        .map(|s| s.id.clone())
+        .chain(
+            state_doc
+                .proteins
+                .iter()
+                .filter(|s| !s.constant)
+                .map(|s| s.id.clone()),
+        )
+        .collect()
+}
+
+/// Retrieves statistics about the current EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+///
+/// # Returns
+/// Stats struct containing counts of all entity types in the document
+pub fn get_stats(state: &EnzymeMLState) -> Stats {
+    let state_doc = state.doc.lock().unwrap();
+    Stats {
+        small_molecules: state_doc.small_molecules.len(),
+        proteins: state_doc.proteins.len(),
+        reactions: state_doc.reactions.len(),
+        vessels: state_doc.vessels.len(),
+        measurements: state_doc.measurements.len(),
+    }
+}
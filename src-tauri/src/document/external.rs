@@ -0,0 +1,284 @@
+use enzymeml::prelude::EnzymeMLDocument;
+use serde_json::{Map, Value};
+use std::path::PathBuf;
+
+use crate::docutils::{entity_key, is_keyed};
+use crate::error::SuiteError;
+use crate::events::{ChangeOp, EventSink, SuiteEvent};
+use crate::io::dataio::read_file_version;
+use crate::states::EnzymeMLState;
+use crate::{update_event, update_report};
+
+/// Entry of a document that has been changed differently in the document and its file
+///
+/// The document keeps its own value of a conflicting entry.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct MergeConflict {
+    /// JSON pointer of the entry, with entities addressed by their ID
+    pub path: String,
+    /// Value kept from the document as JSON, `null` if it has been removed
+    pub local: String,
+    /// Value of the file as JSON, `null` if it has been removed
+    pub theirs: String,
+}
+
+/// Outcome of merging the changes of a file into a document
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct MergeReport {
+    /// Entries that changed differently in the document and its file
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// Replaces the document with the current content of its origin file
+///
+/// Unsaved changes of the document are discarded.
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn reload(state: &EnzymeMLState, events: &dyn EventSink) -> Result<(), SuiteError> {
+    let version = read_file_version(&origin_path(state)?)?;
+
+    *state.title.lock().unwrap() = version.doc.name.clone();
+    let mut state_doc = state.doc.lock().unwrap();
+    *state_doc = version.doc.clone();
+    *state.file_version.lock().unwrap() = Some(version);
+
+    update_event!(events, SuiteEvent::persisted(state, ChangeOp::Bulk));
+    update_report!(state, events, &state_doc);
+
+    Ok(())
+}
+
+/// Keeps the document as it is and dismisses the changes of its origin file
+///
+/// The changed file becomes the version the document is compared against, so
+/// the document counts as unsaved until it is written back to the file.
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn keep_local(state: &EnzymeMLState, events: &dyn EventSink) -> Result<(), SuiteError> {
+    let version = read_file_version(&origin_path(state)?)?;
+    *state.file_version.lock().unwrap() = Some(version);
+
+    update_event!(events, SuiteEvent::document(state, ChangeOp::Update));
+
+    Ok(())
+}
+
+/// Merges the changes of the origin file into the document
+///
+/// Performs a three-way merge of the document and the current file against the
+/// version of the file the document was last loaded from or saved to. Entities
+/// in collections are matched by their ID, so additions, removals and changes
+/// on either side are combined. Entries changed differently on both sides are
+/// reported as conflicts and keep the value of the document.
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result containing the conflicts of the merge
+pub fn merge(state: &EnzymeMLState, events: &dyn EventSink) -> Result<MergeReport, SuiteError> {
+    let theirs = read_file_version(&origin_path(state)?)?;
+    let base = state.file_version.lock().unwrap().clone().ok_or_else(|| {
+        SuiteError::validation("The document has not been loaded from or saved to its file")
+    })?;
+
+    let mut state_doc = state.doc.lock().unwrap();
+    let mut state_title = state.title.lock().unwrap();
+    let (merged, conflicts) = merge_documents(&base.doc, &state_doc, &theirs.doc)?;
+
+    *state_title = merged.name.clone();
+    *state_doc = merged;
+    *state.file_version.lock().unwrap() = Some(theirs);
+
+    update_event!(events, SuiteEvent::document(state, ChangeOp::Bulk));
+    update_report!(state, events, &state_doc);
+
+    Ok(MergeReport { conflicts })
+}
+
+/// Returns the origin file of the document
+fn origin_path(state: &EnzymeMLState) -> Result<PathBuf, SuiteError> {
+    state
+        .path
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| SuiteError::validation("The document does not originate from a file"))
+}
+
+/// Merges two versions of a document that derive from a common base
+///
+/// # Arguments
+/// * `base` - The common version both documents derive from
+/// * `local` - The version of the document, which wins conflicts
+/// * `theirs` - The version of the file
+///
+/// # Returns
+/// Result containing the merged document and the conflicts
+pub fn merge_documents(
+    base: &EnzymeMLDocument,
+    local: &EnzymeMLDocument,
+    theirs: &EnzymeMLDocument,
+) -> Result<(EnzymeMLDocument, Vec<MergeConflict>), SuiteError> {
+    let mut conflicts = Vec::new();
+    let merged = merge_values(
+        Some(&serde_json::to_value(base)?),
+        &serde_json::to_value(local)?,
+        &serde_json::to_value(theirs)?,
+        "",
+        &mut conflicts,
+    );
+
+    Ok((serde_json::from_value(merged)?, conflicts))
+}
+
+/// Merges two values present on both sides
+fn merge_values(
+    base: Option<&Value>,
+    local: &Value,
+    theirs: &Value,
+    path: &str,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Value {
+    if local == theirs || base == Some(theirs) {
+        return local.clone();
+    }
+    if base == Some(local) {
+        return theirs.clone();
+    }
+
+    match (local, theirs) {
+        (Value::Object(local), Value::Object(theirs)) => {
+            let base = base.and_then(Value::as_object);
+            let keys = merged_keys(local.keys(), theirs.keys());
+            let mut merged = Map::new();
+
+            for key in keys {
+                let entry = merge_entry(
+                    base.and_then(|base| base.get(&key)),
+                    local.get(&key),
+                    theirs.get(&key),
+                    &format!("{}/{}", path, key),
+                    conflicts,
+                );
+                if let Some(value) = entry {
+                    merged.insert(key, value);
+                }
+            }
+
+            Value::Object(merged)
+        }
+        (Value::Array(local), Value::Array(theirs)) if is_keyed(local) && is_keyed(theirs) => {
+            let base = base
+                .and_then(Value::as_array)
+                .filter(|base| is_keyed(base))
+                .map(|base| base.as_slice())
+                .unwrap_or_default();
+            let keys = merged_keys(
+                local.iter().filter_map(entity_key),
+                theirs.iter().filter_map(entity_key),
+            );
+
+            let mut merged = Vec::new();
+            for key in keys {
+                let entry = merge_entry(
+                    find_entity(base, &key),
+                    find_entity(local, &key),
+                    find_entity(theirs, &key),
+                    &format!("{}/{}", path, key),
+                    conflicts,
+                );
+                merged.extend(entry);
+            }
+
+            Value::Array(merged)
+        }
+        _ => {
+            conflicts.push(conflict(path, Some(local), Some(theirs)));
+            local.clone()
+        }
+    }
+}
+
+/// Merges an entry that may be missing on either side
+///
+/// # Returns
+/// The merged entry or `None` if it has been removed
+fn merge_entry(
+    base: Option<&Value>,
+    local: Option<&Value>,
+    theirs: Option<&Value>,
+    path: &str,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Option<Value> {
+    match (local, theirs) {
+        (Some(local), Some(theirs)) => Some(merge_values(base, local, theirs, path, conflicts)),
+        // Added on one side or removed on the other without further changes
+        (Some(value), None) | (None, Some(value)) if base.is_none() || base == Some(value) => {
+            if base.is_none() {
+                Some(value.clone())
+            } else {
+                None
+            }
+        }
+        // Changed on one side and removed on the other
+        (Some(local), None) => {
+            conflicts.push(conflict(path, Some(local), None));
+            Some(local.clone())
+        }
+        (None, Some(theirs)) => {
+            conflicts.push(conflict(path, None, Some(theirs)));
+            None
+        }
+        (None, None) => None,
+    }
+}
+
+/// Returns the keys of both sides, in the order of the document followed by new keys of the file
+fn merged_keys<L, T>(local: L, theirs: T) -> Vec<String>
+where
+    L: IntoIterator,
+    L::Item: AsRef<str>,
+    T: IntoIterator,
+    T::Item: AsRef<str>,
+{
+    let mut keys: Vec<String> = local
+        .into_iter()
+        .map(|key| key.as_ref().to_string())
+        .collect();
+
+    for key in theirs {
+        if !keys.iter().any(|known| known == key.as_ref()) {
+            keys.push(key.as_ref().to_string());
+        }
+    }
+
+    keys
+}
+
+/// Returns the entity with the given ID within a collection
+fn find_entity<'a>(items: &'a [Value], key: &str) -> Option<&'a Value> {
+    items.iter().find(|item| entity_key(item) == Some(key))
+}
+
+/// Creates the conflict of an entry
+fn conflict(path: &str, local: Option<&Value>, theirs: Option<&Value>) -> MergeConflict {
+    let json = |value: Option<&Value>| value.unwrap_or(&Value::Null).to_string();
+
+    MergeConflict {
+        path: path.to_string(),
+        local: json(local),
+        theirs: json(theirs),
+    }
+}
@@ -0,0 +1,487 @@
+use enzymeml::prelude::EnzymeMLDocument;
+use regex::{Captures, Regex};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+
+use crate::actions::identifiers::{
+    COMPLEX_PREFIX, MEASUREMENT_PREFIX, PARAMETER_PREFIX, PROTEIN_PREFIX, REACTION_PREFIX,
+    SMALL_MOLECULE_PREFIX, VESSEL_PREFIX,
+};
+use crate::actions::utils::generate_id;
+use crate::document::diff::DiffEntity;
+use crate::docutils::{entity_key, into_object, take_items};
+use crate::error::SuiteError;
+use crate::events::{ChangeOp, EventSink, SuiteEvent};
+use crate::states::EnzymeMLState;
+use crate::{update_event, update_report};
+
+/// Regex matching the symbols of an equation
+pub(crate) const SYMBOL_REGEX: &str = r"\b[A-Za-z_][A-Za-z0-9_]*";
+
+/// Collections of a document whose entities are imported, in the order references are resolved
+const COLLECTIONS: [(&str, DiffEntity, &str); 7] = [
+    ("vessels", DiffEntity::Vessel, VESSEL_PREFIX),
+    (
+        "small_molecules",
+        DiffEntity::SmallMolecule,
+        SMALL_MOLECULE_PREFIX,
+    ),
+    ("proteins", DiffEntity::Protein, PROTEIN_PREFIX),
+    ("complexes", DiffEntity::Complex, COMPLEX_PREFIX),
+    ("parameters", DiffEntity::Parameter, PARAMETER_PREFIX),
+    ("reactions", DiffEntity::Reaction, REACTION_PREFIX),
+    ("measurements", DiffEntity::Measurement, MEASUREMENT_PREFIX),
+];
+
+/// How an imported entity duplicating an entity of the document is resolved
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Resolution {
+    /// The entity of the document is used in place of the imported one
+    #[default]
+    Reuse,
+    /// The entity of the document is overwritten by the imported one, keeping its ID
+    Replace,
+    /// Both entities are kept, the imported one under a new ID if needed
+    KeepBoth,
+}
+
+/// Property by which an imported entity has been recognised as duplicate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateMatch {
+    /// Both entities have the same name
+    Name,
+    /// Both small molecules have the same InChIKey
+    Inchikey,
+    /// Both proteins have the same sequence
+    Sequence,
+    /// Both parameters have the same ID and thus the same symbol in equations
+    Id,
+}
+
+/// Options of importing a document into another
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type, JsonSchema)]
+pub struct ImportOptions {
+    /// Resolution of duplicates without an individual resolution
+    #[serde(default)]
+    pub strategy: Resolution,
+    /// Resolutions of individual duplicates, keyed by the ID of the imported entity
+    #[serde(default)]
+    pub resolutions: HashMap<String, Resolution>,
+    /// Whether only the report is created, leaving the document unchanged
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Imported entity that duplicates an entity of the document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type, JsonSchema)]
+pub struct ImportDuplicate {
+    /// Kind of the entity
+    pub kind: DiffEntity,
+    /// ID of the entity in the imported document
+    pub id: String,
+    /// ID of the duplicated entity in the document
+    pub existing_id: String,
+    /// Name of the imported entity, if it has one
+    pub name: Option<String>,
+    /// Property by which the duplicate has been recognised
+    pub matched_by: DuplicateMatch,
+    /// How the duplicate has been resolved
+    pub resolution: Resolution,
+}
+
+/// Imported entity that is referred to by a different ID in the document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type, JsonSchema)]
+pub struct RemappedId {
+    /// Kind of the entity
+    pub kind: DiffEntity,
+    /// ID of the entity in the imported document
+    pub from: String,
+    /// ID of the entity in the document
+    pub to: String,
+}
+
+/// Outcome of importing a document into another
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, specta::Type, JsonSchema)]
+pub struct ImportReport {
+    /// Imported entities that duplicate entities of the document
+    pub duplicates: Vec<ImportDuplicate>,
+    /// Imported entities whose ID changed, including reused and replaced duplicates
+    pub remapped: Vec<RemappedId>,
+    /// IDs of the entities added to the document
+    pub added: Vec<String>,
+    /// Whether the document has been changed, `false` for dry runs
+    pub applied: bool,
+}
+
+/// Imports the entities of another document into the document
+///
+/// Small molecules are recognised as duplicates by their InChIKey or name,
+/// proteins by their sequence or name, complexes and vessels by their name and
+/// parameters by their ID. Duplicates are resolved as given by the options,
+/// all other entities are added, under a new ID if theirs is already taken.
+/// References to remapped IDs are rewritten throughout the imported
+/// reactions, equations and measurements, including the symbols of equations.
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `incoming` - The document to import
+/// * `options` - How duplicates are resolved and whether the import is a dry run
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result containing the duplicates and remapped IDs of the import
+pub fn import_document(
+    state: &EnzymeMLState,
+    incoming: &EnzymeMLDocument,
+    options: &ImportOptions,
+    events: &dyn EventSink,
+) -> Result<ImportReport, SuiteError> {
+    let mut state_doc = state.doc.lock().unwrap();
+    let (merged, mut report) = import_into(&state_doc, incoming, options)?;
+
+    if options.dry_run {
+        return Ok(report);
+    }
+
+    *state_doc = merged;
+    report.applied = true;
+
+    update_event!(events, SuiteEvent::document(state, ChangeOp::Bulk));
+    update_report!(state, events, &state_doc);
+
+    Ok(report)
+}
+
+/// Imports the entities of a document into a copy of another
+///
+/// # Arguments
+/// * `target` - The document to import into
+/// * `incoming` - The document to import
+/// * `options` - How duplicates are resolved
+///
+/// # Returns
+/// Result containing the combined document and the report of the import
+pub fn import_into(
+    target: &EnzymeMLDocument,
+    incoming: &EnzymeMLDocument,
+    options: &ImportOptions,
+) -> Result<(EnzymeMLDocument, ImportReport), SuiteError> {
+    let mut doc = into_object(serde_json::to_value(target)?);
+    let mut incoming = into_object(serde_json::to_value(incoming)?);
+    let mut import = Import::new(&doc, options);
+
+    for (collection, kind, prefix) in COLLECTIONS {
+        let items = take_items(&mut incoming, collection);
+        import.stage(&doc, collection, kind, prefix, items);
+    }
+
+    let symbols = Regex::new(SYMBOL_REGEX).unwrap();
+    for staged in import.staged.iter_mut() {
+        remap_references(&mut staged.item, &import.ids, &symbols);
+    }
+
+    for staged in import.staged.drain(..) {
+        let items = collection_mut(&mut doc, staged.collection);
+        match staged.replaces {
+            Some(id) => {
+                if let Some(existing) = items
+                    .iter_mut()
+                    .find(|item| entity_key(item) == Some(id.as_str()))
+                {
+                    *existing = staged.item;
+                }
+            }
+            None => items.push(staged.item),
+        }
+    }
+
+    for mut equation in take_items(&mut incoming, "equations") {
+        remap_references(&mut equation, &import.ids, &symbols);
+        let Some(species_id) = entity_key(&equation).map(str::to_string) else {
+            continue;
+        };
+
+        let equations = collection_mut(&mut doc, "equations");
+        match equations
+            .iter_mut()
+            .find(|item| entity_key(item) == Some(species_id.as_str()))
+        {
+            // The equation of a replaced species is replaced as well
+            Some(existing) if import.replaced.contains(&species_id) => *existing = equation,
+            Some(_) => {}
+            None => equations.push(equation),
+        }
+    }
+
+    let creators = take_items(&mut incoming, "creators");
+    let known = collection_mut(&mut doc, "creators");
+    for creator in creators {
+        if !known.contains(&creator) {
+            known.push(creator);
+        }
+    }
+
+    let merged = serde_json::from_value(Value::Object(doc))?;
+    Ok((merged, import.report))
+}
+
+/// Imported entity waiting to be placed into the document
+struct Staged {
+    /// Collection the entity belongs to
+    collection: &'static str,
+    /// ID of the entity of the document that is replaced
+    replaces: Option<String>,
+    /// The serialized entity
+    item: Value,
+}
+
+/// State of an import while the IDs of the imported entities are assigned
+struct Import<'a> {
+    /// How duplicates are resolved
+    options: &'a ImportOptions,
+    /// IDs in use by the document and the already imported entities
+    taken: Vec<String>,
+    /// IDs of the imported entities in the document, keyed by their original ID
+    ids: HashMap<String, String>,
+    /// IDs of the entities of the document that are replaced
+    replaced: HashSet<String>,
+    /// Imported entities to be placed into the document
+    staged: Vec<Staged>,
+    /// Report of the import
+    report: ImportReport,
+}
+
+impl<'a> Import<'a> {
+    /// Creates an import into the given serialized document
+    fn new(doc: &Map<String, Value>, options: &'a ImportOptions) -> Self {
+        let taken = COLLECTIONS
+            .iter()
+            .filter_map(|(collection, _, _)| doc.get(*collection).and_then(Value::as_array))
+            .flatten()
+            .filter_map(|item| item.get("id").and_then(Value::as_str))
+            .map(str::to_string)
+            .collect();
+
+        Self {
+            options,
+            taken,
+            ids: HashMap::new(),
+            replaced: HashSet::new(),
+            staged: Vec::new(),
+            report: ImportReport::default(),
+        }
+    }
+
+    /// Assigns the IDs of the imported entities of a collection and stages them
+    fn stage(
+        &mut self,
+        doc: &Map<String, Value>,
+        collection: &'static str,
+        kind: DiffEntity,
+        prefix: &str,
+        items: Vec<Value>,
+    ) {
+        let existing = doc
+            .get(collection)
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        for mut item in items {
+            let Some(id) = item.get("id").and_then(Value::as_str).map(str::to_string) else {
+                continue;
+            };
+
+            let duplicate =
+                find_duplicate(kind, &item, existing).map(|(existing_id, matched_by)| {
+                    let resolution = self
+                        .options
+                        .resolutions
+                        .get(&id)
+                        .copied()
+                        .unwrap_or(self.options.strategy);
+
+                    self.report.duplicates.push(ImportDuplicate {
+                        kind,
+                        id: id.clone(),
+                        existing_id: existing_id.clone(),
+                        name: item.get("name").and_then(Value::as_str).map(str::to_string),
+                        matched_by,
+                        resolution,
+                    });
+
+                    (existing_id, resolution)
+                });
+
+            let (new_id, replaces) = match duplicate {
+                // Reused duplicates are left out, the document keeps its own entity
+                Some((existing_id, Resolution::Reuse)) => {
+                    if existing_id != id {
+                        self.report.remapped.push(RemappedId {
+                            kind,
+                            from: id.clone(),
+                            to: existing_id.clone(),
+                        });
+                    }
+                    self.ids.insert(id, existing_id);
+                    continue;
+                }
+                Some((existing_id, Resolution::Replace)) => {
+                    self.replaced.insert(existing_id.clone());
+                    (existing_id.clone(), Some(existing_id))
+                }
+                Some((_, Resolution::KeepBoth)) | None => {
+                    let new_id = if self.taken.contains(&id) {
+                        // Parameters keep their symbol recognisable in equations
+                        match kind {
+                            DiffEntity::Parameter => generate_id(&self.taken, &format!("{}_", id)),
+                            _ => generate_id(&self.taken, prefix),
+                        }
+                    } else {
+                        id.clone()
+                    };
+                    self.taken.push(new_id.clone());
+                    self.report.added.push(new_id.clone());
+                    (new_id, None)
+                }
+            };
+
+            if new_id != id {
+                self.report.remapped.push(RemappedId {
+                    kind,
+                    from: id.clone(),
+                    to: new_id.clone(),
+                });
+            }
+
+            if item.get("symbol").and_then(Value::as_str) == Some(id.as_str()) {
+                item["symbol"] = Value::String(new_id.clone());
+            }
+            item["id"] = Value::String(new_id.clone());
+
+            self.staged.push(Staged {
+                collection,
+                replaces,
+                item,
+            });
+            self.ids.insert(id, new_id);
+        }
+    }
+}
+
+/// Finds the entity of the document that an imported entity duplicates
+///
+/// # Returns
+/// The ID of the duplicated entity and the property by which it matched
+fn find_duplicate(
+    kind: DiffEntity,
+    item: &Value,
+    existing: &[Value],
+) -> Option<(String, DuplicateMatch)> {
+    let by = |field: &str, matched_by: DuplicateMatch| {
+        let value = normalised(item, field)?;
+        existing
+            .iter()
+            .find(|other| normalised(other, field).as_ref() == Some(&value))
+            .and_then(|other| other.get("id").and_then(Value::as_str))
+            .map(|id| (id.to_string(), matched_by))
+    };
+
+    match kind {
+        DiffEntity::SmallMolecule => {
+            by("inchikey", DuplicateMatch::Inchikey).or_else(|| by("name", DuplicateMatch::Name))
+        }
+        DiffEntity::Protein => {
+            by("sequence", DuplicateMatch::Sequence).or_else(|| by("name", DuplicateMatch::Name))
+        }
+        DiffEntity::Complex | DiffEntity::Vessel => by("name", DuplicateMatch::Name),
+        // Symbols of equations are case sensitive
+        DiffEntity::Parameter => {
+            let id = item.get("id").and_then(Value::as_str)?;
+            existing
+                .iter()
+                .any(|other| other.get("id").and_then(Value::as_str) == Some(id))
+                .then(|| (id.to_string(), DuplicateMatch::Id))
+        }
+        _ => None,
+    }
+}
+
+/// Returns a text field of an entity in lowercase without whitespace, `None` if it is empty
+fn normalised(item: &Value, field: &str) -> Option<String> {
+    let value: String = item
+        .get(field)?
+        .as_str()?
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    (!value.is_empty()).then_some(value)
+}
+
+/// Rewrites the references of an imported entity to the IDs in the document
+///
+/// Covers the species and vessels referred to by species, complexes,
+/// reactions and measurements as well as the symbols and variables of
+/// equations, including kinetic laws.
+fn remap_references(value: &mut Value, ids: &HashMap<String, String>, symbols: &Regex) {
+    let remap = |id: &mut Value| {
+        if let Some(new_id) = id.as_str().and_then(|id| ids.get(id)) {
+            *id = Value::String(new_id.clone());
+        }
+    };
+
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                match key.as_str() {
+                    "species_id" | "vessel_id" => remap(field),
+                    "participants" => field.as_array_mut().into_iter().flatten().for_each(remap),
+                    "equation" if field.is_string() => {
+                        let equation = field.as_str().unwrap_or_default();
+                        let rewritten = symbols.replace_all(equation, |caps: &Captures| {
+                            ids.get(&caps[0])
+                                .cloned()
+                                .unwrap_or_else(|| caps[0].to_string())
+                        });
+                        *field = Value::String(rewritten.into_owned());
+                    }
+                    "variables" => {
+                        for variable in field.as_array_mut().into_iter().flatten() {
+                            for key in ["id", "symbol"] {
+                                if let Some(field) = variable.get_mut(key) {
+                                    remap(field);
+                                }
+                            }
+                        }
+                    }
+                    _ => remap_references(field, ids, symbols),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                remap_references(item, ids, symbols);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns a collection of a serialized document, creating it if it is missing
+fn collection_mut<'a>(doc: &'a mut Map<String, Value>, collection: &str) -> &'a mut Vec<Value> {
+    let items = doc
+        .entry(collection)
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if !items.is_array() {
+        *items = Value::Array(Vec::new());
+    }
+    items.as_array_mut().unwrap()
+}
@@ -0,0 +1,333 @@
+use enzymeml::prelude::{DataTypes, Measurement, MeasurementBuilder, MeasurementDataBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::actions::identifiers::MEASUREMENT_PREFIX;
+use crate::actions::utils::generate_id;
+use crate::document::enzmldoc::get_species_name;
+use crate::error::SuiteError;
+use crate::events::{ChangeOp, EntityKind, EventSink, SuiteEvent};
+use crate::states::EnzymeMLState;
+use crate::{
+    add_objects, create_object, delete_object, get_object, update_event, update_object,
+    update_report,
+};
+
+/// Data structure for visualization containing an ID and data points
+#[derive(Debug, Clone, Deserialize, Serialize, specta::Type)]
+pub struct VisData {
+    pub id: String,
+    pub data: Vec<DataPoint>,
+}
+
+/// Represents a single data point with x and y coordinates
+#[derive(Debug, Clone, Deserialize, Serialize, specta::Type)]
+pub struct DataPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Creates a new measurement in the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result containing the ID of the created measurement or an error
+pub fn create_measurement(
+    state: &EnzymeMLState,
+    events: &dyn EventSink,
+) -> Result<String, SuiteError> {
+    let mut builder = MeasurementBuilder::default();
+    builder.name("New Measurement".to_string());
+
+    let id = create_object!(state.doc, measurements, builder, MEASUREMENT_PREFIX, id);
+
+    let mut state_doc = state.doc.lock().unwrap();
+
+    // Collect the ids from small molecules and proteins by chaining the iterators
+    let species_ids: Vec<String> = state_doc
+        .small_molecules
+        .iter()
+        .map(|s| s.id.clone())
+        .chain(state_doc.proteins.iter().map(|s| s.id.clone()))
+        .collect();
+
+    let meas = state_doc
+        .measurements
+        .iter_mut()
+        .find(|m| m.id == id)
+        .unwrap();
+
+    meas.species_data = species_ids
+        .iter()
+        .map(|id| {
+            MeasurementDataBuilder::default()
+                .species_id(id.clone())
+                .data_type(DataTypes::Concentration)
+                .time(vec![])
+                .data(vec![])
+                .build()
+                .expect("Failed to build species data")
+        })
+        .collect();
+
+    update_event!(
+        state,
+        events,
+        EntityKind::Measurement,
+        ChangeOp::Create,
+        [&id]
+    );
+    update_report!(state, events, &state_doc);
+
+    Ok(id)
+}
+
+/// Adds a small molecule to the EnzymeML document
+///
+/// This function adds a small molecule to the document's small_molecules collection.
+/// It emits an update event to notify the frontend of the changes.
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state containing the document data
+/// * `object` - The small molecule object to add
+pub fn add_measurement(
+    state: &EnzymeMLState,
+    mut object: Measurement,
+    events: &dyn EventSink,
+) -> String {
+    let mut state_guard = state.doc.lock().unwrap();
+    let id = generate_id(
+        &state_guard
+            .measurements
+            .iter()
+            .map(|s| s.id.clone())
+            .collect(),
+        MEASUREMENT_PREFIX,
+    );
+    object.id = id.clone();
+    state_guard.measurements.push(object.clone());
+    drop(state_guard);
+    update_event!(
+        state,
+        events,
+        EntityKind::Measurement,
+        ChangeOp::Create,
+        [&id]
+    );
+    update_report!(state, events);
+
+    id
+}
+
+/// Adds multiple small molecules to the EnzymeML document
+///
+/// This function adds multiple small molecules to the document's small_molecules collection.
+/// It emits an update event to notify the frontend of the changes.
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state containing the document data
+/// * `data` - The vector of small molecule objects to add
+pub fn add_measurements(
+    state: &EnzymeMLState,
+    mut data: Vec<Measurement>,
+    events: &dyn EventSink,
+) -> Vec<String> {
+    let mut existing_ids: Vec<String> = state
+        .doc
+        .lock()
+        .unwrap()
+        .measurements
+        .iter()
+        .map(|s| s.id.clone())
+        .collect();
+
+    let mut ids = Vec::with_capacity(data.len());
+    let objects: Vec<Measurement> = data
+        .iter_mut()
+        .map(|o| {
+            let id = generate_id(&existing_ids, MEASUREMENT_PREFIX);
+            ids.push(id.clone());
+            existing_ids.push(id.clone());
+            o.id = id;
+            o.clone()
+        })
+        .collect();
+
+    add_objects!(state.doc, measurements, objects);
+    update_event!(
+        state,
+        events,
+        EntityKind::Measurement,
+        ChangeOp::Create,
+        &ids
+    );
+    update_report!(state, events);
+    ids
+}
+
+/// Updates an existing measurement in the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `data` - The updated measurement data
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn update_measurement(
+    state: &EnzymeMLState,
+    data: Measurement,
+    events: &dyn EventSink,
+) -> Result<(), SuiteError> {
+    let id = update_object!(state.doc, measurements, data, id)?;
+
+    update_event!(
+        state,
+        events,
+        EntityKind::Measurement,
+        ChangeOp::Update,
+        [&id]
+    );
+    update_report!(state, events);
+
+    Ok(())
+}
+
+/// Retrieves a list of all measurements with their IDs and names
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+///
+/// # Returns
+/// Vector of tuples containing measurement ID and name pairs
+pub fn list_measurements(state: &EnzymeMLState) -> Vec<(String, String)> {
+    // Extract the guarded state values
+    let state_doc = state.doc.lock().unwrap();
+
+    state_doc
+        .measurements
+        .iter()
+        .map(|s| (s.id.clone(), s.name.clone()))
+        .collect()
+}
+
+/// Retrieves a specific measurement by its ID
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `id` - The ID of the measurement to retrieve
+///
+/// # Returns
+/// Result containing the measurement or an error if not found
+pub fn get_measurement(state: &EnzymeMLState, id: &str) -> Result<Measurement, SuiteError> {
+    get_object!(state.doc, measurements, id, id)
+}
+
+/// Retrieves data points for visualization from a measurement
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `id` - The ID of the measurement to get data points from
+///
+/// # Returns
+/// Result containing a vector of visualization data or an error
+pub fn get_datapoints(state: &EnzymeMLState, id: &str) -> Result<Vec<VisData>, SuiteError> {
+    let meas: Measurement = get_object!(state.doc, measurements, id, id)?;
+    let mut times = vec![];
+
+    for data in &meas.species_data {
+        if !data.time.is_empty() {
+            times.push(data.time.clone());
+        }
+    }
+
+    time_arrays_are_same(&times)?;
+
+    let mut dataset: Vec<VisData> = vec![];
+
+    for species_data in &meas.species_data {
+        if species_data.data.is_empty() {
+            // When there is no data, we can't create a data point
+            continue;
+        }
+
+        let time = species_data.time.clone();
+        let data = species_data.data.clone();
+
+        let zipped: Vec<(&f64, f64)> = time.iter().zip(data).collect();
+
+        let mut data_points = vec![];
+
+        for (time, data) in zipped {
+            data_points.push(DataPoint {
+                y: data as f32,
+                x: *time as f32,
+            });
+        }
+
+        let vis_data = VisData {
+            id: get_species_name(state, &species_data.species_id)?,
+            data: data_points,
+        };
+
+        if !vis_data.data.is_empty() {
+            dataset.push(vis_data);
+        }
+    }
+
+    Ok(dataset)
+}
+
+/// Validates that all time arrays in a collection are identical
+///
+/// # Arguments
+/// * `times` - Vector of time arrays to validate
+///
+/// # Returns
+/// Result indicating success or an error if arrays differ
+fn time_arrays_are_same(times: &[Vec<f64>]) -> Result<(), SuiteError> {
+    if times.is_empty() {
+        return Err(SuiteError::validation("No time vectors found"));
+    } else {
+        let first_time = &times[0];
+        for time in times.iter() {
+            if time != first_time {
+                return Err(SuiteError::validation("Time vectors are not the same"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Deletes a measurement from the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `id` - The ID of the measurement to delete
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn delete_measurement(
+    state: &EnzymeMLState,
+    id: &str,
+    events: &dyn EventSink,
+) -> Result<(), SuiteError> {
+    // Signature: State, Path, ID, ID property
+    delete_object!(state.doc, measurements, id, id);
+
+    update_event!(
+        state,
+        events,
+        EntityKind::Measurement,
+        ChangeOp::Delete,
+        [id]
+    );
+    update_event!(events, SuiteEvent::Visualisation);
+    update_report!(state, events);
+
+    Ok(())
+}
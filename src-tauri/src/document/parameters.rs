@@ -0,0 +1,144 @@
+use enzymeml::prelude::{Parameter, ParameterBuilder};
+
+use crate::actions::identifiers::PARAMETER_PREFIX;
+use crate::actions::utils::generate_id;
+use crate::error::SuiteError;
+use crate::events::{ChangeOp, EntityKind, EventSink};
+use crate::states::EnzymeMLState;
+use crate::{create_object, delete_object, get_object, update_event, update_object, update_report};
+
+/// Retrieves all parameters from the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+///
+/// # Returns
+/// Vector of tuples containing the ID and name of each parameter
+pub fn list_parameters(state: &EnzymeMLState) -> Vec<(String, String)> {
+    // Extract the guarded state values
+    let state_doc = state.doc.lock().unwrap();
+
+    state_doc
+        .parameters
+        .iter()
+        .map(|s| (s.id.clone(), s.name.clone()))
+        .collect()
+}
+
+/// Creates a new parameter in the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result containing the ID of the created parameter or an error
+pub fn create_parameter(
+    state: &EnzymeMLState,
+    events: &dyn EventSink,
+) -> Result<String, SuiteError> {
+    let mut builder = ParameterBuilder::default();
+    builder.name("New Parameter".to_string());
+    builder.symbol("".to_string());
+
+    let id = create_object!(state.doc, parameters, builder, PARAMETER_PREFIX, id);
+
+    // Notify the frontend
+    update_event!(
+        state,
+        events,
+        EntityKind::Parameter,
+        ChangeOp::Create,
+        [&id]
+    );
+    update_report!(state, events);
+
+    Ok(id)
+}
+
+/// Retrieves a specific parameter by its ID
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `id` - The ID of the parameter to retrieve
+///
+/// # Returns
+/// Result containing the parameter or an error if not found
+pub fn get_parameter(state: &EnzymeMLState, id: &str) -> Result<Parameter, SuiteError> {
+    get_object!(state.doc, parameters, id, id)
+}
+
+/// Updates an existing parameter in the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `data` - The updated parameter data
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn update_parameter(
+    state: &EnzymeMLState,
+    data: Parameter,
+    events: &dyn EventSink,
+) -> Result<(), SuiteError> {
+    // Check if the parameter exists
+    let id: String = update_object!(state.doc, parameters, data, id)?;
+
+    update_event!(
+        state,
+        events,
+        EntityKind::Parameter,
+        ChangeOp::Update,
+        [&id]
+    );
+    update_report!(state, events);
+
+    Ok(())
+}
+
+pub fn partial_update_parameter(
+    state: &EnzymeMLState,
+    pid: &str,
+    key: &str,
+    value: f64,
+    events: &dyn EventSink,
+) -> Result<(), SuiteError> {
+    let mut doc = state.doc.lock().unwrap();
+    let parameter = doc
+        .parameters
+        .iter_mut()
+        .find(|p| p.id == pid)
+        .ok_or_else(|| SuiteError::not_found("Parameter", pid))?;
+    match key {
+        "value" => parameter.value = Some(value),
+        "initial_value" => parameter.initial_value = Some(value),
+        "upper_bound" => parameter.upper_bound = Some(value),
+        "lower_bound" => parameter.lower_bound = Some(value),
+        _ => return Err(SuiteError::validation(format!("Invalid key: {}", key))),
+    }
+
+    update_event!(
+        state,
+        events,
+        EntityKind::Parameter,
+        ChangeOp::Update,
+        [&pid]
+    );
+    update_report!(state, events, &doc);
+
+    Ok(())
+}
+
+/// Deletes a parameter from the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `id` - The ID of the parameter to delete
+/// * `events` - Sink notified about the changes
+pub fn delete_parameter(state: &EnzymeMLState, id: &str, events: &dyn EventSink) {
+    delete_object!(state.doc, parameters, id, id);
+
+    update_event!(state, events, EntityKind::Parameter, ChangeOp::Delete, [id]);
+    update_report!(state, events);
+}
@@ -0,0 +1,215 @@
+use enzymeml::prelude::{Equation, Reaction, ReactionBuilder};
+
+use crate::actions::identifiers::REACTION_PREFIX;
+use crate::actions::utils::generate_id;
+use crate::document::equations::process_equation;
+use crate::error::SuiteError;
+use crate::events::{ChangeOp, EntityKind, EventSink, WHOLE_COLLECTION};
+use crate::states::EnzymeMLState;
+use crate::{
+    add_objects, create_object, delete_object, get_object, update_event, update_object,
+    update_report,
+};
+
+/// Adds a small molecule to the EnzymeML document
+///
+/// This function adds a reaction to the document's reactions collection.
+/// It emits an update event to notify the frontend of the changes.
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state containing the document data
+/// * `object` - The reaction object to add
+pub fn add_reaction(
+    state: &EnzymeMLState,
+    mut object: Reaction,
+    events: &dyn EventSink,
+) -> Result<String, SuiteError> {
+    let mut state_guard = state.doc.lock().unwrap();
+    let id = generate_id(
+        &state_guard.reactions.iter().map(|s| s.id.clone()).collect(),
+        REACTION_PREFIX,
+    );
+
+    process_kinetic_law(state, &object.kinetic_law, events)?;
+
+    object.id = id.clone();
+    state_guard.reactions.push(object.clone());
+    drop(state_guard);
+
+    update_event!(state, events, EntityKind::Reaction, ChangeOp::Create, [&id]);
+    update_report!(state, events);
+
+    Ok(id)
+}
+
+/// Adds multiple reactions to the EnzymeML document
+///
+/// This function adds multiple reactions to the document's reactions collection.
+/// It emits an update event to notify the frontend of the changes.
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state containing the document data
+/// * `data` - The vector of reaction objects to add
+pub fn add_reactions(
+    state: &EnzymeMLState,
+    mut data: Vec<Reaction>,
+    events: &dyn EventSink,
+) -> Result<Vec<String>, SuiteError> {
+    let mut existing_ids: Vec<String> = state
+        .doc
+        .lock()
+        .unwrap()
+        .reactions
+        .iter()
+        .map(|s| s.id.clone())
+        .collect();
+
+    let mut ids = Vec::with_capacity(data.len());
+    let objects: Vec<Reaction> = data
+        .iter_mut()
+        .map(|o| {
+            // Process the kinetic law
+            process_kinetic_law(state, &o.kinetic_law, events)?;
+
+            let id = generate_id(&existing_ids, REACTION_PREFIX);
+            ids.push(id.clone());
+            existing_ids.push(id.clone());
+            o.id = id;
+            Ok(o.clone())
+        })
+        .collect::<Result<Vec<Reaction>, SuiteError>>()?;
+
+    add_objects!(state.doc, reactions, objects);
+    update_event!(state, events, EntityKind::Reaction, ChangeOp::Create, &ids);
+    update_report!(state, events);
+
+    Ok(ids)
+}
+
+/// Creates a new reaction in the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// The ID of the created reaction
+pub fn create_reaction(state: &EnzymeMLState, events: &dyn EventSink) -> String {
+    let mut builder = ReactionBuilder::default();
+    builder.name("New Reaction".to_string());
+    builder.reversible(false);
+
+    let id = create_object!(state.doc, reactions, builder, REACTION_PREFIX, id);
+
+    update_event!(state, events, EntityKind::Reaction, ChangeOp::Create, [&id]);
+    update_report!(state, events);
+
+    id
+}
+
+/// Updates an existing reaction in the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `data` - The updated reaction data
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn update_reaction(
+    state: &EnzymeMLState,
+    data: Reaction,
+    events: &dyn EventSink,
+) -> Result<(), SuiteError> {
+    // Process the kinetic law
+    process_kinetic_law(state, &data.kinetic_law, events)?;
+
+    let id = update_object!(state.doc, reactions, data, id)?;
+
+    update_event!(state, events, EntityKind::Reaction, ChangeOp::Update, [&id]);
+    update_report!(state, events);
+
+    Ok(())
+}
+
+/// Retrieves all reactions from the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+///
+/// # Returns
+/// Vector of tuples containing the ID and name of each reaction
+pub fn list_reactions(state: &EnzymeMLState) -> Vec<(String, String)> {
+    // Extract the guarded state values
+    let state_doc = state.doc.lock().unwrap();
+
+    state_doc
+        .reactions
+        .iter()
+        .map(|s| (s.id.clone(), s.name.clone()))
+        .collect()
+}
+
+/// Retrieves a specific reaction by its ID
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `id` - The ID of the reaction to retrieve
+///
+/// # Returns
+/// Result containing the reaction or an error if not found
+pub fn get_reaction(state: &EnzymeMLState, id: &str) -> Result<Reaction, SuiteError> {
+    get_object!(state.doc, reactions, id, id)
+}
+
+/// Deletes a reaction from the EnzymeML document
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `id` - The ID of the reaction to delete
+/// * `events` - Sink notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn delete_reaction(
+    state: &EnzymeMLState,
+    id: &str,
+    events: &dyn EventSink,
+) -> Result<(), SuiteError> {
+    // Signature: State, Path, ID, ID property
+    delete_object!(state.doc, reactions, id, id);
+
+    update_event!(state, events, EntityKind::Reaction, ChangeOp::Delete, [id]);
+    update_report!(state, events);
+
+    Ok(())
+}
+
+/// Processes a kinetic law to extract and create necessary parameters
+///
+/// # Arguments
+/// * `state` - The shared EnzymeML document state
+/// * `kinetic_law` - The kinetic law to process
+///
+/// # Returns
+/// Result indicating success or failure
+fn process_kinetic_law(
+    state: &EnzymeMLState,
+    kinetic_law: &Option<Equation>,
+    events: &dyn EventSink,
+) -> Result<(), SuiteError> {
+    kinetic_law.as_ref().map_or(Ok(()), |law| {
+        process_equation(state, law)?;
+
+        update_event!(
+            state,
+            events,
+            EntityKind::Parameter,
+            ChangeOp::Bulk,
+            WHOLE_COLLECTION
+        );
+        update_report!(state, events);
+
+        Ok(())
+    })
+}
@@ -1,10 +1,126 @@
 use enzymeml::prelude::EnzymeMLDocument;
-use serde_json::Error;
+use serde_json::{Error, Map, Value};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::SuiteError;
 
 pub fn deserialize_doc(json: &str) -> Result<EnzymeMLDocument, Error> {
     serde_json::from_str(json)
 }
 
-pub fn serialize_doc(document: &EnzymeMLDocument) -> Result<String, String> {
-    serde_json::to_string_pretty(document).map_err(|err| err.to_string())
+pub fn serialize_doc(document: &EnzymeMLDocument) -> Result<String, SuiteError> {
+    Ok(serde_json::to_string_pretty(document)?)
+}
+
+/// Returns the ID of an entity within a serialized collection of a document
+///
+/// Entities are identified by their `id`, equations and species data of
+/// measurements by their `species_id`.
+///
+/// # Arguments
+/// * `item` - The serialized entity
+pub fn entity_key(item: &Value) -> Option<&str> {
+    item.get("id")
+        .or_else(|| item.get("species_id"))
+        .and_then(Value::as_str)
+}
+
+/// Returns whether all items of a serialized collection are entities with distinct IDs
+///
+/// # Arguments
+/// * `items` - The serialized items of the collection
+pub fn is_keyed(items: &[Value]) -> bool {
+    let keys: Vec<&str> = items.iter().filter_map(entity_key).collect();
+    keys.len() == items.len()
+        && keys
+            .iter()
+            .enumerate()
+            .all(|(i, key)| !keys[..i].contains(key))
+}
+
+/// Returns the fields of a serialized document
+///
+/// # Arguments
+/// * `value` - The serialized document
+pub fn into_object(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(fields) => fields,
+        _ => Map::new(),
+    }
+}
+
+/// Removes a collection from a serialized document
+///
+/// # Arguments
+/// * `doc` - The fields of the serialized document
+/// * `collection` - Name of the collection, e.g. `small_molecules`
+///
+/// # Returns
+/// The items of the collection, empty if the document has none
+pub fn take_items(doc: &mut Map<String, Value>, collection: &str) -> Vec<Value> {
+    match doc.remove(collection) {
+        Some(Value::Array(items)) => items,
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the SHA-256 digest of a file content as hex string
+///
+/// Used to tell whether a file changed since it was last read or written.
+///
+/// # Arguments
+/// * `content` - The content to digest
+pub fn digest(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Writes a document to a JSON file
+///
+/// A previous version of the file is kept as backup next to it, suffixed with
+/// `.bak`, and the new content is written atomically.
+///
+/// # Arguments
+/// * `path` - The path of the file to write
+/// * `document` - The EnzymeML document to write
+///
+/// # Returns
+/// Result containing the digest of the written content
+pub fn write_doc(path: &Path, document: &EnzymeMLDocument) -> Result<String, SuiteError> {
+    let json = serialize_doc(document)?;
+
+    if path.exists() {
+        let backup = suffixed_path(path, "bak");
+        fs::copy(path, &backup).map_err(|e| SuiteError::io(&backup, e))?;
+    }
+
+    write_atomically(path, &json)?;
+    Ok(digest(&json))
+}
+
+/// Writes content to a file atomically
+///
+/// The content is written to a temporary file next to the target first and
+/// moved in place, so a crash while writing never leaves a truncated file.
+///
+/// # Arguments
+/// * `path` - The path of the file to write
+/// * `content` - The content to write
+pub fn write_atomically(path: &Path, content: &str) -> Result<(), SuiteError> {
+    let temporary = suffixed_path(path, "tmp");
+
+    fs::write(&temporary, content).map_err(|e| SuiteError::io(&temporary, e))?;
+    fs::rename(&temporary, path).map_err(|e| SuiteError::io(path, e))
+}
+
+/// Returns the path of a file with a suffix appended to its name
+fn suffixed_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(suffix);
+    path.with_file_name(name)
 }
@@ -0,0 +1,271 @@
+//! Errors returned by commands and the REST API
+//!
+//! All fallible operations report a [`SuiteError`], which tells the frontend
+//! and API clients what went wrong in a machine readable way. Every variant
+//! carries a human readable `message`, so the error can be shown as is.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::path::Path;
+
+/// Error of an operation of the EnzymeML Suite
+///
+/// Serialized with a `kind` tag, e.g. `{ "kind": "NotFound", "entity": "Small molecule",
+/// "id": "s0", "message": "Small molecule s0 not found" }`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type, JsonSchema)]
+#[serde(tag = "kind")]
+pub enum SuiteError {
+    /// The requested entity does not exist
+    NotFound {
+        /// Kind of the entity that has been looked up
+        entity: String,
+        /// ID of the entity that has been looked up
+        id: String,
+        /// Human readable description of the error
+        message: String,
+    },
+    /// The input or the document is invalid for the requested operation
+    Validation {
+        /// Human readable description of the error
+        message: String,
+    },
+    /// A file could not be read or written
+    Io {
+        /// Path of the affected file, if known
+        path: Option<String>,
+        /// Human readable description of the error
+        message: String,
+    },
+    /// A database query failed
+    Database {
+        /// Human readable description of the error
+        message: String,
+    },
+    /// A document, equation or other input could not be parsed
+    Parse {
+        /// Human readable description of the error
+        message: String,
+    },
+    /// The operation conflicts with existing data
+    Conflict {
+        /// Human readable description of the error
+        message: String,
+    },
+    /// The operation has been cancelled by the user, e.g. by closing a dialog
+    Cancelled {
+        /// Human readable description of the error
+        message: String,
+    },
+    /// Any other failure, such as an external process that could not be run
+    Internal {
+        /// Human readable description of the error
+        message: String,
+    },
+}
+
+impl SuiteError {
+    /// Creates an error for an entity that does not exist
+    ///
+    /// # Arguments
+    /// * `entity` - Kind of the entity, e.g. `Small molecule`
+    /// * `id` - ID of the entity
+    pub fn not_found(entity: &str, id: impl Display) -> Self {
+        SuiteError::NotFound {
+            entity: entity.to_string(),
+            id: id.to_string(),
+            message: format!("{} {} not found", entity, id),
+        }
+    }
+
+    /// Creates an error for invalid input
+    ///
+    /// # Arguments
+    /// * `message` - Human readable description of the error
+    pub fn validation(message: impl Into<String>) -> Self {
+        SuiteError::Validation {
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for a file that could not be read or written
+    ///
+    /// # Arguments
+    /// * `path` - Path of the affected file
+    /// * `err` - The underlying error
+    pub fn io(path: &Path, err: impl Display) -> Self {
+        SuiteError::Io {
+            path: Some(path.display().to_string()),
+            message: format!("{}: {}", path.display(), err),
+        }
+    }
+
+    /// Creates an error for a failed database query
+    ///
+    /// # Arguments
+    /// * `message` - Human readable description of the error
+    pub fn database(message: impl Into<String>) -> Self {
+        SuiteError::Database {
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for input that could not be parsed
+    ///
+    /// # Arguments
+    /// * `message` - Human readable description of the error
+    pub fn parse(message: impl Into<String>) -> Self {
+        SuiteError::Parse {
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for an operation conflicting with existing data
+    ///
+    /// # Arguments
+    /// * `message` - Human readable description of the error
+    pub fn conflict(message: impl Into<String>) -> Self {
+        SuiteError::Conflict {
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for an operation cancelled by the user
+    ///
+    /// # Arguments
+    /// * `message` - Human readable description of the error
+    pub fn cancelled(message: impl Into<String>) -> Self {
+        SuiteError::Cancelled {
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for any other failure
+    ///
+    /// # Arguments
+    /// * `message` - Human readable description of the error
+    pub fn internal(message: impl Into<String>) -> Self {
+        SuiteError::Internal {
+            message: message.into(),
+        }
+    }
+
+    /// Maps the error of a database query for a single entry
+    ///
+    /// Missing entries are reported as [`SuiteError::NotFound`] naming the
+    /// entry, entries violating a uniqueness rule as [`SuiteError::Conflict`]
+    /// and all other failures as [`SuiteError::Database`].
+    ///
+    /// # Arguments
+    /// * `err` - The error of the query
+    /// * `entity` - Kind of the queried entry, e.g. `Document`
+    /// * `id` - ID of the queried entry
+    pub fn from_query(err: diesel::result::Error, entity: &str, id: impl Display) -> Self {
+        match err {
+            diesel::result::Error::NotFound => SuiteError::not_found(entity, id),
+            err => SuiteError::from(err),
+        }
+    }
+
+    /// Returns the human readable description of the error
+    pub fn message(&self) -> &str {
+        match self {
+            SuiteError::NotFound { message, .. }
+            | SuiteError::Validation { message }
+            | SuiteError::Io { message, .. }
+            | SuiteError::Database { message }
+            | SuiteError::Parse { message }
+            | SuiteError::Conflict { message }
+            | SuiteError::Cancelled { message }
+            | SuiteError::Internal { message } => message,
+        }
+    }
+}
+
+/// Returns the entity name of the items of a document collection
+///
+/// Used to name the entity in [`SuiteError::NotFound`] errors raised by the
+/// collection macros.
+///
+/// # Arguments
+/// * `collection` - Name of the collection, e.g. `small_molecules`
+pub fn entity_of(collection: &str) -> &'static str {
+    match collection {
+        "small_molecules" => "Small molecule",
+        "proteins" => "Protein",
+        "complexes" => "Complex",
+        "reactions" => "Reaction",
+        "measurements" => "Measurement",
+        "parameters" => "Parameter",
+        "equations" => "Equation",
+        "vessels" => "Vessel",
+        _ => "Entry",
+    }
+}
+
+impl Display for SuiteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for SuiteError {}
+
+/// Start of the message of SQLite for violations of unique indexes
+const UNIQUE_VIOLATION: &str = "UNIQUE constraint failed: ";
+
+impl From<diesel::result::Error> for SuiteError {
+    fn from(err: diesel::result::Error) -> Self {
+        match err {
+            diesel::result::Error::NotFound => SuiteError::NotFound {
+                entity: "Entry".to_string(),
+                id: String::new(),
+                message: "Entry not found".to_string(),
+            },
+            // Triggers enforcing unique keys raise the message of a unique index
+            diesel::result::Error::DatabaseError(kind, info)
+                if matches!(kind, diesel::result::DatabaseErrorKind::UniqueViolation)
+                    || info.message().starts_with(UNIQUE_VIOLATION) =>
+            {
+                // SQLite names the violated columns, e.g. "UNIQUE constraint failed: proteins.name"
+                let columns = info.message().split_once(": ").map(|(_, columns)| {
+                    columns
+                        .split(", ")
+                        .map(|column| column.rsplit('.').next().unwrap_or(column))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                });
+
+                match columns {
+                    Some(columns) => SuiteError::conflict(format!(
+                        "An entry with the same {} already exists",
+                        columns
+                    )),
+                    None => SuiteError::conflict(info.message()),
+                }
+            }
+            err => SuiteError::database(err.to_string()),
+        }
+    }
+}
+
+impl From<serde_json::Error> for SuiteError {
+    fn from(err: serde_json::Error) -> Self {
+        SuiteError::parse(err.to_string())
+    }
+}
+
+impl From<std::io::Error> for SuiteError {
+    fn from(err: std::io::Error) -> Self {
+        SuiteError::Io {
+            path: None,
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<SuiteError> for String {
+    fn from(err: SuiteError) -> Self {
+        err.to_string()
+    }
+}
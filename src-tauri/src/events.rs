@@ -0,0 +1,328 @@
+//! Notification of document changes independent of Tauri
+//!
+//! Document operations report which parts of the document changed by emitting
+//! a [`SuiteEvent`] to an [`EventSink`]. The desktop application forwards them
+//! to the frontend as typed events via the [`AppHandle`], whereas headless
+//! consumers such as the command line interface or tests can ignore or record
+//! them.
+//!
+//! Every event delivered to the frontend is declared as [`tauri_specta::Event`],
+//! so its name and payload are part of the generated TypeScript bindings.
+//! Changes of entities are described by a single [`DocumentChanged`] event,
+//! from which the coarse collection events such as `update_small_mols` are
+//! derived for views that simply refetch a whole collection.
+
+use enzymeml::validation::consistency::{Report, Severity};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Runtime};
+use tauri_specta::Event;
+
+use crate::states::EnzymeMLState;
+
+/// Event signalling that the whole document has been replaced or renamed
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_document")]
+pub struct UpdateDocument;
+
+/// Event signalling that the validation report has been refreshed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_report")]
+pub struct UpdateReport {
+    /// Whether the document is valid overall
+    pub is_valid: bool,
+    /// Number of issues with severity `Error`
+    pub errors: u32,
+    /// Number of issues with severity `Warning`
+    pub warnings: u32,
+}
+
+impl From<&Report> for UpdateReport {
+    fn from(report: &Report) -> Self {
+        let count = |matches: fn(&Severity) -> bool| {
+            report
+                .errors
+                .iter()
+                .filter(|result| matches(result.severity()))
+                .count() as u32
+        };
+
+        UpdateReport {
+            is_valid: report.is_valid,
+            errors: count(|severity| matches!(severity, Severity::Error)),
+            warnings: count(|severity| matches!(severity, Severity::Warning)),
+        }
+    }
+}
+
+/// Event signalling that the small molecules of the document changed
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_small_mols")]
+pub struct UpdateSmallMols;
+
+/// Event signalling that the proteins of the document changed
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_proteins")]
+pub struct UpdateProteins;
+
+/// Event signalling that the vessels of the document changed
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_vessels")]
+pub struct UpdateVessels;
+
+/// Event signalling that the reactions of the document changed
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_reactions")]
+pub struct UpdateReactions;
+
+/// Event signalling that the measurements of the document changed
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_measurements")]
+pub struct UpdateMeasurements;
+
+/// Event signalling that the parameters of the document changed
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_parameters")]
+pub struct UpdateParameters;
+
+/// Event signalling that the equations of the document changed
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_equations")]
+pub struct UpdateEquations;
+
+/// Event signalling that the data shown in the visualisation changed
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_vis")]
+pub struct UpdateVis;
+
+/// Event signalling that documents have been opened, closed or switched
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "update_workspace")]
+pub struct UpdateWorkspace;
+
+/// Event signalling that the origin file of a document has been changed by another program
+///
+/// Emitted once per external version of the file. The frontend offers to
+/// reload the file, keep the document as it is or merge both.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "file_changed")]
+pub struct FileChanged {
+    /// Handle of the document within the workspace
+    pub document: String,
+    /// Path of the changed file
+    pub path: PathBuf,
+    /// Whether the document has unsaved changes that a reload would discard
+    pub dirty: bool,
+}
+
+/// Event asking the frontend to navigate to a route, e.g. after a file drop
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "navigate_to")]
+pub struct NavigateTo(pub String);
+
+/// Kind of the entities affected by a change of the document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    /// The document itself, e.g. when it has been replaced or renamed
+    Document,
+    SmallMolecule,
+    Protein,
+    Vessel,
+    Reaction,
+    Measurement,
+    Parameter,
+    Equation,
+}
+
+/// Operation that changed the document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeOp {
+    /// Entities have been created
+    Create,
+    /// Entities have been updated
+    Update,
+    /// Entities have been deleted
+    Delete,
+    /// Many entities changed at once, e.g. by an import or a derivation
+    Bulk,
+}
+
+/// IDs of a change affecting a whole collection rather than single entities
+pub const WHOLE_COLLECTION: [&str; 0] = [];
+
+/// Event describing a change of the document
+///
+/// Carries the kind and IDs of the affected entities, so views can refetch
+/// only what changed. The revision increases with every change of the state
+/// and allows to discard outdated responses.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type, Event)]
+#[tauri_specta(event_name = "document_changed")]
+pub struct DocumentChanged {
+    /// Handle of the changed document within the workspace
+    pub document: String,
+    /// Kind of the affected entities
+    pub kind: EntityKind,
+    /// IDs of the affected entities, empty if the whole collection changed
+    pub ids: Vec<String>,
+    /// Operation that changed the entities
+    pub op: ChangeOp,
+    /// Revision of the document after the change
+    pub revision: u64,
+}
+
+/// Change of the document reported by document operations
+#[derive(Debug, Clone, PartialEq)]
+pub enum SuiteEvent {
+    /// Entities of the document have been created, updated or deleted
+    Changed(DocumentChanged),
+    /// The validation report has been refreshed
+    Report(UpdateReport),
+    /// The data shown in the visualisation changed
+    Visualisation,
+    /// Documents have been opened, closed or switched
+    Workspace,
+    /// The origin file of a document has been changed by another program
+    File(FileChanged),
+}
+
+impl SuiteEvent {
+    /// Creates the event for a change of the document
+    ///
+    /// Advances the revision of the state, so every change is reported with
+    /// a revision of its own.
+    ///
+    /// # Arguments
+    /// * `state` - The state holding the changed document
+    /// * `kind` - Kind of the affected entities
+    /// * `op` - Operation that changed the entities
+    /// * `ids` - IDs of the affected entities
+    pub fn changed<I, S>(state: &EnzymeMLState, kind: EntityKind, op: ChangeOp, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        SuiteEvent::Changed(DocumentChanged {
+            document: state.handle.clone(),
+            kind,
+            ids: ids.into_iter().map(|id| id.to_string()).collect(),
+            op,
+            revision: state.next_revision(),
+        })
+    }
+
+    /// Creates the event for a change of the document as a whole
+    ///
+    /// # Arguments
+    /// * `state` - The state holding the changed document
+    /// * `op` - Operation that changed the document
+    pub fn document(state: &EnzymeMLState, op: ChangeOp) -> Self {
+        Self::changed(state, EntityKind::Document, op, WHOLE_COLLECTION)
+    }
+
+    /// Creates the event for the document having been saved or loaded
+    ///
+    /// Unlike [`SuiteEvent::document`], the state is marked as saved, since
+    /// it matches the stored document after this change.
+    ///
+    /// # Arguments
+    /// * `state` - The state holding the saved or loaded document
+    /// * `op` - Operation that changed the document
+    pub fn persisted(state: &EnzymeMLState, op: ChangeOp) -> Self {
+        let event = Self::document(state, op);
+        state.mark_saved();
+        event
+    }
+
+    /// Creates the event for the current validation report of a state
+    ///
+    /// # Arguments
+    /// * `state` - The state holding the refreshed validation report
+    pub fn report(state: &EnzymeMLState) -> Self {
+        SuiteEvent::Report(UpdateReport::from(
+            &*state.validation_report.lock().unwrap(),
+        ))
+    }
+
+    /// Returns the name under which the event is delivered to the frontend
+    pub fn name(&self) -> &str {
+        match self {
+            SuiteEvent::Changed(_) => DocumentChanged::NAME,
+            SuiteEvent::Report(_) => UpdateReport::NAME,
+            SuiteEvent::Visualisation => UpdateVis::NAME,
+            SuiteEvent::Workspace => UpdateWorkspace::NAME,
+            SuiteEvent::File(_) => FileChanged::NAME,
+        }
+    }
+}
+
+/// Receiver of the events emitted by document operations
+pub trait EventSink: Send + Sync {
+    /// Emits the given event
+    ///
+    /// # Arguments
+    /// * `event` - The event to emit
+    ///
+    /// # Returns
+    /// Result indicating whether the event could be delivered
+    fn emit_event(&self, event: SuiteEvent) -> Result<(), String>;
+}
+
+impl<R: Runtime> EventSink for AppHandle<R> {
+    fn emit_event(&self, event: SuiteEvent) -> Result<(), String> {
+        let result = match event {
+            SuiteEvent::Changed(change) => {
+                // Views that refetch whole collections keep listening to the
+                // collection events, which are derived from the change
+                let collection = match change.kind {
+                    EntityKind::Document => UpdateDocument.emit(self),
+                    EntityKind::SmallMolecule => UpdateSmallMols.emit(self),
+                    EntityKind::Protein => UpdateProteins.emit(self),
+                    EntityKind::Vessel => UpdateVessels.emit(self),
+                    EntityKind::Reaction => UpdateReactions.emit(self),
+                    EntityKind::Measurement => UpdateMeasurements.emit(self),
+                    EntityKind::Parameter => UpdateParameters.emit(self),
+                    EntityKind::Equation => UpdateEquations.emit(self),
+                };
+
+                collection.and_then(|_| change.emit(self))
+            }
+            SuiteEvent::Report(report) => report.emit(self),
+            SuiteEvent::Visualisation => UpdateVis.emit(self),
+            // Views showing the active document refetch it on document updates
+            SuiteEvent::Workspace => UpdateWorkspace
+                .emit(self)
+                .and_then(|_| UpdateDocument.emit(self)),
+            SuiteEvent::File(change) => change.emit(self),
+        };
+
+        result.map_err(|e| e.to_string())
+    }
+}
+
+impl<T: EventSink + ?Sized> EventSink for &T {
+    fn emit_event(&self, event: SuiteEvent) -> Result<(), String> {
+        (**self).emit_event(event)
+    }
+}
+
+impl<T: EventSink + ?Sized> EventSink for Arc<T> {
+    fn emit_event(&self, event: SuiteEvent) -> Result<(), String> {
+        (**self).emit_event(event)
+    }
+}
+
+/// Event sink discarding all events
+///
+/// Used when no frontend is attached, such as when serving the REST API from
+/// the command line interface.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopSink;
+
+impl EventSink for NoopSink {
+    fn emit_event(&self, _event: SuiteEvent) -> Result<(), String> {
+        Ok(())
+    }
+}
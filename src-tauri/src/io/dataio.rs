@@ -2,16 +2,24 @@
 
 use diesel::prelude::*;
 use enzymeml::prelude::EnzymeMLDocument;
-use std::error::Error;
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tauri::{AppHandle, Emitter, State};
-use tauri_plugin_dialog::DialogExt;
+use tauri::{AppHandle, State};
+use tauri_plugin_dialog::{DialogExt, FilePath};
+use tauri_specta::Event;
 
-use crate::db::establish_connection;
-use crate::docutils::{deserialize_doc, serialize_doc};
-use crate::models::Document;
-use crate::states::{EnzymeMLState, ExposedEnzymeMLState};
+use crate::actions::utils::seconds_since_epoch;
+use crate::db::{contains_pattern, establish_connection, LIKE_ESCAPE};
+use crate::docutils::{deserialize_doc, digest, serialize_doc, write_doc};
+use crate::error::SuiteError;
+use crate::events::{ChangeOp, EntityKind, NavigateTo, SuiteEvent};
+use crate::io::projection::{project_document, remove_from_projection};
+use crate::io::recent::remember_recent_file;
+use crate::io::search::{index_document, remove_from_index};
+use crate::io::stringarray::StringArray;
+use crate::models::{Document, DocumentMetadata};
+use crate::states::{EnzymeMLState, ExposedEnzymeMLState, FileVersion, Workspace};
 use crate::{models, update_event};
 use crate::{schema, update_report};
 
@@ -22,13 +30,19 @@ use crate::{schema, update_report};
 /// can be safely exposed to the frontend interface.
 ///
 /// # Arguments
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 ///
 /// # Returns
 /// An exposed version of the EnzymeML state for frontend use
 #[tauri::command]
-pub fn get_state(state: State<'_, Arc<EnzymeMLState>>) -> ExposedEnzymeMLState {
-    ExposedEnzymeMLState::from(state.inner())
+#[specta::specta]
+pub fn get_state(
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<ExposedEnzymeMLState, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    Ok(ExposedEnzymeMLState::from(&state))
 }
 
 /// Exports measurement data to an Excel file
@@ -39,15 +53,19 @@ pub fn get_state(state: State<'_, Arc<EnzymeMLState>>) -> ExposedEnzymeMLState {
 /// automatically opened using the system's default Excel application.
 ///
 /// # Arguments
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 ///
 /// # Returns
 /// Result containing either the saved file path or an error message
 #[tauri::command]
+#[specta::specta]
 pub async fn export_measurements(
-    state: State<'_, Arc<EnzymeMLState>>,
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<PathBuf, String> {
+) -> Result<PathBuf, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
     let dialog_result = app_handle
         .dialog()
         .file()
@@ -57,16 +75,16 @@ pub async fn export_measurements(
 
     match dialog_result {
         Some(path) => {
+            let path = selected_path(&path)?;
             let state_doc = state.doc.lock().unwrap();
-            let path = PathBuf::from(path.as_path().unwrap());
             state_doc
                 .to_excel(&path, false, true)
-                .expect("Failed to export to Excel");
+                .map_err(|err| SuiteError::io(&path, err))?;
 
-            open::that(&path).map_err(|err| err.to_string())?;
+            open::that(&path).map_err(|err| SuiteError::io(&path, err))?;
             Ok(path)
         }
-        None => Err("No file selected".to_string()),
+        None => Err(SuiteError::cancelled("No file selected")),
     }
 }
 
@@ -78,16 +96,20 @@ pub async fn export_measurements(
 /// an update event is emitted to notify the frontend of the changes.
 ///
 /// # Arguments
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 /// * `app_handle` - Handle to the Tauri application for event emission
 ///
 /// # Returns
 /// Result containing either the number of imported measurements or an error message
 #[tauri::command]
+#[specta::specta]
 pub async fn import_excel_meas(
-    state: State<'_, Arc<EnzymeMLState>>,
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<usize, String> {
+) -> Result<usize, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
     let dialog_result = app_handle
         .dialog()
         .file()
@@ -97,19 +119,29 @@ pub async fn import_excel_meas(
 
     match dialog_result {
         Some(path) => {
+            let path = selected_path(&path)?;
             let mut state_doc = state.doc.lock().unwrap();
             let prev_amnt_meas = state_doc.measurements.len();
-            let path = PathBuf::from(path.as_path().unwrap());
-            state_doc
-                .add_from_excel(path, true)
-                .map_err(|err| err.to_string())?;
+            state_doc.add_from_excel(path, true).map_err(|err| {
+                SuiteError::parse(format!("Failed to import Excel file: {}", err))
+            })?;
+
+            let ids = state_doc.measurements[prev_amnt_meas..]
+                .iter()
+                .map(|meas| meas.id.clone());
 
-            update_event!(app_handle, "update_measurements");
+            update_event!(
+                state,
+                app_handle,
+                EntityKind::Measurement,
+                ChangeOp::Create,
+                ids
+            );
             update_report!(state, app_handle, &state_doc);
 
             Ok(state_doc.measurements.len() - prev_amnt_meas)
         }
-        None => Err("No file selected".to_string()),
+        None => Err(SuiteError::cancelled("No file selected")),
     }
 }
 
@@ -118,60 +150,113 @@ pub async fn import_excel_meas(
 /// Opens a file dialog allowing the user to save the current EnzymeML document as
 /// a JSON file. The entire document structure including all measurements, vessels,
 /// species, and other data is serialized into a human-readable JSON format that
-/// can be stored, shared, or imported later.
+/// can be stored, shared, or imported later. Unlike `save_to_file_as`, the
+/// exported file does not become the origin of the document.
 ///
 /// # Arguments
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 ///
 /// # Returns
 /// Result containing either the saved file path or an error message
 #[tauri::command]
+#[specta::specta]
 pub async fn export_to_json(
-    state: State<'_, Arc<EnzymeMLState>>,
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<PathBuf, String> {
-    let title = {
-        let state_doc = state.doc.lock().unwrap();
-        state_doc.name.clone().replace(" ", "_").to_lowercase()
+) -> Result<PathBuf, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let path = pick_save_path(&state, &app_handle)?;
+    write_doc(&path, &state.doc.lock().unwrap())?;
+
+    Ok(path)
+}
+
+/// Saves the EnzymeML document back to the file it originates from
+///
+/// Writes the document to the file it was loaded from or last saved to. The
+/// previous version of the file is kept as backup and the new content is
+/// written atomically. Documents that do not originate from a file are saved
+/// to a file chosen in a dialog, just like with `save_to_file_as`.
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing either the path of the written file or an error message
+#[tauri::command]
+#[specta::specta]
+pub async fn save_to_file(
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<PathBuf, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let origin = state.path.lock().unwrap().clone();
+    let path = match origin {
+        Some(path) => path,
+        None => pick_save_path(&state, &app_handle)?,
     };
 
-    let dialog_result = app_handle
-        .dialog()
-        .file()
-        .set_title("Save Document")
-        .set_file_name(format!("{}.json", title))
-        .blocking_save_file();
+    save_into_file(&state, &path, &app_handle)?;
 
-    match dialog_result {
-        Some(path) => {
-            let state_doc = state.doc.lock().unwrap();
-            let json = serialize_doc(&state_doc).expect("Failed to serialize document");
-            let path = PathBuf::from(path.as_path().unwrap());
-            std::fs::write(&path, json).expect("Failed to write file");
-            Ok(path)
-        }
-        None => Err("No file selected".to_string()),
-    }
+    Ok(path)
+}
+
+/// Saves the EnzymeML document to a new file
+///
+/// Opens a file dialog allowing the user to choose the file to save the
+/// document to. The chosen file becomes the origin of the document, so later
+/// calls of `save_to_file` write back to it.
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing either the path of the written file or an error message
+#[tauri::command]
+#[specta::specta]
+pub async fn save_to_file_as(
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<PathBuf, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let path = pick_save_path(&state, &app_handle)?;
+
+    save_into_file(&state, &path, &app_handle)?;
+
+    Ok(path)
 }
 
 /// Loads an EnzymeML document from a JSON file
 ///
 /// Opens a file dialog allowing the user to select and load a previously saved
 /// EnzymeML document from a JSON file. The loaded document completely replaces
-/// the current document in memory. After successful loading, an update event
-/// is emitted to refresh the entire frontend interface.
+/// the current document in memory and the file becomes its origin. After
+/// successful loading, an update event is emitted to refresh the entire
+/// frontend interface.
 ///
 /// # Arguments
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 /// * `app_handle` - Handle to the Tauri application for event emission
 ///
 /// # Returns
 /// Result indicating success or failure
 #[tauri::command]
+#[specta::specta]
 pub async fn load_json(
-    state: State<'_, Arc<EnzymeMLState>>,
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<(), String> {
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
     let dialog_result = app_handle
         .dialog()
         .file()
@@ -180,19 +265,8 @@ pub async fn load_json(
         .blocking_pick_file();
 
     match dialog_result {
-        Some(path) => {
-            let mut state_doc = state.doc.lock().unwrap();
-            let path = PathBuf::from(path.as_path().unwrap());
-            let json = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
-            let doc = deserialize_doc(json.as_str()).map_err(|err| err.to_string())?;
-            *state_doc = doc;
-
-            update_event!(app_handle, "update_document");
-            update_report!(state, app_handle, &state_doc);
-
-            Ok(())
-        }
-        None => Err("No file selected".to_string()),
+        Some(path) => open_file(&state, &selected_path(&path)?, &app_handle),
+        None => Err(SuiteError::cancelled("No file selected")),
     }
 }
 
@@ -200,49 +274,28 @@ pub async fn load_json(
 ///
 /// Loads an EnzymeML document from a specified file path without opening a file dialog.
 /// This function is designed for drag-and-drop functionality where the file path
-/// is already known. The document content is read, deserialized, and loaded into
-/// the application state. An update event is emitted to refresh the frontend.
+/// is already known, and for reopening recent files. The document content is read,
+/// deserialized, and loaded into the application state, and the file becomes its
+/// origin. An update event is emitted to refresh the frontend.
 ///
 /// # Arguments
 /// * `file_path` - The path to the JSON file to load
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 /// * `app_handle` - Handle to the Tauri application for event emission
 ///
 /// # Returns
 /// Result indicating success or failure
 #[tauri::command]
+#[specta::specta]
 pub async fn load_json_from_path(
     file_path: String,
-    state: State<'_, Arc<EnzymeMLState>>,
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<(), String> {
-    let path = PathBuf::from(&file_path);
-
-    // Validate file extension
-    if let Some(extension) = path.extension() {
-        if extension != "json" {
-            return Err("Only JSON files are supported for EnzymeML documents".to_string());
-        }
-    } else {
-        return Err("File must have a .json extension".to_string());
-    }
-
-    // Read and parse the file
-    let json = std::fs::read_to_string(&path)
-        .map_err(|err| format!("Failed to read file {}: {}", file_path, err))?;
-
-    let doc = deserialize_doc(json.as_str())
-        .map_err(|err| format!("Failed to parse EnzymeML document: {}", err))?;
-
-    // Update the state
-    let mut state_doc = state.doc.lock().unwrap();
-    *state_doc = doc;
-
-    // Notify the frontend
-    update_event!(app_handle, "update_document");
-    update_report!(state, app_handle, &state_doc);
-
-    Ok(())
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    open_file(&state, Path::new(&file_path), &app_handle)
 }
 
 /// Imports measurements from an Excel file at a specific path (for drag and drop)
@@ -253,41 +306,110 @@ pub async fn load_json_from_path(
 ///
 /// # Arguments
 /// * `file_path` - The path to the Excel file to import
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 /// * `app_handle` - Handle to the Tauri application for event emission
 ///
 /// # Returns
 /// Result containing either the number of imported measurements or an error message
 #[tauri::command]
+#[specta::specta]
 pub async fn import_excel_from_path(
     file_path: String,
-    state: State<'_, Arc<EnzymeMLState>>,
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<usize, String> {
-    let path = PathBuf::from(&file_path);
+) -> Result<usize, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let mut state_doc = state.doc.lock().unwrap();
+    let count = import_measurements(&mut state_doc, Path::new(&file_path))?;
+    let ids = state_doc.measurements[state_doc.measurements.len() - count..]
+        .iter()
+        .map(|meas| meas.id.clone());
+
+    update_event!(
+        state,
+        app_handle,
+        EntityKind::Measurement,
+        ChangeOp::Create,
+        ids
+    );
+    update_report!(state, app_handle, &state_doc);
+
+    Ok(count)
+}
+
+/// Reads an EnzymeML document from a JSON file
+///
+/// Shared by the drag and drop handling and the command line interface.
+///
+/// # Arguments
+/// * `path` - The path to the JSON file to read
+///
+/// # Returns
+/// Result containing either the parsed document or an error message
+pub fn read_document(path: &Path) -> Result<EnzymeMLDocument, SuiteError> {
+    read_file_version(path).map(|version| version.doc)
+}
+
+/// Reads an EnzymeML document from a JSON file along with the digest of the file
+///
+/// # Arguments
+/// * `path` - The path to the JSON file to read
+///
+/// # Returns
+/// Result containing either the read file version or an error message
+pub fn read_file_version(path: &Path) -> Result<FileVersion, SuiteError> {
+    // Validate file extension
+    if let Some(extension) = path.extension() {
+        if extension != "json" {
+            return Err(SuiteError::validation(
+                "Only JSON files are supported for EnzymeML documents",
+            ));
+        }
+    } else {
+        return Err(SuiteError::validation("File must have a .json extension"));
+    }
+
+    // Read and parse the file
+    let json = std::fs::read_to_string(path).map_err(|err| SuiteError::io(path, err))?;
+    let doc = deserialize_doc(json.as_str())
+        .map_err(|err| SuiteError::parse(format!("Failed to parse EnzymeML document: {}", err)))?;
+
+    Ok(FileVersion {
+        digest: digest(&json),
+        doc,
+    })
+}
 
+/// Adds the measurements of an Excel file to a document
+///
+/// Shared by the drag and drop handling and the command line interface.
+///
+/// # Arguments
+/// * `doc` - The EnzymeML document to add the measurements to
+/// * `path` - The path to the Excel file to import
+///
+/// # Returns
+/// Result containing either the number of imported measurements or an error message
+pub fn import_measurements(doc: &mut EnzymeMLDocument, path: &Path) -> Result<usize, SuiteError> {
     // Validate file extension
     if let Some(extension) = path.extension() {
         if extension != "xlsx" {
-            return Err(
-                "Only Excel files (.xlsx) are supported for measurement imports".to_string(),
-            );
+            return Err(SuiteError::validation(
+                "Only Excel files (.xlsx) are supported for measurement imports",
+            ));
         }
     } else {
-        return Err("File must have a .xlsx extension".to_string());
+        return Err(SuiteError::validation("File must have a .xlsx extension"));
     }
 
-    let mut state_doc = state.doc.lock().unwrap();
-    let prev_amnt_meas = state_doc.measurements.len();
-
-    state_doc
-        .add_from_excel(path, true)
-        .map_err(|err| format!("Failed to import Excel file: {}", err))?;
+    let prev_amnt_meas = doc.measurements.len();
 
-    update_event!(app_handle, "update_measurements");
-    update_report!(state, app_handle, &state_doc);
+    doc.add_from_excel(path.to_path_buf(), true)
+        .map_err(|err| SuiteError::parse(format!("Failed to import Excel file: {}", err)))?;
 
-    Ok(state_doc.measurements.len() - prev_amnt_meas)
+    Ok(doc.measurements.len() - prev_amnt_meas)
 }
 
 /// Handles file drop events by processing dropped files
@@ -298,19 +420,22 @@ pub async fn import_excel_from_path(
 ///
 /// # Arguments
 /// * `file_paths` - Array of file paths that were dropped
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 /// * `app_handle` - Handle to the Tauri application for event emission
 ///
 /// # Returns
 /// Result containing a summary of the processing results
 #[tauri::command]
+#[specta::specta]
 pub async fn handle_file_drop(
     file_paths: Vec<String>,
-    state: State<'_, Arc<EnzymeMLState>>,
+    workspace: State<'_, Arc<Workspace>>,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<String, String> {
+) -> Result<String, SuiteError> {
     if file_paths.is_empty() {
-        return Err("No files provided".to_string());
+        return Err(SuiteError::validation("No files provided"));
     }
 
     let mut results = Vec::new();
@@ -326,8 +451,13 @@ pub async fn handle_file_drop(
 
         match extension.as_deref() {
             Some("json") => {
-                match load_json_from_path(file_path.clone(), state.clone(), app_handle.clone())
-                    .await
+                match load_json_from_path(
+                    file_path.clone(),
+                    workspace.clone(),
+                    handle.clone(),
+                    app_handle.clone(),
+                )
+                .await
                 {
                     Ok(()) => {
                         let file_name = path
@@ -337,7 +467,7 @@ pub async fn handle_file_drop(
                         results.push(format!("Loaded EnzymeML document: {}", file_name));
 
                         // Emit navigation event to go to home
-                        app_handle.emit("navigate_to", "/").ok();
+                        NavigateTo("/".to_string()).emit(&app_handle).ok();
                     }
                     Err(e) => {
                         results.push(format!("Failed to load {}: {}", file_path, e));
@@ -345,8 +475,13 @@ pub async fn handle_file_drop(
                 }
             }
             Some("xlsx") => {
-                match import_excel_from_path(file_path.clone(), state.clone(), app_handle.clone())
-                    .await
+                match import_excel_from_path(
+                    file_path.clone(),
+                    workspace.clone(),
+                    handle.clone(),
+                    app_handle.clone(),
+                )
+                .await
                 {
                     Ok(count) => {
                         let file_name = path
@@ -359,7 +494,9 @@ pub async fn handle_file_drop(
                         ));
 
                         // Emit navigation event to go to measurements
-                        app_handle.emit("navigate_to", "/measurements").ok();
+                        NavigateTo("/measurements".to_string())
+                            .emit(&app_handle)
+                            .ok();
                     }
                     Err(e) => {
                         results.push(format!("Failed to import {}: {}", file_path, e));
@@ -386,10 +523,18 @@ pub async fn handle_file_drop(
 /// been saved to the database yet. An update event is emitted to refresh the frontend.
 ///
 /// # Arguments
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 /// * `app_handle` - Handle to the Tauri application for event emission
 #[tauri::command]
-pub fn new_document(state: State<Arc<EnzymeMLState>>, app_handle: AppHandle) {
+#[specta::specta]
+pub fn new_document(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+
     // Extract the guarded state values
     let mut state_doc = state.doc.lock().unwrap();
     let mut state_title = state.title.lock().unwrap();
@@ -399,10 +544,14 @@ pub fn new_document(state: State<Arc<EnzymeMLState>>, app_handle: AppHandle) {
     *state_title = "New Document".to_string();
     *state_doc = EnzymeMLDocument::default();
     *state_id = None;
+    *state.path.lock().unwrap() = None;
+    *state.file_version.lock().unwrap() = None;
 
     // Notify the frontend
-    update_event!(app_handle, "update_document");
+    update_event!(app_handle, SuiteEvent::persisted(&state, ChangeOp::Create));
     update_report!(state, app_handle, &state_doc);
+
+    Ok(())
 }
 
 /// Saves the current EnzymeML document to the database
@@ -413,31 +562,26 @@ pub fn new_document(state: State<Arc<EnzymeMLState>>, app_handle: AppHandle) {
 /// an update event is emitted to refresh the frontend interface.
 ///
 /// # Arguments
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 /// * `app_handle` - Handle to the Tauri application for event emission
 ///
 /// # Returns
 /// Result containing either the document ID or an error message
 #[tauri::command]
-pub fn save(state: State<Arc<EnzymeMLState>>, app_handle: AppHandle) -> Result<i32, String> {
-    // Extract the guarded state values
-    let state_doc = state.doc.lock().unwrap();
-    let state_title = state.title.lock().unwrap();
-    let mut state_id = state.id.lock().unwrap();
-
-    if let Some(id) = *state_id {
-        update_event!(app_handle, "update_document");
-        update_report!(state, app_handle, &state_doc);
-        update_document(id, &state_doc).map_err(|err| err.to_string())
-    } else {
-        let id = insert_document(&state_title, &state_doc).map_err(|err| err.to_string())?;
-        *state_id = Some(id);
+#[specta::specta]
+pub fn save(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<i32, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let id = save_state(&state)?;
 
-        update_event!(app_handle, "update_document");
-        update_report!(state, app_handle, &state_doc);
+    update_event!(app_handle, SuiteEvent::persisted(&state, ChangeOp::Update));
+    update_report!(state, app_handle);
 
-        Ok(id)
-    }
+    Ok(id)
 }
 
 /// Loads an EnzymeML document from the database
@@ -448,32 +592,189 @@ pub fn save(state: State<Arc<EnzymeMLState>>, app_handle: AppHandle) -> Result<i
 ///
 /// # Arguments
 /// * `id` - The ID of the document to load
-/// * `state` - The shared EnzymeML document state
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
 ///
 /// # Returns
 /// Result indicating success or failure
 #[tauri::command]
+#[specta::specta]
 pub fn load(
     id: i32,
-    state: State<Arc<EnzymeMLState>>,
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
     app_handle: AppHandle,
-) -> Result<(), String> {
+) -> Result<(), SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    load_into_state(&state, id)?;
+
+    update_event!(app_handle, SuiteEvent::persisted(&state, ChangeOp::Bulk));
+    update_report!(state, app_handle);
+
+    Ok(())
+}
+
+/// Returns the local path of a file selected in a dialog
+///
+/// # Arguments
+/// * `path` - The path returned by the dialog
+///
+/// # Returns
+/// Result containing the local path or an error if the selection is not a local file
+pub(crate) fn selected_path(path: &FilePath) -> Result<PathBuf, SuiteError> {
+    path.as_path()
+        .map(Path::to_path_buf)
+        .ok_or_else(|| SuiteError::validation("The selected file is not a local file"))
+}
+
+/// Asks the user for the file to save a document to
+///
+/// # Arguments
+/// * `state` - The state holding the document to save
+/// * `app_handle` - Handle to the Tauri application used to open the dialog
+///
+/// # Returns
+/// Result containing the chosen path or an error if the dialog was cancelled
+fn pick_save_path(state: &EnzymeMLState, app_handle: &AppHandle) -> Result<PathBuf, SuiteError> {
+    let title = {
+        let state_doc = state.doc.lock().unwrap();
+        state_doc.name.clone().replace(" ", "_").to_lowercase()
+    };
+
+    let dialog_result = app_handle
+        .dialog()
+        .file()
+        .set_title("Save Document")
+        .set_file_name(format!("{}.json", title))
+        .add_filter("EnzymeML Files", &["json"])
+        .blocking_save_file();
+
+    match dialog_result {
+        Some(path) => selected_path(&path),
+        None => Err(SuiteError::cancelled("No file selected")),
+    }
+}
+
+/// Replaces the document held in a state with the content of a file
+///
+/// The file becomes the origin of the document and is added to the recent
+/// files. The state is left untouched if the file cannot be read.
+///
+/// # Arguments
+/// * `state` - The state to load into
+/// * `path` - The path of the JSON file to load
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result indicating success or failure
+fn open_file(state: &EnzymeMLState, path: &Path, app_handle: &AppHandle) -> Result<(), SuiteError> {
+    let version = read_file_version(path)?;
+
+    // Update the state
+    *state.title.lock().unwrap() = version.doc.name.clone();
+    *state.path.lock().unwrap() = Some(path.to_path_buf());
+    let mut state_doc = state.doc.lock().unwrap();
+    *state_doc = version.doc.clone();
+    *state.file_version.lock().unwrap() = Some(version);
+
+    // Failing to remember the file must not fail the loading
+    remember_recent_file(app_handle, path, &state_doc.name).ok();
+
+    // Notify the frontend
+    update_event!(app_handle, SuiteEvent::persisted(state, ChangeOp::Bulk));
+    update_report!(state, app_handle, &state_doc);
+
+    Ok(())
+}
+
+/// Writes the document held in a state to a file
+///
+/// The file becomes the origin of the document and is added to the recent
+/// files.
+///
+/// # Arguments
+/// * `state` - The state holding the document to save
+/// * `path` - The path of the file to write
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result indicating success or failure
+fn save_into_file(
+    state: &EnzymeMLState,
+    path: &Path,
+    app_handle: &AppHandle,
+) -> Result<(), SuiteError> {
+    let state_doc = state.doc.lock().unwrap();
+    let digest = write_doc(path, &state_doc)?;
+    *state.path.lock().unwrap() = Some(path.to_path_buf());
+    *state.file_version.lock().unwrap() = Some(FileVersion {
+        digest,
+        doc: state_doc.clone(),
+    });
+
+    // Failing to remember the file must not fail the saving
+    remember_recent_file(app_handle, path, &state_doc.name).ok();
+
+    update_event!(app_handle, SuiteEvent::persisted(state, ChangeOp::Update));
+
+    Ok(())
+}
+
+/// Persists the document held in the application state to the database
+///
+/// If the state already refers to a stored document, the stored content is
+/// replaced. Otherwise a new entry is created and its ID is written back to
+/// the state. This is shared by the `save` command and the REST API.
+///
+/// # Arguments
+/// * `state` - The EnzymeML document state to persist
+///
+/// # Returns
+/// Result containing either the document ID or an error
+pub fn save_state(state: &EnzymeMLState) -> Result<i32, SuiteError> {
     // Extract the guarded state values
+    let state_doc = state.doc.lock().unwrap();
+    let state_title = state.title.lock().unwrap();
     let mut state_id = state.id.lock().unwrap();
+
+    if let Some(id) = *state_id {
+        update_document(id, &state_doc)
+    } else {
+        let id = insert_document(&state_title, &state_doc)?;
+        *state_id = Some(id);
+        Ok(id)
+    }
+}
+
+/// Replaces the document held in the application state with a stored document
+///
+/// Retrieves the entry from the database, deserializes it and updates the
+/// document, title and ID of the state. The state is left untouched if the
+/// entry does not exist or cannot be parsed. The document no longer originates
+/// from a file afterwards. This is shared by the `load` command and the REST API.
+///
+/// # Arguments
+/// * `state` - The EnzymeML document state to load into
+/// * `id` - The ID of the document to load
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn load_into_state(state: &EnzymeMLState, id: i32) -> Result<(), SuiteError> {
+    // Load the document before touching the state
+    let entry = retrieve_document_by_id(id)?;
+    let doc = deserialize_doc(entry.content.as_str())?;
+
+    // Extract the guarded state values
     let mut state_doc = state.doc.lock().unwrap();
     let mut state_title = state.title.lock().unwrap();
-
-    // Load the document
-    let entry = retrieve_document_by_id(id).map_err(|err| err.to_string())?;
-    let doc = deserialize_doc(entry.content.as_str()).map_err(|err| err.to_string())?;
+    let mut state_id = state.id.lock().unwrap();
 
     // Update the state
-    *state_id = Some(entry.id);
-    *state_title = entry.title;
     *state_doc = doc;
-
-    update_event!(app_handle, "update_document");
-    update_report!(state, app_handle, &state_doc);
+    *state_title = entry.title;
+    *state_id = Some(entry.id);
+    *state.path.lock().unwrap() = None;
+    *state.file_version.lock().unwrap() = None;
 
     Ok(())
 }
@@ -487,15 +788,230 @@ pub fn load(
 /// # Returns
 /// Result containing either a vector of (title, id) tuples or an error message
 #[tauri::command]
-pub fn list_all_entries() -> Result<Vec<(String, i32)>, String> {
+#[specta::specta]
+pub fn list_all_entries() -> Result<Vec<(String, i32)>, SuiteError> {
     // Retrieve all documents from the database
-    let entries = retrieve_all_documents().map_err(|err| err.to_string())?;
+    let entries = retrieve_all_documents()?;
     Ok(entries
         .iter()
         .map(|entry| (entry.title.clone(), entry.id))
         .collect())
 }
 
+/// Field by which stored documents are sorted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentSort {
+    /// Alphabetically by title
+    Title,
+    /// By the time the documents have been stored
+    Created,
+    /// By the time the documents have last been changed
+    #[default]
+    Modified,
+}
+
+/// Filter and order of listed stored documents
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
+pub struct DocumentQuery {
+    /// Text the title or description has to contain
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Tags the documents must all carry
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Whether the documents in the trash are listed instead of the others
+    #[serde(default)]
+    pub trashed: bool,
+    /// Field the documents are sorted by
+    #[serde(default)]
+    pub sort: DocumentSort,
+    /// Whether the documents are sorted in descending order
+    #[serde(default)]
+    pub descending: bool,
+}
+
+/// Lists the stored documents with their metadata
+///
+/// Unlike [`list_all_entries`], the documents can be filtered by text, tags and
+/// whether they are in the trash, and sorted by title or time.
+///
+/// # Arguments
+/// * `query` - Filter and order of the documents, all documents not in the trash if omitted
+///
+/// # Returns
+/// Result containing either the metadata of the matching documents or an error
+#[tauri::command]
+#[specta::specta]
+pub fn list_documents(query: Option<DocumentQuery>) -> Result<Vec<DocumentMetadata>, SuiteError> {
+    query_documents(&query.unwrap_or_default())
+}
+
+/// Renames a stored document
+///
+/// Open documents that have been saved to it are renamed as well.
+///
+/// # Arguments
+/// * `id` - The ID of the document to rename
+/// * `title` - The new title of the document
+/// * `workspace` - The open documents of the application
+/// * `app_handle` - The Tauri application handle notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+#[tauri::command]
+#[specta::specta]
+pub fn rename_stored_document(
+    id: i32,
+    title: String,
+    workspace: State<Arc<Workspace>>,
+    app_handle: AppHandle,
+) -> Result<(), SuiteError> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(SuiteError::validation("The title must not be empty"));
+    }
+
+    rename_document(id, title)?;
+
+    for state in workspace.find_by_id(id) {
+        *state.title.lock().unwrap() = title.to_string();
+        update_event!(app_handle, SuiteEvent::document(&state, ChangeOp::Update));
+    }
+
+    Ok(())
+}
+
+/// Duplicates a stored document
+///
+/// # Arguments
+/// * `id` - The ID of the document to duplicate
+/// * `title` - The title of the copy, defaults to the title of the document suffixed with "(Copy)"
+///
+/// # Returns
+/// Result containing either the ID of the copy or an error
+#[tauri::command]
+#[specta::specta]
+pub fn duplicate_stored_document(id: i32, title: Option<String>) -> Result<i32, SuiteError> {
+    duplicate_document(id, title.as_deref())
+}
+
+/// Moves a stored document to the trash
+///
+/// # Arguments
+/// * `id` - The ID of the document to move to the trash
+///
+/// # Returns
+/// Result indicating success or failure
+#[tauri::command]
+#[specta::specta]
+pub fn trash_stored_document(id: i32) -> Result<(), SuiteError> {
+    trash_document(id, true)?;
+    Ok(())
+}
+
+/// Restores a stored document from the trash
+///
+/// # Arguments
+/// * `id` - The ID of the document to restore
+///
+/// # Returns
+/// Result indicating success or failure
+#[tauri::command]
+#[specta::specta]
+pub fn restore_stored_document(id: i32) -> Result<(), SuiteError> {
+    trash_document(id, false)?;
+    Ok(())
+}
+
+/// Deletes a stored document permanently
+///
+/// Open documents that have been saved to it are detached, so saving them
+/// stores them anew.
+///
+/// # Arguments
+/// * `id` - The ID of the document to delete
+/// * `workspace` - The open documents of the application
+/// * `app_handle` - The Tauri application handle notified about the changes
+///
+/// # Returns
+/// Result indicating success or failure
+#[tauri::command]
+#[specta::specta]
+pub fn delete_stored_document(
+    id: i32,
+    workspace: State<Arc<Workspace>>,
+    app_handle: AppHandle,
+) -> Result<(), SuiteError> {
+    delete_document(id)?;
+    detach_open_documents(&workspace, &[id], &app_handle);
+    Ok(())
+}
+
+/// Deletes all stored documents in the trash permanently
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `app_handle` - The Tauri application handle notified about the changes
+///
+/// # Returns
+/// Result containing either the number of deleted documents or an error
+#[tauri::command]
+#[specta::specta]
+pub fn empty_document_trash(
+    workspace: State<Arc<Workspace>>,
+    app_handle: AppHandle,
+) -> Result<usize, SuiteError> {
+    let ids = empty_trash()?;
+    detach_open_documents(&workspace, &ids, &app_handle);
+    Ok(ids.len())
+}
+
+/// Replaces the tags of a stored document
+///
+/// # Arguments
+/// * `id` - The ID of the document to tag
+/// * `tags` - The new tags of the document
+///
+/// # Returns
+/// Result indicating success or failure
+#[tauri::command]
+#[specta::specta]
+pub fn tag_stored_document(id: i32, tags: Vec<String>) -> Result<(), SuiteError> {
+    tag_document(id, &tags)?;
+    Ok(())
+}
+
+/// Replaces the description of a stored document
+///
+/// # Arguments
+/// * `id` - The ID of the document to describe
+/// * `description` - The new description, `None` to remove it
+///
+/// # Returns
+/// Result indicating success or failure
+#[tauri::command]
+#[specta::specta]
+pub fn describe_stored_document(id: i32, description: Option<String>) -> Result<(), SuiteError> {
+    describe_document(id, description.as_deref())?;
+    Ok(())
+}
+
+/// Detaches open documents from their deleted database entries
+///
+/// The documents stay open, and the next save creates a new entry.
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `ids` - The IDs of the deleted documents
+/// * `app_handle` - The Tauri application handle notified about the changes
+pub(crate) fn detach_open_documents(workspace: &Workspace, ids: &[i32], app_handle: &AppHandle) {
+    for state in ids.iter().flat_map(|id| workspace.find_by_id(*id)) {
+        *state.id.lock().unwrap() = None;
+        update_event!(app_handle, SuiteEvent::document(&state, ChangeOp::Delete));
+    }
+}
+
 /// Inserts a new document into the database
 ///
 /// Creates a new database record for an EnzymeML document. The document is
@@ -507,19 +1023,33 @@ pub fn list_all_entries() -> Result<Vec<(String, i32)>, String> {
 /// * `enzmldoc` - The EnzymeML document to insert
 ///
 /// # Returns
-/// QueryResult containing either the inserted document ID or an error
-fn insert_document(title: &str, enzmldoc: &EnzymeMLDocument) -> QueryResult<i32> {
-    let mut connection = establish_connection();
+/// Result containing either the inserted document ID or an error
+pub fn insert_document(title: &str, enzmldoc: &EnzymeMLDocument) -> Result<i32, SuiteError> {
+    let mut connection = establish_connection()?;
 
     // Serialize document to JSON
-    let json = serialize_doc(enzmldoc).expect("Failed to serialize document");
+    let json = serialize_doc(enzmldoc)?;
     let content = json.as_str();
 
-    // Insert document into database
-    diesel::insert_into(schema::documents::table)
-        .values(&models::NewDocument { title, content })
-        .returning(schema::documents::id)
-        .get_result(&mut connection)
+    // Insert document into database, the search index and the projection
+    let now = seconds_since_epoch() as i64;
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        let id = diesel::insert_into(schema::documents::table)
+            .values(&models::NewDocument {
+                title,
+                content,
+                description: None,
+                tags: StringArray::default(),
+                created_at: now,
+                modified_at: now,
+            })
+            .returning(schema::documents::id)
+            .get_result(connection)?;
+
+        index_document(connection, id)?;
+        project_document(connection, id)?;
+        Ok(id)
+    })
 }
 
 /// Updates an existing document in the database
@@ -534,34 +1064,310 @@ fn insert_document(title: &str, enzmldoc: &EnzymeMLDocument) -> QueryResult<i32>
 ///
 /// # Returns
 /// Result containing either the updated document ID or an error
-fn update_document(id: i32, enzmldoc: &EnzymeMLDocument) -> Result<i32, Box<dyn Error>> {
-    let mut connection = establish_connection();
+pub fn update_document(id: i32, enzmldoc: &EnzymeMLDocument) -> Result<i32, SuiteError> {
+    edit_document(id, None, Some(enzmldoc))
+}
+
+/// Updates the title and/or content of an existing document in the database
+///
+/// Both changes are written in a single transaction, so neither is stored if
+/// the other one fails, e.g. because of a conflicting title.
+///
+/// # Arguments
+/// * `id` - The ID of the document to update
+/// * `title` - The new title of the document, if it changes
+/// * `enzmldoc` - The updated EnzymeML document, if it changes
+///
+/// # Returns
+/// Result containing either the updated document ID or an error
+pub fn edit_document(
+    id: i32,
+    title: Option<&str>,
+    enzmldoc: Option<&EnzymeMLDocument>,
+) -> Result<i32, SuiteError> {
+    let mut connection = establish_connection()?;
 
     // Retrieve the document from the database
     let entry = retrieve_document_by_id(id)?;
 
     // Serialize document to JSON
-    let json = serialize_doc(enzmldoc).expect("Failed to serialize document");
-    let content = json.as_str();
+    let json = enzmldoc.map(serialize_doc).transpose()?;
+
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        let modified_at = schema::documents::modified_at.eq(seconds_since_epoch() as i64);
+
+        if let Some(content) = json.as_deref() {
+            diesel::update(&entry)
+                .set((schema::documents::content.eq(content), modified_at))
+                .execute(connection)?;
+        }
+
+        if let Some(title) = title {
+            diesel::update(&entry)
+                .set((schema::documents::title.eq(title), modified_at))
+                .execute(connection)?;
+        }
+
+        index_document(connection, entry.id)?;
+        if json.is_some() {
+            project_document(connection, entry.id)?;
+        }
+        Ok(entry.id)
+    })
+}
+
+/// Renames an existing document in the database
+///
+/// Only the title of the stored entry is changed, the content is preserved.
+///
+/// # Arguments
+/// * `id` - The ID of the document to rename
+/// * `title` - The new title of the document
+///
+/// # Returns
+/// Result containing either the renamed document ID or an error
+pub fn rename_document(id: i32, title: &str) -> Result<i32, SuiteError> {
+    edit_document(id, Some(title), None)
+}
+
+/// Deletes a document from the database
+///
+/// The document is removed permanently, see [`trash_document`] for moving it
+/// to the trash instead.
+///
+/// # Arguments
+/// * `id` - The ID of the document to delete
+///
+/// # Returns
+/// Result containing either the deleted document ID or an error
+pub fn delete_document(id: i32) -> Result<i32, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    // Retrieve the document from the database
+    let entry = retrieve_document_by_id(id)?;
+
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        diesel::delete(&entry).execute(connection)?;
 
+        remove_from_index(connection, &[entry.id])?;
+        remove_from_projection(connection, &[entry.id])?;
+        Ok(entry.id)
+    })
+}
+
+/// Duplicates a document in the database
+///
+/// The copy keeps the content, description and tags of the document and counts
+/// as newly created.
+///
+/// # Arguments
+/// * `id` - The ID of the document to duplicate
+/// * `title` - The title of the copy, defaults to the title of the document suffixed with "(Copy)"
+///
+/// # Returns
+/// Result containing either the ID of the copy or an error
+pub fn duplicate_document(id: i32, title: Option<&str>) -> Result<i32, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    // Retrieve the document from the database
+    let entry = retrieve_document_by_id(id)?;
+
+    let title = title
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{} (Copy)", entry.title));
+    let now = seconds_since_epoch() as i64;
+
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        let id = diesel::insert_into(schema::documents::table)
+            .values(&models::NewDocument {
+                title: &title,
+                content: &entry.content,
+                description: entry.description.as_deref(),
+                tags: entry.tags.clone(),
+                created_at: now,
+                modified_at: now,
+            })
+            .returning(schema::documents::id)
+            .get_result(connection)?;
+
+        index_document(connection, id)?;
+        project_document(connection, id)?;
+        Ok(id)
+    })
+}
+
+/// Moves a document to the trash or restores it from there
+///
+/// Documents in the trash are left out of [`retrieve_all_documents`] until
+/// they are restored or deleted permanently.
+///
+/// # Arguments
+/// * `id` - The ID of the document
+/// * `trashed` - Whether the document is moved to the trash or restored
+///
+/// # Returns
+/// Result containing either the document ID or an error
+pub fn trash_document(id: i32, trashed: bool) -> Result<i32, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    // Retrieve the document from the database
+    let entry = retrieve_document_by_id(id)?;
+
+    let deleted_at = trashed.then(|| seconds_since_epoch() as i64);
     diesel::update(&entry)
-        .set(schema::documents::content.eq(content))
+        .set(schema::documents::deleted_at.eq(deleted_at))
         .execute(&mut connection)?;
 
     Ok(entry.id)
 }
 
+/// Deletes all documents in the trash permanently
+///
+/// # Returns
+/// Result containing either the IDs of the deleted documents or an error
+pub fn empty_trash() -> Result<Vec<i32>, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        let ids = diesel::delete(
+            schema::documents::table.filter(schema::documents::deleted_at.is_not_null()),
+        )
+        .returning(schema::documents::id)
+        .get_results(connection)?;
+
+        remove_from_index(connection, &ids)?;
+        remove_from_projection(connection, &ids)?;
+        Ok(ids)
+    })
+}
+
+/// Replaces the tags of a document in the database
+///
+/// Tags are trimmed, and empty as well as repeated tags are left out.
+///
+/// # Arguments
+/// * `id` - The ID of the document to tag
+/// * `tags` - The new tags of the document
+///
+/// # Returns
+/// Result containing either the document ID or an error
+pub fn tag_document(id: i32, tags: &[String]) -> Result<i32, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    // Retrieve the document from the database
+    let entry = retrieve_document_by_id(id)?;
+
+    let mut unique: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags.iter().map(|tag| tag.trim()) {
+        if !tag.is_empty() && !unique.iter().any(|known| known == tag) {
+            unique.push(tag.to_string());
+        }
+    }
+
+    diesel::update(&entry)
+        .set((
+            schema::documents::tags.eq(StringArray(unique)),
+            schema::documents::modified_at.eq(seconds_since_epoch() as i64),
+        ))
+        .execute(&mut connection)?;
+
+    Ok(entry.id)
+}
+
+/// Replaces the description of a document in the database
+///
+/// # Arguments
+/// * `id` - The ID of the document to describe
+/// * `description` - The new description, `None` to remove it
+///
+/// # Returns
+/// Result containing either the document ID or an error
+pub fn describe_document(id: i32, description: Option<&str>) -> Result<i32, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    // Retrieve the document from the database
+    let entry = retrieve_document_by_id(id)?;
+
+    let description = description.map(str::trim).filter(|text| !text.is_empty());
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        diesel::update(&entry)
+            .set((
+                schema::documents::description.eq(description),
+                schema::documents::modified_at.eq(seconds_since_epoch() as i64),
+            ))
+            .execute(connection)?;
+
+        index_document(connection, entry.id)?;
+        Ok(entry.id)
+    })
+}
+
+/// Retrieves the metadata of the stored documents matching a query
+///
+/// The title and description are matched case-insensitively against the text
+/// of the query, and the documents must carry all tags of the query.
+///
+/// # Arguments
+/// * `query` - Filter and order of the documents
+///
+/// # Returns
+/// Result containing either the metadata of the matching documents or an error
+pub fn query_documents(query: &DocumentQuery) -> Result<Vec<DocumentMetadata>, SuiteError> {
+    use schema::documents::dsl;
+
+    let mut connection = establish_connection()?;
+    let mut statement = dsl::documents
+        .select(DocumentMetadata::as_select())
+        .into_boxed();
+
+    statement = if query.trashed {
+        statement.filter(dsl::deleted_at.is_not_null())
+    } else {
+        statement.filter(dsl::deleted_at.is_null())
+    };
+
+    if let Some(text) = query.text.as_deref().map(str::trim) {
+        if !text.is_empty() {
+            let pattern = contains_pattern(text);
+            statement = statement.filter(
+                dsl::title
+                    .like(pattern.clone())
+                    .escape(LIKE_ESCAPE)
+                    .or(dsl::description.like(pattern).escape(LIKE_ESCAPE)),
+            );
+        }
+    }
+
+    statement = match (query.sort, query.descending) {
+        (DocumentSort::Title, false) => statement.order(dsl::title.asc()),
+        (DocumentSort::Title, true) => statement.order(dsl::title.desc()),
+        (DocumentSort::Created, false) => statement.order(dsl::created_at.asc()),
+        (DocumentSort::Created, true) => statement.order(dsl::created_at.desc()),
+        (DocumentSort::Modified, false) => statement.order(dsl::modified_at.asc()),
+        (DocumentSort::Modified, true) => statement.order(dsl::modified_at.desc()),
+    };
+
+    let entries = statement.load::<DocumentMetadata>(&mut connection)?;
+    Ok(entries
+        .into_iter()
+        .filter(|entry| query.tags.iter().all(|tag| entry.tags.0.contains(tag)))
+        .collect())
+}
+
 /// Retrieves all documents from the database
 ///
 /// Fetches all EnzymeML document records from the database, including their
-/// titles, IDs, and content. This function is used internally by other functions
-/// that need to access or list multiple documents.
+/// titles, IDs, and content. Documents in the trash are left out. This function
+/// is used internally by other functions that need to access or list multiple
+/// documents.
 ///
 /// # Returns
-/// QueryResult containing either a vector of Documents or an error
-pub fn retrieve_all_documents() -> QueryResult<Vec<Document>> {
-    let mut connection = establish_connection();
-    schema::documents::table.load::<Document>(&mut connection)
+/// Result containing either a vector of Documents or an error
+pub fn retrieve_all_documents() -> Result<Vec<Document>, SuiteError> {
+    let mut connection = establish_connection()?;
+    Ok(schema::documents::table
+        .filter(schema::documents::deleted_at.is_null())
+        .load::<Document>(&mut connection)?)
 }
 
 /// Retrieves a specific document from the database by ID
@@ -574,10 +1380,12 @@ pub fn retrieve_all_documents() -> QueryResult<Vec<Document>> {
 /// * `id` - The ID of the document to retrieve
 ///
 /// # Returns
-/// QueryResult containing either the requested Document or an error
-pub fn retrieve_document_by_id(id: i32) -> QueryResult<Document> {
-    let mut connection = establish_connection();
+/// Result containing either the requested Document or an error, which is
+/// [`SuiteError::NotFound`] if no document with this ID exists
+pub fn retrieve_document_by_id(id: i32) -> Result<Document, SuiteError> {
+    let mut connection = establish_connection()?;
     schema::documents::table
         .filter(schema::documents::id.eq(id))
         .first::<Document>(&mut connection)
+        .map_err(|err| SuiteError::from_query(err, "Document", id))
 }
@@ -1,12 +1,57 @@
 use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+use enzymeml::prelude::{
+    Creator, CreatorBuilder, Protein, ProteinBuilder, UnitDefinition, Vessel, VesselBuilder,
+};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tauri::State;
+use tauri::{AppHandle, State};
 
 use crate::{
-    db::establish_db_connection, filter_table, get_object, get_row, get_rows, models,
-    schema::small_molecules, states::EnzymeMLState, upsert_row,
+    db::{contains_pattern, establish_connection, LIKE_ESCAPE},
+    delete_row,
+    document::{enzmldoc::add_creator, proteins::add_protein, vessels::add_vessel},
+    error::SuiteError,
+    filter_table, get_object, get_row, get_rows,
+    io::library::{delete_law_relations, insert_law_relations, load_law, ArchivedLaw},
+    io::stringarray::StringArray,
+    models,
+    schema::{creators, laws, proteins, small_molecules, vessels},
+    states::Workspace,
+    unit::UnitDefinitions,
+    update_row, upsert_row,
 };
 
+/// Filter of a protein search in the library
+///
+/// Text filters match case-insensitively any part of the respective fields,
+/// taking `%` and `_` literally. Omitted filters match every protein.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
+pub struct ProteinQuery {
+    /// Matches the name of the protein
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Matches the EC number of the protein, e.g. "1.1.1"
+    #[serde(default)]
+    pub ecnumber: Option<String>,
+    /// Matches the organism the protein originates from
+    #[serde(default)]
+    pub organism: Option<String>,
+    /// Matches a part of the amino acid sequence, whitespace is ignored
+    #[serde(default)]
+    pub sequence: Option<String>,
+}
+
+/// Kinetic law of the library, including the species, assignables and parameters it involves
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct LibraryLaw {
+    /// The unique database ID of the law
+    pub id: i32,
+    /// Name, equation and relations of the law
+    #[serde(flatten)]
+    pub law: ArchivedLaw,
+}
+
 /// Saves or updates a small molecule in the database
 ///
 /// This function retrieves a small molecule from the EnzymeML document state
@@ -15,19 +60,22 @@ use crate::{
 /// records based on the molecule's name.
 ///
 /// # Arguments
-/// * `state` - The shared EnzymeML document state containing the document data
+/// * `workspace` - The open documents of the application
 /// * `id` - The unique identifier of the small molecule to save
+/// * `handle` - Handle of the document, `None` for the active document
 ///
 /// # Returns
-/// Result containing the number of affected rows on success, or an error message on failure
+/// Result containing the number of affected rows on success, or an error on failure
 #[tauri::command]
-pub fn save_mol_to_db(state: State<Arc<EnzymeMLState>>, id: String) -> Result<usize, String> {
-    let mol = get_object!(state.doc, small_molecules, id, id);
-    if let Ok(mol) = mol {
-        upsert_row!(small_molecules, models::DBNewSmallMolecule, name, mol)
-    } else {
-        Err(format!("Failed to get small molecule with id {}", id))
-    }
+#[specta::specta]
+pub fn save_mol_to_db(
+    workspace: State<Arc<Workspace>>,
+    id: String,
+    handle: Option<String>,
+) -> Result<usize, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let mol = get_object!(state.doc, small_molecules, id, id)?;
+    upsert_row!(small_molecules, models::DBNewSmallMolecule, name, mol)
 }
 
 /// Filters small molecules by name
@@ -40,9 +88,10 @@ pub fn save_mol_to_db(state: State<Arc<EnzymeMLState>>, id: String) -> Result<us
 /// * `name` - The name of the small molecule to search for
 ///
 /// # Returns
-/// Result containing a vector of matching small molecules or an error message
+/// Result containing a vector of matching small molecules or an error
 #[tauri::command]
-pub fn filter_small_mols(name: String) -> Result<Vec<models::DBSmallMolecule>, String> {
+#[specta::specta]
+pub fn filter_small_mols(name: String) -> Result<Vec<models::DBSmallMolecule>, SuiteError> {
     filter_table!(
         small_molecules,         // The table to filter
         models::DBSmallMolecule, // The type to deserialize rows into
@@ -54,23 +103,18 @@ pub fn filter_small_mols(name: String) -> Result<Vec<models::DBSmallMolecule>, S
 /// Gets a small molecule by ID
 ///
 /// This function retrieves a specific small molecule from the database using its
-/// unique ID. If the molecule has references, they are processed from a comma-separated
-/// string into a vector format for easier consumption by the frontend.
+/// unique ID.
 ///
 /// # Arguments
 /// * `id` - The unique database ID of the small molecule to retrieve
 ///
 /// # Returns
-/// Result containing the requested small molecule or an error message if not found
+/// Result containing the requested small molecule or an error if not found
 #[tauri::command]
-pub fn get_small_mol_by_id(id: i32) -> Result<models::DBSmallMolecule, String> {
-    let row = get_row!(small_molecules, models::DBSmallMolecule, id);
-    if let Ok(mut row) = row {
-        row.references = row.references.map(|refs| refs.split(',').collect());
-        Ok(row)
-    } else {
-        Err(format!("Failed to get small molecule with id {}", id))
-    }
+#[specta::specta]
+pub fn get_small_mol_by_id(id: i32) -> Result<models::DBSmallMolecule, SuiteError> {
+    get_row!(small_molecules, models::DBSmallMolecule, id)
+        .map_err(|err| SuiteError::from_query(err, "Small molecule", id))
 }
 
 /// Gets all small molecules from the database
@@ -80,8 +124,762 @@ pub fn get_small_mol_by_id(id: i32) -> Result<models::DBSmallMolecule, String> {
 /// or for performing bulk operations on the entire small molecules collection.
 ///
 /// # Returns
-/// Result containing a vector of all small molecules or an error message on failure
+/// Result containing a vector of all small molecules or an error on failure
 #[tauri::command]
-pub fn get_all_small_mols() -> Result<Vec<models::DBSmallMolecule>, String> {
+#[specta::specta]
+pub fn get_all_small_mols() -> Result<Vec<models::DBSmallMolecule>, SuiteError> {
     get_rows!(small_molecules, models::DBSmallMolecule)
 }
+
+/// Updates a small molecule of the database in place
+///
+/// The name is required, blank optional fields are stored as missing. The
+/// name and the InChI Key, if given, have to be unique within the library.
+///
+/// # Arguments
+/// * `entry` - The small molecule holding its database ID and the new values
+///
+/// # Returns
+/// Result containing the updated small molecule, or an error if it is not
+/// found or conflicts with another small molecule
+#[tauri::command]
+#[specta::specta]
+pub fn update_small_mol_in_db(
+    mut entry: models::DBSmallMolecule,
+) -> Result<models::DBSmallMolecule, SuiteError> {
+    let id = entry.id;
+    entry.name = required("name", &entry.name)?;
+    entry.canonical_smiles = optional(entry.canonical_smiles);
+    entry.inchi = optional(entry.inchi);
+    entry.inchikey = optional(entry.inchikey);
+    entry.references = StringArray(
+        entry
+            .references
+            .0
+            .into_iter()
+            .filter_map(|reference| optional(Some(reference)))
+            .collect(),
+    );
+
+    update_row!(small_molecules, models::DBSmallMolecule, entry)
+        .map_err(|err| SuiteError::from_query(err, "Small molecule", id))
+}
+
+/// Deletes a small molecule from the database
+///
+/// # Arguments
+/// * `id` - The unique database ID of the small molecule to delete
+///
+/// # Returns
+/// Result indicating success, or an error if the small molecule is not found
+#[tauri::command]
+#[specta::specta]
+pub fn delete_small_mol_from_db(id: i32) -> Result<(), SuiteError> {
+    delete_row!(small_molecules, "Small molecule", id)
+}
+
+/// Saves or updates a protein in the database
+///
+/// This function retrieves a protein from the EnzymeML document state using
+/// the provided ID and saves or updates it in the database. Existing records
+/// are matched by the protein's name.
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `id` - The unique identifier of the protein to save
+/// * `handle` - Handle of the document, `None` for the active document
+///
+/// # Returns
+/// Result containing the number of affected rows on success, or an error on failure
+#[tauri::command]
+#[specta::specta]
+pub fn save_protein_to_db(
+    workspace: State<Arc<Workspace>>,
+    id: String,
+    handle: Option<String>,
+) -> Result<usize, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let protein = get_object!(state.doc, proteins, id, id)?;
+    upsert_row!(proteins, models::DBNewProtein, name, protein)
+}
+
+/// Searches the proteins of the database
+///
+/// All given filters have to match, so e.g. a name and an organism narrow the
+/// search down to proteins of this name from this organism.
+///
+/// # Arguments
+/// * `query` - The filters of the search, `None` lists all proteins
+///
+/// # Returns
+/// Result containing the matching proteins ordered by name or an error
+#[tauri::command]
+#[specta::specta]
+pub fn filter_proteins(query: Option<ProteinQuery>) -> Result<Vec<models::DBProtein>, SuiteError> {
+    find_proteins(&query.unwrap_or_default())
+}
+
+/// Gets a protein by ID
+///
+/// # Arguments
+/// * `id` - The unique database ID of the protein to retrieve
+///
+/// # Returns
+/// Result containing the requested protein or an error if not found
+#[tauri::command]
+#[specta::specta]
+pub fn get_protein_by_id(id: i32) -> Result<models::DBProtein, SuiteError> {
+    get_row!(proteins, models::DBProtein, id)
+        .map_err(|err| SuiteError::from_query(err, "Protein", id))
+}
+
+/// Gets all proteins from the database
+///
+/// # Returns
+/// Result containing a vector of all proteins or an error on failure
+#[tauri::command]
+#[specta::specta]
+pub fn get_all_proteins() -> Result<Vec<models::DBProtein>, SuiteError> {
+    get_rows!(proteins, models::DBProtein)
+}
+
+/// Updates a protein of the database in place
+///
+/// The name is required, blank optional fields are stored as missing. The
+/// name and the sequence, if given, have to be unique within the library.
+///
+/// # Arguments
+/// * `entry` - The protein holding its database ID and the new values
+///
+/// # Returns
+/// Result containing the updated protein, or an error if it is not found or
+/// conflicts with another protein
+#[tauri::command]
+#[specta::specta]
+pub fn update_protein_in_db(mut entry: models::DBProtein) -> Result<models::DBProtein, SuiteError> {
+    let id = entry.id;
+    entry.name = required("name", &entry.name)?;
+    entry.sequence = optional(entry.sequence);
+    entry.ecnumber = optional(entry.ecnumber);
+    entry.organism = optional(entry.organism);
+    entry.organism_tax_id = optional(entry.organism_tax_id);
+
+    update_row!(proteins, models::DBProtein, entry)
+        .map_err(|err| SuiteError::from_query(err, "Protein", id))
+}
+
+/// Deletes a protein from the database
+///
+/// # Arguments
+/// * `id` - The unique database ID of the protein to delete
+///
+/// # Returns
+/// Result indicating success, or an error if the protein is not found
+#[tauri::command]
+#[specta::specta]
+pub fn delete_protein_from_db(id: i32) -> Result<(), SuiteError> {
+    delete_row!(proteins, "Protein", id)
+}
+
+/// Adds a protein of the database to an EnzymeML document
+///
+/// The protein receives a new ID within the document and is treated as
+/// constant, since proteins are usually not consumed by the reactions.
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `id` - The unique database ID of the protein to add
+/// * `handle` - Handle of the document, `None` for the active document
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing the ID of the protein within the document
+#[tauri::command]
+#[specta::specta]
+pub fn add_protein_from_db(
+    workspace: State<Arc<Workspace>>,
+    id: i32,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<String, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let protein = to_protein(get_protein_by_id(id)?)?;
+
+    Ok(add_protein(&state, protein, &app_handle))
+}
+
+/// Finds the proteins of the database matching a query
+///
+/// # Arguments
+/// * `query` - The filters of the search
+///
+/// # Returns
+/// Result containing the matching proteins ordered by name or an error
+pub fn find_proteins(query: &ProteinQuery) -> Result<Vec<models::DBProtein>, SuiteError> {
+    let pattern = |filter: &Option<String>| {
+        filter
+            .as_deref()
+            .map(str::trim)
+            .filter(|filter| !filter.is_empty())
+            .map(contains_pattern)
+    };
+
+    let mut statement = proteins::table.into_boxed();
+    if let Some(name) = pattern(&query.name) {
+        statement = statement.filter(proteins::name.like(name).escape(LIKE_ESCAPE));
+    }
+    if let Some(ecnumber) = pattern(&query.ecnumber) {
+        statement = statement.filter(proteins::ecnumber.like(ecnumber).escape(LIKE_ESCAPE));
+    }
+    if let Some(organism) = pattern(&query.organism) {
+        statement = statement.filter(proteins::organism.like(organism).escape(LIKE_ESCAPE));
+    }
+    let sequence = query
+        .sequence
+        .as_ref()
+        .map(|sequence| sequence.split_whitespace().collect::<String>());
+    if let Some(sequence) = pattern(&sequence) {
+        statement = statement.filter(proteins::sequence.like(sequence).escape(LIKE_ESCAPE));
+    }
+
+    let mut connection = establish_connection()?;
+    Ok(statement
+        .order(proteins::name.asc())
+        .load::<models::DBProtein>(&mut connection)?)
+}
+
+/// Converts a protein of the database into a protein of an EnzymeML document
+///
+/// The ID is left empty and assigned when adding the protein to a document.
+///
+/// # Arguments
+/// * `row` - The protein of the database
+///
+/// # Returns
+/// Result containing the protein or an error if it cannot be built
+pub fn to_protein(row: models::DBProtein) -> Result<Protein, SuiteError> {
+    let mut builder = ProteinBuilder::default();
+    builder.id(String::new());
+    builder.name(row.name);
+    builder.constant(true);
+
+    let mut protein = builder
+        .build()
+        .map_err(|e| SuiteError::internal(e.to_string()))?;
+    protein.sequence = row.sequence;
+    protein.ecnumber = row.ecnumber;
+    protein.organism = row.organism;
+    protein.organism_tax_id = row.organism_tax_id;
+
+    Ok(protein)
+}
+
+/// Saves or updates a vessel in the database
+///
+/// This function retrieves a vessel from the EnzymeML document state using
+/// the provided ID and saves or updates it in the database. Existing records
+/// are matched by the vessel's name.
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `id` - The unique identifier of the vessel to save
+/// * `handle` - Handle of the document, `None` for the active document
+///
+/// # Returns
+/// Result containing the number of affected rows on success, or an error on failure
+#[tauri::command]
+#[specta::specta]
+pub fn save_vessel_to_db(
+    workspace: State<Arc<Workspace>>,
+    id: String,
+    handle: Option<String>,
+) -> Result<usize, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let vessel = get_object!(state.doc, vessels, id, id)?;
+    upsert_row!(vessels, models::DBNewVessel, name, vessel)
+}
+
+/// Filters vessels by name
+///
+/// # Arguments
+/// * `name` - The name of the vessel to search for
+///
+/// # Returns
+/// Result containing a vector of matching vessels or an error
+#[tauri::command]
+#[specta::specta]
+pub fn filter_vessels(name: String) -> Result<Vec<models::DBVessel>, SuiteError> {
+    filter_table!(vessels, models::DBVessel, vessels::name, name)
+}
+
+/// Gets a vessel by ID
+///
+/// # Arguments
+/// * `id` - The unique database ID of the vessel to retrieve
+///
+/// # Returns
+/// Result containing the requested vessel or an error if not found
+#[tauri::command]
+#[specta::specta]
+pub fn get_vessel_by_id(id: i32) -> Result<models::DBVessel, SuiteError> {
+    get_row!(vessels, models::DBVessel, id).map_err(|err| SuiteError::from_query(err, "Vessel", id))
+}
+
+/// Gets all vessels from the database
+///
+/// # Returns
+/// Result containing a vector of all vessels or an error on failure
+#[tauri::command]
+#[specta::specta]
+pub fn get_all_vessels() -> Result<Vec<models::DBVessel>, SuiteError> {
+    get_rows!(vessels, models::DBVessel)
+}
+
+/// Updates a vessel of the database in place
+///
+/// The name has to be unique within the library, the volume positive and the
+/// unit a known volume unit.
+///
+/// # Arguments
+/// * `entry` - The vessel holding its database ID and the new values
+///
+/// # Returns
+/// Result containing the updated vessel, or an error if it is not found, is
+/// invalid or conflicts with another vessel
+#[tauri::command]
+#[specta::specta]
+pub fn update_vessel_in_db(mut entry: models::DBVessel) -> Result<models::DBVessel, SuiteError> {
+    let id = entry.id;
+    entry.name = required("name", &entry.name)?;
+    if !entry.volume.is_finite() || entry.volume <= 0.0 {
+        return Err(SuiteError::validation(format!(
+            "The volume of a vessel must be positive, got {}",
+            entry.volume
+        )));
+    }
+    volume_unit(&entry.unit)?;
+
+    update_row!(vessels, models::DBVessel, entry)
+        .map_err(|err| SuiteError::from_query(err, "Vessel", id))
+}
+
+/// Deletes a vessel from the database
+///
+/// # Arguments
+/// * `id` - The unique database ID of the vessel to delete
+///
+/// # Returns
+/// Result indicating success, or an error if the vessel is not found
+#[tauri::command]
+#[specta::specta]
+pub fn delete_vessel_from_db(id: i32) -> Result<(), SuiteError> {
+    delete_row!(vessels, "Vessel", id)
+}
+
+/// Adds a vessel of the database to an EnzymeML document
+///
+/// The vessel receives a new ID within the document.
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `id` - The unique database ID of the vessel to add
+/// * `handle` - Handle of the document, `None` for the active document
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing the ID of the vessel within the document
+#[tauri::command]
+#[specta::specta]
+pub fn add_vessel_from_db(
+    workspace: State<Arc<Workspace>>,
+    id: i32,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<String, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let vessel = to_vessel(get_vessel_by_id(id)?)?;
+
+    Ok(add_vessel(&state, vessel, &app_handle))
+}
+
+/// Saves or updates the creators of an EnzymeML document in the database
+///
+/// Existing records are matched by the creator's mail address, or by the name
+/// if the creator has no mail address.
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `handle` - Handle of the document, `None` for the active document
+///
+/// # Returns
+/// Result containing the number of affected rows on success, or an error on failure
+#[tauri::command]
+#[specta::specta]
+pub fn save_creators_to_db(
+    workspace: State<Arc<Workspace>>,
+    handle: Option<String>,
+) -> Result<usize, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let document_creators = state.doc.lock().unwrap().creators.clone();
+
+    let mut connection = establish_connection()?;
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        let mut affected = 0;
+        for creator in document_creators.iter() {
+            let new_row = models::DBNewCreator::from(creator);
+            affected += match find_creator(connection, creator)? {
+                Some(id) => diesel::update(creators::table.find(id))
+                    .set(&new_row)
+                    .execute(connection)?,
+                None => diesel::insert_into(creators::table)
+                    .values(&new_row)
+                    .execute(connection)?,
+            };
+        }
+
+        Ok(affected)
+    })
+}
+
+/// Searches the creators of the database
+///
+/// Every word of the query has to match a part of the given name, the family
+/// name or the mail address, so e.g. "jane doe" finds Jane Doe. Wildcards such
+/// as `_` are taken literally, so "j_doe" does not find jxdoe@example.org.
+///
+/// # Arguments
+/// * `query` - The words to search for, an empty query lists all creators
+///
+/// # Returns
+/// Result containing the matching creators ordered by name or an error
+#[tauri::command]
+#[specta::specta]
+pub fn filter_creators(query: String) -> Result<Vec<models::DBCreator>, SuiteError> {
+    let mut statement = creators::table.into_boxed();
+    for term in query.split_whitespace() {
+        let pattern = contains_pattern(term);
+        statement = statement.filter(
+            creators::given_name
+                .like(pattern.clone())
+                .escape(LIKE_ESCAPE)
+                .or(creators::family_name
+                    .like(pattern.clone())
+                    .escape(LIKE_ESCAPE))
+                .or(creators::mail.like(pattern).escape(LIKE_ESCAPE)),
+        );
+    }
+
+    let mut connection = establish_connection()?;
+    Ok(statement
+        .order((creators::family_name.asc(), creators::given_name.asc()))
+        .load::<models::DBCreator>(&mut connection)?)
+}
+
+/// Gets a creator by ID
+///
+/// # Arguments
+/// * `id` - The unique database ID of the creator to retrieve
+///
+/// # Returns
+/// Result containing the requested creator or an error if not found
+#[tauri::command]
+#[specta::specta]
+pub fn get_creator_by_id(id: i32) -> Result<models::DBCreator, SuiteError> {
+    get_row!(creators, models::DBCreator, id)
+        .map_err(|err| SuiteError::from_query(err, "Creator", id))
+}
+
+/// Gets all creators from the database
+///
+/// # Returns
+/// Result containing a vector of all creators or an error on failure
+#[tauri::command]
+#[specta::specta]
+pub fn get_all_creators() -> Result<Vec<models::DBCreator>, SuiteError> {
+    get_rows!(creators, models::DBCreator)
+}
+
+/// Updates a creator of the database in place
+///
+/// The given and family name are required. The mail address, if given, has
+/// to be unique within the library.
+///
+/// # Arguments
+/// * `entry` - The creator holding its database ID and the new values
+///
+/// # Returns
+/// Result containing the updated creator, or an error if it is not found or
+/// conflicts with another creator
+#[tauri::command]
+#[specta::specta]
+pub fn update_creator_in_db(mut entry: models::DBCreator) -> Result<models::DBCreator, SuiteError> {
+    let id = entry.id;
+    entry.given_name = required("given name", &entry.given_name)?;
+    entry.family_name = required("family name", &entry.family_name)?;
+    entry.mail = entry.mail.trim().to_string();
+
+    update_row!(creators, models::DBCreator, entry)
+        .map_err(|err| SuiteError::from_query(err, "Creator", id))
+}
+
+/// Deletes a creator from the database
+///
+/// # Arguments
+/// * `id` - The unique database ID of the creator to delete
+///
+/// # Returns
+/// Result indicating success, or an error if the creator is not found
+#[tauri::command]
+#[specta::specta]
+pub fn delete_creator_from_db(id: i32) -> Result<(), SuiteError> {
+    delete_row!(creators, "Creator", id)
+}
+
+/// Adds a creator of the database to an EnzymeML document
+///
+/// # Arguments
+/// * `workspace` - The open documents of the application
+/// * `id` - The unique database ID of the creator to add
+/// * `handle` - Handle of the document, `None` for the active document
+/// * `app_handle` - Handle to the Tauri application for event emission
+///
+/// # Returns
+/// Result containing whether the creator has been added, which is not the
+/// case if the document already lists the creator
+#[tauri::command]
+#[specta::specta]
+pub fn add_creator_from_db(
+    workspace: State<Arc<Workspace>>,
+    id: i32,
+    handle: Option<String>,
+    app_handle: AppHandle,
+) -> Result<bool, SuiteError> {
+    let state = workspace.document(handle.as_deref())?;
+    let creator = to_creator(get_creator_by_id(id)?)?;
+
+    add_creator(&state, creator, &app_handle)
+}
+
+/// Gets a kinetic law by ID
+///
+/// # Arguments
+/// * `id` - The unique database ID of the kinetic law to retrieve
+///
+/// # Returns
+/// Result containing the requested kinetic law or an error if not found
+#[tauri::command]
+#[specta::specta]
+pub fn get_law_by_id(id: i32) -> Result<LibraryLaw, SuiteError> {
+    let mut connection = establish_connection()?;
+    laws::table
+        .find(id)
+        .select(laws::id)
+        .first::<i32>(&mut connection)
+        .map_err(|err| SuiteError::from_query(err, "Kinetic law", id))?;
+
+    Ok(LibraryLaw {
+        id,
+        law: load_law(&mut connection, id)?,
+    })
+}
+
+/// Gets all kinetic laws from the database
+///
+/// # Returns
+/// Result containing the kinetic laws ordered by name or an error on failure
+#[tauri::command]
+#[specta::specta]
+pub fn get_all_laws() -> Result<Vec<LibraryLaw>, SuiteError> {
+    let mut connection = establish_connection()?;
+    laws::table
+        .order(laws::name.asc())
+        .select(laws::id)
+        .load::<i32>(&mut connection)?
+        .into_iter()
+        .map(|id| {
+            Ok(LibraryLaw {
+                id,
+                law: load_law(&mut connection, id)?,
+            })
+        })
+        .collect()
+}
+
+/// Updates a kinetic law of the database in place
+///
+/// The species, assignables and parameters of the law are replaced by the
+/// given ones. The name has to be unique within the library.
+///
+/// # Arguments
+/// * `entry` - The kinetic law holding its database ID and the new values
+///
+/// # Returns
+/// Result containing the updated kinetic law, or an error if it is not found
+/// or conflicts with another kinetic law
+#[tauri::command]
+#[specta::specta]
+pub fn update_law_in_db(mut entry: LibraryLaw) -> Result<LibraryLaw, SuiteError> {
+    let id = entry.id;
+    entry.law.name = required("name", &entry.law.name)?;
+    entry.law.equation = required("equation", &entry.law.equation)?;
+
+    let mut connection = establish_connection()?;
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        let updated = diesel::update(laws::table.find(id))
+            .set((
+                laws::name.eq(&entry.law.name),
+                laws::equation.eq(&entry.law.equation),
+            ))
+            .execute(connection)?;
+        if updated == 0 {
+            return Err(SuiteError::not_found("Kinetic law", id));
+        }
+
+        delete_law_relations(connection, id)?;
+        insert_law_relations(connection, &entry.law, id)?;
+
+        Ok(LibraryLaw {
+            id,
+            law: load_law(connection, id)?,
+        })
+    })
+}
+
+/// Deletes a kinetic law from the database, including its relations
+///
+/// # Arguments
+/// * `id` - The unique database ID of the kinetic law to delete
+///
+/// # Returns
+/// Result indicating success, or an error if the kinetic law is not found
+#[tauri::command]
+#[specta::specta]
+pub fn delete_law_from_db(id: i32) -> Result<(), SuiteError> {
+    let mut connection = establish_connection()?;
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        delete_law_relations(connection, id)?;
+        match diesel::delete(laws::table.find(id)).execute(connection)? {
+            0 => Err(SuiteError::not_found("Kinetic law", id)),
+            _ => Ok(()),
+        }
+    })
+}
+
+/// Finds the database entry of a creator of a document
+///
+/// # Arguments
+/// * `connection` - Connection to the database
+/// * `creator` - The creator of the document
+///
+/// # Returns
+/// Result containing the database ID of the creator, if it has been saved
+fn find_creator(
+    connection: &mut SqliteConnection,
+    creator: &Creator,
+) -> Result<Option<i32>, SuiteError> {
+    let statement = creators::table
+        .select(creators::id)
+        .filter(creators::mail.eq(&creator.mail))
+        .into_boxed();
+    let statement = if creator.mail.is_empty() {
+        statement
+            .filter(creators::given_name.eq(&creator.given_name))
+            .filter(creators::family_name.eq(&creator.family_name))
+    } else {
+        statement
+    };
+
+    Ok(statement.first::<i32>(connection).optional()?)
+}
+
+/// Converts a vessel of the database into a vessel of an EnzymeML document
+///
+/// The ID is left empty and assigned when adding the vessel to a document.
+///
+/// # Arguments
+/// * `row` - The vessel of the database
+///
+/// # Returns
+/// Result containing the vessel or an error if its unit is unknown
+pub fn to_vessel(row: models::DBVessel) -> Result<Vessel, SuiteError> {
+    let mut builder = VesselBuilder::default();
+    builder.id(String::new());
+    builder.name(row.name);
+    builder.volume(row.volume);
+    builder.unit(volume_unit(&row.unit)?);
+    builder.constant(row.constant);
+
+    builder
+        .build()
+        .map_err(|e| SuiteError::internal(e.to_string()))
+}
+
+/// Converts a creator of the database into a creator of an EnzymeML document
+///
+/// # Arguments
+/// * `row` - The creator of the database
+///
+/// # Returns
+/// Result containing the creator or an error if it cannot be built
+pub fn to_creator(row: models::DBCreator) -> Result<Creator, SuiteError> {
+    let mut builder = CreatorBuilder::default();
+    builder.given_name(row.given_name);
+    builder.family_name(row.family_name);
+    builder.mail(row.mail);
+
+    builder
+        .build()
+        .map_err(|e| SuiteError::internal(e.to_string()))
+}
+
+/// Resolves the unit of a vessel stored by its name
+///
+/// # Arguments
+/// * `name` - Name of the unit, e.g. "ml"
+///
+/// # Returns
+/// Result containing the unit definition or an error if the unit is unknown
+fn volume_unit(name: &str) -> Result<UnitDefinition, SuiteError> {
+    UnitDefinitions::get_unit(name)
+        .ok()
+        .or_else(|| {
+            UnitDefinitions::default()
+                .volume
+                .into_values()
+                .find(|unit| unit.name.as_deref() == Some(name))
+        })
+        .ok_or_else(|| SuiteError::validation(format!("Unknown volume unit '{}'", name)))
+}
+
+/// Trims a required text field of a library entry
+///
+/// # Arguments
+/// * `field` - Name of the field, e.g. "name"
+/// * `value` - The value of the field
+///
+/// # Returns
+/// Result containing the trimmed value or an error if it is blank
+fn required(field: &str, value: &str) -> Result<String, SuiteError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(SuiteError::validation(format!(
+            "The {} must not be empty",
+            field
+        )));
+    }
+
+    Ok(value.to_string())
+}
+
+/// Trims an optional text field of a library entry
+///
+/// # Arguments
+/// * `value` - The value of the field
+///
+/// # Returns
+/// The trimmed value, or `None` if it is blank
+fn optional(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
@@ -0,0 +1,872 @@
+//! Export and import of the whole library
+//!
+//! The library consists of the stored documents and the small molecules,
+//! proteins, vessels, creators and kinetic laws saved to the database. It is
+//! exported to a portable JSON archive, which leaves out database IDs, so it
+//! can be imported into the database of another installation. Entries of the
+//! archive that conflict with existing entries are skipped, replace the
+//! existing entries or are added under a new name, as requested.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tauri_plugin_dialog::DialogExt;
+
+use crate::actions::utils::seconds_since_epoch;
+use crate::db::establish_connection;
+use crate::docutils::write_atomically;
+use crate::error::SuiteError;
+use crate::io::dataio::selected_path;
+use crate::io::projection::project_document;
+use crate::io::search::index_document;
+use crate::io::stringarray::StringArray;
+use crate::schema::{
+    creators, documents, laws, laws_assignables, laws_parameters, laws_species, proteins,
+    small_molecules, vessels,
+};
+
+/// Version of the archive format written by this application
+///
+/// Format 2 stores the references of small molecules as array instead of a
+/// comma separated string. Archives of format 1 are still read.
+pub const ARCHIVE_FORMAT: u32 = 2;
+/// Default file name of an exported archive
+const ARCHIVE_FILE_NAME: &str = "enzymeml-library.json";
+
+/// Portable archive of the library
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LibraryArchive {
+    /// Version of the archive format
+    pub format: u32,
+    /// Time the archive was exported, in seconds since the Unix epoch
+    pub exported_at: u64,
+    /// Stored documents, including the documents in the trash
+    #[serde(default)]
+    pub documents: Vec<ArchivedDocument>,
+    /// Saved small molecules
+    #[serde(default)]
+    pub small_molecules: Vec<ArchivedSmallMolecule>,
+    /// Saved proteins
+    #[serde(default)]
+    pub proteins: Vec<ArchivedProtein>,
+    /// Saved vessels
+    #[serde(default)]
+    pub vessels: Vec<ArchivedVessel>,
+    /// Saved creators
+    #[serde(default)]
+    pub creators: Vec<ArchivedCreator>,
+    /// Saved kinetic laws
+    #[serde(default)]
+    pub laws: Vec<ArchivedLaw>,
+}
+
+/// Stored document within an archive
+#[derive(
+    Debug, Clone, PartialEq, Queryable, Selectable, Insertable, AsChangeset, Serialize, Deserialize,
+)]
+#[diesel(table_name = documents)]
+#[diesel(treat_none_as_null = true)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct ArchivedDocument {
+    pub title: String,
+    pub content: String,
+    pub description: Option<String>,
+    pub tags: StringArray,
+    pub created_at: i64,
+    pub modified_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+/// Small molecule within an archive
+#[derive(
+    Debug, Clone, PartialEq, Queryable, Selectable, Insertable, AsChangeset, Serialize, Deserialize,
+)]
+#[diesel(table_name = small_molecules)]
+#[diesel(treat_none_as_null = true)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct ArchivedSmallMolecule {
+    pub name: String,
+    pub canonical_smiles: Option<String>,
+    pub inchi: Option<String>,
+    pub inchikey: Option<String>,
+    #[serde(default, deserialize_with = "StringArray::deserialize_legacy")]
+    pub references: StringArray,
+}
+
+/// Protein within an archive
+#[derive(
+    Debug, Clone, PartialEq, Queryable, Selectable, Insertable, AsChangeset, Serialize, Deserialize,
+)]
+#[diesel(table_name = proteins)]
+#[diesel(treat_none_as_null = true)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct ArchivedProtein {
+    pub name: String,
+    pub sequence: Option<String>,
+    pub ecnumber: Option<String>,
+    pub organism: Option<String>,
+    pub organism_tax_id: Option<String>,
+}
+
+/// Vessel within an archive
+#[derive(
+    Debug, Clone, PartialEq, Queryable, Selectable, Insertable, AsChangeset, Serialize, Deserialize,
+)]
+#[diesel(table_name = vessels)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct ArchivedVessel {
+    pub name: String,
+    pub volume: f64,
+    pub unit: String,
+    pub constant: bool,
+}
+
+/// Creator within an archive
+#[derive(
+    Debug, Clone, PartialEq, Queryable, Selectable, Insertable, AsChangeset, Serialize, Deserialize,
+)]
+#[diesel(table_name = creators)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct ArchivedCreator {
+    pub given_name: String,
+    pub family_name: String,
+    pub mail: String,
+}
+
+/// Kinetic law within an archive, including the species, assignables and parameters it involves
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct ArchivedLaw {
+    pub name: String,
+    pub equation: String,
+    /// Species of the law as pairs of name and role
+    #[serde(default)]
+    pub species: Vec<(String, String)>,
+    /// Assignables of the law as pairs of name and type
+    #[serde(default)]
+    pub assignables: Vec<(String, String)>,
+    /// Names of the parameters of the law
+    #[serde(default)]
+    pub parameters: Vec<String>,
+}
+
+/// How entries of an archive that conflict with existing entries are handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// The existing entry is kept and the archived one left out
+    #[default]
+    Skip,
+    /// The existing entry is overwritten with the archived one, unless
+    /// another entry shares a unique key with the archived one
+    Replace,
+    /// The archived entry is added under a new name, unless the entries
+    /// share another unique key, such as the mail address of a creator
+    KeepBoth,
+}
+
+/// Table of the library
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum LibraryTable {
+    /// Stored documents
+    Documents,
+    /// Saved small molecules
+    SmallMolecules,
+    /// Saved proteins
+    Proteins,
+    /// Saved vessels
+    Vessels,
+    /// Saved creators
+    Creators,
+    /// Saved kinetic laws
+    Laws,
+}
+
+/// Numbers of imported entries of a table
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct TableImport {
+    /// The table the entries were imported into
+    pub table: LibraryTable,
+    /// Number of entries added, including the ones added under a new name
+    pub added: usize,
+    /// Number of existing entries that were overwritten
+    pub replaced: usize,
+    /// Number of entries left out, because they exist already
+    pub skipped: usize,
+}
+
+/// Entry of an archive that conflicts with an existing entry
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct LibraryConflict {
+    /// The table holding the entries
+    pub table: LibraryTable,
+    /// Name of the conflicting entries
+    pub name: String,
+    /// How the conflict was resolved
+    pub resolution: ConflictStrategy,
+    /// New name of the archived entry, if it was added under a new name
+    pub renamed_to: Option<String>,
+}
+
+/// Outcome of importing an archive
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
+pub struct LibraryImportReport {
+    /// Numbers of imported entries per table
+    pub tables: Vec<TableImport>,
+    /// Entries that differ from existing entries of the same name
+    pub conflicts: Vec<LibraryConflict>,
+}
+
+/// Exports the whole library to an archive
+///
+/// Opens a file dialog allowing the user to choose the file the archive is
+/// written to.
+///
+/// # Arguments
+/// * `app_handle` - Handle to the Tauri application used to open the dialog
+///
+/// # Returns
+/// Result containing the path of the written archive
+#[tauri::command]
+#[specta::specta]
+pub async fn export_library(app_handle: AppHandle) -> Result<PathBuf, SuiteError> {
+    let dialog_result = app_handle
+        .dialog()
+        .file()
+        .set_title("Export Library")
+        .set_file_name(ARCHIVE_FILE_NAME)
+        .add_filter("EnzymeML Library", &["json"])
+        .blocking_save_file();
+
+    let Some(path) = dialog_result else {
+        return Err(SuiteError::cancelled("No file selected"));
+    };
+    let path = selected_path(&path)?;
+
+    export_archive(&path)?;
+    Ok(path)
+}
+
+/// Imports an archive into the library
+///
+/// Opens a file dialog allowing the user to choose the archive. Entries that
+/// equal existing entries are always skipped.
+///
+/// # Arguments
+/// * `strategy` - How conflicting entries are handled, skipped if omitted
+/// * `app_handle` - Handle to the Tauri application used to open the dialog
+///
+/// # Returns
+/// Result containing the numbers of imported entries and the conflicts
+#[tauri::command]
+#[specta::specta]
+pub async fn import_library(
+    strategy: Option<ConflictStrategy>,
+    app_handle: AppHandle,
+) -> Result<LibraryImportReport, SuiteError> {
+    let dialog_result = app_handle
+        .dialog()
+        .file()
+        .set_title("Import Library")
+        .add_filter("EnzymeML Library", &["json"])
+        .blocking_pick_file();
+
+    let Some(path) = dialog_result else {
+        return Err(SuiteError::cancelled("No file selected"));
+    };
+    let path = selected_path(&path)?;
+
+    import_archive(&path, strategy.unwrap_or_default())
+}
+
+/// Writes the whole library to an archive
+///
+/// # Arguments
+/// * `path` - Path of the archive
+///
+/// # Returns
+/// Result containing the written archive
+pub fn export_archive(path: &Path) -> Result<LibraryArchive, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    let archive = LibraryArchive {
+        format: ARCHIVE_FORMAT,
+        exported_at: seconds_since_epoch(),
+        documents: documents::table
+            .order(documents::id)
+            .select(ArchivedDocument::as_select())
+            .load(&mut connection)?,
+        small_molecules: small_molecules::table
+            .order(small_molecules::id)
+            .select(ArchivedSmallMolecule::as_select())
+            .load(&mut connection)?,
+        proteins: proteins::table
+            .order(proteins::id)
+            .select(ArchivedProtein::as_select())
+            .load(&mut connection)?,
+        vessels: vessels::table
+            .order(vessels::id)
+            .select(ArchivedVessel::as_select())
+            .load(&mut connection)?,
+        creators: creators::table
+            .order(creators::id)
+            .select(ArchivedCreator::as_select())
+            .load(&mut connection)?,
+        laws: laws::table
+            .order(laws::id)
+            .select(laws::id)
+            .load::<i32>(&mut connection)?
+            .into_iter()
+            .map(|id| load_law(&mut connection, id))
+            .collect::<Result<_, _>>()?,
+    };
+
+    write_atomically(path, &serde_json::to_string_pretty(&archive)?)?;
+    Ok(archive)
+}
+
+/// Imports an archive into the library
+///
+/// The archive is imported in a single transaction, so either all or none of
+/// its entries end up in the library.
+///
+/// # Arguments
+/// * `path` - Path of the archive
+/// * `strategy` - How conflicting entries are handled
+///
+/// # Returns
+/// Result containing the numbers of imported entries and the conflicts
+pub fn import_archive(
+    path: &Path,
+    strategy: ConflictStrategy,
+) -> Result<LibraryImportReport, SuiteError> {
+    let content = fs::read_to_string(path).map_err(|e| SuiteError::io(path, e))?;
+    let archive: LibraryArchive = serde_json::from_str(&content)
+        .map_err(|e| SuiteError::parse(format!("Invalid library archive: {}", e)))?;
+
+    if archive.format > ARCHIVE_FORMAT {
+        return Err(SuiteError::validation(format!(
+            "The library archive has format {}, but only formats up to {} are supported",
+            archive.format, ARCHIVE_FORMAT
+        )));
+    }
+
+    let mut connection = establish_connection()?;
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        let mut report = LibraryImportReport::default();
+        merge(connection, archive.documents, strategy, &mut report)?;
+        merge(connection, archive.small_molecules, strategy, &mut report)?;
+        merge(connection, archive.proteins, strategy, &mut report)?;
+        merge(connection, archive.vessels, strategy, &mut report)?;
+        merge(connection, archive.creators, strategy, &mut report)?;
+        merge(connection, archive.laws, strategy, &mut report)?;
+
+        Ok(report)
+    })
+}
+
+/// Entry of an archive that can be merged into its table
+trait ArchiveEntry: Sized {
+    /// The table holding the entries
+    const TABLE: LibraryTable;
+
+    /// Returns the name identifying the entry
+    fn name(&self) -> String;
+
+    /// Returns whether the entry equals an existing entry of the same name
+    fn same_as(&self, existing: &Self) -> bool;
+
+    /// Looks up the existing entry the entry conflicts with
+    ///
+    /// # Returns
+    /// Result containing the ID and content of the existing entry, if any
+    fn find(&self, connection: &mut SqliteConnection) -> Result<Option<(i32, Self)>, SuiteError>;
+
+    /// Renames the entry, so it no longer conflicts with existing entries
+    ///
+    /// # Returns
+    /// Result containing the new name, `None` if renaming doesn't resolve the
+    /// conflict, e.g. because the entries share another unique key
+    fn make_unique(
+        &mut self,
+        connection: &mut SqliteConnection,
+    ) -> Result<Option<String>, SuiteError>;
+
+    /// Inserts the entry into its table
+    fn insert(&self, connection: &mut SqliteConnection) -> Result<(), SuiteError>;
+
+    /// Overwrites an existing entry with the entry
+    ///
+    /// # Returns
+    /// Result containing whether the entry was overwritten, `false` if
+    /// another entry shares a unique key with the entry, e.g. its InChI Key
+    fn replace(&self, connection: &mut SqliteConnection, id: i32) -> Result<bool, SuiteError>;
+}
+
+/// Merges the entries of an archive into their table
+///
+/// # Arguments
+/// * `connection` - Connection to the database
+/// * `entries` - The archived entries
+/// * `strategy` - How conflicting entries are handled
+/// * `report` - The report the outcome is added to
+fn merge<T: ArchiveEntry>(
+    connection: &mut SqliteConnection,
+    entries: Vec<T>,
+    strategy: ConflictStrategy,
+    report: &mut LibraryImportReport,
+) -> Result<(), SuiteError> {
+    let mut counts = TableImport {
+        table: T::TABLE,
+        added: 0,
+        replaced: 0,
+        skipped: 0,
+    };
+
+    for mut entry in entries {
+        let Some((id, existing)) = entry.find(connection)? else {
+            entry.insert(connection)?;
+            counts.added += 1;
+            continue;
+        };
+
+        if entry.same_as(&existing) {
+            counts.skipped += 1;
+            continue;
+        }
+
+        let mut resolution = strategy;
+        let mut renamed_to = None;
+        match strategy {
+            ConflictStrategy::Skip => counts.skipped += 1,
+            ConflictStrategy::Replace => {
+                if entry.replace(connection, id)? {
+                    counts.replaced += 1;
+                } else {
+                    // Another entry holds the key, so the existing entry is kept
+                    counts.skipped += 1;
+                    resolution = ConflictStrategy::Skip;
+                }
+            }
+            ConflictStrategy::KeepBoth => match entry.make_unique(connection)? {
+                Some(name) => {
+                    entry.insert(connection)?;
+                    counts.added += 1;
+                    renamed_to = Some(name);
+                }
+                // Renaming cannot resolve the conflict, so the existing entry is kept
+                None => {
+                    counts.skipped += 1;
+                    resolution = ConflictStrategy::Skip;
+                }
+            },
+        }
+
+        report.conflicts.push(LibraryConflict {
+            table: T::TABLE,
+            name: existing.name(),
+            resolution,
+            renamed_to,
+        });
+    }
+
+    report.tables.push(counts);
+    Ok(())
+}
+
+/// Returns the first name derived from a name that is not taken yet
+///
+/// Names are derived by appending a number, e.g. "ABTS (2)".
+///
+/// # Arguments
+/// * `connection` - Connection to the database
+/// * `name` - The name to derive from
+/// * `taken` - Returns whether a name is taken
+fn unique_name(
+    connection: &mut SqliteConnection,
+    name: &str,
+    taken: impl Fn(&mut SqliteConnection, &str) -> Result<bool, SuiteError>,
+) -> Result<String, SuiteError> {
+    let mut i = 2;
+    loop {
+        let candidate = format!("{} ({})", name, i);
+        if !taken(connection, &candidate)? {
+            return Ok(candidate);
+        }
+        i += 1;
+    }
+}
+
+/// Loads a kinetic law with the species, assignables and parameters it involves
+pub(crate) fn load_law(
+    connection: &mut SqliteConnection,
+    id: i32,
+) -> Result<ArchivedLaw, SuiteError> {
+    let (name, equation) = laws::table
+        .find(id)
+        .select((laws::name, laws::equation))
+        .first::<(String, String)>(connection)?;
+
+    Ok(ArchivedLaw {
+        name,
+        equation,
+        species: laws_species::table
+            .filter(laws_species::law_id.eq(id))
+            .order(laws_species::id)
+            .select((laws_species::species_name, laws_species::role))
+            .load(connection)?,
+        assignables: laws_assignables::table
+            .filter(laws_assignables::law_id.eq(id))
+            .order(laws_assignables::id)
+            .select((
+                laws_assignables::assignable_name,
+                laws_assignables::assignable_type,
+            ))
+            .load(connection)?,
+        parameters: laws_parameters::table
+            .filter(laws_parameters::law_id.eq(id))
+            .order(laws_parameters::id)
+            .select(laws_parameters::parameter_name)
+            .load(connection)?,
+    })
+}
+
+/// Inserts the species, assignables and parameters of a kinetic law
+pub(crate) fn insert_law_relations(
+    connection: &mut SqliteConnection,
+    law: &ArchivedLaw,
+    id: i32,
+) -> Result<(), SuiteError> {
+    for (species_name, role) in &law.species {
+        diesel::insert_into(laws_species::table)
+            .values((
+                laws_species::law_id.eq(id),
+                laws_species::species_name.eq(species_name),
+                laws_species::role.eq(role),
+            ))
+            .execute(connection)?;
+    }
+
+    for (assignable_name, assignable_type) in &law.assignables {
+        diesel::insert_into(laws_assignables::table)
+            .values((
+                laws_assignables::law_id.eq(id),
+                laws_assignables::assignable_name.eq(assignable_name),
+                laws_assignables::assignable_type.eq(assignable_type),
+            ))
+            .execute(connection)?;
+    }
+
+    for parameter_name in &law.parameters {
+        diesel::insert_into(laws_parameters::table)
+            .values((
+                laws_parameters::law_id.eq(id),
+                laws_parameters::parameter_name.eq(parameter_name),
+            ))
+            .execute(connection)?;
+    }
+
+    Ok(())
+}
+
+/// Deletes the species, assignables and parameters of a kinetic law
+pub(crate) fn delete_law_relations(
+    connection: &mut SqliteConnection,
+    id: i32,
+) -> Result<(), SuiteError> {
+    diesel::delete(laws_species::table.filter(laws_species::law_id.eq(id))).execute(connection)?;
+    diesel::delete(laws_assignables::table.filter(laws_assignables::law_id.eq(id)))
+        .execute(connection)?;
+    diesel::delete(laws_parameters::table.filter(laws_parameters::law_id.eq(id)))
+        .execute(connection)?;
+
+    Ok(())
+}
+
+/// Implements [`ArchiveEntry`] for an entry identified by a unique `name` column
+///
+/// Entries may be unique by another optional column as well, e.g. small
+/// molecules by their InChI Key. An entry conflicts with existing entries
+/// sharing either its name or this key, preferring the one of the same name.
+macro_rules! named_archive_entry {
+    ($type:ty, $table:ident, $kind:expr $(, $key:ident)?) => {
+        impl ArchiveEntry for $type {
+            const TABLE: LibraryTable = $kind;
+
+            fn name(&self) -> String {
+                self.name.clone()
+            }
+
+            fn same_as(&self, existing: &Self) -> bool {
+                self == existing
+            }
+
+            fn find(
+                &self,
+                connection: &mut SqliteConnection,
+            ) -> Result<Option<(i32, Self)>, SuiteError> {
+                #[allow(unused_mut)]
+                let mut statement = $table::table
+                    .filter($table::name.eq(&self.name))
+                    .into_boxed();
+                $(
+                    if let Some(key) = self.$key.as_deref().filter(|key| !key.is_empty()) {
+                        statement = statement.or_filter($table::$key.eq(key));
+                    }
+                )?
+
+                Ok(statement
+                    .order(($table::name.ne(&self.name), $table::id))
+                    .select(($table::id, <$type>::as_select()))
+                    .first::<(i32, Self)>(connection)
+                    .optional()?)
+            }
+
+            fn make_unique(
+                &mut self,
+                connection: &mut SqliteConnection,
+            ) -> Result<Option<String>, SuiteError> {
+                $(
+                    if let Some(key) = self.$key.as_deref().filter(|key| !key.is_empty()) {
+                        let taken = diesel::select(diesel::dsl::exists(
+                            $table::table.filter($table::$key.eq(key)),
+                        ))
+                        .get_result::<bool>(connection)?;
+                        if taken {
+                            return Ok(None);
+                        }
+                    }
+                )?
+
+                self.name = unique_name(connection, &self.name, |connection, name| {
+                    Ok(diesel::select(diesel::dsl::exists(
+                        $table::table.filter($table::name.eq(name)),
+                    ))
+                    .get_result::<bool>(connection)?)
+                })?;
+                Ok(Some(self.name.clone()))
+            }
+
+            fn insert(&self, connection: &mut SqliteConnection) -> Result<(), SuiteError> {
+                diesel::insert_into($table::table)
+                    .values(self)
+                    .execute(connection)?;
+                Ok(())
+            }
+
+            fn replace(
+                &self,
+                connection: &mut SqliteConnection,
+                id: i32,
+            ) -> Result<bool, SuiteError> {
+                $(
+                    if let Some(key) = self.$key.as_deref().filter(|key| !key.is_empty()) {
+                        // The entry may keep its key, even if other entries share it
+                        let current = $table::table
+                            .find(id)
+                            .select($table::$key)
+                            .first::<Option<String>>(connection)?;
+                        let taken = diesel::select(diesel::dsl::exists(
+                            $table::table
+                                .filter($table::$key.eq(key))
+                                .filter($table::id.ne(id)),
+                        ))
+                        .get_result::<bool>(connection)?;
+                        if taken && current.as_deref() != Some(key) {
+                            return Ok(false);
+                        }
+                    }
+                )?
+
+                diesel::update($table::table.find(id))
+                    .set(self)
+                    .execute(connection)?;
+                Ok(true)
+            }
+        }
+    };
+}
+
+named_archive_entry!(
+    ArchivedSmallMolecule,
+    small_molecules,
+    LibraryTable::SmallMolecules,
+    inchikey
+);
+named_archive_entry!(ArchivedProtein, proteins, LibraryTable::Proteins, sequence);
+named_archive_entry!(ArchivedVessel, vessels, LibraryTable::Vessels);
+
+/// Documents are identified by their title, disregarding documents in the trash
+impl ArchiveEntry for ArchivedDocument {
+    const TABLE: LibraryTable = LibraryTable::Documents;
+
+    fn name(&self) -> String {
+        self.title.clone()
+    }
+
+    fn same_as(&self, existing: &Self) -> bool {
+        self.content == existing.content
+    }
+
+    fn find(&self, connection: &mut SqliteConnection) -> Result<Option<(i32, Self)>, SuiteError> {
+        Ok(documents::table
+            .filter(documents::title.eq(&self.title))
+            .filter(documents::deleted_at.is_null())
+            .order(documents::id)
+            .select((documents::id, ArchivedDocument::as_select()))
+            .first::<(i32, Self)>(connection)
+            .optional()?)
+    }
+
+    fn make_unique(
+        &mut self,
+        connection: &mut SqliteConnection,
+    ) -> Result<Option<String>, SuiteError> {
+        self.title = unique_name(connection, &self.title, |connection, title| {
+            Ok(diesel::select(diesel::dsl::exists(
+                documents::table
+                    .filter(documents::title.eq(title))
+                    .filter(documents::deleted_at.is_null()),
+            ))
+            .get_result::<bool>(connection)?)
+        })?;
+        Ok(Some(self.title.clone()))
+    }
+
+    fn insert(&self, connection: &mut SqliteConnection) -> Result<(), SuiteError> {
+        let id = diesel::insert_into(documents::table)
+            .values(self)
+            .returning(documents::id)
+            .get_result(connection)?;
+
+        index_document(connection, id)?;
+        project_document(connection, id)
+    }
+
+    fn replace(&self, connection: &mut SqliteConnection, id: i32) -> Result<bool, SuiteError> {
+        diesel::update(documents::table.find(id))
+            .set(self)
+            .execute(connection)?;
+
+        index_document(connection, id)?;
+        project_document(connection, id)?;
+        Ok(true)
+    }
+}
+
+/// Creators are identified by their mail address, or by their name if they
+/// have none, and may share their name
+impl ArchiveEntry for ArchivedCreator {
+    const TABLE: LibraryTable = LibraryTable::Creators;
+
+    fn name(&self) -> String {
+        format!("{} {}", self.given_name, self.family_name)
+    }
+
+    fn same_as(&self, existing: &Self) -> bool {
+        self == existing
+    }
+
+    fn find(&self, connection: &mut SqliteConnection) -> Result<Option<(i32, Self)>, SuiteError> {
+        let statement = creators::table
+            .filter(creators::mail.eq(&self.mail))
+            .into_boxed();
+        let statement = if self.mail.is_empty() {
+            statement
+                .filter(creators::given_name.eq(&self.given_name))
+                .filter(creators::family_name.eq(&self.family_name))
+        } else {
+            statement
+        };
+
+        Ok(statement
+            .order(creators::id)
+            .select((creators::id, ArchivedCreator::as_select()))
+            .first::<(i32, Self)>(connection)
+            .optional()?)
+    }
+
+    fn make_unique(
+        &mut self,
+        _connection: &mut SqliteConnection,
+    ) -> Result<Option<String>, SuiteError> {
+        Ok(None)
+    }
+
+    fn insert(&self, connection: &mut SqliteConnection) -> Result<(), SuiteError> {
+        diesel::insert_into(creators::table)
+            .values(self)
+            .execute(connection)?;
+        Ok(())
+    }
+
+    fn replace(&self, connection: &mut SqliteConnection, id: i32) -> Result<bool, SuiteError> {
+        diesel::update(creators::table.find(id))
+            .set(self)
+            .execute(connection)?;
+        Ok(true)
+    }
+}
+
+/// Kinetic laws are identified by their name and replaced including their relations
+impl ArchiveEntry for ArchivedLaw {
+    const TABLE: LibraryTable = LibraryTable::Laws;
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn same_as(&self, existing: &Self) -> bool {
+        self == existing
+    }
+
+    fn find(&self, connection: &mut SqliteConnection) -> Result<Option<(i32, Self)>, SuiteError> {
+        let id = laws::table
+            .filter(laws::name.eq(&self.name))
+            .order(laws::id)
+            .select(laws::id)
+            .first::<i32>(connection)
+            .optional()?;
+
+        match id {
+            Some(id) => Ok(Some((id, load_law(connection, id)?))),
+            None => Ok(None),
+        }
+    }
+
+    fn make_unique(
+        &mut self,
+        connection: &mut SqliteConnection,
+    ) -> Result<Option<String>, SuiteError> {
+        self.name = unique_name(connection, &self.name, |connection, name| {
+            Ok(
+                diesel::select(diesel::dsl::exists(laws::table.filter(laws::name.eq(name))))
+                    .get_result::<bool>(connection)?,
+            )
+        })?;
+        Ok(Some(self.name.clone()))
+    }
+
+    fn insert(&self, connection: &mut SqliteConnection) -> Result<(), SuiteError> {
+        let id = diesel::insert_into(laws::table)
+            .values((laws::name.eq(&self.name), laws::equation.eq(&self.equation)))
+            .returning(laws::id)
+            .get_result(connection)?;
+
+        insert_law_relations(connection, self, id)
+    }
+
+    fn replace(&self, connection: &mut SqliteConnection, id: i32) -> Result<(), SuiteError> {
+        diesel::update(laws::table.find(id))
+            .set(laws::equation.eq(&self.equation))
+            .execute(connection)?;
+
+        delete_law_relations(connection, id)?;
+        insert_law_relations(connection, self, id)?;
+        Ok(true)
+    }
+}
@@ -1,13 +1,14 @@
 //! Macros for database operations
 //!
-//! This module contains macros for filtering and getting rows from the database.
+//! This module contains macros for filtering, getting, updating and deleting rows of the database.
 //! The macros are used to simplify common database operations and reduce code duplication.
 
 /// Filters a database table by a given column value using LIKE pattern matching
 ///
 /// This macro performs a database query to filter rows from the specified table
 /// where the given column contains the provided value. The filtering uses SQL LIKE
-/// pattern matching with wildcards on both sides of the search term.
+/// pattern matching with wildcards on both sides of the search term, wildcards
+/// within the search term match literally.
 ///
 /// # Arguments
 /// * `$table` - The database table to filter
@@ -16,15 +17,19 @@
 /// * `$id` - The value to search for within the column
 ///
 /// # Returns
-/// Result containing a vector of filtered rows or an error string
+/// Result containing a vector of filtered rows or a database error
 #[macro_export]
 macro_rules! filter_table {
     ($table:ident, $type:ty, $id_prop:expr, $id:expr) => {{
-        let mut connection = establish_db_connection();
+        let mut connection = $crate::db::establish_connection()?;
         $table::table
-            .filter($id_prop.like(format!("%{}%", $id)))
+            .filter(
+                $id_prop
+                    .like($crate::db::contains_pattern(&$id))
+                    .escape($crate::db::LIKE_ESCAPE),
+            )
             .load::<$type>(&mut connection)
-            .map_err(|e| e.to_string())
+            .map_err($crate::error::SuiteError::from)
     }};
 }
 
@@ -40,15 +45,12 @@ macro_rules! filter_table {
 /// * `$id` - The primary key value to search for
 ///
 /// # Returns
-/// Result containing the found row or an error string
+/// QueryResult containing the found row or the error of the query
 #[macro_export]
 macro_rules! get_row {
     ($table:ident, $type:ty, $id:expr) => {{
-        let mut connection = establish_db_connection();
-        $table::table
-            .find($id)
-            .first::<$type>(&mut connection)
-            .map_err(|e| e.to_string())
+        let mut connection = $crate::db::establish_connection()?;
+        $table::table.find($id).first::<$type>(&mut connection)
     }};
 }
 
@@ -63,14 +65,14 @@ macro_rules! get_row {
 /// * `$type` - The type to deserialize the rows into
 ///
 /// # Returns
-/// Result containing a vector of all rows or an error string
+/// Result containing a vector of all rows or a database error
 #[macro_export]
 macro_rules! get_rows {
     ($table:ident, $type:ty) => {{
-        let mut connection = establish_db_connection();
+        let mut connection = $crate::db::establish_connection()?;
         $table::table
             .load::<$type>(&mut connection)
-            .map_err(|e| e.to_string())
+            .map_err($crate::error::SuiteError::from)
     }};
 }
 
@@ -88,11 +90,11 @@ macro_rules! get_rows {
 /// * `$data` - The data to insert or update
 ///
 /// # Returns
-/// Result containing the number of affected rows or an error string
+/// Result containing the number of affected rows or a database error
 #[macro_export]
 macro_rules! upsert_row {
     ($table:ident, $newtype:ty, $col:ident, $data:expr) => {{
-        let mut connection = establish_db_connection();
+        let mut connection = $crate::db::establish_connection()?;
         let new_row = <$newtype>::from(&$data);
 
         diesel::insert_into($table::table)
@@ -101,6 +103,54 @@ macro_rules! upsert_row {
             .do_update()
             .set(&new_row)
             .execute(&mut connection)
-            .map_err(|e| e.to_string())
+            .map_err($crate::error::SuiteError::from)
+    }};
+}
+
+/// Updates a row of a database table in place
+///
+/// This macro overwrites the row with the primary key of the given row with
+/// its values and returns the row as stored afterwards.
+///
+/// # Arguments
+/// * `$table` - The database table to update
+/// * `$type` - The type of the row, which has to implement `AsChangeset`
+/// * `$row` - The row holding the primary key and the new values
+///
+/// # Returns
+/// QueryResult containing the updated row or the error of the query
+#[macro_export]
+macro_rules! update_row {
+    ($table:ident, $type:ty, $row:expr) => {{
+        let mut connection = $crate::db::establish_connection()?;
+        let row: $type = $row;
+
+        diesel::update($table::table.find(row.id))
+            .set(&row)
+            .get_result::<$type>(&mut connection)
+    }};
+}
+
+/// Deletes a row from a database table by its primary key
+///
+/// # Arguments
+/// * `$table` - The database table to delete from
+/// * `$entity` - Kind of the entry, used to report a missing row
+/// * `$id` - The primary key value of the row
+///
+/// # Returns
+/// Result indicating success, or a not found error if no row has the primary key
+#[macro_export]
+macro_rules! delete_row {
+    ($table:ident, $entity:expr, $id:expr) => {{
+        let mut connection = $crate::db::establish_connection()?;
+        let deleted = diesel::delete($table::table.find($id))
+            .execute(&mut connection)
+            .map_err($crate::error::SuiteError::from)?;
+
+        match deleted {
+            0 => Err($crate::error::SuiteError::not_found($entity, $id)),
+            _ => Ok(()),
+        }
     }};
 }
@@ -0,0 +1,871 @@
+//! Normalised projection of stored documents
+//!
+//! Stored documents keep their content as serialized JSON, which cannot be
+//! queried across documents. If enabled, the species, reactions, parameters and
+//! measurement conditions of every stored document are additionally written to
+//! the `document_*` tables whenever the document is saved. The projection is
+//! derived from the content and can be rebuilt at any time.
+//!
+//! Parameters are linked to the species of the kinetic laws and equations they
+//! appear in. A parameter of the kinetic law of a reaction refers to all
+//! participants of the reaction, including its modifiers, so that e.g. the Km
+//! values of an enzyme can be looked up across documents.
+
+use std::collections::{BTreeSet, HashSet};
+
+use diesel::prelude::*;
+use diesel::sql_query;
+use diesel::sql_types::{Double, Integer, Nullable, Text};
+use diesel::sqlite::SqliteConnection;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::db::establish_connection;
+use crate::document::import::SYMBOL_REGEX;
+use crate::error::SuiteError;
+use crate::schema;
+
+/// Separator of the values aggregated by `group_concat`
+const LIST_SEPARATOR: char = '\u{1f}';
+/// Tolerance when comparing temperatures in Kelvin, to compensate rounding of converted units
+const TEMPERATURE_TOLERANCE: f64 = 0.005;
+
+/// Collections of species of a document and the kind they are projected as
+const SPECIES_COLLECTIONS: [(&str, &str); 3] = [
+    ("small_molecules", "small_molecule"),
+    ("proteins", "protein"),
+    ("complexes", "complex"),
+];
+
+/// Roles of the participants of a reaction and the collection holding them
+const REACTION_ROLES: [(&str, &str); 3] = [
+    ("reactants", "reactant"),
+    ("products", "product"),
+    ("modifiers", "modifier"),
+];
+
+/// Filter of a parameter query
+///
+/// Text filters match case-insensitively any part of the respective fields.
+/// Omitted filters match every parameter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
+pub struct ParameterQuery {
+    /// Matches the name, symbol or ID of the parameter, e.g. "Km"
+    #[serde(default)]
+    pub parameter: Option<String>,
+    /// Matches the name, ID or EC number of a protein the parameter refers to
+    #[serde(default)]
+    pub protein: Option<String>,
+}
+
+/// Parameter of a stored document
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ParameterRecord {
+    /// Database ID of the document
+    pub document_id: i32,
+    /// Title of the document
+    pub document_title: String,
+    /// ID of the parameter within the document
+    pub parameter_id: String,
+    /// Name of the parameter
+    pub name: String,
+    /// Symbol of the parameter in equations
+    pub symbol: String,
+    /// Value of the parameter, if estimated or given
+    pub value: Option<f64>,
+    /// Name of the unit of the parameter
+    pub unit: Option<String>,
+    /// Standard error of the value
+    pub stderr: Option<f64>,
+    /// Names of the proteins the parameter refers to
+    pub proteins: Vec<String>,
+}
+
+/// Filter of a measurement query
+///
+/// Temperatures are given in Kelvin and bounds are inclusive. Measurements
+/// lacking a bounded condition or stating it in an unknown unit do not match.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
+pub struct MeasurementQuery {
+    /// Lowest temperature in Kelvin
+    #[serde(default)]
+    pub min_temperature: Option<f64>,
+    /// Highest temperature in Kelvin
+    #[serde(default)]
+    pub max_temperature: Option<f64>,
+    /// Lowest pH
+    #[serde(default)]
+    pub min_ph: Option<f64>,
+    /// Highest pH
+    #[serde(default)]
+    pub max_ph: Option<f64>,
+    /// Matches case-insensitively any part of the name or ID of a measured species
+    #[serde(default)]
+    pub species: Option<String>,
+}
+
+/// Initial condition of a species in a measurement
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct InitialCondition {
+    /// ID of the species within the document
+    pub species_id: String,
+    /// Name of the species, if it is defined in the document
+    pub name: Option<String>,
+    /// Initial amount of the species
+    pub initial: Option<f64>,
+    /// Name of the unit of the measured data
+    pub unit: Option<String>,
+}
+
+/// Measurement of a stored document
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct MeasurementRecord {
+    /// Database ID of the document
+    pub document_id: i32,
+    /// Title of the document
+    pub document_title: String,
+    /// ID of the measurement within the document
+    pub measurement_id: String,
+    /// Name of the measurement
+    pub name: String,
+    /// pH of the measurement
+    pub ph: Option<f64>,
+    /// Temperature of the measurement as stated in the document
+    pub temperature: Option<f64>,
+    /// Name of the unit of the temperature
+    pub temperature_unit: Option<String>,
+    /// Temperature of the measurement in Kelvin, if the unit is known
+    pub temperature_kelvin: Option<f64>,
+    /// Initial conditions of the measured species
+    pub initials: Vec<InitialCondition>,
+}
+
+/// Row of the parameter query
+#[derive(QueryableByName)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+struct ParameterRow {
+    #[diesel(sql_type = Integer)]
+    document_id: i32,
+    #[diesel(sql_type = Text)]
+    document_title: String,
+    #[diesel(sql_type = Text)]
+    parameter_id: String,
+    #[diesel(sql_type = Text)]
+    name: String,
+    #[diesel(sql_type = Text)]
+    symbol: String,
+    #[diesel(sql_type = Nullable<Double>)]
+    value: Option<f64>,
+    #[diesel(sql_type = Nullable<Text>)]
+    unit: Option<String>,
+    #[diesel(sql_type = Nullable<Double>)]
+    stderr: Option<f64>,
+    #[diesel(sql_type = Nullable<Text>)]
+    proteins: Option<String>,
+}
+
+/// Row of the measurement query
+#[derive(QueryableByName)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+struct MeasurementRow {
+    #[diesel(sql_type = Integer)]
+    document_id: i32,
+    #[diesel(sql_type = Text)]
+    document_title: String,
+    #[diesel(sql_type = Text)]
+    measurement_id: String,
+    #[diesel(sql_type = Text)]
+    name: String,
+    #[diesel(sql_type = Nullable<Double>)]
+    ph: Option<f64>,
+    #[diesel(sql_type = Nullable<Double>)]
+    temperature: Option<f64>,
+    #[diesel(sql_type = Nullable<Text>)]
+    temperature_unit: Option<String>,
+    #[diesel(sql_type = Nullable<Double>)]
+    temperature_kelvin: Option<f64>,
+}
+
+/// Row of the initial conditions of a measurement
+#[derive(QueryableByName)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+struct InitialRow {
+    #[diesel(sql_type = Text)]
+    species_id: String,
+    #[diesel(sql_type = Nullable<Text>)]
+    name: Option<String>,
+    #[diesel(sql_type = Nullable<Double>)]
+    initial: Option<f64>,
+    #[diesel(sql_type = Nullable<Text>)]
+    data_unit: Option<String>,
+}
+
+/// Returns whether the normalised projection of stored documents is maintained
+///
+/// # Returns
+/// Result containing whether the projection is enabled
+#[tauri::command]
+#[specta::specta]
+pub fn get_projection_enabled() -> Result<bool, SuiteError> {
+    is_enabled(&mut establish_connection()?)
+}
+
+/// Enables or disables the normalised projection of stored documents
+///
+/// Enabling the projection projects all stored documents, disabling it clears
+/// the projection.
+///
+/// # Arguments
+/// * `enabled` - Whether the projection is maintained
+///
+/// # Returns
+/// Result containing the number of projected documents
+#[tauri::command]
+#[specta::specta]
+pub fn set_projection_enabled(enabled: bool) -> Result<usize, SuiteError> {
+    set_enabled(enabled)
+}
+
+/// Rebuilds the normalised projection from all stored documents
+///
+/// # Returns
+/// Result containing the number of projected documents, 0 if the projection is disabled
+#[tauri::command]
+#[specta::specta]
+pub fn rebuild_document_projection() -> Result<usize, SuiteError> {
+    rebuild_projection()
+}
+
+/// Looks up parameters across the stored documents
+///
+/// Answers questions such as "all Km values of laccase", by combining a
+/// parameter and a protein filter. Documents in the trash are left out.
+///
+/// # Arguments
+/// * `query` - The filter of the parameters, all parameters if omitted
+///
+/// # Returns
+/// Result containing the matching parameters, ordered by name and document
+#[tauri::command]
+#[specta::specta]
+pub fn query_parameters(query: Option<ParameterQuery>) -> Result<Vec<ParameterRecord>, SuiteError> {
+    find_parameters(&query.unwrap_or_default())
+}
+
+/// Looks up measurements across the stored documents
+///
+/// Answers questions such as "all measurements at 30 °C", by filtering the
+/// conditions of the measurements. Documents in the trash are left out.
+///
+/// # Arguments
+/// * `query` - The filter of the measurements, all measurements if omitted
+///
+/// # Returns
+/// Result containing the matching measurements, ordered by document and name
+#[tauri::command]
+#[specta::specta]
+pub fn query_measurements(
+    query: Option<MeasurementQuery>,
+) -> Result<Vec<MeasurementRecord>, SuiteError> {
+    find_measurements(&query.unwrap_or_default())
+}
+
+/// Returns whether the normalised projection of stored documents is maintained
+///
+/// # Arguments
+/// * `connection` - Connection to the database
+///
+/// # Returns
+/// Result containing whether the projection is enabled
+pub fn is_enabled(connection: &mut SqliteConnection) -> Result<bool, SuiteError> {
+    let enabled = schema::projection_state::table
+        .select(schema::projection_state::enabled)
+        .first::<bool>(connection)
+        .optional()?;
+
+    Ok(enabled.unwrap_or(false))
+}
+
+/// Enables or disables the normalised projection of stored documents
+///
+/// # Arguments
+/// * `enabled` - Whether the projection is maintained
+///
+/// # Returns
+/// Result containing the number of projected documents
+pub fn set_enabled(enabled: bool) -> Result<usize, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    diesel::replace_into(schema::projection_state::table)
+        .values((
+            schema::projection_state::id.eq(1),
+            schema::projection_state::enabled.eq(enabled),
+        ))
+        .execute(&mut connection)?;
+
+    rebuild_projection()
+}
+
+/// Rebuilds the normalised projection from all stored documents
+///
+/// The projection is cleared if it is disabled.
+///
+/// # Returns
+/// Result containing the number of projected documents
+pub fn rebuild_projection() -> Result<usize, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        clear_projection(connection)?;
+
+        if !is_enabled(connection)? {
+            return Ok(0);
+        }
+
+        let ids = schema::documents::table
+            .select(schema::documents::id)
+            .load::<i32>(connection)?;
+        for id in &ids {
+            project_document(connection, *id)?;
+        }
+
+        Ok(ids.len())
+    })
+}
+
+/// Projects a stored document, replacing its previous projection
+///
+/// Does nothing if the projection is disabled. Documents whose content cannot
+/// be parsed are left out of the projection.
+///
+/// # Arguments
+/// * `connection` - Connection to the database holding the document
+/// * `id` - The ID of the document to project
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn project_document(connection: &mut SqliteConnection, id: i32) -> Result<(), SuiteError> {
+    if !is_enabled(connection)? {
+        return Ok(());
+    }
+
+    let content = schema::documents::table
+        .filter(schema::documents::id.eq(id))
+        .select(schema::documents::content)
+        .first::<String>(connection)
+        .map_err(|err| SuiteError::from_query(err, "Document", id))?;
+
+    remove_from_projection(connection, &[id])?;
+
+    let Ok(doc) = serde_json::from_str::<Value>(&content) else {
+        return Ok(());
+    };
+
+    let species_ids = project_species(connection, id, &doc)?;
+    project_reactions(connection, id, &doc)?;
+    project_parameters(connection, id, &doc, &species_ids)?;
+    project_measurements(connection, id, &doc)?;
+
+    Ok(())
+}
+
+/// Removes documents from the normalised projection
+///
+/// # Arguments
+/// * `connection` - Connection to the database holding the projection
+/// * `ids` - The IDs of the documents to remove
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn remove_from_projection(
+    connection: &mut SqliteConnection,
+    ids: &[i32],
+) -> Result<(), SuiteError> {
+    use schema::{
+        document_measurement_species as measurement_species, document_measurements as measurements,
+        document_parameter_species as parameter_species, document_parameters as parameters,
+        document_reaction_species as reaction_species, document_reactions as reactions,
+        document_species as species,
+    };
+
+    diesel::delete(species::table.filter(species::document_id.eq_any(ids))).execute(connection)?;
+    diesel::delete(reactions::table.filter(reactions::document_id.eq_any(ids)))
+        .execute(connection)?;
+    diesel::delete(reaction_species::table.filter(reaction_species::document_id.eq_any(ids)))
+        .execute(connection)?;
+    diesel::delete(parameters::table.filter(parameters::document_id.eq_any(ids)))
+        .execute(connection)?;
+    diesel::delete(parameter_species::table.filter(parameter_species::document_id.eq_any(ids)))
+        .execute(connection)?;
+    diesel::delete(measurements::table.filter(measurements::document_id.eq_any(ids)))
+        .execute(connection)?;
+    diesel::delete(measurement_species::table.filter(measurement_species::document_id.eq_any(ids)))
+        .execute(connection)?;
+
+    Ok(())
+}
+
+/// Looks up parameters across the stored documents
+///
+/// # Arguments
+/// * `query` - The filter of the parameters
+///
+/// # Returns
+/// Result containing the matching parameters, ordered by name and document
+pub fn find_parameters(query: &ParameterQuery) -> Result<Vec<ParameterRecord>, SuiteError> {
+    let parameter = query.parameter.as_deref().map(like_pattern);
+    let protein = query.protein.as_deref().map(like_pattern);
+
+    // Proteins the parameter refers to, joined by the list separator
+    let statement = format!(
+        "SELECT p.document_id AS document_id, d.title AS document_title, \
+         p.parameter_id AS parameter_id, p.name AS name, p.symbol AS symbol, \
+         p.value AS value, p.unit AS unit, p.stderr AS stderr, \
+         (SELECT group_concat(s.name, char({separator})) \
+          FROM document_parameter_species ps \
+          JOIN document_species s \
+          ON s.document_id = ps.document_id AND s.species_id = ps.species_id \
+          WHERE ps.document_id = p.document_id AND ps.parameter_id = p.parameter_id \
+          AND s.kind = 'protein') AS proteins \
+         FROM document_parameters p \
+         JOIN documents d ON d.id = p.document_id \
+         WHERE d.deleted_at IS NULL \
+         AND (? IS NULL OR p.name LIKE ? OR p.symbol LIKE ? OR p.parameter_id LIKE ?) \
+         AND (? IS NULL OR EXISTS ( \
+          SELECT 1 FROM document_parameter_species ps \
+          JOIN document_species s \
+          ON s.document_id = ps.document_id AND s.species_id = ps.species_id \
+          WHERE ps.document_id = p.document_id AND ps.parameter_id = p.parameter_id \
+          AND s.kind = 'protein' \
+          AND (s.name LIKE ? OR s.species_id LIKE ? OR s.ecnumber LIKE ?))) \
+         ORDER BY p.name COLLATE NOCASE, d.title COLLATE NOCASE",
+        separator = LIST_SEPARATOR as u32
+    );
+
+    let mut connection = establish_connection()?;
+    let rows = sql_query(statement)
+        .bind::<Nullable<Text>, _>(&parameter)
+        .bind::<Nullable<Text>, _>(&parameter)
+        .bind::<Nullable<Text>, _>(&parameter)
+        .bind::<Nullable<Text>, _>(&parameter)
+        .bind::<Nullable<Text>, _>(&protein)
+        .bind::<Nullable<Text>, _>(&protein)
+        .bind::<Nullable<Text>, _>(&protein)
+        .bind::<Nullable<Text>, _>(&protein)
+        .load::<ParameterRow>(&mut connection)?;
+
+    Ok(rows.into_iter().map(ParameterRecord::from).collect())
+}
+
+/// Looks up measurements across the stored documents
+///
+/// # Arguments
+/// * `query` - The filter of the measurements
+///
+/// # Returns
+/// Result containing the matching measurements, ordered by document and name
+pub fn find_measurements(query: &MeasurementQuery) -> Result<Vec<MeasurementRecord>, SuiteError> {
+    let species = query.species.as_deref().map(like_pattern);
+    let min_temperature = query.min_temperature.map(|t| t - TEMPERATURE_TOLERANCE);
+    let max_temperature = query.max_temperature.map(|t| t + TEMPERATURE_TOLERANCE);
+
+    let mut connection = establish_connection()?;
+    let rows = sql_query(
+        "SELECT m.document_id AS document_id, d.title AS document_title, \
+         m.measurement_id AS measurement_id, m.name AS name, m.ph AS ph, \
+         m.temperature AS temperature, m.temperature_unit AS temperature_unit, \
+         m.temperature_kelvin AS temperature_kelvin \
+         FROM document_measurements m \
+         JOIN documents d ON d.id = m.document_id \
+         WHERE d.deleted_at IS NULL \
+         AND (? IS NULL OR m.temperature_kelvin >= ?) \
+         AND (? IS NULL OR m.temperature_kelvin <= ?) \
+         AND (? IS NULL OR m.ph >= ?) \
+         AND (? IS NULL OR m.ph <= ?) \
+         AND (? IS NULL OR EXISTS ( \
+          SELECT 1 FROM document_measurement_species ms \
+          LEFT JOIN document_species s \
+          ON s.document_id = ms.document_id AND s.species_id = ms.species_id \
+          WHERE ms.document_id = m.document_id AND ms.measurement_id = m.measurement_id \
+          AND (ms.species_id LIKE ? OR s.name LIKE ?))) \
+         ORDER BY d.title COLLATE NOCASE, m.name COLLATE NOCASE",
+    )
+    .bind::<Nullable<Double>, _>(min_temperature)
+    .bind::<Nullable<Double>, _>(min_temperature)
+    .bind::<Nullable<Double>, _>(max_temperature)
+    .bind::<Nullable<Double>, _>(max_temperature)
+    .bind::<Nullable<Double>, _>(query.min_ph)
+    .bind::<Nullable<Double>, _>(query.min_ph)
+    .bind::<Nullable<Double>, _>(query.max_ph)
+    .bind::<Nullable<Double>, _>(query.max_ph)
+    .bind::<Nullable<Text>, _>(&species)
+    .bind::<Nullable<Text>, _>(&species)
+    .bind::<Nullable<Text>, _>(&species)
+    .load::<MeasurementRow>(&mut connection)?;
+
+    rows.into_iter()
+        .map(|row| {
+            let initials = sql_query(
+                "SELECT ms.species_id AS species_id, s.name AS name, \
+                 ms.initial AS initial, ms.data_unit AS data_unit \
+                 FROM document_measurement_species ms \
+                 LEFT JOIN document_species s \
+                 ON s.document_id = ms.document_id AND s.species_id = ms.species_id \
+                 WHERE ms.document_id = ? AND ms.measurement_id = ? \
+                 ORDER BY ms.id",
+            )
+            .bind::<Integer, _>(row.document_id)
+            .bind::<Text, _>(&row.measurement_id)
+            .load::<InitialRow>(&mut connection)?
+            .into_iter()
+            .map(InitialCondition::from)
+            .collect();
+
+            Ok(MeasurementRecord {
+                document_id: row.document_id,
+                document_title: row.document_title,
+                measurement_id: row.measurement_id,
+                name: row.name,
+                ph: row.ph,
+                temperature: row.temperature,
+                temperature_unit: row.temperature_unit,
+                temperature_kelvin: row.temperature_kelvin,
+                initials,
+            })
+        })
+        .collect()
+}
+
+/// Removes all documents from the normalised projection
+fn clear_projection(connection: &mut SqliteConnection) -> Result<(), SuiteError> {
+    diesel::delete(schema::document_species::table).execute(connection)?;
+    diesel::delete(schema::document_reactions::table).execute(connection)?;
+    diesel::delete(schema::document_reaction_species::table).execute(connection)?;
+    diesel::delete(schema::document_parameters::table).execute(connection)?;
+    diesel::delete(schema::document_parameter_species::table).execute(connection)?;
+    diesel::delete(schema::document_measurements::table).execute(connection)?;
+    diesel::delete(schema::document_measurement_species::table).execute(connection)?;
+
+    Ok(())
+}
+
+/// Projects the small molecules, proteins and complexes of a document
+///
+/// # Returns
+/// Result containing the IDs of the projected species
+fn project_species(
+    connection: &mut SqliteConnection,
+    document_id: i32,
+    doc: &Value,
+) -> Result<HashSet<String>, SuiteError> {
+    use schema::document_species::dsl;
+
+    let mut species_ids = HashSet::new();
+    for (collection, kind) in SPECIES_COLLECTIONS {
+        for item in items(doc, collection) {
+            let Some(species_id) = text(item, "id") else {
+                continue;
+            };
+
+            diesel::insert_into(dsl::document_species)
+                .values((
+                    dsl::document_id.eq(document_id),
+                    dsl::species_id.eq(species_id),
+                    dsl::kind.eq(kind),
+                    dsl::name.eq(text(item, "name").unwrap_or(species_id)),
+                    dsl::inchikey.eq(text(item, "inchikey")),
+                    dsl::sequence.eq(text(item, "sequence")),
+                    dsl::ecnumber.eq(text(item, "ecnumber")),
+                    dsl::organism.eq(text(item, "organism")),
+                ))
+                .execute(connection)?;
+            species_ids.insert(species_id.to_string());
+        }
+    }
+
+    Ok(species_ids)
+}
+
+/// Projects the reactions of a document and their participants
+fn project_reactions(
+    connection: &mut SqliteConnection,
+    document_id: i32,
+    doc: &Value,
+) -> Result<(), SuiteError> {
+    use schema::{document_reaction_species as participants, document_reactions as reactions};
+
+    for reaction in items(doc, "reactions") {
+        let Some(reaction_id) = text(reaction, "id") else {
+            continue;
+        };
+
+        diesel::insert_into(reactions::table)
+            .values((
+                reactions::document_id.eq(document_id),
+                reactions::reaction_id.eq(reaction_id),
+                reactions::name.eq(text(reaction, "name").unwrap_or(reaction_id)),
+                reactions::reversible.eq(reaction
+                    .get("reversible")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false)),
+                reactions::kinetic_law.eq(reaction
+                    .get("kinetic_law")
+                    .and_then(|law| text(law, "equation"))),
+            ))
+            .execute(connection)?;
+
+        for (collection, role) in REACTION_ROLES {
+            for participant in items(reaction, collection) {
+                let Some(species_id) = text(participant, "species_id") else {
+                    continue;
+                };
+
+                diesel::insert_into(participants::table)
+                    .values((
+                        participants::document_id.eq(document_id),
+                        participants::reaction_id.eq(reaction_id),
+                        participants::species_id.eq(species_id),
+                        participants::role.eq(role),
+                    ))
+                    .execute(connection)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Projects the parameters of a document and the species they refer to
+///
+/// # Arguments
+/// * `species_ids` - The IDs of the species of the document
+fn project_parameters(
+    connection: &mut SqliteConnection,
+    document_id: i32,
+    doc: &Value,
+    species_ids: &HashSet<String>,
+) -> Result<(), SuiteError> {
+    use schema::{document_parameter_species as references, document_parameters as parameters};
+
+    let links = parameter_species(doc, species_ids);
+
+    for parameter in items(doc, "parameters") {
+        let Some(parameter_id) = text(parameter, "id") else {
+            continue;
+        };
+
+        diesel::insert_into(parameters::table)
+            .values((
+                parameters::document_id.eq(document_id),
+                parameters::parameter_id.eq(parameter_id),
+                parameters::name.eq(text(parameter, "name").unwrap_or(parameter_id)),
+                parameters::symbol.eq(text(parameter, "symbol").unwrap_or(parameter_id)),
+                parameters::value.eq(number(parameter, "value")),
+                parameters::unit.eq(unit_name(parameter, "unit")),
+                parameters::stderr.eq(number(parameter, "stderr")),
+                parameters::lower_bound.eq(number(parameter, "lower_bound")),
+                parameters::upper_bound.eq(number(parameter, "upper_bound")),
+            ))
+            .execute(connection)?;
+
+        let symbols = [Some(parameter_id), text(parameter, "symbol")];
+        for (symbol, species_id) in &links {
+            if !symbols.contains(&Some(symbol.as_str())) {
+                continue;
+            }
+
+            diesel::insert_into(references::table)
+                .values((
+                    references::document_id.eq(document_id),
+                    references::parameter_id.eq(parameter_id),
+                    references::species_id.eq(species_id),
+                ))
+                .execute(connection)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Projects the measurements of a document and their initial conditions
+fn project_measurements(
+    connection: &mut SqliteConnection,
+    document_id: i32,
+    doc: &Value,
+) -> Result<(), SuiteError> {
+    use schema::{document_measurement_species as initials, document_measurements as measurements};
+
+    for measurement in items(doc, "measurements") {
+        let Some(measurement_id) = text(measurement, "id") else {
+            continue;
+        };
+
+        let temperature = number(measurement, "temperature");
+        let temperature_unit = unit_name(measurement, "temperature_unit");
+
+        diesel::insert_into(measurements::table)
+            .values((
+                measurements::document_id.eq(document_id),
+                measurements::measurement_id.eq(measurement_id),
+                measurements::name.eq(text(measurement, "name").unwrap_or(measurement_id)),
+                measurements::ph.eq(number(measurement, "ph")),
+                measurements::temperature.eq(temperature),
+                measurements::temperature_unit.eq(temperature_unit),
+                measurements::temperature_kelvin
+                    .eq(temperature.and_then(|value| kelvin(value, temperature_unit?))),
+            ))
+            .execute(connection)?;
+
+        for data in items(measurement, "species_data") {
+            let Some(species_id) = text(data, "species_id") else {
+                continue;
+            };
+
+            diesel::insert_into(initials::table)
+                .values((
+                    initials::document_id.eq(document_id),
+                    initials::measurement_id.eq(measurement_id),
+                    initials::species_id.eq(species_id),
+                    initials::initial.eq(number(data, "initial")),
+                    initials::data_unit.eq(unit_name(data, "data_unit")),
+                ))
+                .execute(connection)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the pairs of symbol and species ID appearing together in the
+/// kinetic laws and equations of a document
+///
+/// The symbols of the kinetic law of a reaction are paired with all
+/// participants of the reaction, the symbols of an equation with the species
+/// the equation is defined for and the species it contains.
+fn parameter_species(doc: &Value, species_ids: &HashSet<String>) -> BTreeSet<(String, String)> {
+    let symbols = Regex::new(SYMBOL_REGEX).unwrap();
+    let mut links = BTreeSet::new();
+
+    let mut link = |equation: &str, mut species: Vec<String>| {
+        let found: Vec<&str> = symbols
+            .find_iter(equation)
+            .map(|symbol| symbol.as_str())
+            .collect();
+        species.extend(
+            found
+                .iter()
+                .filter(|symbol| species_ids.contains(*symbol))
+                .map(|symbol| symbol.to_string()),
+        );
+
+        for symbol in found.iter().filter(|symbol| !species_ids.contains(*symbol)) {
+            for species_id in &species {
+                links.insert((symbol.to_string(), species_id.clone()));
+            }
+        }
+    };
+
+    for reaction in items(doc, "reactions") {
+        let Some(equation) = reaction
+            .get("kinetic_law")
+            .and_then(|law| text(law, "equation"))
+        else {
+            continue;
+        };
+
+        let participants = REACTION_ROLES
+            .iter()
+            .flat_map(|(collection, _)| items(reaction, collection))
+            .filter_map(|participant| text(participant, "species_id"))
+            .map(str::to_string)
+            .collect();
+        link(equation, participants);
+    }
+
+    for equation in items(doc, "equations") {
+        if let Some(expression) = text(equation, "equation") {
+            let species = text(equation, "species_id").map(str::to_string);
+            link(expression, species.into_iter().collect());
+        }
+    }
+
+    links
+}
+
+/// Returns the items of a collection of a serialized entity
+fn items<'a>(value: &'a Value, collection: &str) -> &'a [Value] {
+    value
+        .get(collection)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Returns a text field of a serialized entity
+fn text<'a>(value: &'a Value, field: &str) -> Option<&'a str> {
+    value.get(field).and_then(Value::as_str)
+}
+
+/// Returns a numeric field of a serialized entity
+fn number(value: &Value, field: &str) -> Option<f64> {
+    value.get(field).and_then(Value::as_f64)
+}
+
+/// Returns the name of a unit field of a serialized entity
+fn unit_name<'a>(value: &'a Value, field: &str) -> Option<&'a str> {
+    let unit = value.get(field)?;
+    unit.as_str().or_else(|| text(unit, "name"))
+}
+
+/// Converts a temperature to Kelvin
+///
+/// # Returns
+/// The temperature in Kelvin or `None` if the unit is unknown
+fn kelvin(value: f64, unit: &str) -> Option<f64> {
+    match unit.trim().to_lowercase().as_str() {
+        "k" | "kelvin" => Some(value),
+        "c" | "°c" | "degc" | "celsius" => Some(value + 273.15),
+        "f" | "°f" | "degf" | "fahrenheit" => Some((value - 32.0) * 5.0 / 9.0 + 273.15),
+        _ => None,
+    }
+}
+
+/// Returns a LIKE pattern matching any text that contains the given text
+fn like_pattern(text: &str) -> String {
+    format!("%{}%", text.trim())
+}
+
+impl From<ParameterRow> for ParameterRecord {
+    fn from(row: ParameterRow) -> Self {
+        let mut proteins: Vec<String> = row
+            .proteins
+            .map(|names| names.split(LIST_SEPARATOR).map(str::to_string).collect())
+            .unwrap_or_default();
+        proteins.sort();
+        proteins.dedup();
+
+        ParameterRecord {
+            document_id: row.document_id,
+            document_title: row.document_title,
+            parameter_id: row.parameter_id,
+            name: row.name,
+            symbol: row.symbol,
+            value: row.value,
+            unit: row.unit,
+            stderr: row.stderr,
+            proteins,
+        }
+    }
+}
+
+impl From<InitialRow> for InitialCondition {
+    fn from(row: InitialRow) -> Self {
+        InitialCondition {
+            species_id: row.species_id,
+            name: row.name,
+            initial: row.initial,
+            unit: row.data_unit,
+        }
+    }
+}
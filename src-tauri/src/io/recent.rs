@@ -0,0 +1,123 @@
+//! Recently opened and saved document files
+//!
+//! The files are kept in the config store, most recent first, so they can be
+//! offered for reopening across sessions.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::actions::utils::get_config_store_path;
+use crate::error::SuiteError;
+
+/// Config store key of the recent files
+const RECENT_FILES_KEY: &str = "recent_files";
+/// Maximum number of remembered files
+pub const MAX_RECENT_FILES: usize = 10;
+
+/// A document file that has recently been opened or saved
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct RecentFile {
+    /// Path of the file
+    pub path: PathBuf,
+    /// Title of the document when the file was last used
+    pub title: String,
+}
+
+/// Loads the recent files from the config store
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing the recent files, the most recent first
+pub fn load_recent_files(app: &AppHandle) -> Result<Vec<RecentFile>, SuiteError> {
+    let store_path = get_config_store_path()?;
+    let store = app
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
+
+    let files = store
+        .get(RECENT_FILES_KEY)
+        .and_then(|v| serde_json::from_value::<Vec<RecentFile>>(v).ok())
+        .unwrap_or_default();
+
+    Ok(files)
+}
+
+/// Persists the recent files in the config store
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+/// * `files` - The recent files, the most recent first
+///
+/// # Returns
+/// Result indicating success or failure
+fn save_recent_files(app: &AppHandle, files: &[RecentFile]) -> Result<(), SuiteError> {
+    let store_path = get_config_store_path()?;
+    let store = app
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
+
+    store.set(RECENT_FILES_KEY, serde_json::to_value(files)?);
+    store.save().map_err(|e| SuiteError::io(&store_path, e))
+}
+
+/// Moves a file to the top of the recent files
+///
+/// Only the [`MAX_RECENT_FILES`] most recent files are kept.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+/// * `path` - Path of the opened or saved file
+/// * `title` - Title of the document held by the file
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn remember_recent_file(app: &AppHandle, path: &Path, title: &str) -> Result<(), SuiteError> {
+    let mut files = load_recent_files(app)?;
+    files.retain(|file| file.path != path);
+    files.insert(
+        0,
+        RecentFile {
+            path: path.to_path_buf(),
+            title: title.to_string(),
+        },
+    );
+    files.truncate(MAX_RECENT_FILES);
+
+    save_recent_files(app, &files)
+}
+
+/// Lists the recently opened and saved document files
+///
+/// Files that no longer exist are left out.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing the recent files, the most recent first
+#[tauri::command]
+#[specta::specta]
+pub fn list_recent_files(app: AppHandle) -> Result<Vec<RecentFile>, SuiteError> {
+    let files = load_recent_files(&app)?;
+    Ok(files
+        .into_iter()
+        .filter(|file| file.path.exists())
+        .collect())
+}
+
+/// Forgets all recently opened and saved document files
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result indicating success or failure
+#[tauri::command]
+#[specta::specta]
+pub fn clear_recent_files(app: AppHandle) -> Result<(), SuiteError> {
+    save_recent_files(&app, &[])
+}
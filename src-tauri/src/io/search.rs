@@ -0,0 +1,374 @@
+//! Full-text search across stored documents
+//!
+//! Stored documents are indexed in the `documents_search` FTS5 table, keyed by
+//! their database ID. The index holds the title, the names of the species, the
+//! names, EC numbers and organisms of the proteins, the names of the reactions
+//! and the descriptions of every document. It is updated whenever a stored
+//! document changes and brought in sync with the documents on start.
+
+use diesel::prelude::*;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Double, Integer, Nullable, Text};
+use diesel::sqlite::SqliteConnection;
+use enzymeml::prelude::EnzymeMLDocument;
+use serde::{Deserialize, Serialize};
+
+use crate::db::establish_connection;
+use crate::docutils::deserialize_doc;
+use crate::error::SuiteError;
+use crate::models::Document;
+use crate::schema;
+
+/// Marker inserted before the matched terms of a snippet
+pub const MATCH_START: &str = "<mark>";
+/// Marker inserted after the matched terms of a snippet
+pub const MATCH_END: &str = "</mark>";
+/// Maximum number of tokens of a snippet
+const SNIPPET_TOKENS: u32 = 12;
+/// Maximum number of hits if none is given
+const DEFAULT_LIMIT: u32 = 50;
+
+/// Indexed fields of a document, in the order of the columns of the index
+const FIELDS: [SearchField; 5] = [
+    SearchField::Title,
+    SearchField::Species,
+    SearchField::Proteins,
+    SearchField::Reactions,
+    SearchField::Notes,
+];
+
+/// Indexed field of a stored document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchField {
+    /// Title of the document
+    Title,
+    /// Names and synonyms of the small molecules and complexes
+    Species,
+    /// Names, EC numbers and organisms of the proteins
+    Proteins,
+    /// Names of the reactions
+    Reactions,
+    /// Descriptions of the stored and the EnzymeML document
+    Notes,
+}
+
+/// Excerpt of a field that matches the search
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct SearchSnippet {
+    /// The matching field
+    pub field: SearchField,
+    /// Excerpt of the field with the matched terms enclosed in [`MATCH_START`] and [`MATCH_END`]
+    pub text: String,
+}
+
+/// Stored document matching a search
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct SearchHit {
+    /// Database ID of the document
+    pub id: i32,
+    /// Title of the document
+    pub title: String,
+    /// Relevance of the document, higher is better
+    pub score: f64,
+    /// Excerpts of the matching fields
+    pub snippets: Vec<SearchSnippet>,
+}
+
+/// Row of the search query
+#[derive(QueryableByName)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+struct HitRow {
+    #[diesel(sql_type = Integer)]
+    id: i32,
+    #[diesel(sql_type = Text)]
+    title: String,
+    #[diesel(sql_type = Double)]
+    score: f64,
+    #[diesel(sql_type = Nullable<Text>)]
+    title_snippet: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    species_snippet: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    proteins_snippet: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    reactions_snippet: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    notes_snippet: Option<String>,
+}
+
+/// Row of a count query
+#[derive(QueryableByName)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+struct CountRow {
+    #[diesel(sql_type = BigInt)]
+    count: i64,
+}
+
+/// Searches the stored documents
+///
+/// Every term of the query has to match the beginning of a word in any of the
+/// indexed fields, the connective "and" is ignored. Documents in the trash are
+/// left out. Matches in the title weigh most, followed by species and
+/// proteins, reactions and descriptions.
+///
+/// # Arguments
+/// * `query` - The terms to search for, e.g. "ABTS laccase"
+/// * `limit` - Maximum number of hits, 50 if omitted
+///
+/// # Returns
+/// Result containing the hits, the most relevant first
+#[tauri::command]
+#[specta::specta]
+pub fn search_documents(query: String, limit: Option<u32>) -> Result<Vec<SearchHit>, SuiteError> {
+    search(&query, limit.unwrap_or(DEFAULT_LIMIT))
+}
+
+/// Rebuilds the search index from all stored documents
+///
+/// # Returns
+/// Result containing the number of indexed documents
+#[tauri::command]
+#[specta::specta]
+pub fn rebuild_search_index() -> Result<usize, SuiteError> {
+    rebuild_index()
+}
+
+/// Searches the stored documents
+///
+/// # Arguments
+/// * `query` - The terms to search for
+/// * `limit` - Maximum number of hits
+///
+/// # Returns
+/// Result containing the hits, the most relevant first
+pub fn search(query: &str, limit: u32) -> Result<Vec<SearchHit>, SuiteError> {
+    let Some(expression) = match_expression(query) else {
+        return Ok(Vec::new());
+    };
+
+    let snippets = FIELDS
+        .iter()
+        .enumerate()
+        .map(|(column, field)| {
+            format!(
+                "snippet(documents_search, {}, '{}', '{}', '…', {}) AS {}_snippet",
+                column,
+                MATCH_START,
+                MATCH_END,
+                SNIPPET_TOKENS,
+                column_name(*field)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let statement = format!(
+        "SELECT documents.id AS id, documents.title AS title, \
+         -bm25(documents_search, 10.0, 5.0, 5.0, 2.0, 1.0) AS score, {} \
+         FROM documents_search \
+         JOIN documents ON documents.id = documents_search.rowid \
+         WHERE documents_search MATCH ? AND documents.deleted_at IS NULL \
+         ORDER BY score DESC \
+         LIMIT ?",
+        snippets
+    );
+
+    let mut connection = establish_connection()?;
+    let rows = sql_query(statement)
+        .bind::<Text, _>(expression)
+        .bind::<Integer, _>(limit as i32)
+        .load::<HitRow>(&mut connection)?;
+
+    Ok(rows.into_iter().map(SearchHit::from).collect())
+}
+
+/// Indexes a stored document, replacing its previous entry
+///
+/// Documents whose content cannot be parsed are indexed by their title and
+/// description only.
+///
+/// # Arguments
+/// * `connection` - Connection to the database holding the document
+/// * `id` - The ID of the document to index
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn index_document(connection: &mut SqliteConnection, id: i32) -> Result<(), SuiteError> {
+    let entry = schema::documents::table
+        .filter(schema::documents::id.eq(id))
+        .first::<Document>(connection)
+        .map_err(|err| SuiteError::from_query(err, "Document", id))?;
+
+    remove_from_index(connection, &[id])?;
+
+    let doc = deserialize_doc(&entry.content).ok();
+    let fields = indexed_fields(&entry, doc.as_ref());
+
+    sql_query(
+        "INSERT INTO documents_search (rowid, title, species, proteins, reactions, notes) \
+         VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind::<Integer, _>(entry.id)
+    .bind::<Text, _>(&fields[0])
+    .bind::<Text, _>(&fields[1])
+    .bind::<Text, _>(&fields[2])
+    .bind::<Text, _>(&fields[3])
+    .bind::<Text, _>(&fields[4])
+    .execute(connection)?;
+
+    Ok(())
+}
+
+/// Removes documents from the search index
+///
+/// # Arguments
+/// * `connection` - Connection to the database holding the index
+/// * `ids` - The IDs of the documents to remove
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn remove_from_index(connection: &mut SqliteConnection, ids: &[i32]) -> Result<(), SuiteError> {
+    for id in ids {
+        sql_query("DELETE FROM documents_search WHERE rowid = ?")
+            .bind::<Integer, _>(*id)
+            .execute(connection)?;
+    }
+
+    Ok(())
+}
+
+/// Rebuilds the search index from all stored documents
+///
+/// # Returns
+/// Result containing the number of indexed documents
+pub fn rebuild_index() -> Result<usize, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    connection.transaction::<_, SuiteError, _>(|connection| {
+        sql_query("DELETE FROM documents_search").execute(connection)?;
+
+        let ids = schema::documents::table
+            .select(schema::documents::id)
+            .load::<i32>(connection)?;
+        for id in &ids {
+            index_document(connection, *id)?;
+        }
+
+        Ok(ids.len())
+    })
+}
+
+/// Rebuilds the search index if it does not cover all stored documents
+///
+/// This is the case for documents stored before the index existed.
+///
+/// # Returns
+/// Result containing whether the index has been rebuilt
+pub fn sync_index() -> Result<bool, SuiteError> {
+    let mut connection = establish_connection()?;
+
+    let documents = schema::documents::table
+        .count()
+        .get_result::<i64>(&mut connection)?;
+    let indexed = sql_query("SELECT count(*) AS count FROM documents_search")
+        .get_result::<CountRow>(&mut connection)?
+        .count;
+
+    if documents == indexed {
+        return Ok(false);
+    }
+
+    rebuild_index()?;
+    Ok(true)
+}
+
+/// Converts a search query into an FTS5 match expression
+///
+/// Terms are quoted, so special characters cannot break the syntax, and
+/// matched as prefixes.
+///
+/// # Returns
+/// The match expression or `None` if the query has no terms
+fn match_expression(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .filter(|term| !term.eq_ignore_ascii_case("and"))
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect();
+
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// Returns the texts of a stored document, in the order of the columns of the index
+fn indexed_fields(entry: &Document, doc: Option<&EnzymeMLDocument>) -> [String; 5] {
+    let mut notes: Vec<&str> = entry.description.iter().map(String::as_str).collect();
+    let mut species: Vec<&str> = Vec::new();
+    let mut proteins: Vec<&str> = Vec::new();
+    let mut reactions: Vec<&str> = Vec::new();
+
+    if let Some(doc) = doc {
+        for small_mol in &doc.small_molecules {
+            species.push(&small_mol.name);
+            species.extend(small_mol.synonymous_names.iter().map(String::as_str));
+        }
+        species.extend(doc.complexes.iter().map(|complex| complex.name.as_str()));
+
+        for protein in &doc.proteins {
+            proteins.push(&protein.name);
+            proteins.extend(protein.ecnumber.as_deref());
+            proteins.extend(protein.organism.as_deref());
+        }
+
+        reactions.extend(doc.reactions.iter().map(|reaction| reaction.name.as_str()));
+        notes.extend(doc.description.as_deref());
+    }
+
+    [
+        entry.title.clone(),
+        species.join("; "),
+        proteins.join("; "),
+        reactions.join("; "),
+        notes.join("; "),
+    ]
+}
+
+/// Returns the column of the search index holding a field
+fn column_name(field: SearchField) -> &'static str {
+    match field {
+        SearchField::Title => "title",
+        SearchField::Species => "species",
+        SearchField::Proteins => "proteins",
+        SearchField::Reactions => "reactions",
+        SearchField::Notes => "notes",
+    }
+}
+
+impl From<HitRow> for SearchHit {
+    fn from(row: HitRow) -> Self {
+        let texts = [
+            row.title_snippet,
+            row.species_snippet,
+            row.proteins_snippet,
+            row.reactions_snippet,
+            row.notes_snippet,
+        ];
+
+        // Snippets of fields without matches carry no markers
+        let snippets = FIELDS
+            .into_iter()
+            .zip(texts)
+            .filter_map(|(field, text)| {
+                text.filter(|text| text.contains(MATCH_START))
+                    .map(|text| SearchSnippet { field, text })
+            })
+            .collect();
+
+        SearchHit {
+            id: row.id,
+            title: row.title,
+            score: row.score,
+            snippets,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Sources of documents that are not necessarily open
+//!
+//! Operations comparing or combining documents accept a [`DocumentSource`],
+//! so the frontend and the REST API can refer to open, stored and inline
+//! documents alike. File based documents are only accepted from the frontend,
+//! where the user picks the files, since API clients must not read arbitrary
+//! files of the host.
+
+use enzymeml::prelude::EnzymeMLDocument;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::docutils::deserialize_doc;
+use crate::error::SuiteError;
+use crate::io::dataio::{read_document, retrieve_document_by_id};
+use crate::states::Workspace;
+
+/// Reference to an EnzymeML document
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DocumentSource {
+    /// An open document, the active one if no handle is given
+    Open {
+        /// Handle of the open document
+        #[serde(default)]
+        handle: Option<String>,
+    },
+    /// A document stored in the database
+    Stored {
+        /// Database ID of the document
+        id: i32,
+    },
+    /// An EnzymeML JSON file, not accepted from API clients
+    File {
+        /// Path of the file
+        path: PathBuf,
+    },
+    /// A document given as is
+    Inline {
+        /// The EnzymeML document
+        content: EnzymeMLDocument,
+    },
+}
+
+impl DocumentSource {
+    /// Retrieves a copy of the referenced document
+    ///
+    /// # Arguments
+    /// * `workspace` - The open documents of the application
+    ///
+    /// # Returns
+    /// Result containing the document or an error if it cannot be retrieved
+    pub fn resolve(&self, workspace: &Workspace) -> Result<EnzymeMLDocument, SuiteError> {
+        match self {
+            DocumentSource::Open { handle } => {
+                let state = workspace.document(handle.as_deref())?;
+                let doc = state.doc.lock().unwrap().clone();
+                Ok(doc)
+            }
+            DocumentSource::Stored { id } => {
+                let entry = retrieve_document_by_id(*id)?;
+                Ok(deserialize_doc(entry.content.as_str())?)
+            }
+            DocumentSource::File { path } => read_document(path),
+            DocumentSource::Inline { content } => Ok(content.clone()),
+        }
+    }
+
+    /// Retrieves a copy of the referenced document on behalf of an API client
+    ///
+    /// # Arguments
+    /// * `workspace` - The open documents of the application
+    ///
+    /// # Returns
+    /// Result containing the document or an error if it cannot be retrieved,
+    /// with a `Validation` error for file sources
+    pub fn resolve_remote(&self, workspace: &Workspace) -> Result<EnzymeMLDocument, SuiteError> {
+        if let DocumentSource::File { .. } = self {
+            return Err(SuiteError::validation(
+                "File sources are not accepted via the API, send the document inline instead",
+            ));
+        }
+
+        self.resolve(workspace)
+    }
+}
@@ -2,15 +2,94 @@ use diesel::deserialize::{self, FromSql};
 use diesel::serialize::{self, IsNull, Output, ToSql};
 use diesel::sql_types::Text;
 use diesel::sqlite::{Sqlite, SqliteValue};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// A wrapper type for storing arrays of strings in SQLite
 ///
 /// This type allows storing Vec<String> in SQLite by serializing/deserializing to JSON.
 /// It implements the necessary Diesel traits for database operations.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    diesel::expression::AsExpression,
+    diesel::deserialize::FromSqlRow,
+    specta::Type,
+)]
+#[diesel(sql_type = Text)]
 pub struct StringArray(pub Vec<String>);
 
+impl StringArray {
+    /// Deserializes a string array that may be given in the legacy format
+    ///
+    /// Older versions stored lists such as the references of small molecules
+    /// as a single comma separated string. Besides an array, such a string,
+    /// split by [`StringArray::split_legacy`], and a missing value, read as an
+    /// empty array, are accepted.
+    ///
+    /// # Arguments
+    /// * `deserializer` - The deserializer to read from
+    ///
+    /// # Returns
+    /// Result containing the StringArray or a deserialization error
+    pub fn deserialize_legacy<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<StringArray, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Legacy {
+            Array(Vec<String>),
+            Joined(String),
+        }
+
+        Ok(match Option::<Legacy>::deserialize(deserializer)? {
+            Some(Legacy::Array(items)) => StringArray(items),
+            Some(Legacy::Joined(joined)) => StringArray::split_legacy(&joined),
+            None => StringArray::default(),
+        })
+    }
+
+    /// Splits a comma separated list of the legacy format
+    ///
+    /// The items were joined without escaping, so commas may also be part of
+    /// an item. A part following a URL continues the URL, unless it contains a
+    /// colon and thus looks like an item of its own, e.g. `https://...` or
+    /// `PMID:123`. Items are trimmed and empty items left out. The migration
+    /// of the references of saved small molecules applies the same rule.
+    ///
+    /// # Arguments
+    /// * `joined` - The comma separated list
+    ///
+    /// # Returns
+    /// The StringArray holding the items of the list
+    pub fn split_legacy(joined: &str) -> StringArray {
+        let mut items = Vec::new();
+        let mut current = String::new();
+
+        for part in joined.split(',') {
+            if current.contains("://") && !part.trim().is_empty() && !part.contains(':') {
+                current.push(',');
+                current.push_str(part);
+                continue;
+            }
+
+            let item = std::mem::replace(&mut current, part.to_string());
+            if !item.trim().is_empty() {
+                items.push(item.trim().to_string());
+            }
+        }
+
+        if !current.trim().is_empty() {
+            items.push(current.trim().to_string());
+        }
+
+        StringArray(items)
+    }
+}
+
 /// Internal wrapper to allow using StringArray with Diesel's SQL type system
 ///
 /// This wrapper implements the necessary traits to use StringArray as a Text column.
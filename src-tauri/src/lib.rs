@@ -0,0 +1,297 @@
+//! Backend of the EnzymeML Suite
+//!
+//! Holds the document state, database access, Tauri commands and the local
+//! REST API. The desktop application is started via [`run`], while the
+//! headless command line interface uses the modules directly.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use tauri::async_runtime::spawn;
+use tauri::Manager;
+use tauri_plugin_store::StoreExt;
+
+use crate::actions::utils::{get_backup_dir, get_config_store_path, get_recovery_dir};
+use crate::actions::{
+    diff, enzmldoc, equations, external, import, jupyter, measurements, parameters, proteins,
+    reactions, recovery, settings, smallmols, units, validation, vessels, windows, workspace,
+};
+use crate::api::{auth, create_rocket};
+use crate::backup::{Backups, BACKUP_CHECK_INTERVAL};
+use crate::recovery::{Recovery, AUTOSAVE_INTERVAL};
+use crate::states::{ApiState, JupyterState, Workspace};
+use crate::watcher::{FileWatcher, WATCH_INTERVAL};
+
+/// API module for handling HTTP endpoints and server functionality
+pub mod api;
+/// Rotating backups of the database
+pub mod backup;
+/// TypeScript bindings of the commands and events
+pub mod bindings;
+/// Database initialization and connection handling
+pub mod db;
+/// Document utilities for EnzymeML document operations
+pub mod docutils;
+/// Typed errors returned by commands and the REST API
+pub mod error;
+/// Event sink for notifying about document changes
+pub mod events;
+/// Libraries the application can switch between
+pub mod libraries;
+/// MCP module to install the MCP server and serve the in-process MCP server
+pub mod mcp;
+/// Data models and structures used throughout the application
+mod models;
+/// Recovery snapshots of unsaved documents
+pub mod recovery;
+/// Database schema definitions
+mod schema;
+/// Application state management
+pub mod states;
+/// Unit definitions and conversions
+pub mod unit;
+/// Detection of changes made to document files by other programs
+pub mod watcher;
+
+/// Input/output operations and data handling
+pub mod io {
+    /// Data input/output operations
+    pub mod dataio;
+    /// Database operations
+    pub mod dbops;
+    /// Export and import of the whole library
+    pub mod library;
+    /// Utility macros for I/O operations
+    pub mod macros;
+    /// Normalised projection of stored documents for queries across documents
+    pub mod projection;
+    /// Recently opened and saved document files
+    pub mod recent;
+    /// Full-text search across stored documents
+    pub mod search;
+    /// Sources of documents that are not necessarily open
+    pub mod source;
+    /// String array handling utilities
+    pub mod stringarray;
+}
+
+/// Document operations independent of Tauri, shared by commands, API and CLI
+pub mod document {
+    /// Structural comparison of documents
+    pub mod diff;
+    /// EnzymeML document management
+    pub mod enzmldoc;
+    /// Equation handling and derivation from reactions
+    pub mod equations;
+    /// Reloading and merging of document files changed by other programs
+    pub mod external;
+    /// Import of other documents with duplicate detection and ID remapping
+    pub mod import;
+    /// Measurement management and visualization data
+    pub mod measurements;
+    /// Parameter management
+    pub mod parameters;
+    /// Protein management
+    pub mod proteins;
+    /// Reaction management
+    pub mod reactions;
+    /// Small molecule management
+    pub mod smallmols;
+    /// Vessel management
+    pub mod vessels;
+}
+
+/// Tauri command actions for frontend-backend communication
+pub mod actions {
+    /// Commands to take and restore backups of the database
+    pub mod backup;
+    /// Document comparison commands
+    pub mod diff;
+    /// EnzymeML document management commands
+    pub mod enzmldoc;
+    /// Equation handling and management
+    pub mod equations;
+    /// Commands to resolve changes of document files by other programs
+    pub mod external;
+    /// ID generation and management utilities
+    pub mod identifiers;
+    /// Commands to import other documents
+    pub mod import;
+    /// Jupyter notebook integration commands
+    pub mod jupyter;
+    /// Commands to manage and switch between libraries
+    pub mod libraries;
+    /// Utility macros for action implementations
+    pub mod macros;
+    /// MCP commands
+    pub mod mcp;
+    /// Measurement data handling commands
+    pub mod measurements;
+    /// Parameter management commands
+    pub mod parameters;
+    /// Protein entity management commands
+    pub mod proteins;
+    /// Reaction entity management commands
+    pub mod reactions;
+    /// Commands to restore unsaved documents after a crash
+    pub mod recovery;
+    /// Settings and configuration commands
+    pub mod settings;
+    /// Small molecule entity management commands
+    pub mod smallmols;
+    /// Unit definition and conversion commands
+    pub mod units;
+    /// Utility functions for action implementations
+    pub mod utils;
+    /// Validation commands
+    pub mod validation;
+    /// Vessel entity management commands
+    pub mod vessels;
+    /// Window management commands
+    pub mod windows;
+    /// Commands to open, switch and close documents
+    pub mod workspace;
+}
+
+/// Starts the EnzymeML Suite desktop application
+///
+/// Sets up the database, the config store and the local REST API and opens the
+/// main window. If started with the MCP stdio flag, the application instead
+/// acts as bridge to the MCP server of an already running instance.
+pub async fn run() {
+    let _ = fix_path_env::fix();
+
+    // Act as bridge to the MCP server of the running application if requested
+    if std::env::args().any(|arg| arg == mcp::stdio::STDIO_FLAG) {
+        if let Err(e) = mcp::stdio::run().await {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // Initialize state and clone for both tauri and warp
+    // Fetch env variable TESTING to determine if we are in testing mode
+
+    let workspace = Arc::new(Workspace::default());
+    let jupyter_state = Arc::new(JupyterState::default());
+    let rocket_workspace = Arc::clone(&workspace);
+    let tauri_workspace = Arc::clone(&workspace);
+    let autosave_workspace = Arc::clone(&workspace);
+    let watcher_workspace = Arc::clone(&workspace);
+
+    // Regenerate the TypeScript bindings of the commands and events
+    if cfg!(debug_assertions) {
+        bindings::export(Path::new(bindings::BINDINGS_DIR))
+            .expect("Failed to export typescript bindings");
+    }
+
+    let builder = bindings::builder();
+
+    tauri::Builder::default()
+        .plugin(tauri_plugin_window_state::Builder::new().build())
+        .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_store::Builder::new().build())
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_http::init())
+        .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_cors_fetch::init())
+        .invoke_handler(builder.invoke_handler())
+        .setup(move |app| {
+            // Allow emitting the typed events to the frontend.
+            builder.mount_events(app);
+
+            #[cfg(debug_assertions)] // only include this code on debug builds
+            if let Some(window) = app.get_webview_window("main") {
+                {
+                    window.open_devtools();
+                    window.close_devtools();
+                }
+            }
+
+            // Open the active library. If it is unavailable, the application
+            // keeps running, so users can switch to another library.
+            match db::init() {
+                // Index documents stored before the search index existed.
+                Ok(()) => {
+                    if let Err(e) = io::search::sync_index() {
+                        eprintln!("Syncing the search index failed: {}", e);
+                    }
+                }
+                Err(e) => eprintln!("Opening the database failed: {}", e),
+            }
+
+            // Initialize the JSON store.
+            let store_path = get_config_store_path().expect("Failed to get config store path");
+            app.store(store_path)?;
+
+            // Periodically write unsaved documents to the recovery directory.
+            let recovery = Arc::new(Recovery::new(get_recovery_dir()?));
+            app.manage(Arc::clone(&recovery));
+
+            spawn(async move {
+                loop {
+                    tokio::time::sleep(AUTOSAVE_INTERVAL).await;
+                    if let Err(e) = recovery.autosave(&autosave_workspace) {
+                        eprintln!("Autosave failed: {}", e);
+                    }
+                }
+            });
+
+            // Periodically check the files of documents for changes by other programs.
+            let watcher = FileWatcher::new();
+            let watcher_handle = app.handle().clone();
+
+            spawn(async move {
+                loop {
+                    tokio::time::sleep(WATCH_INTERVAL).await;
+                    if let Err(e) = watcher.check(&watcher_workspace, &watcher_handle) {
+                        eprintln!("Checking document files failed: {}", e);
+                    }
+                }
+            });
+
+            // Periodically take a backup of the database, if one is due.
+            let backups = Arc::new(Backups::new(get_backup_dir()?));
+            app.manage(Arc::clone(&backups));
+            let backup_handle = app.handle().clone();
+
+            spawn(async move {
+                loop {
+                    let result = backup::load_settings(&backup_handle)
+                        .and_then(|settings| backups.run_if_due(&settings));
+                    if let Err(e) = result {
+                        eprintln!("Backup failed: {}", e);
+                    }
+                    tokio::time::sleep(BACKUP_CHECK_INTERVAL).await;
+                }
+            });
+
+            // Initialize the API token and settings.
+            let api_settings = auth::load_settings(app.handle())?;
+            let api_token = auth::load_or_create_token(app.handle())?;
+            let api_state = Arc::new(ApiState::new(api_token, api_settings.allow_writes));
+            app.manage(Arc::clone(&api_state));
+
+            // Initialize the Rocket server.
+            let app_handle = app.handle().clone();
+
+            spawn(async move {
+                create_rocket(
+                    rocket_workspace,
+                    Arc::new(app_handle),
+                    api_settings,
+                    api_state,
+                )
+                .launch()
+                .await
+                .expect("Rocket failed to launch");
+            });
+
+            Ok(())
+        })
+        .manage(tauri_workspace)
+        .manage(jupyter_state)
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
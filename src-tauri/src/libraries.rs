@@ -0,0 +1,232 @@
+//! Libraries the application can switch between
+//!
+//! A library is a database holding the stored documents and the entries saved
+//! for reuse, such as small molecules and kinetic laws. Besides the personal
+//! library, users can register further libraries, e.g. one shared by a lab on a
+//! network drive, and switch between them. The registered libraries and the
+//! active one are kept in the config store. The environment variable
+//! [`DB_PATH_ENV`] takes precedence over the active library when starting.
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::actions::utils::get_config_store_path;
+use crate::db;
+use crate::error::SuiteError;
+
+/// Environment variable holding the path to the database to work with
+pub const DB_PATH_ENV: &str = "ENZYMEML_SUITE_DB";
+/// Name of the library registered by default
+pub const DEFAULT_LIBRARY: &str = "Personal";
+/// Name of the library given by the environment variable [`DB_PATH_ENV`]
+pub const ENV_LIBRARY: &str = "Environment";
+
+/// Config store key of the library settings
+const LIBRARY_SETTINGS_KEY: &str = "libraries";
+
+/// Database holding stored documents and entries saved for reuse
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct Library {
+    /// Unique name of the library
+    pub name: String,
+    /// Path to the database file of the library
+    pub path: String,
+}
+
+/// Registered libraries and the one to work with
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct LibrarySettings {
+    /// Name of the library opened when starting
+    pub active: String,
+    /// The registered libraries
+    pub libraries: Vec<Library>,
+}
+
+impl LibrarySettings {
+    /// Creates the settings registering only the personal library
+    ///
+    /// # Returns
+    /// Result containing the settings or an error if the location of the
+    /// personal library cannot be determined
+    pub fn personal() -> Result<Self, SuiteError> {
+        Ok(LibrarySettings {
+            active: DEFAULT_LIBRARY.to_string(),
+            libraries: vec![Library {
+                name: DEFAULT_LIBRARY.to_string(),
+                path: db::default_db_path()?,
+            }],
+        })
+    }
+
+    /// Checks that the libraries have unique names and paths and that the
+    /// active library is registered
+    ///
+    /// # Returns
+    /// Result indicating whether the settings are valid
+    pub fn validate(&self) -> Result<(), SuiteError> {
+        for (i, library) in self.libraries.iter().enumerate() {
+            if library.name.trim().is_empty() {
+                return Err(SuiteError::validation(
+                    "The name of a library must not be empty",
+                ));
+            }
+
+            if library.name == ENV_LIBRARY {
+                return Err(SuiteError::validation(format!(
+                    "The name '{}' is reserved",
+                    ENV_LIBRARY
+                )));
+            }
+
+            if library.path.trim().is_empty() {
+                return Err(SuiteError::validation(format!(
+                    "The path of the library '{}' must not be empty",
+                    library.name
+                )));
+            }
+
+            if let Some(other) = self.libraries[..i]
+                .iter()
+                .find(|other| other.name == library.name || other.path == library.path)
+            {
+                return Err(SuiteError::validation(format!(
+                    "The libraries '{}' and '{}' must have different names and paths",
+                    other.name, library.name
+                )));
+            }
+        }
+
+        if self.get(&self.active).is_none() {
+            return Err(SuiteError::validation(format!(
+                "The library '{}' is not registered",
+                self.active
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Gets a registered library by its name
+    ///
+    /// # Arguments
+    /// * `name` - Name of the library
+    ///
+    /// # Returns
+    /// The library or `None` if no library with this name is registered
+    pub fn get(&self, name: &str) -> Option<&Library> {
+        self.libraries.iter().find(|library| library.name == name)
+    }
+
+    /// Gets the library opened when starting
+    ///
+    /// # Returns
+    /// Result containing the active library, the personal library if the
+    /// active one is not registered, or an error if the location of the
+    /// personal library cannot be determined
+    pub fn active_library(&self) -> Result<Library, SuiteError> {
+        match self.get(&self.active) {
+            Some(library) => Ok(library.clone()),
+            None => Ok(LibrarySettings::personal()?.libraries.remove(0)),
+        }
+    }
+}
+
+/// Loads the library settings from the config store
+///
+/// Falls back to the defaults if no settings have been stored yet or the
+/// stored settings are invalid.
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+///
+/// # Returns
+/// Result containing the library settings or an error
+pub fn load_settings(app: &AppHandle) -> Result<LibrarySettings, SuiteError> {
+    let store_path = get_config_store_path()?;
+    let store = app
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
+
+    match store
+        .get(LIBRARY_SETTINGS_KEY)
+        .and_then(|v| serde_json::from_value::<LibrarySettings>(v).ok())
+        .filter(|settings| settings.validate().is_ok())
+    {
+        Some(settings) => Ok(settings),
+        None => LibrarySettings::personal(),
+    }
+}
+
+/// Validates and persists the library settings in the config store
+///
+/// # Arguments
+/// * `app` - The Tauri application handle used to access the store
+/// * `settings` - The settings to persist
+///
+/// # Returns
+/// Result indicating success or failure
+pub fn save_settings(app: &AppHandle, settings: &LibrarySettings) -> Result<(), SuiteError> {
+    settings.validate()?;
+
+    let store_path = get_config_store_path()?;
+    let store = app
+        .store(&store_path)
+        .map_err(|e| SuiteError::internal(format!("Failed to access store: {}", e)))?;
+
+    store.set(LIBRARY_SETTINGS_KEY, serde_json::to_value(settings)?);
+    store.save().map_err(|e| SuiteError::io(&store_path, e))
+}
+
+/// Reads the library settings from the config store file
+///
+/// Used when the Tauri application is not running yet or at all, such as by
+/// the command line interface. Falls back to the defaults if no settings have
+/// been stored yet or the stored settings are invalid.
+///
+/// # Returns
+/// Result containing the library settings or an error if the config store
+/// cannot be read
+pub fn load_stored_settings() -> Result<LibrarySettings, SuiteError> {
+    let store_path = get_config_store_path()?;
+
+    if !store_path.exists() {
+        return LibrarySettings::personal();
+    }
+
+    let content =
+        std::fs::read_to_string(&store_path).map_err(|e| SuiteError::io(&store_path, e))?;
+    let store: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| SuiteError::parse(format!("Failed to parse config store: {}", e)))?;
+
+    match store
+        .get(LIBRARY_SETTINGS_KEY)
+        .and_then(|v| serde_json::from_value::<LibrarySettings>(v.clone()).ok())
+        .filter(|settings| settings.validate().is_ok())
+    {
+        Some(settings) => Ok(settings),
+        None => LibrarySettings::personal(),
+    }
+}
+
+/// Resolves the library to open when starting
+///
+/// The environment variable [`DB_PATH_ENV`] takes precedence, otherwise the
+/// active library is read from the config store file.
+///
+/// # Returns
+/// Result containing the library or an error if the config store cannot be
+/// read or the location of the personal library cannot be determined
+pub fn resolve_library() -> Result<Library, SuiteError> {
+    if let Some(path) = std::env::var(DB_PATH_ENV)
+        .ok()
+        .filter(|path| !path.trim().is_empty())
+    {
+        return Ok(Library {
+            name: ENV_LIBRARY.to_string(),
+            path,
+        });
+    }
+
+    load_stored_settings()?.active_library()
+}
@@ -3,9 +3,26 @@
 //! This module handles the installation and management of the EnzymeML MCP server binary.
 //! The MCP server enables integration with AI assistants and other tools that support
 //! the Model Context Protocol, allowing them to interact with EnzymeML documents and data.
+//!
+//! In addition to the standalone binary, the Suite provides an in-process MCP server
+//! which operates on the live application state. It is served via streamable HTTP
+//! on the local REST API and can be used by stdio-only clients through the bridge
+//! in [`stdio`].
 
+use regex::Regex;
 use sha2::{Digest, Sha256};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Resources exposed by the in-process MCP server
+mod resources;
+/// JSON-RPC message handling of the in-process MCP server
+pub(crate) mod server;
+/// Bridge between stdio based MCP clients and the in-process MCP server
+pub mod stdio;
+/// Tools exposed by the in-process MCP server
+mod tools;
 
 const APPLE_SILICON_MCP: &str = "enzymeml-mcp-aarch64-apple-darwin/enzymeml-mcp";
 const APPLE_INTEL_MCP: &str = "enzymeml-mcp-x86_64-apple-darwin/enzymeml-mcp";
@@ -13,6 +30,11 @@ const WINDOWS_MCP: &str = "enzymeml-mcp-x86_64-pc-windows-msvc/enzymeml-mcp.exe"
 const LINUX_AARCH64_MCP: &str = "enzymeml-mcp-aarch64-unknown-linux-gnu/enzymeml-mcp";
 const LINUX_AMD64_MCP: &str = "enzymeml-mcp-x86_64-unknown-linux-gnu/enzymeml-mcp";
 
+/// Maximum time to wait for an MCP binary to report its version
+const VERSION_TIMEOUT: Duration = Duration::from_secs(5);
+/// Regex matching a semantic version in the output of `--version`
+const VERSION_REGEX: &str = r"\d+\.\d+\.\d+(?:[-+][0-9A-Za-z.-]+)?";
+
 /// Computes the SHA-256 hash of a file.
 ///
 /// # Arguments
@@ -77,6 +99,86 @@ pub fn install(resource_path: PathBuf) -> Result<(), String> {
     Ok(())
 }
 
+/// Returns the path of the MCP binary bundled with the application
+///
+/// # Arguments
+/// * `resource_path` - The path to the application's resource directory containing MCP binaries
+///
+/// # Returns
+/// * `PathBuf` - The path of the bundled binary for the current target
+pub fn bundled_path(resource_path: &Path) -> PathBuf {
+    resource_path.join(resource_mcp_name())
+}
+
+/// Checks whether the installed MCP binary is identical to the bundled one
+///
+/// # Arguments
+/// * `resource_path` - The path to the application's resource directory containing MCP binaries
+///
+/// # Returns
+/// * `Ok(true)` if both binaries exist and their SHA-256 hashes match
+/// * `Ok(false)` if a binary is missing or the hashes differ
+/// * `Err(String)` if a binary could not be read
+pub fn is_up_to_date(resource_path: &Path) -> Result<bool, String> {
+    let mcp_path = get_mcp_path();
+    let bundled_path = bundled_path(resource_path);
+
+    if !mcp_path.exists() || !bundled_path.exists() {
+        return Ok(false);
+    }
+
+    Ok(compute_file_hash(&mcp_path)? == compute_file_hash(&bundled_path)?)
+}
+
+/// Queries the version of an MCP binary by running it with `--version`
+///
+/// The binary is started without stdin, so a server that does not understand
+/// the flag exits immediately instead of waiting for messages. If it does not
+/// exit within a few seconds, it is killed.
+///
+/// # Arguments
+/// * `path` - The path of the MCP binary
+///
+/// # Returns
+/// * `Some(String)` - The version reported by the binary
+/// * `None` - If the binary does not exist or did not report a version
+pub fn binary_version(path: &Path) -> Option<String> {
+    if !path.exists() {
+        return None;
+    }
+
+    let mut child = Command::new(path)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    let started = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if started.elapsed() < VERSION_TIMEOUT => {
+                std::thread::sleep(Duration::from_millis(50))
+            }
+            _ => {
+                child.kill().ok();
+                child.wait().ok();
+                return None;
+            }
+        }
+    }
+
+    let output = child.wait_with_output().ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    Regex::new(VERSION_REGEX)
+        .unwrap()
+        .find(&stdout)
+        .map(|version| version.as_str().to_string())
+}
+
 #[cfg(unix)]
 fn set_executable_if_needed(path: &PathBuf) -> Result<(), String> {
     use std::fs::Permissions;
@@ -0,0 +1,153 @@
+//! Resources exposed by the in-process MCP server
+//!
+//! Exposes the document currently opened in the application, the documents
+//! stored in the database and the bundled Jupyter notebook templates.
+
+use serde_json::{json, Value};
+
+use super::server::{McpContext, RpcError, INTERNAL_ERROR, RESOURCE_NOT_FOUND};
+use crate::actions::jupyter::{template_as_script, JUPYTER_TEMPLATE_METADATA};
+use crate::io::dataio::{retrieve_all_documents, retrieve_document_by_id};
+
+/// URI of the document currently opened in the application
+const CURRENT_DOCUMENT_URI: &str = "enzymeml://documents/current";
+/// URI of the overview of all stored documents
+const DOCUMENTS_URI: &str = "enzymeml://documents";
+/// URI prefix of stored documents, followed by their database ID
+const DOCUMENT_URI_PREFIX: &str = "enzymeml://documents/";
+/// URI of the overview of all Jupyter templates
+const TEMPLATES_URI: &str = "enzymeml://jupyter/templates";
+/// URI prefix of Jupyter templates, followed by their template ID
+const TEMPLATE_URI_PREFIX: &str = "enzymeml://jupyter/templates/";
+
+/// Lists all concrete resources as returned by `resources/list`
+///
+/// # Returns
+/// Result containing the `resources/list` result or an error if the stored
+/// documents cannot be read
+pub(super) fn list() -> Result<Value, RpcError> {
+    let mut resources = vec![
+        resource(
+            CURRENT_DOCUMENT_URI,
+            "Current document",
+            "The EnzymeML document currently opened in the EnzymeML Suite",
+            "application/json",
+        ),
+        resource(
+            DOCUMENTS_URI,
+            "Stored documents",
+            "Titles and IDs of all documents stored in the EnzymeML Suite",
+            "application/json",
+        ),
+        resource(
+            TEMPLATES_URI,
+            "Jupyter templates",
+            "Metadata of all Jupyter notebook templates bundled with the EnzymeML Suite",
+            "application/json",
+        ),
+    ];
+
+    let documents =
+        retrieve_all_documents().map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))?;
+
+    resources.extend(documents.into_iter().map(|doc| {
+        resource(
+            &format!("{DOCUMENT_URI_PREFIX}{}", doc.id),
+            &doc.title,
+            "EnzymeML document stored in the EnzymeML Suite",
+            "application/json",
+        )
+    }));
+
+    resources.extend(JUPYTER_TEMPLATE_METADATA.iter().map(|template| {
+        resource(
+            &format!("{TEMPLATE_URI_PREFIX}{}", template.id),
+            &template.name,
+            "Jupyter notebook template as percent-formatted Python script",
+            "text/x-python",
+        )
+    }));
+
+    Ok(json!({ "resources": resources }))
+}
+
+/// Lists the resource templates as returned by `resources/templates/list`
+pub(super) fn templates() -> Vec<Value> {
+    vec![
+        json!({
+            "uriTemplate": format!("{DOCUMENT_URI_PREFIX}{{id}}"),
+            "name": "Stored document",
+            "description": "EnzymeML document stored in the EnzymeML Suite by its database ID",
+            "mimeType": "application/json",
+        }),
+        json!({
+            "uriTemplate": format!("{TEMPLATE_URI_PREFIX}{{template_id}}"),
+            "name": "Jupyter template",
+            "description": "Jupyter notebook template as percent-formatted Python script",
+            "mimeType": "text/x-python",
+        }),
+    ]
+}
+
+/// Reads the resource with the given URI
+///
+/// # Arguments
+/// * `context` - The application state holding the current document
+/// * `uri` - URI of the resource to read
+///
+/// # Returns
+/// Result containing the `resources/read` result or an error if the resource
+/// does not exist
+pub(super) fn read(context: &McpContext, uri: &str) -> Result<Value, RpcError> {
+    let (text, mime_type) = if uri == CURRENT_DOCUMENT_URI {
+        let doc = context.state.doc.lock().unwrap();
+        (to_json(&*doc)?, "application/json")
+    } else if uri == DOCUMENTS_URI {
+        let documents = retrieve_all_documents()
+            .map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))?
+            .into_iter()
+            .map(|doc| json!({ "id": doc.id, "title": doc.title }))
+            .collect::<Vec<Value>>();
+        (to_json(&documents)?, "application/json")
+    } else if uri == TEMPLATES_URI {
+        (to_json(&*JUPYTER_TEMPLATE_METADATA)?, "application/json")
+    } else if let Some(template_id) = uri.strip_prefix(TEMPLATE_URI_PREFIX) {
+        let script = template_as_script(template_id).map_err(|e| not_found(uri, e))?;
+        (script, "text/x-python")
+    } else if let Some(id) = uri.strip_prefix(DOCUMENT_URI_PREFIX) {
+        let id: i32 = id
+            .parse()
+            .map_err(|_| not_found(uri, "Invalid document ID"))?;
+        let doc = retrieve_document_by_id(id).map_err(|e| not_found(uri, e))?;
+        (doc.content, "application/json")
+    } else {
+        return Err(not_found(uri, "Unknown resource"));
+    };
+
+    Ok(json!({
+        "contents": [{ "uri": uri, "mimeType": mime_type, "text": text }],
+    }))
+}
+
+/// Creates the description of a concrete resource
+fn resource(uri: &str, name: &str, description: &str, mime_type: &str) -> Value {
+    json!({
+        "uri": uri,
+        "name": name,
+        "description": description,
+        "mimeType": mime_type,
+    })
+}
+
+/// Serializes a resource as pretty printed JSON
+fn to_json<T: serde::Serialize + ?Sized>(value: &T) -> Result<String, RpcError> {
+    serde_json::to_string_pretty(value).map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))
+}
+
+/// Creates the error for a resource that could not be found
+fn not_found(uri: &str, reason: impl std::fmt::Display) -> RpcError {
+    RpcError::new(
+        RESOURCE_NOT_FOUND,
+        format!("Resource {uri} not found: {reason}"),
+    )
+}
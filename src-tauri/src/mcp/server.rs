@@ -0,0 +1,285 @@
+//! JSON-RPC message handling of the in-process MCP server
+//!
+//! Implements the lifecycle, tool and resource methods of the Model Context
+//! Protocol on top of plain `serde_json` values. The transport is not part of
+//! this module: the streamable HTTP endpoint of the REST API passes every
+//! received message to [`handle_message`] and returns the produced response.
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{resources, tools};
+use crate::events::EventSink;
+use crate::states::EnzymeMLState;
+
+/// Protocol versions supported by the server, latest first
+const PROTOCOL_VERSIONS: [&str; 2] = ["2025-06-18", "2025-03-26"];
+/// Name the server reports to clients during initialization
+const SERVER_NAME: &str = "EnzymeML Suite";
+
+/// JSON-RPC error code for malformed requests
+const INVALID_REQUEST: i64 = -32600;
+/// JSON-RPC error code for unknown methods
+const METHOD_NOT_FOUND: i64 = -32601;
+/// JSON-RPC error code for invalid method parameters
+const INVALID_PARAMS: i64 = -32602;
+/// JSON-RPC error code for internal errors
+pub(super) const INTERNAL_ERROR: i64 = -32603;
+/// MCP error code for unknown resources
+pub(super) const RESOURCE_NOT_FOUND: i64 = -32002;
+
+/// Everything a request needs to operate on the live application
+pub(crate) struct McpContext<'a> {
+    /// The shared EnzymeML document state
+    pub(crate) state: &'a EnzymeMLState,
+    /// Sink notified about changes made by tools
+    pub(crate) events: &'a dyn EventSink,
+    /// Whether tools that modify the document may be called
+    pub(crate) allow_writes: bool,
+}
+
+/// Error returned to the client as JSON-RPC error object
+#[derive(Debug)]
+pub(super) struct RpcError {
+    /// JSON-RPC error code
+    pub(super) code: i64,
+    /// Human readable description of the error
+    pub(super) message: String,
+}
+
+impl RpcError {
+    /// Creates a new error with the given code and message
+    ///
+    /// # Arguments
+    /// * `code` - JSON-RPC error code
+    /// * `message` - Human readable description of the error
+    pub(super) fn new(code: i64, message: impl Into<String>) -> Self {
+        RpcError {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// A JSON-RPC request or notification sent by the client
+#[derive(Deserialize)]
+struct JsonRpcRequest {
+    /// ID of the request, absent for notifications
+    #[serde(default)]
+    id: Option<Value>,
+    /// Name of the invoked method
+    method: String,
+    /// Parameters of the method
+    #[serde(default)]
+    params: Value,
+}
+
+/// Parameters of a `tools/call` request
+#[derive(Deserialize)]
+struct ToolCallParams {
+    /// Name of the tool to call
+    name: String,
+    /// Arguments passed to the tool
+    #[serde(default)]
+    arguments: Value,
+}
+
+/// Parameters of a `resources/read` request
+#[derive(Deserialize)]
+struct ResourceReadParams {
+    /// URI of the resource to read
+    uri: String,
+}
+
+/// Handles a single JSON-RPC message or a batch of messages
+///
+/// Notifications and responses sent by the client do not produce a response,
+/// in which case `None` is returned and the transport acknowledges the message
+/// without a body.
+///
+/// # Arguments
+/// * `context` - The application state the request operates on
+/// * `message` - The received JSON-RPC message
+///
+/// # Returns
+/// The JSON-RPC response to send back to the client, if any
+pub(crate) fn handle_message(context: &McpContext, message: Value) -> Option<Value> {
+    match message {
+        Value::Array(messages) => {
+            let responses: Vec<Value> = messages
+                .into_iter()
+                .filter_map(|message| handle_single(context, message))
+                .collect();
+
+            if responses.is_empty() {
+                None
+            } else {
+                Some(Value::Array(responses))
+            }
+        }
+        message => handle_single(context, message),
+    }
+}
+
+/// Handles a single JSON-RPC message
+///
+/// # Arguments
+/// * `context` - The application state the request operates on
+/// * `message` - The received JSON-RPC message
+///
+/// # Returns
+/// The JSON-RPC response to send back to the client, if any
+fn handle_single(context: &McpContext, message: Value) -> Option<Value> {
+    // Responses to server initiated requests are not expected, thus ignored
+    if message.get("method").is_none()
+        && (message.get("result").is_some() || message.get("error").is_some())
+    {
+        return None;
+    }
+
+    let request: JsonRpcRequest = match serde_json::from_value(message) {
+        Ok(request) => request,
+        Err(e) => {
+            let error = RpcError::new(INVALID_REQUEST, format!("Invalid request: {e}"));
+            return Some(error_response(Value::Null, error));
+        }
+    };
+
+    // Notifications such as `notifications/initialized` need no response
+    let id = request.id?;
+
+    match dispatch(context, &request.method, request.params) {
+        Ok(result) => Some(json!({ "jsonrpc": "2.0", "id": id, "result": result })),
+        Err(error) => Some(error_response(id, error)),
+    }
+}
+
+/// Routes a request to the implementation of its method
+///
+/// # Arguments
+/// * `context` - The application state the request operates on
+/// * `method` - Name of the invoked method
+/// * `params` - Parameters of the method
+///
+/// # Returns
+/// Result containing the result object of the method or an error
+fn dispatch(context: &McpContext, method: &str, params: Value) -> Result<Value, RpcError> {
+    match method {
+        "initialize" => Ok(initialize(&params)),
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({ "tools": tools::definitions() })),
+        "tools/call" => {
+            let params: ToolCallParams = parse_params(params)?;
+
+            if !tools::exists(&params.name) {
+                return Err(RpcError::new(
+                    INVALID_PARAMS,
+                    format!("Unknown tool: {}", params.name),
+                ));
+            }
+
+            Ok(tool_result(tools::call(
+                context,
+                &params.name,
+                params.arguments,
+            )))
+        }
+        "resources/list" => resources::list(),
+        "resources/templates/list" => Ok(json!({ "resourceTemplates": resources::templates() })),
+        "resources/read" => {
+            let params: ResourceReadParams = parse_params(params)?;
+            resources::read(context, &params.uri)
+        }
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Method not found: {method}"),
+        )),
+    }
+}
+
+/// Negotiates the protocol version and announces the server capabilities
+///
+/// # Arguments
+/// * `params` - Parameters of the `initialize` request
+///
+/// # Returns
+/// The result object of the `initialize` request
+fn initialize(params: &Value) -> Value {
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let version = PROTOCOL_VERSIONS
+        .iter()
+        .find(|version| Some(**version) == requested)
+        .unwrap_or(&PROTOCOL_VERSIONS[0]);
+
+    json!({
+        "protocolVersion": version,
+        "capabilities": {
+            "tools": { "listChanged": false },
+            "resources": { "subscribe": false, "listChanged": false },
+        },
+        "serverInfo": {
+            "name": SERVER_NAME,
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "instructions": "Tools operate on the document currently opened in the EnzymeML Suite. \
+            Changes are shown to the user immediately but are only persisted once the user saves the document.",
+    })
+}
+
+/// Wraps the outcome of a tool call into a `tools/call` result
+///
+/// Errors raised by the tool itself are reported as part of the result, so the
+/// model can see and react to them, rather than as JSON-RPC errors.
+///
+/// # Arguments
+/// * `result` - The outcome of the tool call
+///
+/// # Returns
+/// The result object of the `tools/call` request
+fn tool_result(result: Result<Value, String>) -> Value {
+    match result {
+        Ok(value) => {
+            let text = match &value {
+                Value::String(text) => text.clone(),
+                value => serde_json::to_string_pretty(value).unwrap_or_default(),
+            };
+
+            json!({
+                "content": [{ "type": "text", "text": text }],
+                "isError": false,
+            })
+        }
+        Err(message) => json!({
+            "content": [{ "type": "text", "text": message }],
+            "isError": true,
+        }),
+    }
+}
+
+/// Deserializes the parameters of a request
+///
+/// # Arguments
+/// * `params` - The raw parameters of the request
+///
+/// # Returns
+/// Result containing the typed parameters or an invalid params error
+fn parse_params<T: for<'de> Deserialize<'de>>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid params: {e}")))
+}
+
+/// Creates a JSON-RPC error response
+///
+/// # Arguments
+/// * `id` - ID of the request the error belongs to
+/// * `error` - The error to report
+///
+/// # Returns
+/// The JSON-RPC error response
+pub(super) fn error_response(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+}
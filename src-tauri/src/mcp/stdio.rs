@@ -0,0 +1,149 @@
+//! Bridge between stdio based MCP clients and the in-process MCP server
+//!
+//! Most MCP clients launch servers as subprocesses and talk to them via
+//! newline-delimited JSON-RPC on stdin and stdout. When the application is
+//! started with [`STDIO_FLAG`], it does not open a window but forwards every
+//! message to the streamable HTTP endpoint of the already running EnzymeML
+//! Suite and writes the responses back to stdout.
+//!
+//! Release builds of the application use the GUI subsystem on Windows and have
+//! no console streams, so the command line interface, which is bundled next to
+//! the application and understands the same flag, acts as bridge there.
+
+use serde_json::Value;
+use std::path::PathBuf;
+use tauri_plugin_http::reqwest;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+use super::server::{error_response, RpcError, INTERNAL_ERROR};
+use crate::api::auth::resolve_client_credentials;
+
+/// Command line flag starting the application as stdio bridge
+pub const STDIO_FLAG: &str = "--mcp-stdio";
+/// Path of the streamable HTTP endpoint of the MCP server
+const MCP_PATH: &str = "/mcp";
+/// File name of the command line interface bundled next to the application on Windows
+const WINDOWS_BRIDGE: &str = "enzymeml-suite-cli.exe";
+
+/// Returns the executable MCP clients launch with [`STDIO_FLAG`] to reach the server
+///
+/// # Returns
+/// The path of the running executable, or of the bundled command line
+/// interface on Windows, or an error message
+pub fn bridge_command() -> Result<PathBuf, String> {
+    let current = std::env::current_exe()
+        .map_err(|e| format!("Failed to determine the application path: {}", e))?;
+
+    if cfg!(target_os = "windows") {
+        Ok(current.with_file_name(WINDOWS_BRIDGE))
+    } else {
+        Ok(current)
+    }
+}
+
+/// Forwards MCP messages from stdin to the running application until stdin closes
+///
+/// # Returns
+/// Result indicating success or failure
+pub async fn run() -> Result<(), String> {
+    let (base_url, token) = resolve_client_credentials()?;
+    let endpoint = format!("{}{}", base_url.trim_end_matches('/'), MCP_PATH);
+    let client = reqwest::Client::new();
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut stdout = tokio::io::stdout();
+
+    while let Some(line) = lines
+        .next_line()
+        .await
+        .map_err(|e| format!("Failed to read from stdin: {}", e))?
+    {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match forward(&client, &endpoint, &token, &line).await {
+            Ok(response) => response,
+            Err(e) => unreachable_response(&line, e),
+        };
+
+        if let Some(response) = response {
+            stdout
+                .write_all(format!("{}\n", response).as_bytes())
+                .await
+                .map_err(|e| format!("Failed to write to stdout: {}", e))?;
+            stdout
+                .flush()
+                .await
+                .map_err(|e| format!("Failed to write to stdout: {}", e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Sends a single message to the MCP endpoint of the running application
+///
+/// # Arguments
+/// * `client` - The HTTP client used for the request
+/// * `endpoint` - URL of the MCP endpoint
+/// * `token` - The API token of this install
+/// * `message` - The raw JSON-RPC message
+///
+/// # Returns
+/// Result containing the response on a single line, if any, or an error message
+async fn forward(
+    client: &reqwest::Client,
+    endpoint: &str,
+    token: &str,
+    message: &str,
+) -> Result<Option<String>, String> {
+    let response = client
+        .post(endpoint)
+        .bearer_auth(token)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, text/event-stream")
+        .body(message.to_string())
+        .send()
+        .await
+        .map_err(|e| format!("The EnzymeML Suite is not reachable: {}", e))?;
+
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+
+    if !status.is_success() {
+        return Err(format!(
+            "The EnzymeML Suite responded with {}: {}",
+            status, body
+        ));
+    }
+
+    if body.trim().is_empty() {
+        return Ok(None);
+    }
+
+    // Re-serialize to guarantee the response is on a single line
+    let body: Value =
+        serde_json::from_str(&body).map_err(|e| format!("Invalid response: {}", e))?;
+
+    Ok(Some(body.to_string()))
+}
+
+/// Creates the error response for a message that could not be forwarded
+///
+/// # Arguments
+/// * `message` - The raw JSON-RPC message
+/// * `reason` - Why the message could not be forwarded
+///
+/// # Returns
+/// The serialized error response, or `None` for notifications
+fn unreachable_response(message: &str, reason: String) -> Option<String> {
+    let id = serde_json::from_str::<Value>(message)
+        .ok()
+        .and_then(|message| message.get("id").cloned())?;
+
+    Some(error_response(id, RpcError::new(INTERNAL_ERROR, reason)).to_string())
+}
@@ -0,0 +1,537 @@
+//! Tools exposed by the in-process MCP server
+//!
+//! All tools operate on the document currently opened in the application. Tools
+//! that modify the document notify the frontend in the same way as the Tauri
+//! commands do and are rejected if write access to the API is disabled.
+
+use enzymeml::prelude::{
+    Equation, Measurement, Parameter, Protein, Reaction, SmallMolecule, Vessel,
+};
+use schemars::{schema_for, JsonSchema};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::server::McpContext;
+use crate::actions::identifiers::{
+    MEASUREMENT_PREFIX, PARAMETER_PREFIX, PROTEIN_PREFIX, REACTION_PREFIX, SMALL_MOLECULE_PREFIX,
+    VESSEL_PREFIX,
+};
+use crate::actions::utils::generate_id;
+use crate::actions::validation::{Report, ValidationResult};
+use crate::document::equations::{cleanup_parameters, derive_equations, process_equation};
+use crate::events::{ChangeOp, EntityKind as ChangedKind, SuiteEvent, WHOLE_COLLECTION};
+
+/// Names of all tools that modify the document
+const WRITE_TOOLS: [&str; 4] = [
+    "upsert_entity",
+    "delete_entity",
+    "add_measurement",
+    "derive_equations",
+];
+
+/// Kinds of entities of an EnzymeML document accessible through the tools
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum EntityKind {
+    SmallMolecule,
+    Protein,
+    Reaction,
+    Measurement,
+    Vessel,
+    Equation,
+    Parameter,
+}
+
+impl EntityKind {
+    /// Returns the event notifying the frontend about a change of entities of this kind
+    ///
+    /// # Arguments
+    /// * `context` - The application state that has been changed
+    /// * `op` - Operation that changed the entities
+    /// * `ids` - IDs of the affected entities
+    fn changed<I, S>(&self, context: &McpContext, op: ChangeOp, ids: I) -> SuiteEvent
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        let kind = match self {
+            EntityKind::SmallMolecule => ChangedKind::SmallMolecule,
+            EntityKind::Protein => ChangedKind::Protein,
+            EntityKind::Reaction => ChangedKind::Reaction,
+            EntityKind::Measurement => ChangedKind::Measurement,
+            EntityKind::Vessel => ChangedKind::Vessel,
+            EntityKind::Equation => ChangedKind::Equation,
+            EntityKind::Parameter => ChangedKind::Parameter,
+        };
+
+        SuiteEvent::changed(context.state, kind, op, ids)
+    }
+
+    /// Returns the prefix of generated IDs, if entities of this kind have one
+    ///
+    /// Equations are identified by the species they describe and can thus not
+    /// be assigned a generated ID.
+    fn prefix(&self) -> Option<&'static str> {
+        match self {
+            EntityKind::SmallMolecule => Some(SMALL_MOLECULE_PREFIX),
+            EntityKind::Protein => Some(PROTEIN_PREFIX),
+            EntityKind::Reaction => Some(REACTION_PREFIX),
+            EntityKind::Measurement => Some(MEASUREMENT_PREFIX),
+            EntityKind::Vessel => Some(VESSEL_PREFIX),
+            EntityKind::Equation => None,
+            EntityKind::Parameter => Some(PARAMETER_PREFIX),
+        }
+    }
+}
+
+/// Common access to the identifier of document entities
+trait Entity: Serialize + DeserializeOwned {
+    /// Returns the identifier of the entity
+    fn entity_id(&self) -> &str;
+    /// Replaces the identifier of the entity
+    fn set_entity_id(&mut self, id: String);
+}
+
+/// Implements [`Entity`] for a type using the given field as identifier
+macro_rules! impl_entity {
+    ($type:ty, $id_prop:ident) => {
+        impl Entity for $type {
+            fn entity_id(&self) -> &str {
+                &self.$id_prop
+            }
+
+            fn set_entity_id(&mut self, id: String) {
+                self.$id_prop = id;
+            }
+        }
+    };
+}
+
+impl_entity!(SmallMolecule, id);
+impl_entity!(Protein, id);
+impl_entity!(Reaction, id);
+impl_entity!(Measurement, id);
+impl_entity!(Vessel, id);
+impl_entity!(Equation, species_id);
+impl_entity!(Parameter, id);
+
+/// Runs an expression against the document collection of the given kind
+///
+/// Binds the collection as mutable reference to `$collection` and evaluates
+/// `$body` for the concrete entity type of the kind.
+macro_rules! with_collection {
+    ($doc:expr, $kind:expr, $collection:ident => $body:expr) => {
+        match $kind {
+            EntityKind::SmallMolecule => {
+                let $collection = &mut $doc.small_molecules;
+                $body
+            }
+            EntityKind::Protein => {
+                let $collection = &mut $doc.proteins;
+                $body
+            }
+            EntityKind::Reaction => {
+                let $collection = &mut $doc.reactions;
+                $body
+            }
+            EntityKind::Measurement => {
+                let $collection = &mut $doc.measurements;
+                $body
+            }
+            EntityKind::Vessel => {
+                let $collection = &mut $doc.vessels;
+                $body
+            }
+            EntityKind::Equation => {
+                let $collection = &mut $doc.equations;
+                $body
+            }
+            EntityKind::Parameter => {
+                let $collection = &mut $doc.parameters;
+                $body
+            }
+        }
+    };
+}
+
+/// Arguments of tools that do not take any input
+#[derive(Deserialize, JsonSchema)]
+struct NoArgs {}
+
+/// Arguments of the `list_entities` tool
+#[derive(Deserialize, JsonSchema)]
+struct ListArgs {
+    /// Kind of the entities to list
+    kind: EntityKind,
+}
+
+/// Arguments of the `get_entity` and `delete_entity` tools
+#[derive(Deserialize, JsonSchema)]
+struct EntityArgs {
+    /// Kind of the entity
+    kind: EntityKind,
+    /// ID of the entity, or the species ID for equations
+    id: String,
+}
+
+/// Arguments of the `upsert_entity` tool
+#[derive(Deserialize, JsonSchema)]
+struct UpsertArgs {
+    /// Kind of the entity
+    kind: EntityKind,
+    /// The complete entity. An existing entity with the same ID is replaced,
+    /// otherwise the entity is added. An empty ID is replaced by a generated one.
+    entity: Value,
+}
+
+/// Arguments of the `add_measurement` tool
+#[derive(Deserialize, JsonSchema)]
+struct MeasurementArgs {
+    /// The measurement to add. Its ID is replaced by a generated one.
+    measurement: Measurement,
+}
+
+/// Arguments of the `validate_document` tool
+#[derive(Deserialize, JsonSchema)]
+struct ValidateArgs {
+    /// Only report issues concerning the entity with this ID
+    #[serde(default)]
+    identifier: Option<String>,
+}
+
+/// Returns the definitions of all tools as listed by `tools/list`
+pub(super) fn definitions() -> Vec<Value> {
+    vec![
+        tool::<NoArgs>(
+            "get_document",
+            "Get the complete EnzymeML document currently opened in the EnzymeML Suite.",
+        ),
+        tool::<ListArgs>(
+            "list_entities",
+            "List the IDs and names of all entities of a kind in the current document.",
+        ),
+        tool::<EntityArgs>(
+            "get_entity",
+            "Get a single entity of the current document by its ID.",
+        ),
+        tool::<UpsertArgs>(
+            "upsert_entity",
+            "Add an entity to the current document or replace the entity with the same ID.",
+        ),
+        tool::<EntityArgs>(
+            "delete_entity",
+            "Delete an entity from the current document.",
+        ),
+        tool::<MeasurementArgs>(
+            "add_measurement",
+            "Add a measurement including its time course data to the current document.",
+        ),
+        tool::<NoArgs>(
+            "derive_equations",
+            "Derive the ODE equations of all species from the kinetic laws of the reactions.",
+        ),
+        tool::<ValidateArgs>(
+            "validate_document",
+            "Check the current document for consistency and report all issues found.",
+        ),
+    ]
+}
+
+/// Checks whether a tool with the given name exists
+///
+/// # Arguments
+/// * `name` - Name of the tool
+pub(super) fn exists(name: &str) -> bool {
+    definitions()
+        .iter()
+        .any(|definition| definition["name"] == name)
+}
+
+/// Calls a tool against the current document
+///
+/// # Arguments
+/// * `context` - The application state the tool operates on
+/// * `name` - Name of the tool to call
+/// * `arguments` - Arguments passed to the tool
+///
+/// # Returns
+/// Result containing the output of the tool or an error message
+pub(super) fn call(context: &McpContext, name: &str, arguments: Value) -> Result<Value, String> {
+    if WRITE_TOOLS.contains(&name) && !context.allow_writes {
+        return Err("Write access to the EnzymeML Suite is disabled in the settings".to_string());
+    }
+
+    match name {
+        "get_document" => get_document(context),
+        "list_entities" => list_entities(context, parse_args(arguments)?),
+        "get_entity" => get_entity(context, parse_args(arguments)?),
+        "upsert_entity" => upsert_entity(context, parse_args(arguments)?),
+        "delete_entity" => delete_entity(context, parse_args(arguments)?),
+        "add_measurement" => add_measurement(context, parse_args(arguments)?),
+        "derive_equations" => derive(context),
+        "validate_document" => validate_document(context, parse_args(arguments)?),
+        _ => Err(format!("Unknown tool: {name}")),
+    }
+}
+
+/// Returns the current document along with its title and database ID
+fn get_document(context: &McpContext) -> Result<Value, String> {
+    let doc = context.state.doc.lock().unwrap();
+    let title = context.state.title.lock().unwrap();
+    let id = context.state.id.lock().unwrap();
+
+    Ok(json!({
+        "title": *title,
+        "id": *id,
+        "document": *doc,
+    }))
+}
+
+/// Lists the IDs and names of all entities of a kind
+fn list_entities(context: &McpContext, args: ListArgs) -> Result<Value, String> {
+    let mut doc = context.state.doc.lock().unwrap();
+    with_collection!(doc, args.kind, collection => Ok(list(collection)))
+}
+
+/// Returns a single entity by its ID
+fn get_entity(context: &McpContext, args: EntityArgs) -> Result<Value, String> {
+    let mut doc = context.state.doc.lock().unwrap();
+    with_collection!(doc, args.kind, collection => get(collection, &args.id))
+}
+
+/// Adds or replaces an entity and registers parameters introduced by its equations
+fn upsert_entity(context: &McpContext, args: UpsertArgs) -> Result<Value, String> {
+    let (id, created) = {
+        let mut doc = context.state.doc.lock().unwrap();
+        with_collection!(doc, args.kind, collection => upsert(collection, args.entity, args.kind.prefix()))?
+    };
+
+    let op = if created {
+        ChangeOp::Create
+    } else {
+        ChangeOp::Update
+    };
+
+    let mut events = vec![args.kind.changed(context, op, [&id])];
+    if sync_parameters(context, args.kind, &id)? {
+        events.push(EntityKind::Parameter.changed(context, ChangeOp::Bulk, WHOLE_COLLECTION));
+    }
+
+    notify(context, &events)?;
+
+    Ok(json!({ "id": id, "created": created }))
+}
+
+/// Deletes an entity by its ID
+fn delete_entity(context: &McpContext, args: EntityArgs) -> Result<Value, String> {
+    {
+        let mut doc = context.state.doc.lock().unwrap();
+        with_collection!(doc, args.kind, collection => remove(collection, &args.id))?;
+    }
+
+    let mut events = vec![args.kind.changed(context, ChangeOp::Delete, [&args.id])];
+    if args.kind == EntityKind::Equation {
+        cleanup_parameters(context.state);
+        events.push(EntityKind::Parameter.changed(context, ChangeOp::Bulk, WHOLE_COLLECTION));
+    }
+
+    notify(context, &events)?;
+
+    Ok(json!({ "id": args.id, "deleted": true }))
+}
+
+/// Adds a measurement under a newly generated ID
+fn add_measurement(context: &McpContext, args: MeasurementArgs) -> Result<Value, String> {
+    let mut measurement = args.measurement;
+
+    {
+        let mut doc = context.state.doc.lock().unwrap();
+        let ids = doc.measurements.iter().map(|m| m.id.clone()).collect();
+        measurement.id = generate_id(&ids, MEASUREMENT_PREFIX);
+        doc.measurements.push(measurement.clone());
+    }
+
+    notify(
+        context,
+        &[EntityKind::Measurement.changed(context, ChangeOp::Create, [&measurement.id])],
+    )?;
+
+    Ok(json!({ "id": measurement.id }))
+}
+
+/// Derives the ODE equations from the kinetic laws of the reactions
+fn derive(context: &McpContext) -> Result<Value, String> {
+    derive_equations(context.state)?;
+
+    notify(
+        context,
+        &[
+            EntityKind::Equation.changed(context, ChangeOp::Bulk, WHOLE_COLLECTION),
+            EntityKind::Parameter.changed(context, ChangeOp::Bulk, WHOLE_COLLECTION),
+        ],
+    )?;
+
+    let doc = context.state.doc.lock().unwrap();
+    Ok(json!(doc.equations))
+}
+
+/// Runs the consistency check and returns the report
+fn validate_document(context: &McpContext, args: ValidateArgs) -> Result<Value, String> {
+    context.state.update_report();
+    let report = context.state.validation_report.lock().unwrap().clone();
+
+    match args.identifier {
+        Some(identifier) => {
+            let results: Vec<ValidationResult> = report
+                .filter_results(&identifier)
+                .into_iter()
+                .map(|r| r.into())
+                .collect();
+            serde_json::to_value(results).map_err(|e| e.to_string())
+        }
+        None => serde_json::to_value(Report::from(report)).map_err(|e| e.to_string()),
+    }
+}
+
+/// Lists the IDs and names of the given entities
+fn list<T: Entity>(collection: &[T]) -> Value {
+    collection
+        .iter()
+        .map(|entity| {
+            let name = serde_json::to_value(entity)
+                .ok()
+                .and_then(|value| value.get("name").cloned())
+                .unwrap_or(Value::Null);
+
+            json!({ "id": entity.entity_id(), "name": name })
+        })
+        .collect()
+}
+
+/// Returns the serialized entity with the given ID
+fn get<T: Entity>(collection: &[T], id: &str) -> Result<Value, String> {
+    let entity = collection
+        .iter()
+        .find(|entity| entity.entity_id() == id)
+        .ok_or(format!("{id:?} not found"))?;
+
+    serde_json::to_value(entity).map_err(|e| e.to_string())
+}
+
+/// Replaces the entity with the same ID or adds it to the collection
+///
+/// # Returns
+/// Result containing the ID of the entity and whether it has been added
+fn upsert<T: Entity>(
+    collection: &mut Vec<T>,
+    entity: Value,
+    prefix: Option<&str>,
+) -> Result<(String, bool), String> {
+    let mut entity: T =
+        serde_json::from_value(entity).map_err(|e| format!("Invalid entity: {e}"))?;
+
+    if entity.entity_id().is_empty() {
+        let prefix = prefix.ok_or("Equations require the ID of the species they describe")?;
+        let ids = collection
+            .iter()
+            .map(|e| e.entity_id().to_string())
+            .collect();
+        entity.set_entity_id(generate_id(&ids, prefix));
+    }
+
+    let id = entity.entity_id().to_string();
+    match collection.iter().position(|e| e.entity_id() == id) {
+        Some(index) => {
+            collection[index] = entity;
+            Ok((id, false))
+        }
+        None => {
+            collection.push(entity);
+            Ok((id, true))
+        }
+    }
+}
+
+/// Removes the entity with the given ID from the collection
+fn remove<T: Entity>(collection: &mut Vec<T>, id: &str) -> Result<(), String> {
+    let index = collection
+        .iter()
+        .position(|entity| entity.entity_id() == id)
+        .ok_or(format!("{id:?} not found"))?;
+
+    collection.remove(index);
+    Ok(())
+}
+
+/// Registers the parameters used by the equation or kinetic law of an entity
+///
+/// # Returns
+/// Result indicating whether the parameters of the document may have changed
+fn sync_parameters(context: &McpContext, kind: EntityKind, id: &str) -> Result<bool, String> {
+    let equation = {
+        let doc = context.state.doc.lock().unwrap();
+        match kind {
+            EntityKind::Equation => doc.equations.iter().find(|e| e.species_id == id).cloned(),
+            EntityKind::Reaction => doc
+                .reactions
+                .iter()
+                .find(|r| r.id == id)
+                .and_then(|r| r.kinetic_law.clone()),
+            _ => None,
+        }
+    };
+
+    let Some(equation) = equation else {
+        return Ok(false);
+    };
+
+    process_equation(context.state, &equation)?;
+    if kind == EntityKind::Equation {
+        cleanup_parameters(context.state);
+    }
+
+    Ok(true)
+}
+
+/// Refreshes the validation report and notifies the frontend about changes
+///
+/// # Arguments
+/// * `context` - The application state that has been changed
+/// * `events` - The update events to emit
+fn notify(context: &McpContext, events: &[SuiteEvent]) -> Result<(), String> {
+    context.state.update_report();
+
+    for event in events.iter() {
+        context.events.emit_event(event.clone())?;
+    }
+
+    context.events.emit_event(SuiteEvent::report(context.state))
+}
+
+/// Deserializes the arguments of a tool call
+///
+/// Missing arguments are treated as an empty object, so tools without
+/// required arguments can be called without any.
+fn parse_args<T: DeserializeOwned>(arguments: Value) -> Result<T, String> {
+    let arguments = match arguments {
+        Value::Null => json!({}),
+        arguments => arguments,
+    };
+
+    serde_json::from_value(arguments).map_err(|e| format!("Invalid arguments: {e}"))
+}
+
+/// Creates the definition of a tool taking arguments of type `T`
+///
+/// # Arguments
+/// * `name` - Name of the tool
+/// * `description` - Description of the tool shown to the model
+fn tool<T: JsonSchema>(name: &str, description: &str) -> Value {
+    json!({
+        "name": name,
+        "description": description,
+        "inputSchema": schema_for!(T),
+        "annotations": { "readOnlyHint": !WRITE_TOOLS.contains(&name) },
+    })
+}
@@ -10,6 +10,7 @@
 //!
 //! # Models
 //! - Document: Represents a document in the database
+//! - DocumentMetadata: Represents the metadata of a document without its content
 //! - DBSmallMolecule: Represents a small molecule in the database
 //! - DBProtein: Represents a protein in the database
 //! - DBVessel: Represents a vessel in the database
@@ -18,6 +19,7 @@
 //! Each model has a corresponding "New" struct for database insertions.
 
 use super::schema::*;
+use crate::io::stringarray::StringArray;
 use diesel::prelude::*;
 use enzymeml::versions::v2;
 use serde::{Deserialize, Serialize};
@@ -30,6 +32,25 @@ pub struct Document {
     pub id: i32,
     pub title: String,
     pub content: String,
+    pub description: Option<String>,
+    pub tags: StringArray,
+    pub created_at: i64,
+    pub modified_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+/// Represents the metadata of a document in the database, leaving out its content
+#[derive(Queryable, Selectable, Debug, Serialize, Deserialize, specta::Type)]
+#[diesel(table_name = documents)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DocumentMetadata {
+    pub id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub tags: StringArray,
+    pub created_at: i64,
+    pub modified_at: i64,
+    pub deleted_at: Option<i64>,
 }
 
 /// Represents a new document to be inserted into the database
@@ -39,11 +60,18 @@ pub struct Document {
 pub struct NewDocument<'a> {
     pub title: &'a str,
     pub content: &'a str,
+    pub description: Option<&'a str>,
+    pub tags: StringArray,
+    pub created_at: i64,
+    pub modified_at: i64,
 }
 
 /// Represents a small molecule in the database
-#[derive(Queryable, Identifiable, AsChangeset, Selectable, Debug, Serialize, Deserialize)]
+#[derive(
+    Queryable, Identifiable, AsChangeset, Selectable, Debug, Serialize, Deserialize, specta::Type,
+)]
 #[diesel(table_name = small_molecules)]
+#[diesel(treat_none_as_null = true)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 pub struct DBSmallMolecule {
     pub id: i32,
@@ -51,7 +79,7 @@ pub struct DBSmallMolecule {
     pub canonical_smiles: Option<String>,
     pub inchi: Option<String>,
     pub inchikey: Option<String>,
-    pub references: Option<String>,
+    pub references: StringArray,
 }
 
 /// Represents a new small molecule to be inserted into the database
@@ -63,12 +91,15 @@ pub struct DBNewSmallMolecule<'a> {
     pub canonical_smiles: Option<&'a str>,
     pub inchi: Option<&'a str>,
     pub inchikey: Option<&'a str>,
-    pub references: Option<String>,
+    pub references: StringArray,
 }
 
 /// Represents a protein in the database
-#[derive(Queryable, Identifiable, AsChangeset, Selectable, Debug)]
+#[derive(
+    Queryable, Identifiable, AsChangeset, Selectable, Debug, Serialize, Deserialize, specta::Type,
+)]
 #[diesel(table_name = proteins)]
+#[diesel(treat_none_as_null = true)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 pub struct DBProtein {
     pub id: i32,
@@ -80,7 +111,7 @@ pub struct DBProtein {
 }
 
 /// Represents a new protein to be inserted into the database
-#[derive(Insertable, Debug)]
+#[derive(Insertable, AsChangeset, Debug)]
 #[diesel(table_name = proteins)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 pub struct DBNewProtein<'a> {
@@ -92,7 +123,9 @@ pub struct DBNewProtein<'a> {
 }
 
 /// Represents a vessel in the database
-#[derive(Queryable, Identifiable, AsChangeset, Selectable, Debug)]
+#[derive(
+    Queryable, Identifiable, AsChangeset, Selectable, Debug, Serialize, Deserialize, specta::Type,
+)]
 #[diesel(table_name = vessels)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 pub struct DBVessel {
@@ -100,6 +133,7 @@ pub struct DBVessel {
     pub name: String,
     pub volume: f64,
     pub unit: String,
+    pub constant: bool,
 }
 
 /// Represents a new vessel to be inserted into the database
@@ -110,10 +144,13 @@ pub struct DBNewVessel<'a> {
     pub name: &'a str,
     pub volume: f64,
     pub unit: &'a str,
+    pub constant: bool,
 }
 
 /// Represents a creator in the database
-#[derive(Queryable, Identifiable, AsChangeset, Selectable, Debug)]
+#[derive(
+    Queryable, Identifiable, AsChangeset, Selectable, Debug, Serialize, Deserialize, specta::Type,
+)]
 #[diesel(table_name = creators)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 pub struct DBCreator {
@@ -124,7 +161,7 @@ pub struct DBCreator {
 }
 
 /// Represents a new creator to be inserted into the database
-#[derive(Insertable, Debug)]
+#[derive(Insertable, AsChangeset, Debug)]
 #[diesel(table_name = creators)]
 #[diesel(check_for_backend(diesel::sqlite::Sqlite))]
 pub struct DBNewCreator<'a> {
@@ -185,19 +222,13 @@ pub struct DBLawSpecies {
 /// Converts an EnzymeML SmallMolecule to a database SmallMolecule
 impl From<v2::SmallMolecule> for DBSmallMolecule {
     fn from(value: v2::SmallMolecule) -> Self {
-        let references = if !value.references.is_empty() {
-            Some(value.references.join(","))
-        } else {
-            None
-        };
-
         DBSmallMolecule {
             id: 0,
             name: value.name.clone(),
             canonical_smiles: value.canonical_smiles,
             inchi: value.inchi,
             inchikey: value.inchikey,
-            references,
+            references: StringArray(value.references),
         }
     }
 }
@@ -224,6 +255,7 @@ impl From<v2::Vessel> for DBVessel {
             name: value.name.clone(),
             volume: value.volume,
             unit: value.unit.name.clone().unwrap_or_default(),
+            constant: value.constant,
         }
     }
 }
@@ -244,18 +276,12 @@ impl From<v2::Creator> for DBCreator {
 /// Converts an EnzymeML SmallMolecule reference to a new database SmallMolecule
 impl<'a> From<&'a v2::SmallMolecule> for DBNewSmallMolecule<'a> {
     fn from(value: &'a v2::SmallMolecule) -> Self {
-        let references = if !value.references.is_empty() {
-            Some(value.references.join(","))
-        } else {
-            None
-        };
-
         DBNewSmallMolecule {
             name: &value.name,
             canonical_smiles: value.canonical_smiles.as_deref(),
             inchi: value.inchi.as_deref(),
             inchikey: value.inchikey.as_deref(),
-            references,
+            references: StringArray(value.references.clone()),
         }
     }
 }
@@ -280,6 +306,7 @@ impl<'a> From<&'a v2::Vessel> for DBNewVessel<'a> {
             name: &value.name,
             volume: value.volume,
             unit: value.unit.name.as_deref().unwrap_or_default(),
+            constant: value.constant,
         }
     }
 }
@@ -0,0 +1,289 @@
+//! Recovery snapshots of unsaved documents
+//!
+//! While the application is running, open documents with unsaved changes are
+//! periodically written to the recovery directory of the suite. A snapshot
+//! holds everything needed to reopen the document as it was, so unsaved edits
+//! survive a crash or a killed process. Snapshots are prefixed with the session
+//! that wrote them, which tells the leftovers of a previous run apart from the
+//! snapshots the running session keeps up to date.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use enzymeml::prelude::{EnzymeMLDocument, Parameter};
+use serde::{Deserialize, Serialize};
+
+use crate::actions::utils::seconds_since_epoch;
+use crate::docutils::write_atomically;
+use crate::error::SuiteError;
+use crate::states::{EnzymeMLState, FileVersion, Workspace};
+
+/// Interval in which unsaved documents are written to the recovery directory
+pub const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Extension of the snapshot files
+const SNAPSHOT_EXTENSION: &str = "json";
+
+/// Content of a recovery snapshot
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecoverySnapshot {
+    /// Title of the document
+    pub title: String,
+    /// The unsaved EnzymeML document
+    pub doc: EnzymeMLDocument,
+    /// Database ID of the document, if it has been saved before
+    pub id: Option<i32>,
+    /// File the document was loaded from or last saved to
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+    /// Content of the file when it was last loaded or saved
+    #[serde(default)]
+    pub file_version: Option<FileVersion>,
+    /// Parameters buffered while deriving equations
+    pub param_buffer: Vec<Parameter>,
+    /// Time the snapshot was written, in seconds since the Unix epoch
+    pub saved_at: u64,
+}
+
+impl RecoverySnapshot {
+    /// Takes a snapshot of the document held by a state
+    ///
+    /// # Arguments
+    /// * `state` - The state holding the document
+    pub fn from_state(state: &EnzymeMLState) -> Self {
+        RecoverySnapshot {
+            title: state.title.lock().unwrap().clone(),
+            doc: state.doc.lock().unwrap().clone(),
+            id: *state.id.lock().unwrap(),
+            path: state.path.lock().unwrap().clone(),
+            file_version: state.file_version.lock().unwrap().clone(),
+            param_buffer: state.param_buffer.lock().unwrap().clone(),
+            saved_at: seconds_since_epoch(),
+        }
+    }
+
+    /// Turns the snapshot back into a state
+    ///
+    /// The restored state is marked as dirty, since its changes have not been
+    /// saved yet.
+    pub fn into_state(self) -> EnzymeMLState {
+        let state = EnzymeMLState::with_document(self.doc);
+        *state.title.lock().unwrap() = self.title;
+        *state.id.lock().unwrap() = self.id;
+        *state.path.lock().unwrap() = self.path;
+        *state.file_version.lock().unwrap() = self.file_version;
+        *state.param_buffer.lock().unwrap() = self.param_buffer;
+        state.next_revision();
+
+        state
+    }
+}
+
+/// Summary of a leftover recovery snapshot offered for restoring
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct RecoveryEntry {
+    /// File name of the snapshot within the recovery directory
+    pub file: String,
+    /// Title of the document
+    pub title: String,
+    /// Database ID of the document, if it has been saved before
+    pub id: Option<i32>,
+    /// Time the snapshot was written, in seconds since the Unix epoch
+    pub saved_at: u64,
+}
+
+/// Writer of the recovery snapshots of a session
+#[derive(Debug)]
+pub struct Recovery {
+    /// Directory holding the snapshots
+    dir: PathBuf,
+    /// Prefix of the snapshots written by this session
+    session: String,
+    /// Revisions of the snapshots written by this session, keyed by handle
+    written: Mutex<HashMap<String, u64>>,
+}
+
+impl Recovery {
+    /// Creates the snapshot writer of a new session
+    ///
+    /// # Arguments
+    /// * `dir` - Directory holding the snapshots
+    pub fn new(dir: PathBuf) -> Self {
+        Recovery {
+            dir,
+            session: format!("{}-{}", seconds_since_epoch(), std::process::id()),
+            written: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Writes snapshots of all open documents with unsaved changes
+    ///
+    /// Documents are only written if they changed since their last snapshot.
+    /// Snapshots of documents that have been saved or closed meanwhile are
+    /// removed.
+    ///
+    /// # Arguments
+    /// * `workspace` - The open documents of the application
+    ///
+    /// # Returns
+    /// Result containing the number of written snapshots
+    pub fn autosave(&self, workspace: &Workspace) -> Result<usize, SuiteError> {
+        fs::create_dir_all(&self.dir).map_err(|e| SuiteError::io(&self.dir, e))?;
+
+        let documents = workspace.documents();
+        let mut written = self.written.lock().unwrap();
+        let mut count = 0;
+
+        for state in &documents {
+            if !state.is_dirty() {
+                if written.remove(&state.handle).is_some() {
+                    remove_snapshot(&self.snapshot_path(&state.handle))?;
+                }
+                continue;
+            }
+
+            let revision = *state.revision.lock().unwrap();
+            if written.get(&state.handle) == Some(&revision) {
+                continue;
+            }
+
+            let snapshot = RecoverySnapshot::from_state(state);
+            write_snapshot(&self.snapshot_path(&state.handle), &snapshot)?;
+            written.insert(state.handle.clone(), revision);
+            count += 1;
+        }
+
+        let closed: Vec<String> = written
+            .keys()
+            .filter(|handle| !documents.iter().any(|state| state.handle == **handle))
+            .cloned()
+            .collect();
+
+        for handle in closed {
+            written.remove(&handle);
+            remove_snapshot(&self.snapshot_path(&handle))?;
+        }
+
+        Ok(count)
+    }
+
+    /// Lists the snapshots left over by previous sessions
+    ///
+    /// Files that cannot be parsed as snapshot are skipped.
+    ///
+    /// # Returns
+    /// Result containing the leftover snapshots, the most recent first
+    pub fn leftovers(&self) -> Result<Vec<RecoveryEntry>, SuiteError> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&self.dir).map_err(|e| SuiteError::io(&self.dir, e))? {
+            let path = entry.map_err(|e| SuiteError::io(&self.dir, e))?.path();
+            let Some(file) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+
+            if !self.is_leftover(file) {
+                continue;
+            }
+
+            if let Ok(snapshot) = read_snapshot(&path) {
+                entries.push(RecoveryEntry {
+                    file: file.to_string(),
+                    title: snapshot.title,
+                    id: snapshot.id,
+                    saved_at: snapshot.saved_at,
+                });
+            }
+        }
+
+        entries.sort_by(|a, b| b.saved_at.cmp(&a.saved_at));
+        Ok(entries)
+    }
+
+    /// Restores a leftover snapshot and removes it from the recovery directory
+    ///
+    /// # Arguments
+    /// * `file` - File name of the snapshot within the recovery directory
+    ///
+    /// # Returns
+    /// Result containing the restored state, which still has to be opened
+    pub fn restore(&self, file: &str) -> Result<EnzymeMLState, SuiteError> {
+        let path = self.leftover_path(file)?;
+        let snapshot = read_snapshot(&path)?;
+        remove_snapshot(&path)?;
+
+        Ok(snapshot.into_state())
+    }
+
+    /// Removes a leftover snapshot without restoring it
+    ///
+    /// # Arguments
+    /// * `file` - File name of the snapshot within the recovery directory
+    pub fn discard(&self, file: &str) -> Result<(), SuiteError> {
+        remove_snapshot(&self.leftover_path(file)?)
+    }
+
+    /// Returns the path of the snapshot of an open document
+    fn snapshot_path(&self, handle: &str) -> PathBuf {
+        self.dir.join(format!(
+            "{}_{}.{}",
+            self.session, handle, SNAPSHOT_EXTENSION
+        ))
+    }
+
+    /// Returns whether a file name denotes a snapshot of a previous session
+    fn is_leftover(&self, file: &str) -> bool {
+        Path::new(file).extension().and_then(|ext| ext.to_str()) == Some(SNAPSHOT_EXTENSION)
+            && !file.starts_with(&format!("{}_", self.session))
+    }
+
+    /// Resolves the path of a leftover snapshot
+    ///
+    /// Only plain file names of leftover snapshots are accepted, so callers
+    /// cannot address other files or the snapshots of the running session.
+    fn leftover_path(&self, file: &str) -> Result<PathBuf, SuiteError> {
+        let is_plain = Path::new(file).file_name().and_then(|name| name.to_str()) == Some(file);
+        if !is_plain || !self.is_leftover(file) {
+            return Err(SuiteError::validation(format!(
+                "'{}' is not a recovery snapshot",
+                file
+            )));
+        }
+
+        let path = self.dir.join(file);
+        if !path.exists() {
+            return Err(SuiteError::not_found("Recovery snapshot", file));
+        }
+
+        Ok(path)
+    }
+}
+
+/// Reads a snapshot from a file
+fn read_snapshot(path: &Path) -> Result<RecoverySnapshot, SuiteError> {
+    let content = fs::read_to_string(path).map_err(|e| SuiteError::io(path, e))?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Writes a snapshot to a file
+///
+/// The snapshot is written atomically, so a crash while writing never leaves a
+/// truncated snapshot behind.
+fn write_snapshot(path: &Path, snapshot: &RecoverySnapshot) -> Result<(), SuiteError> {
+    write_atomically(path, &serde_json::to_string(snapshot)?)
+}
+
+/// Removes a snapshot, ignoring snapshots that do not exist
+fn remove_snapshot(path: &Path) -> Result<(), SuiteError> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(SuiteError::io(path, e)),
+        _ => Ok(()),
+    }
+}
@@ -8,6 +8,166 @@ diesel::table! {
         title -> Varchar,
         // The main content/body of the document
         content -> Text,
+        // Free text describing the document (optional)
+        description -> Nullable<Text>,
+        // Tags of the document as JSON array of strings
+        tags -> Text,
+        // Time the document was stored, in seconds since the Unix epoch
+        created_at -> BigInt,
+        // Time the document was last changed, in seconds since the Unix epoch
+        modified_at -> BigInt,
+        // Time the document was moved to the trash, if it has been (optional)
+        deleted_at -> Nullable<BigInt>,
+    }
+}
+
+// Single row table holding whether the normalised projection of documents is maintained
+diesel::table! {
+    projection_state (id) {
+        // Primary key, always 1
+        id -> Integer,
+        // Whether the projection tables are updated when documents are saved
+        enabled -> Bool,
+    }
+}
+
+// Species of stored documents, part of the normalised projection
+// Covers small molecules, proteins and complexes
+diesel::table! {
+    document_species (id) {
+        // Primary key for the projected species
+        id -> Integer,
+        // ID of the stored document holding the species
+        document_id -> Integer,
+        // ID of the species within the document
+        species_id -> Varchar,
+        // Kind of the species (small_molecule, protein or complex)
+        kind -> Varchar,
+        // Name of the species
+        name -> Varchar,
+        // InChI Key of a small molecule (optional)
+        inchikey -> Nullable<Varchar>,
+        // Amino acid sequence of a protein (optional)
+        sequence -> Nullable<Text>,
+        // EC number of a protein (optional)
+        ecnumber -> Nullable<Varchar>,
+        // Organism of a protein (optional)
+        organism -> Nullable<Varchar>,
+    }
+}
+
+// Reactions of stored documents, part of the normalised projection
+diesel::table! {
+    document_reactions (id) {
+        // Primary key for the projected reaction
+        id -> Integer,
+        // ID of the stored document holding the reaction
+        document_id -> Integer,
+        // ID of the reaction within the document
+        reaction_id -> Varchar,
+        // Name of the reaction
+        name -> Varchar,
+        // Whether the reaction is reversible
+        reversible -> Bool,
+        // Equation of the kinetic law of the reaction (optional)
+        kinetic_law -> Nullable<Text>,
+    }
+}
+
+// Participants of the reactions of stored documents, part of the normalised projection
+diesel::table! {
+    document_reaction_species (id) {
+        // Primary key for the reaction-species relationship
+        id -> Integer,
+        // ID of the stored document holding the reaction
+        document_id -> Integer,
+        // ID of the reaction within the document
+        reaction_id -> Varchar,
+        // ID of the participating species within the document
+        species_id -> Varchar,
+        // Role of the species in the reaction (reactant, product or modifier)
+        role -> Varchar,
+    }
+}
+
+// Parameters of stored documents, part of the normalised projection
+diesel::table! {
+    document_parameters (id) {
+        // Primary key for the projected parameter
+        id -> Integer,
+        // ID of the stored document holding the parameter
+        document_id -> Integer,
+        // ID of the parameter within the document
+        parameter_id -> Varchar,
+        // Name of the parameter
+        name -> Varchar,
+        // Symbol of the parameter in equations
+        symbol -> Varchar,
+        // Value of the parameter (optional)
+        value -> Nullable<Double>,
+        // Name of the unit of the parameter (optional)
+        unit -> Nullable<Varchar>,
+        // Standard error of the value (optional)
+        stderr -> Nullable<Double>,
+        // Lower bound of the value (optional)
+        lower_bound -> Nullable<Double>,
+        // Upper bound of the value (optional)
+        upper_bound -> Nullable<Double>,
+    }
+}
+
+// Species the parameters of stored documents refer to, part of the normalised projection
+// A parameter refers to the species of the kinetic laws and equations it appears in
+diesel::table! {
+    document_parameter_species (id) {
+        // Primary key for the parameter-species relationship
+        id -> Integer,
+        // ID of the stored document holding the parameter
+        document_id -> Integer,
+        // ID of the parameter within the document
+        parameter_id -> Varchar,
+        // ID of the species within the document
+        species_id -> Varchar,
+    }
+}
+
+// Measurements of stored documents, part of the normalised projection
+diesel::table! {
+    document_measurements (id) {
+        // Primary key for the projected measurement
+        id -> Integer,
+        // ID of the stored document holding the measurement
+        document_id -> Integer,
+        // ID of the measurement within the document
+        measurement_id -> Varchar,
+        // Name of the measurement
+        name -> Varchar,
+        // pH of the measurement (optional)
+        ph -> Nullable<Double>,
+        // Temperature of the measurement as stated in the document (optional)
+        temperature -> Nullable<Double>,
+        // Name of the unit of the temperature (optional)
+        temperature_unit -> Nullable<Varchar>,
+        // Temperature of the measurement in Kelvin, if the unit is known (optional)
+        temperature_kelvin -> Nullable<Double>,
+    }
+}
+
+// Initial conditions of the measurements of stored documents, part of the normalised projection
+diesel::table! {
+    document_measurement_species (id) {
+        // Primary key for the measurement-species relationship
+        id -> Integer,
+        // ID of the stored document holding the measurement
+        document_id -> Integer,
+        // ID of the measurement within the document
+        measurement_id -> Varchar,
+        // ID of the measured species within the document
+        species_id -> Varchar,
+        // Initial amount of the species (optional)
+        initial -> Nullable<Double>,
+        // Name of the unit of the data (optional)
+        data_unit -> Nullable<Varchar>,
     }
 }
 
@@ -44,8 +204,8 @@ diesel::table! {
         inchi -> Nullable<Text>,
         // InChI Key - hashed version of InChI for easier searching (optional)
         inchikey -> Nullable<Varchar>,
-        // Literature or database references for the molecule (optional)
-        references -> Nullable<Text>,
+        // Literature or database references for the molecule as JSON array of strings
+        references -> Text,
     }
 }
 
@@ -3,15 +3,44 @@ use enzymeml::{
     validation::consistency::{check_consistency, Report},
 };
 use regex::Regex;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
+use crate::error::SuiteError;
+
+/// State of an open EnzymeML document
+///
+/// Whenever several of the guarded values are held at once, they are locked
+/// in the order `doc`, `title`, `id`, `path`, `file_version`.
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct EnzymeMLState {
+    /// Handle of the document within the [`Workspace`], empty until opened
+    pub handle: String,
     pub title: Mutex<String>,
     pub doc: Mutex<EnzymeMLDocument>,
     pub id: Mutex<Option<i32>>,
+    /// File the document was loaded from or last saved to
+    pub path: Mutex<Option<PathBuf>>,
+    /// Content of the file at `path` when it was last loaded or saved
+    pub file_version: Mutex<Option<FileVersion>>,
     pub param_buffer: Mutex<Vec<Parameter>>,
     pub validation_report: Mutex<Report>,
+    /// Revision of the document, advanced with every change
+    pub revision: Mutex<u64>,
+    /// Revision at which the document was last saved or loaded
+    pub saved_revision: Mutex<u64>,
+}
+
+/// Content of a document file at the time it was loaded or saved
+///
+/// Serves to detect changes of the file made by other programs and as common
+/// base when merging such changes with the unsaved changes of the document.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct FileVersion {
+    /// SHA-256 digest of the file content
+    pub digest: String,
+    /// The document held by the file
+    pub doc: EnzymeMLDocument,
 }
 
 impl Default for EnzymeMLState {
@@ -24,16 +53,73 @@ impl Default for EnzymeMLState {
         let report = check_consistency(&enzmldoc);
 
         EnzymeMLState {
+            handle: String::new(),
             title: Mutex::new("Document Title".to_string()),
             doc: Mutex::new(enzmldoc),
             id: Mutex::new(None),
+            path: Mutex::new(None),
+            file_version: Mutex::new(None),
             param_buffer: Mutex::new(Vec::new()),
             validation_report: Mutex::new(report),
+            revision: Mutex::new(0),
+            saved_revision: Mutex::new(0),
         }
     }
 }
 
 impl EnzymeMLState {
+    /// Creates a state holding the given document
+    ///
+    /// The document is titled by its name and not associated with a database
+    /// entry. Used to run document actions outside of the application, such as
+    /// in the command line interface.
+    ///
+    /// # Arguments
+    /// * `doc` - The EnzymeML document to hold
+    pub fn with_document(doc: EnzymeMLDocument) -> Self {
+        let report = check_consistency(&doc);
+
+        EnzymeMLState {
+            handle: String::new(),
+            title: Mutex::new(doc.name.clone()),
+            doc: Mutex::new(doc),
+            id: Mutex::new(None),
+            path: Mutex::new(None),
+            file_version: Mutex::new(None),
+            param_buffer: Mutex::new(Vec::new()),
+            validation_report: Mutex::new(report),
+            revision: Mutex::new(0),
+            saved_revision: Mutex::new(0),
+        }
+    }
+
+    /// Advances the revision of the document
+    ///
+    /// Called for every change of the document, so listeners can order the
+    /// changes and discard outdated data.
+    ///
+    /// # Returns
+    /// The revision of the document after the change
+    pub fn next_revision(&self) -> u64 {
+        let mut revision = self.revision.lock().unwrap();
+        *revision += 1;
+        *revision
+    }
+
+    /// Marks the document as matching its stored or loaded version
+    ///
+    /// Called once the document has been saved or replaced by a stored one,
+    /// after the revision of that change has been taken.
+    pub fn mark_saved(&self) {
+        let revision = *self.revision.lock().unwrap();
+        *self.saved_revision.lock().unwrap() = revision;
+    }
+
+    /// Returns whether the document has changed since it was last saved or loaded
+    pub fn is_dirty(&self) -> bool {
+        *self.revision.lock().unwrap() != *self.saved_revision.lock().unwrap()
+    }
+
     /// Updates the validation report by checking consistency of the current document
     ///
     /// This method locks the document mutex, performs a consistency check on the
@@ -73,26 +159,294 @@ impl EnzymeMLState {
     }
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Debug)]
+/// Runtime state of the REST API shared between Tauri and Rocket
+///
+/// Holds the values that can be changed while the server is running, so that
+/// regenerating the token or toggling write access takes effect immediately.
+#[derive(Debug)]
+pub struct ApiState {
+    /// Token clients have to provide to access the API
+    pub token: Mutex<String>,
+    /// Whether routes that modify documents are enabled
+    pub allow_writes: Mutex<bool>,
+}
+
+impl ApiState {
+    /// Creates a new ApiState with the given token and write access
+    ///
+    /// # Arguments
+    /// * `token` - The API token of this install
+    /// * `allow_writes` - Whether routes that modify documents are enabled
+    pub fn new(token: String, allow_writes: bool) -> Self {
+        ApiState {
+            token: Mutex::new(token),
+            allow_writes: Mutex::new(allow_writes),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, specta::Type, Debug)]
 pub struct ExposedEnzymeMLState {
+    pub handle: String,
     pub title: String,
     pub doc: EnzymeMLDocument,
     pub id: Option<i32>,
+    pub path: Option<PathBuf>,
+    pub revision: u64,
+    pub dirty: bool,
 }
 
 impl From<&Arc<EnzymeMLState>> for ExposedEnzymeMLState {
     fn from(state: &Arc<EnzymeMLState>) -> Self {
-        let title = state.title.lock().unwrap();
         let doc = state.doc.lock().unwrap();
+        let title = state.title.lock().unwrap();
         let id = state.id.lock().unwrap();
+        let path = state.path.lock().unwrap();
+        let revision = state.revision.lock().unwrap();
+        let saved_revision = state.saved_revision.lock().unwrap();
 
         ExposedEnzymeMLState {
+            handle: state.handle.clone(),
             title: title.clone(),
             doc: doc.clone(),
             id: *id,
+            path: path.clone(),
+            revision: *revision,
+            dirty: *revision != *saved_revision,
         }
     }
 }
+/// Prefix of the handles assigned to open documents
+pub const DOCUMENT_HANDLE_PREFIX: &str = "doc";
+
+/// Documents opened side by side in the application
+///
+/// Every open document is addressed by a handle, which stays valid until the
+/// document is closed and is never reused afterwards. Commands and API routes
+/// operate on the active document unless a handle is given. The workspace
+/// always holds at least one document, closing the last one opens a new one.
+#[derive(Debug)]
+pub struct Workspace {
+    /// Open documents along with the active one, guarded by a single lock
+    documents: Mutex<OpenDocuments>,
+    /// Number of documents opened so far, used to generate the handles
+    opened: Mutex<u64>,
+}
+
+/// Open documents of a [`Workspace`] and the handle of the active one
+///
+/// Both are kept behind the same lock, so the active handle always refers to
+/// an open document.
+#[derive(Debug, Default)]
+struct OpenDocuments {
+    /// Open documents in the order they have been opened
+    states: Vec<Arc<EnzymeMLState>>,
+    /// Handle of the active document
+    active: String,
+}
+
+impl OpenDocuments {
+    /// Returns an open document by its handle
+    fn find(&self, handle: &str) -> Option<Arc<EnzymeMLState>> {
+        self.states
+            .iter()
+            .find(|state| state.handle == handle)
+            .cloned()
+    }
+}
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Workspace::new(EnzymeMLState::default())
+    }
+}
+
+impl Workspace {
+    /// Creates a workspace with a single, active document
+    ///
+    /// # Arguments
+    /// * `state` - The state of the document to open
+    pub fn new(state: EnzymeMLState) -> Self {
+        let workspace = Workspace {
+            documents: Mutex::new(OpenDocuments::default()),
+            opened: Mutex::new(0),
+        };
+
+        workspace.open(state);
+        workspace
+    }
+
+    /// Opens a document and makes it the active one
+    ///
+    /// # Arguments
+    /// * `state` - The state of the document to open
+    ///
+    /// # Returns
+    /// The opened state, carrying its newly assigned handle
+    pub fn open(&self, state: EnzymeMLState) -> Arc<EnzymeMLState> {
+        let state = self.assign_handle(state);
+
+        let mut documents = self.documents.lock().unwrap();
+        documents.states.push(state.clone());
+        documents.active = state.handle.clone();
+
+        state
+    }
+
+    /// Returns the active document
+    ///
+    /// # Returns
+    /// The state of the active document or an `Internal` error if it is not
+    /// open, which indicates a bug in the workspace
+    pub fn active(&self) -> Result<Arc<EnzymeMLState>, SuiteError> {
+        let documents = self.documents.lock().unwrap();
+        documents
+            .find(&documents.active)
+            .ok_or_else(|| SuiteError::internal("The active document is not open"))
+    }
+
+    /// Returns an open document by its handle or the active document
+    ///
+    /// # Arguments
+    /// * `handle` - Handle of the document, `None` for the active document
+    ///
+    /// # Returns
+    /// The state of the document or a `NotFound` error if no open document
+    /// has the given handle
+    pub fn document(&self, handle: Option<&str>) -> Result<Arc<EnzymeMLState>, SuiteError> {
+        match handle {
+            Some(handle) => self
+                .find(handle)
+                .ok_or_else(|| SuiteError::not_found("Document", handle)),
+            None => self.active(),
+        }
+    }
+
+    /// Makes an open document the active one
+    ///
+    /// # Arguments
+    /// * `handle` - Handle of the document to activate
+    ///
+    /// # Returns
+    /// The activated state or a `NotFound` error for unknown handles
+    pub fn switch(&self, handle: &str) -> Result<Arc<EnzymeMLState>, SuiteError> {
+        let mut documents = self.documents.lock().unwrap();
+        let state = documents
+            .find(handle)
+            .ok_or_else(|| SuiteError::not_found("Document", handle))?;
+
+        documents.active = state.handle.clone();
+        Ok(state)
+    }
+
+    /// Closes an open document
+    ///
+    /// If the active document is closed, its neighbour becomes active. Closing
+    /// the last document opens a new, empty one.
+    ///
+    /// # Arguments
+    /// * `handle` - Handle of the document to close
+    ///
+    /// # Returns
+    /// The active state after closing or a `NotFound` error for unknown handles
+    pub fn close(&self, handle: &str) -> Result<Arc<EnzymeMLState>, SuiteError> {
+        let mut documents = self.documents.lock().unwrap();
+        let position = documents
+            .states
+            .iter()
+            .position(|state| state.handle == handle)
+            .ok_or_else(|| SuiteError::not_found("Document", handle))?;
+
+        documents.states.remove(position);
+
+        if documents.active == handle {
+            let neighbour = documents.states.get(position.saturating_sub(1)).cloned();
+            let next = match neighbour {
+                Some(next) => next,
+                None => {
+                    let state = self.assign_handle(EnzymeMLState::default());
+                    documents.states.push(state.clone());
+                    state
+                }
+            };
+            documents.active = next.handle.clone();
+        }
+
+        documents
+            .find(&documents.active)
+            .ok_or_else(|| SuiteError::internal("The active document is not open"))
+    }
+
+    /// Returns all open documents in the order they have been opened
+    pub fn documents(&self) -> Vec<Arc<EnzymeMLState>> {
+        self.documents.lock().unwrap().states.clone()
+    }
+
+    /// Returns the open documents associated with a database entry
+    ///
+    /// # Arguments
+    /// * `id` - The database ID of the document
+    pub fn find_by_id(&self, id: i32) -> Vec<Arc<EnzymeMLState>> {
+        self.documents()
+            .into_iter()
+            .filter(|state| *state.id.lock().unwrap() == Some(id))
+            .collect()
+    }
+
+    /// Lists the open documents for tabs and API clients
+    pub fn list(&self) -> Vec<OpenDocument> {
+        let (states, active) = {
+            let documents = self.documents.lock().unwrap();
+            (documents.states.clone(), documents.active.clone())
+        };
+
+        states
+            .iter()
+            .map(|state| OpenDocument {
+                handle: state.handle.clone(),
+                title: state.title.lock().unwrap().clone(),
+                id: *state.id.lock().unwrap(),
+                active: state.handle == active,
+                dirty: state.is_dirty(),
+            })
+            .collect()
+    }
+
+    /// Returns an open document by its handle
+    fn find(&self, handle: &str) -> Option<Arc<EnzymeMLState>> {
+        self.documents.lock().unwrap().find(handle)
+    }
+
+    /// Assigns a new, unique handle to a document about to be opened
+    ///
+    /// # Arguments
+    /// * `state` - The state of the document to open
+    fn assign_handle(&self, mut state: EnzymeMLState) -> Arc<EnzymeMLState> {
+        let mut opened = self.opened.lock().unwrap();
+        *opened += 1;
+
+        state.handle = format!("{}{}", DOCUMENT_HANDLE_PREFIX, *opened);
+        Arc::new(state)
+    }
+}
+
+/// Summary of a document open in the [`Workspace`]
+#[derive(
+    Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type, schemars::JsonSchema,
+)]
+pub struct OpenDocument {
+    /// Handle addressing the document while it is open
+    pub handle: String,
+    /// Title of the document
+    pub title: String,
+    /// Database ID of the document, if it has been saved
+    pub id: Option<i32>,
+    /// Whether the document is the active one
+    pub active: bool,
+    /// Whether the document has unsaved changes
+    pub dirty: bool,
+}
+
 /// Represents a detected Python installation
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
 pub struct PythonInstallation {
@@ -4,7 +4,12 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::error::Error;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Group of units offered for a kind of quantity
+///
+/// Exported as `UnitGroup` to the frontend, since the EnzymeML `UnitType` of
+/// base units is exported under its own name.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[specta(rename = "UnitGroup")]
 pub enum UnitType {
     Volume,
     Mass,
@@ -0,0 +1,99 @@
+//! Detection of changes made to the origin files of open documents
+//!
+//! Documents loaded from or saved to a file remember the digest of the file
+//! content at that time. The watcher periodically compares the files against
+//! these digests and reports files changed by other programs, such as a
+//! notebook rewriting the document, instead of letting both versions silently
+//! diverge. Every external version of a file is reported once, until the
+//! change has been resolved by reloading, keeping or merging.
+
+use std::collections::HashMap;
+use std::fs;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::docutils::digest;
+use crate::error::SuiteError;
+use crate::events::{EventSink, FileChanged, SuiteEvent};
+use crate::states::Workspace;
+
+/// Interval in which the origin files of open documents are checked
+pub const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Watcher of the origin files of the open documents
+#[derive(Debug, Default)]
+pub struct FileWatcher {
+    /// Digests of the reported external versions, keyed by handle
+    reported: Mutex<HashMap<String, String>>,
+}
+
+impl FileWatcher {
+    /// Creates a watcher that has not reported any changes yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reports the origin files that changed since they were loaded or saved
+    ///
+    /// Files that cannot be read, e.g. because they are being written or have
+    /// been removed, are skipped and checked again next time.
+    ///
+    /// # Arguments
+    /// * `workspace` - The open documents of the application
+    /// * `events` - Sink notified about changed files
+    ///
+    /// # Returns
+    /// Result containing the number of newly reported files
+    pub fn check(
+        &self,
+        workspace: &Workspace,
+        events: &impl EventSink,
+    ) -> Result<usize, SuiteError> {
+        let documents = workspace.documents();
+        let mut reported = self.reported.lock().unwrap();
+        let mut count = 0;
+
+        for state in &documents {
+            let Some(path) = state.path.lock().unwrap().clone() else {
+                continue;
+            };
+            let Some(known) = state
+                .file_version
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|version| version.digest.clone())
+            else {
+                continue;
+            };
+            let Ok(content) = fs::read_to_string(&path) else {
+                continue;
+            };
+
+            let current = digest(&content);
+            if current == known {
+                reported.remove(&state.handle);
+                continue;
+            }
+
+            if reported.get(&state.handle) == Some(&current) {
+                continue;
+            }
+
+            events
+                .emit_event(SuiteEvent::File(FileChanged {
+                    document: state.handle.clone(),
+                    path,
+                    dirty: state.is_dirty(),
+                }))
+                .map_err(SuiteError::internal)?;
+
+            reported.insert(state.handle.clone(), current);
+            count += 1;
+        }
+
+        reported.retain(|handle, _| documents.iter().any(|state| state.handle == *handle));
+
+        Ok(count)
+    }
+}
@@ -0,0 +1,38 @@
+//! Check that the TypeScript bindings of the frontend are up to date
+//!
+//! The bindings are exported into a temporary directory and compared to the
+//! checked in file. Set `UPDATE_BINDINGS=1` to regenerate it instead.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use enzymeml_suite::bindings::{self, BINDINGS_DIR, BINDINGS_FILE};
+
+fn checked_in_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(BINDINGS_DIR)
+}
+
+#[test]
+fn bindings_are_up_to_date() {
+    if std::env::var("UPDATE_BINDINGS").is_ok_and(|value| value == "1") {
+        bindings::export(&checked_in_dir()).expect("Failed to regenerate bindings");
+        return;
+    }
+
+    let generated_dir =
+        std::env::temp_dir().join(format!("enzymeml-suite-bindings-{}", std::process::id()));
+    fs::create_dir_all(&generated_dir).expect("Failed to create temporary directory");
+    bindings::export(&generated_dir).expect("Failed to export bindings");
+
+    let generated = fs::read_to_string(generated_dir.join(BINDINGS_FILE)).unwrap_or_default();
+    let checked_in = fs::read_to_string(checked_in_dir().join(BINDINGS_FILE)).unwrap_or_default();
+
+    fs::remove_dir_all(&generated_dir).ok();
+
+    assert!(
+        generated == checked_in,
+        "TypeScript bindings in {} are out of date. Regenerate them with \
+         `UPDATE_BINDINGS=1 cargo test --test bindings`",
+        BINDINGS_FILE
+    );
+}
@@ -0,0 +1,133 @@
+//! Fixtures shared by the integration tests
+//!
+//! Every test file is compiled on its own and uses only some of the fixtures.
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use enzymeml::prelude::{
+    EnzymeMLDocument, EnzymeMLDocumentBuilder, Equation, EquationBuilder, EquationType, Parameter,
+    ParameterBuilder, Reaction, ReactionBuilder, ReactionElement, SmallMolecule,
+    SmallMoleculeBuilder,
+};
+
+use enzymeml_suite::db;
+use enzymeml_suite::libraries::Library;
+
+/// Library in a temporary directory, which is removed when dropped
+///
+/// The application works with one library at a time, so tests using a
+/// temporary library run one after another.
+pub struct TemporaryLibrary {
+    pub dir: PathBuf,
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl Drop for TemporaryLibrary {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+static LIBRARY_LOCK: Mutex<()> = Mutex::new(());
+
+/// Creates an empty library in a new temporary directory and opens it
+///
+/// # Arguments
+/// * `name` - Name distinguishing the directory of the test file
+pub fn open_temporary_library(name: &str) -> TemporaryLibrary {
+    let guard = LIBRARY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!(
+        "enzymeml-suite-{}-{}-{}",
+        name,
+        std::process::id(),
+        nanos
+    ));
+    std::fs::create_dir_all(&dir).expect("Failed to create temporary directory");
+
+    db::open(Library {
+        name: "Test".to_string(),
+        path: dir.join("db.sqlite").to_string_lossy().to_string(),
+    })
+    .expect("Failed to open temporary library");
+
+    TemporaryLibrary { dir, _guard: guard }
+}
+
+pub fn document(name: &str) -> EnzymeMLDocument {
+    EnzymeMLDocumentBuilder::default()
+        .name(name)
+        .build()
+        .expect("Failed to build document")
+}
+
+pub fn small_molecule(id: &str, name: &str) -> SmallMolecule {
+    SmallMoleculeBuilder::default()
+        .id(id.to_string())
+        .name(name.to_string())
+        .constant(false)
+        .build()
+        .expect("Failed to build small molecule")
+}
+
+pub fn parameter(id: &str) -> Parameter {
+    ParameterBuilder::default()
+        .id(id.to_string())
+        .name(id.to_string())
+        .symbol(id.to_string())
+        .build()
+        .expect("Failed to build parameter")
+}
+
+pub fn equation(species_id: &str, equation_type: EquationType, equation: &str) -> Equation {
+    EquationBuilder::default()
+        .species_id(species_id.to_string())
+        .equation_type(equation_type)
+        .equation(equation.to_string())
+        .build()
+        .expect("Failed to build equation")
+}
+
+pub fn element(species_id: &str, stoichiometry: f64) -> ReactionElement {
+    serde_json::from_value(serde_json::json!({
+        "species_id": species_id,
+        "stoichiometry": stoichiometry,
+    }))
+    .expect("Failed to build reaction element")
+}
+
+pub fn reaction(
+    id: &str,
+    reactants: &[(&str, f64)],
+    products: &[(&str, f64)],
+    law: &str,
+) -> Reaction {
+    let mut reaction = ReactionBuilder::default()
+        .id(id.to_string())
+        .name(id.to_string())
+        .reversible(false)
+        .build()
+        .expect("Failed to build reaction");
+
+    reaction.reactants = reactants.iter().map(|(s, n)| element(s, *n)).collect();
+    reaction.products = products.iter().map(|(s, n)| element(s, *n)).collect();
+    reaction.kinetic_law = Some(equation("", EquationType::RateLaw, law));
+
+    reaction
+}
+
+/// Gets the ODE of a species, which has to exist
+pub fn ode<'a>(doc: &'a EnzymeMLDocument, species_id: &str) -> &'a str {
+    &doc.equations
+        .iter()
+        .find(|e| e.species_id == species_id)
+        .expect("Missing equation")
+        .equation
+}
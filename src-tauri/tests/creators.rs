@@ -0,0 +1,40 @@
+//! Tests of searching the creators saved in the library
+
+mod common;
+
+use diesel::prelude::*;
+
+use enzymeml_suite::db::establish_connection;
+use enzymeml_suite::io::dbops::filter_creators;
+
+use common::open_temporary_library;
+
+fn mails(query: &str) -> Vec<String> {
+    filter_creators(query.to_string())
+        .expect("Failed to filter creators")
+        .into_iter()
+        .map(|creator| creator.mail)
+        .collect()
+}
+
+#[test]
+fn wildcards_in_creator_searches_match_literally() {
+    let _library = open_temporary_library("creators");
+    let mut connection = establish_connection().expect("Failed to connect to the library");
+    diesel::sql_query(
+        "INSERT INTO `creators` (`given_name`, `family_name`, `mail`) VALUES \
+         ('Jane', 'Doe', 'j_doe@example.org'), \
+         ('John', 'Doe', 'jxdoe@example.org'), \
+         ('Max', 'Mustermann', 'max%@example.org')",
+    )
+    .execute(&mut connection)
+    .expect("Failed to insert creators");
+
+    assert_eq!(mails("j_doe"), vec!["j_doe@example.org"]);
+    assert_eq!(mails("%"), vec!["max%@example.org"]);
+    assert_eq!(
+        mails("doe J"),
+        vec!["j_doe@example.org", "jxdoe@example.org"]
+    );
+    assert!(mails("\\").is_empty());
+}
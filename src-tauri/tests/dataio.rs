@@ -0,0 +1,77 @@
+//! Tests of listing the documents stored in the library
+//!
+//! A library is created in a temporary directory, so the documents are stored
+//! and queried by the same code paths as in the application.
+
+mod common;
+
+use enzymeml_suite::io::dataio::{
+    describe_document, insert_document, query_documents, restore_stored_document,
+    trash_stored_document, DocumentQuery, DocumentSort,
+};
+
+use common::{document, open_temporary_library};
+
+fn titles(text: &str, trashed: bool) -> Vec<String> {
+    let query = DocumentQuery {
+        text: Some(text.to_string()),
+        trashed,
+        sort: DocumentSort::Title,
+        ..Default::default()
+    };
+
+    query_documents(&query)
+        .expect("Failed to query documents")
+        .into_iter()
+        .map(|entry| entry.title)
+        .collect()
+}
+
+fn store(title: &str) -> i32 {
+    insert_document(title, &document(title)).expect("Failed to store document")
+}
+
+#[test]
+fn wildcards_in_document_searches_match_literally() {
+    let _library = open_temporary_library("dataio-search");
+    store("Yield 100%");
+    let described = store("Yield 1000");
+    store("k_cat screen");
+    store("kxcat screen");
+    describe_document(described, Some("Half of the substrate (50%) converted"))
+        .expect("Failed to describe document");
+
+    assert_eq!(titles("Yield", false), vec!["Yield 100%", "Yield 1000"]);
+    assert_eq!(titles("%", false), vec!["Yield 100%", "Yield 1000"]);
+    assert_eq!(titles("100%", false), vec!["Yield 100%"]);
+    assert_eq!(titles("50%", false), vec!["Yield 1000"]);
+    assert_eq!(titles("K_CAT", false), vec!["k_cat screen"]);
+    assert_eq!(titles("_", false), vec!["k_cat screen"]);
+    assert!(titles("\\", false).is_empty());
+}
+
+#[test]
+fn trashed_documents_are_listed_apart_until_restored() {
+    let _library = open_temporary_library("dataio-trash");
+    let id = store("Laccase kinetics");
+    store("Laccase inhibition");
+
+    trash_stored_document(id).expect("Failed to trash document");
+    assert_eq!(titles("Laccase", false), vec!["Laccase inhibition"]);
+
+    let trashed = query_documents(&DocumentQuery {
+        trashed: true,
+        ..Default::default()
+    })
+    .expect("Failed to query documents");
+    assert_eq!(trashed.len(), 1);
+    assert_eq!(trashed[0].id, id);
+    assert!(trashed[0].deleted_at.is_some());
+
+    restore_stored_document(id).expect("Failed to restore document");
+    assert_eq!(
+        titles("Laccase", false),
+        vec!["Laccase inhibition", "Laccase kinetics"]
+    );
+    assert!(titles("Laccase", true).is_empty());
+}
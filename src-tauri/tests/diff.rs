@@ -0,0 +1,106 @@
+//! Tests of the structural comparison of two documents
+
+mod common;
+
+use enzymeml::prelude::{EnzymeMLDocument, Measurement};
+
+use enzymeml_suite::document::diff::{diff_documents, DiffEntity, DiffOptions, DiffStatus};
+use enzymeml_suite::error::SuiteError;
+
+use common::{document, parameter};
+
+fn measurement(data: &[f64]) -> Measurement {
+    serde_json::from_value(serde_json::json!({
+        "id": "m0",
+        "name": "Initial rates",
+        "species_data": [{
+            "species_id": "s0",
+            "initial": 10.0,
+            "time": [0.0, 1.0, 2.0],
+            "data": data,
+        }],
+    }))
+    .expect("Failed to build measurement")
+}
+
+fn kinetics(km: f64, data: &[f64]) -> EnzymeMLDocument {
+    let mut doc = document("Kinetics");
+    let mut km_parameter = parameter("Km");
+    km_parameter.value = Some(km);
+    doc.parameters.push(km_parameter);
+    doc.measurements.push(measurement(data));
+    doc
+}
+
+fn options(tolerance: f64) -> DiffOptions {
+    DiffOptions {
+        match_by_name: false,
+        tolerance,
+    }
+}
+
+#[test]
+fn negative_and_non_finite_tolerances_are_rejected() {
+    let doc = kinetics(12.5, &[10.0, 8.0, 6.5]);
+
+    for tolerance in [-0.1, f64::NAN, f64::INFINITY] {
+        let result = diff_documents(&doc, &doc, &options(tolerance));
+        assert!(
+            matches!(result, Err(SuiteError::Validation { .. })),
+            "tolerance {} was accepted",
+            tolerance
+        );
+    }
+
+    let diff = diff_documents(&doc, &doc, &options(0.0)).expect("Failed to compare documents");
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn numbers_are_equal_within_the_tolerance() {
+    let old = kinetics(12.5, &[10.0, 8.0, 6.5]);
+    let new = kinetics(12.5004, &[10.0, 8.0002, 6.5]);
+
+    let diff = diff_documents(&old, &new, &options(0.001)).expect("Failed to compare documents");
+    assert!(diff.is_empty(), "unexpected differences: {:?}", diff);
+
+    let diff = diff_documents(&old, &new, &options(0.0)).expect("Failed to compare documents");
+    assert!(diff.changes.is_empty());
+    assert_eq!(diff.entities.len(), 2);
+}
+
+#[test]
+fn numbers_beyond_the_tolerance_are_reported() {
+    let old = kinetics(12.5, &[10.0, 8.0, 6.5]);
+    let new = kinetics(12.6, &[10.0, 8.0, 6.4]);
+
+    let diff = diff_documents(&old, &new, &options(0.01)).expect("Failed to compare documents");
+    assert!(diff.changes.is_empty());
+
+    let parameter = diff
+        .entities
+        .iter()
+        .find(|entity| entity.kind == DiffEntity::Parameter)
+        .expect("Missing parameter difference");
+    assert_eq!(parameter.id, "Km");
+    assert_eq!(parameter.status, DiffStatus::Modified);
+    assert_eq!(parameter.changes.len(), 1);
+    assert_eq!(parameter.changes[0].path, "/value");
+    assert_eq!(parameter.changes[0].old.as_deref(), Some("12.5"));
+    assert_eq!(parameter.changes[0].new.as_deref(), Some("12.6"));
+
+    // Data arrays differ as a whole, addressed by the species of the data
+    let measurement = diff
+        .entities
+        .iter()
+        .find(|entity| entity.kind == DiffEntity::Measurement)
+        .expect("Missing measurement difference");
+    assert_eq!(measurement.id, "m0");
+    assert_eq!(measurement.status, DiffStatus::Modified);
+    assert_eq!(measurement.changes.len(), 1);
+    assert_eq!(measurement.changes[0].path, "/species_data/s0/data");
+    assert_eq!(
+        measurement.changes[0].new.as_deref(),
+        Some("[10.0,8.0,6.4]")
+    );
+}
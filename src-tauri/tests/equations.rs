@@ -0,0 +1,200 @@
+//! Tests of the equation handling of the document library
+//!
+//! The document operations are exercised directly on an [`EnzymeMLState`],
+//! without a running application, while a [`RecordingSink`] captures the
+//! events that would have been sent to the frontend.
+
+mod common;
+
+use std::sync::Mutex;
+
+use enzymeml::prelude::{EnzymeMLDocument, EquationType, ParameterBuilder};
+
+use enzymeml_suite::document::equations::{
+    cleanup_parameters, derive_equations, derive_from_reactions, process_equation,
+};
+use enzymeml_suite::events::{EventSink, SuiteEvent};
+use enzymeml_suite::states::EnzymeMLState;
+
+use common::{document, equation, ode, reaction, small_molecule};
+
+/// Event sink remembering the names of all emitted events
+#[derive(Default)]
+struct RecordingSink {
+    events: Mutex<Vec<String>>,
+}
+
+impl RecordingSink {
+    fn events(&self) -> Vec<String> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl EventSink for RecordingSink {
+    fn emit_event(&self, event: SuiteEvent) -> Result<(), String> {
+        self.events.lock().unwrap().push(event.name().to_string());
+        Ok(())
+    }
+}
+
+/// Document with the conversion of `s0` into `s1` following mass action kinetics
+fn conversion_document() -> EnzymeMLDocument {
+    let mut doc = document("Conversion");
+
+    doc.small_molecules = vec![small_molecule("s0", "s0"), small_molecule("s1", "s1")];
+    doc.equations = vec![
+        equation("s0", EquationType::Ode, ""),
+        equation("s1", EquationType::Ode, ""),
+    ];
+    doc.reactions = vec![reaction("r0", &[("s0", 1.0)], &[("s1", 1.0)], "k * s0")];
+
+    doc
+}
+
+fn parameter_ids(state: &EnzymeMLState) -> Vec<String> {
+    let doc = state.doc.lock().unwrap();
+    doc.parameters.iter().map(|p| p.id.clone()).collect()
+}
+
+#[test]
+fn derive_from_reactions_builds_odes_and_notifies() {
+    let state = EnzymeMLState::with_document(conversion_document());
+    let sink = RecordingSink::default();
+
+    derive_from_reactions(&state, &sink).expect("Failed to derive equations");
+
+    {
+        let doc = state.doc.lock().unwrap();
+        assert_eq!(ode(&doc, "s0"), "-k * s0");
+        assert_eq!(ode(&doc, "s1"), "k * s0");
+    }
+
+    assert_eq!(parameter_ids(&state), vec!["k".to_string()]);
+    assert_eq!(sink.events(), vec!["document_changed", "update_report"]);
+    assert_eq!(*state.revision.lock().unwrap(), 1);
+}
+
+#[test]
+fn derive_equations_weights_by_stoichiometry() {
+    let mut doc = conversion_document();
+    doc.reactions = vec![reaction("r0", &[("s0", 2.0)], &[("s1", 1.0)], "k * s0")];
+    let state = EnzymeMLState::with_document(doc);
+
+    derive_equations(&state).expect("Failed to derive equations");
+
+    let doc = state.doc.lock().unwrap();
+    assert_eq!(ode(&doc, "s0"), "-2 * k * s0");
+    assert_eq!(ode(&doc, "s1"), "k * s0");
+}
+
+#[test]
+fn derive_equations_requires_kinetic_laws() {
+    let mut doc = conversion_document();
+    doc.reactions[0].kinetic_law = None;
+    let state = EnzymeMLState::with_document(doc);
+
+    assert!(derive_equations(&state).is_err());
+
+    let mut doc = conversion_document();
+    doc.reactions.clear();
+    let state = EnzymeMLState::with_document(doc);
+
+    assert!(derive_equations(&state).is_err());
+}
+
+#[test]
+fn process_equation_adds_unknown_symbols_as_parameters() {
+    let state = EnzymeMLState::with_document(conversion_document());
+
+    process_equation(
+        &state,
+        &equation("s0", EquationType::Ode, "-kcat * s0 / (km + s0)"),
+    )
+    .expect("Failed to process equation");
+
+    let mut ids = parameter_ids(&state);
+    ids.sort();
+    assert_eq!(ids, vec!["kcat".to_string(), "km".to_string()]);
+}
+
+#[test]
+fn process_equation_rejects_invalid_equations() {
+    let state = EnzymeMLState::with_document(conversion_document());
+
+    assert!(process_equation(&state, &equation("s0", EquationType::Ode, "k * (s0")).is_err());
+    assert!(parameter_ids(&state).is_empty());
+}
+
+#[test]
+fn process_equation_restores_parameters_from_buffer() {
+    let state = EnzymeMLState::with_document(conversion_document());
+
+    let mut buffered = ParameterBuilder::default()
+        .id("k".to_string())
+        .name("Rate constant".to_string())
+        .symbol("k".to_string())
+        .build()
+        .expect("Failed to build parameter");
+    buffered.value = Some(4.2);
+    state.param_buffer.lock().unwrap().push(buffered);
+
+    process_equation(&state, &equation("s0", EquationType::Ode, "-k * s0"))
+        .expect("Failed to process equation");
+
+    let doc = state.doc.lock().unwrap();
+    assert_eq!(doc.parameters.len(), 1);
+    assert_eq!(doc.parameters[0].name, "Rate constant");
+    assert_eq!(doc.parameters[0].value, Some(4.2));
+    assert!(state.param_buffer.lock().unwrap().is_empty());
+}
+
+#[test]
+fn cleanup_parameters_moves_unused_parameters_to_buffer() {
+    let state = EnzymeMLState::with_document(conversion_document());
+    let sink = RecordingSink::default();
+
+    derive_from_reactions(&state, &sink).expect("Failed to derive equations");
+    {
+        let mut doc = state.doc.lock().unwrap();
+        for equation in doc.equations.iter_mut() {
+            equation.equation = "0".to_string();
+        }
+    }
+
+    cleanup_parameters(&state);
+
+    assert!(parameter_ids(&state).is_empty());
+
+    let buffer = state.param_buffer.lock().unwrap();
+    assert_eq!(buffer.len(), 1);
+    assert_eq!(buffer[0].id, "k");
+}
+
+#[test]
+fn cleanup_parameters_caps_the_buffer() {
+    let state = EnzymeMLState::with_document(conversion_document());
+
+    {
+        let mut doc = state.doc.lock().unwrap();
+        for equation in doc.equations.iter_mut() {
+            equation.equation = "0".to_string();
+        }
+        doc.parameters = (0..12)
+            .map(|i| {
+                ParameterBuilder::default()
+                    .id(format!("p{}", i))
+                    .name(format!("p{}", i))
+                    .symbol(format!("p{}", i))
+                    .build()
+                    .expect("Failed to build parameter")
+            })
+            .collect();
+    }
+
+    cleanup_parameters(&state);
+
+    let buffer = state.param_buffer.lock().unwrap();
+    assert_eq!(buffer.len(), 10);
+    assert_eq!(buffer[0].id, "p2");
+    assert_eq!(buffer[9].id, "p11");
+}
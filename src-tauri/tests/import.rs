@@ -0,0 +1,158 @@
+//! Tests of importing the entities of one document into another
+//!
+//! The imports are exercised on plain documents via [`import_into`], which
+//! resolves duplicates and rewrites the references of the imported entities.
+
+mod common;
+
+use enzymeml::prelude::{EquationType, MeasurementBuilder, MeasurementDataBuilder, SmallMolecule};
+
+use enzymeml_suite::document::diff::DiffEntity;
+use enzymeml_suite::document::import::{
+    import_into, DuplicateMatch, ImportOptions, RemappedId, Resolution,
+};
+
+use common::{document, equation, ode, parameter, reaction, small_molecule};
+
+const GLUCOSE_INCHIKEY: &str = "WQZGKKKJIJFFOK-GASJEMHNSA-N";
+
+/// Small molecule identified as glucose by its InChI Key
+fn glucose(id: &str, name: &str) -> SmallMolecule {
+    let mut molecule = small_molecule(id, name);
+    molecule.inchikey = Some(GLUCOSE_INCHIKEY.to_string());
+
+    molecule
+}
+
+fn options(strategy: Resolution) -> ImportOptions {
+    ImportOptions {
+        strategy,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn reused_species_are_remapped_in_reactions_equations_and_measurements() {
+    let mut target = document("Target");
+    target.small_molecules = vec![glucose("s0", "Glucose")];
+
+    let mut incoming = document("Incoming");
+    incoming.small_molecules = vec![
+        glucose("s7", "D-Glucose"),
+        small_molecule("s8", "Gluconolactone"),
+    ];
+    incoming.reactions = vec![reaction("r0", &[("s7", 1.0)], &[("s8", 1.0)], "k * s7")];
+    incoming.equations = vec![equation("s7", EquationType::Ode, "-k * s7")];
+    incoming.measurements = vec![MeasurementBuilder::default()
+        .id("m0")
+        .name("Initial rates")
+        .species_data(vec![MeasurementDataBuilder::default()
+            .species_id("s7")
+            .initial(10.0)
+            .build()
+            .expect("Failed to build measurement data")])
+        .build()
+        .expect("Failed to build measurement")];
+
+    let (merged, report) = import_into(&target, &incoming, &options(Resolution::Reuse))
+        .expect("Failed to import document");
+
+    let ids: Vec<&str> = merged
+        .small_molecules
+        .iter()
+        .map(|s| s.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["s0", "s8"]);
+
+    let reaction = &merged.reactions[0];
+    assert_eq!(reaction.reactants[0].species_id, "s0");
+    assert_eq!(reaction.products[0].species_id, "s8");
+    assert_eq!(
+        reaction
+            .kinetic_law
+            .as_ref()
+            .map(|law| law.equation.as_str()),
+        Some("k * s0")
+    );
+
+    assert_eq!(ode(&merged, "s0"), "-k * s0");
+    assert_eq!(merged.measurements[0].species_data[0].species_id, "s0");
+
+    assert_eq!(report.duplicates.len(), 1);
+    assert_eq!(report.duplicates[0].matched_by, DuplicateMatch::Inchikey);
+    assert_eq!(report.duplicates[0].existing_id, "s0");
+    assert!(report.remapped.contains(&RemappedId {
+        kind: DiffEntity::SmallMolecule,
+        from: "s7".to_string(),
+        to: "s0".to_string(),
+    }));
+}
+
+#[test]
+fn colliding_parameters_are_renamed_and_rewritten_in_equations() {
+    let mut target = document("Target");
+    target.small_molecules = vec![small_molecule("s0", "Glucose")];
+    target.parameters = vec![parameter("k")];
+    target.reactions = vec![reaction("r0", &[("s0", 1.0)], &[("s0", 1.0)], "k * s0")];
+
+    let mut incoming = document("Incoming");
+    incoming.small_molecules = vec![small_molecule("s0", "Glucose")];
+    incoming.parameters = vec![parameter("k")];
+    incoming.reactions = vec![reaction("r0", &[("s0", 1.0)], &[("s0", 1.0)], "k * s0")];
+
+    let mut options = options(Resolution::Reuse);
+    options
+        .resolutions
+        .insert("k".to_string(), Resolution::KeepBoth);
+
+    let (merged, report) =
+        import_into(&target, &incoming, &options).expect("Failed to import document");
+
+    let parameters: Vec<(&str, &str)> = merged
+        .parameters
+        .iter()
+        .map(|p| (p.id.as_str(), p.symbol.as_str()))
+        .collect();
+    assert_eq!(parameters, vec![("k", "k"), ("k_1", "k_1")]);
+
+    let laws: Vec<(&str, &str)> = merged
+        .reactions
+        .iter()
+        .map(|r| {
+            let law = r.kinetic_law.as_ref().expect("Missing kinetic law");
+            (r.id.as_str(), law.equation.as_str())
+        })
+        .collect();
+    assert_eq!(laws, vec![("r0", "k * s0"), ("r1", "k_1 * s0")]);
+
+    assert!(report.remapped.contains(&RemappedId {
+        kind: DiffEntity::Parameter,
+        from: "k".to_string(),
+        to: "k_1".to_string(),
+    }));
+}
+
+#[test]
+fn replaced_species_take_over_the_imported_equation() {
+    let mut target = document("Target");
+    target.small_molecules = vec![small_molecule("s0", "Glucose")];
+    target.equations = vec![equation("s0", EquationType::Ode, "-k * s0")];
+
+    let mut incoming = document("Incoming");
+    incoming.small_molecules = vec![small_molecule("s3", "Glucose")];
+    incoming.equations = vec![equation("s3", EquationType::Ode, "-kcat * s3")];
+
+    let (reused, _) = import_into(&target, &incoming, &options(Resolution::Reuse))
+        .expect("Failed to import document");
+    assert_eq!(ode(&reused, "s0"), "-k * s0");
+
+    let (replaced, report) = import_into(&target, &incoming, &options(Resolution::Replace))
+        .expect("Failed to import document");
+
+    assert_eq!(replaced.small_molecules.len(), 1);
+    assert_eq!(replaced.small_molecules[0].id, "s0");
+    assert_eq!(replaced.equations.len(), 1);
+    assert_eq!(ode(&replaced, "s0"), "-kcat * s0");
+    assert_eq!(report.duplicates[0].resolution, Resolution::Replace);
+    assert!(report.added.is_empty());
+}
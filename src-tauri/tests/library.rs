@@ -0,0 +1,171 @@
+//! Tests of reading the references of library entries in the legacy format
+//!
+//! Older versions stored the references of small molecules as a single comma
+//! separated string. Archives are read by [`StringArray::deserialize_legacy`]
+//! and saved small molecules are converted by the library entries migration,
+//! which both have to split the string the same way.
+
+use diesel::prelude::*;
+use diesel::sql_types::{Integer, Text};
+use diesel::sqlite::SqliteConnection;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use serde::Deserialize;
+
+use enzymeml_suite::io::stringarray::StringArray;
+
+const MIGRATIONS: EmbeddedMigrations = embed_migrations!();
+const LIBRARY_ENTRIES: &str = "library-entries";
+const URL_WITH_COMMA: &str = "https://example.org/search?q=a,b, PMID:123";
+
+#[derive(Debug, Deserialize)]
+struct Entry {
+    #[serde(default, deserialize_with = "StringArray::deserialize_legacy")]
+    references: StringArray,
+}
+
+#[derive(QueryableByName)]
+struct StoredReferences {
+    #[diesel(sql_type = Text)]
+    name: String,
+    #[diesel(sql_type = Text)]
+    references: String,
+}
+
+#[derive(QueryableByName)]
+struct Count {
+    #[diesel(sql_type = Integer)]
+    count: i32,
+}
+
+fn references(json: serde_json::Value) -> Vec<String> {
+    serde_json::from_value::<Entry>(json)
+        .expect("Failed to deserialize entry")
+        .references
+        .0
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|item| item.to_string()).collect()
+}
+
+/// Opens an in-memory database migrated up to the library entries migration
+fn legacy_library() -> SqliteConnection {
+    let mut connection =
+        SqliteConnection::establish(":memory:").expect("Failed to open in-memory database");
+    let migrations = connection
+        .pending_migrations(MIGRATIONS)
+        .expect("Failed to list migrations");
+
+    for migration in migrations
+        .iter()
+        .take_while(|m| !m.name().to_string().contains(LIBRARY_ENTRIES))
+    {
+        connection
+            .run_migration(migration.as_ref())
+            .expect("Failed to run migration");
+    }
+
+    connection
+}
+
+fn insert_legacy_small_molecule(
+    connection: &mut SqliteConnection,
+    name: &str,
+    inchikey: Option<&str>,
+    references: Option<&str>,
+) {
+    diesel::sql_query(
+        "INSERT INTO `small_molecules` (`name`, `inchikey`, `references`) VALUES (?, ?, ?)",
+    )
+    .bind::<Text, _>(name)
+    .bind::<diesel::sql_types::Nullable<Text>, _>(inchikey)
+    .bind::<diesel::sql_types::Nullable<Text>, _>(references)
+    .execute(connection)
+    .expect("Failed to insert small molecule");
+}
+
+#[test]
+fn legacy_references_are_deserialized_as_arrays() {
+    assert!(references(serde_json::json!({})).is_empty());
+    assert!(references(serde_json::json!({ "references": null })).is_empty());
+    assert!(references(serde_json::json!({ "references": "" })).is_empty());
+    assert_eq!(
+        references(serde_json::json!({ "references": "a, b ,,c" })),
+        strings(&["a", "b", "c"])
+    );
+    assert_eq!(
+        references(serde_json::json!({ "references": URL_WITH_COMMA })),
+        strings(&["https://example.org/search?q=a,b", "PMID:123"])
+    );
+    assert_eq!(
+        references(serde_json::json!({ "references": ["a,b", "c"] })),
+        strings(&["a,b", "c"])
+    );
+}
+
+#[test]
+fn migrated_references_are_split_like_deserialized_ones() {
+    let mut connection = legacy_library();
+    let legacy = [
+        ("Null", None),
+        ("Empty", Some("")),
+        ("List", Some("a, b ,,c")),
+        ("Url", Some(URL_WITH_COMMA)),
+    ];
+    for (name, references) in legacy {
+        insert_legacy_small_molecule(&mut connection, name, None, references);
+    }
+
+    connection
+        .run_pending_migrations(MIGRATIONS)
+        .expect("Failed to run migrations");
+
+    let stored: Vec<StoredReferences> =
+        diesel::sql_query("SELECT `name`, `references` FROM `small_molecules` ORDER BY `id`")
+            .load(&mut connection)
+            .expect("Failed to read small molecules");
+
+    assert_eq!(stored.len(), legacy.len());
+    for (row, (name, references)) in stored.iter().zip(legacy) {
+        assert_eq!(row.name, name);
+        let migrated: Vec<String> =
+            serde_json::from_str(&row.references).expect("References are not a JSON array");
+        let deserialized = StringArray::split_legacy(references.unwrap_or_default()).0;
+        assert_eq!(migrated, deserialized, "references of {}", name);
+    }
+}
+
+#[test]
+fn duplicate_keys_are_kept_when_migrating_but_rejected_afterwards() {
+    let mut connection = legacy_library();
+    insert_legacy_small_molecule(&mut connection, "Glucose", Some("KEY"), None);
+    insert_legacy_small_molecule(&mut connection, "D-Glucose", Some("KEY"), None);
+
+    connection
+        .run_pending_migrations(MIGRATIONS)
+        .expect("Failed to run migrations");
+
+    let count: Count = diesel::sql_query(
+        "SELECT count(*) AS `count` FROM `small_molecules` WHERE `inchikey` = 'KEY'",
+    )
+    .get_result(&mut connection)
+    .expect("Failed to count small molecules");
+    assert_eq!(count.count, 2);
+
+    // Existing entries may still be saved under their name
+    diesel::sql_query(
+        "INSERT INTO `small_molecules` (`name`, `inchikey`) VALUES ('Glucose', 'KEY') \
+         ON CONFLICT (`name`) DO UPDATE SET `inchikey` = excluded.`inchikey`",
+    )
+    .execute(&mut connection)
+    .expect("Failed to save an existing small molecule");
+
+    let error = diesel::sql_query(
+        "INSERT INTO `small_molecules` (`name`, `inchikey`) VALUES ('Dextrose', 'KEY')",
+    )
+    .execute(&mut connection)
+    .expect_err("A new duplicate key was accepted");
+    assert!(error
+        .to_string()
+        .starts_with("UNIQUE constraint failed: small_molecules.inchikey"));
+}
@@ -0,0 +1,109 @@
+//! Tests of the registration state of MCP clients
+//!
+//! Client configurations are written to a temporary directory and inspected
+//! the same way as the configurations of installed clients.
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use enzymeml_suite::actions::mcp::{client_status_at, ClientType};
+use enzymeml_suite::mcp::stdio::bridge_command;
+
+/// Creates a new temporary directory for client configurations
+fn temporary_dir() -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!(
+        "enzymeml-suite-mcp-{}-{}",
+        std::process::id(),
+        nanos
+    ));
+    std::fs::create_dir_all(&dir).expect("Failed to create temporary directory");
+
+    dir
+}
+
+fn claude_config(command: &str, env: serde_json::Value) -> String {
+    serde_json::json!({
+        "mcpServers": {
+            "Other": { "command": "other" },
+            "EnzymeMLSuite": {
+                "command": command,
+                "args": ["--mcp-stdio"],
+                "env": env,
+            },
+        },
+    })
+    .to_string()
+}
+
+#[test]
+fn registrations_are_checked_against_the_stdio_bridge() {
+    let dir = temporary_dir();
+    let bridge = bridge_command().expect("Failed to determine the bridge command");
+    let path = dir.join("claude_desktop_config.json");
+
+    std::fs::write(
+        &path,
+        claude_config(
+            &bridge.display().to_string(),
+            serde_json::json!({ "ENZYMEML_SUITE_API_URL": "http://127.0.0.1:13452" }),
+        ),
+    )
+    .expect("Failed to write configuration");
+    let status = client_status_at(&ClientType::ClaudeDesktop, &path);
+    assert!(status.registered);
+    assert_eq!(status.command, Some(bridge.display().to_string()));
+    assert_eq!(status.command_exists, bridge.exists());
+    assert!(status.command_matches);
+    assert!(!status.exposes_token);
+    assert_eq!(status.error, None);
+
+    // Registrations of earlier versions point to the standalone binary and hold the token
+    std::fs::write(
+        &path,
+        claude_config(
+            &dir.join("enzymeml-mcp").display().to_string(),
+            serde_json::json!({ "ENZYMEML_SUITE_API_TOKEN": "secret" }),
+        ),
+    )
+    .expect("Failed to write configuration");
+    let status = client_status_at(&ClientType::ClaudeDesktop, &path);
+    assert!(status.registered);
+    assert!(!status.command_exists);
+    assert!(!status.command_matches);
+    assert!(status.exposes_token);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[test]
+fn missing_and_invalid_configurations_are_reported() {
+    let dir = temporary_dir();
+
+    let status = client_status_at(&ClientType::Cursor, &dir.join("missing.json"));
+    assert!(!status.registered);
+    assert_eq!(status.command, None);
+    assert_eq!(status.error, None);
+
+    let path = dir.join("config.toml");
+    std::fs::write(&path, "[mcp_servers\ncommand = ").expect("Failed to write configuration");
+    let status = client_status_at(&ClientType::Codex, &path);
+    assert!(!status.registered);
+    assert!(status.error.is_some());
+
+    std::fs::write(
+        &path,
+        "[mcp_servers.EnzymeMLSuite]\ncommand = \"other\"\nenv = { ENZYMEML_SUITE_API_TOKEN = \"secret\" }\n",
+    )
+    .expect("Failed to write configuration");
+    let status = client_status_at(&ClientType::Codex, &path);
+    assert!(status.registered);
+    assert_eq!(status.command.as_deref(), Some("other"));
+    assert!(!status.command_matches);
+    assert!(status.exposes_token);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
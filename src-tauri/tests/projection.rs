@@ -0,0 +1,129 @@
+//! Tests of the normalised projection of stored documents
+//!
+//! A library is created in a temporary directory and documents are stored in
+//! it, so the projection is written and queried by the same code paths as in
+//! the application.
+
+mod common;
+
+use enzymeml::prelude::EnzymeMLDocument;
+
+use enzymeml_suite::io::dataio::insert_document;
+use enzymeml_suite::io::projection::{
+    find_measurements, find_parameters, set_enabled, MeasurementQuery, ParameterQuery,
+};
+
+use common::open_temporary_library;
+
+fn glucose_oxidase() -> EnzymeMLDocument {
+    serde_json::from_value(serde_json::json!({
+        "name": "Glucose oxidase",
+        "creators": [],
+        "vessels": [],
+        "proteins": [{
+            "id": "p0",
+            "name": "Glucose oxidase",
+            "constant": true,
+            "ecnumber": "1.1.3.4",
+        }],
+        "small_molecules": [
+            { "id": "s0", "name": "Glucose", "constant": false },
+            { "id": "s1", "name": "Gluconolactone", "constant": false },
+        ],
+        "reactions": [{
+            "id": "r0",
+            "name": "Oxidation",
+            "reversible": false,
+            "kinetic_law": {
+                "species_id": "",
+                "equation": "vmax * s0 / (Km + s0)",
+                "equation_type": "rateLaw",
+            },
+            "reactants": [{ "species_id": "s0", "stoichiometry": 1.0 }],
+            "products": [{ "species_id": "s1", "stoichiometry": 1.0 }],
+            "modifiers": [{ "species_id": "p0", "role": "biocatalyst" }],
+        }],
+        "parameters": [
+            { "id": "Km", "name": "Km", "symbol": "Km", "value": 12.5 },
+            { "id": "vmax", "name": "vmax", "symbol": "vmax", "value": 3.0 },
+            { "id": "kd", "name": "kd", "symbol": "kd", "value": 0.1 },
+        ],
+        "measurements": [
+            {
+                "id": "m0",
+                "name": "Warm",
+                "ph": 7.0,
+                "temperature": 30.0,
+                "temperature_unit": { "name": "Celsius" },
+                "species_data": [{ "species_id": "s0", "initial": 10.0 }],
+            },
+            {
+                "id": "m1",
+                "name": "Room",
+                "ph": 7.0,
+                "temperature": 298.15,
+                "temperature_unit": { "name": "K" },
+                "species_data": [{ "species_id": "s0", "initial": 5.0 }],
+            },
+        ],
+    }))
+    .expect("Failed to build document")
+}
+
+#[test]
+fn stored_documents_are_found_by_parameters_and_measurement_conditions() {
+    let _library = open_temporary_library("projection");
+    set_enabled(true).expect("Failed to enable the projection");
+    let id =
+        insert_document("Glucose oxidase", &glucose_oxidase()).expect("Failed to store document");
+
+    // The Km refers to the enzyme only through the modifiers of the reaction
+    let parameters = find_parameters(&ParameterQuery {
+        parameter: Some("km".to_string()),
+        protein: Some("1.1.3.4".to_string()),
+    })
+    .expect("Failed to find parameters");
+    assert_eq!(parameters.len(), 1);
+    assert_eq!(parameters[0].document_id, id);
+    assert_eq!(parameters[0].parameter_id, "Km");
+    assert_eq!(parameters[0].value, Some(12.5));
+    assert_eq!(parameters[0].proteins, vec!["Glucose oxidase".to_string()]);
+
+    // Parameters outside of the kinetic law are not linked to the enzyme
+    let parameters = find_parameters(&ParameterQuery {
+        parameter: None,
+        protein: Some("oxidase".to_string()),
+    })
+    .expect("Failed to find parameters");
+    let ids: Vec<&str> = parameters.iter().map(|p| p.parameter_id.as_str()).collect();
+    assert_eq!(ids, vec!["Km", "vmax"]);
+
+    // 30 °C are compared in Kelvin
+    let measurements = find_measurements(&MeasurementQuery {
+        min_temperature: Some(300.0),
+        max_temperature: Some(310.0),
+        species: Some("glucose".to_string()),
+        ..Default::default()
+    })
+    .expect("Failed to find measurements");
+    assert_eq!(measurements.len(), 1);
+    assert_eq!(measurements[0].measurement_id, "m0");
+    assert_eq!(measurements[0].temperature_unit.as_deref(), Some("Celsius"));
+    let kelvin = measurements[0]
+        .temperature_kelvin
+        .expect("Missing temperature in Kelvin");
+    assert!((kelvin - 303.15).abs() < 1e-9);
+    assert_eq!(measurements[0].initials[0].species_id, "s0");
+    assert_eq!(measurements[0].initials[0].initial, Some(10.0));
+
+    let measurements = find_measurements(&MeasurementQuery {
+        max_temperature: Some(300.0),
+        ..Default::default()
+    })
+    .expect("Failed to find measurements");
+    let ids: Vec<&str> = measurements
+        .iter()
+        .map(|m| m.measurement_id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["m1"]);
+}
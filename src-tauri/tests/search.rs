@@ -0,0 +1,113 @@
+//! Tests of the full-text search of stored documents
+//!
+//! A library is created in a temporary directory and documents are stored in
+//! it, so the search index is written and queried by the same code paths as in
+//! the application.
+
+mod common;
+
+use diesel::prelude::*;
+use diesel::sql_types::BigInt;
+use enzymeml::prelude::EnzymeMLDocument;
+
+use enzymeml_suite::db::establish_connection;
+use enzymeml_suite::io::dataio::{
+    delete_document, insert_document, rename_document, trash_document, update_document,
+};
+use enzymeml_suite::io::search::{search, sync_index};
+
+use common::{document, open_temporary_library, small_molecule};
+
+#[derive(QueryableByName)]
+struct Count {
+    #[diesel(sql_type = BigInt)]
+    count: i64,
+}
+
+fn ids(query: &str) -> Vec<i32> {
+    search(query, 10)
+        .expect("Failed to search")
+        .into_iter()
+        .map(|hit| hit.id)
+        .collect()
+}
+
+fn indexed() -> i64 {
+    let mut connection = establish_connection().expect("Failed to connect to the library");
+    diesel::sql_query("SELECT count(*) AS `count` FROM `documents_search`")
+        .get_result::<Count>(&mut connection)
+        .expect("Failed to count indexed documents")
+        .count
+}
+
+fn laccase() -> EnzymeMLDocument {
+    let mut doc = document("Laccase kinetics");
+    doc.small_molecules = vec![small_molecule("s0", "ABTS"), small_molecule("s1", "Oxygen")];
+    doc.description = Some("Measured near room temperature".to_string());
+    doc
+}
+
+#[test]
+fn changes_of_stored_documents_are_indexed() {
+    let _library = open_temporary_library("search-changes");
+    let id = insert_document("Laccase kinetics", &laccase()).expect("Failed to store document");
+    assert_eq!(ids("ABTS"), vec![id]);
+    assert_eq!(ids("abt"), vec![id]);
+
+    let mut doc = laccase();
+    doc.small_molecules[0] = small_molecule("s0", "Syringaldazine");
+    update_document(id, &doc).expect("Failed to update document");
+    assert!(ids("ABTS").is_empty());
+    assert_eq!(ids("syringaldazine"), vec![id]);
+
+    rename_document(id, "Oxidation of phenols").expect("Failed to rename document");
+    assert!(ids("kinetics").is_empty());
+    assert_eq!(ids("phenols"), vec![id]);
+
+    // Documents in the trash stay indexed, but are left out of the hits
+    trash_document(id, true).expect("Failed to trash document");
+    assert!(ids("phenols").is_empty());
+    trash_document(id, false).expect("Failed to restore document");
+    assert_eq!(ids("phenols"), vec![id]);
+
+    delete_document(id).expect("Failed to delete document");
+    assert!(ids("phenols").is_empty());
+    assert_eq!(indexed(), 0);
+}
+
+#[test]
+fn documents_stored_before_the_index_are_indexed_when_syncing() {
+    let _library = open_temporary_library("search-sync");
+    let id = insert_document("Laccase kinetics", &laccase()).expect("Failed to store document");
+    assert!(!sync_index().expect("Failed to sync the index"));
+
+    // Libraries created before the index existed have an empty index
+    let mut connection = establish_connection().expect("Failed to connect to the library");
+    diesel::sql_query("DELETE FROM `documents_search`")
+        .execute(&mut connection)
+        .expect("Failed to clear the index");
+    assert!(ids("ABTS").is_empty());
+
+    assert!(sync_index().expect("Failed to sync the index"));
+    assert_eq!(ids("ABTS"), vec![id]);
+    assert_eq!(indexed(), 1);
+    assert!(!sync_index().expect("Failed to sync the index"));
+}
+
+#[test]
+fn query_syntax_in_searches_is_matched_literally() {
+    let _library = open_temporary_library("search-syntax");
+    let id = insert_document("Laccase kinetics", &laccase()).expect("Failed to store document");
+
+    assert_eq!(ids("ABTS\""), vec![id]);
+    assert_eq!(ids("\"ABTS oxygen"), vec![id]);
+    assert_eq!(ids("ABTS*"), vec![id]);
+    assert!(ids("*").is_empty());
+    assert!(ids("\"").is_empty());
+
+    // NEAR is searched as a word instead of being applied as operator
+    assert_eq!(ids("ABTS and oxygen"), vec![id]);
+    assert!(ids("NEAR(ABTS oxygen)").is_empty());
+    assert_eq!(ids("ABTS NEAR oxygen"), vec![id]);
+    assert!(ids("ABTS NEAR kinetics OR glucose").is_empty());
+}
@@ -28,6 +28,8 @@ import { useFileMenuShortcuts } from "@hooks/useKeyboardShortcuts";
 import { NotificationType } from "@suite/components/NotificationProvider";
 import { useDragDropTauriListener, useNavigationTauriListener } from "@hooks/useTauriListener";
 import ExtractModal from "@llm/ExtractModal";
+import RecoveryModal from "@components/RecoveryModal";
+import FileChangedModal from "@components/FileChangedModal";
 import { useExtractionModalShortcuts } from "@hooks/useKeyboardShortcuts";
 import { ExtractionContextMap } from "@suite-types/context";
 import useLLMStore from "@suite/stores/llmstore";
@@ -214,6 +216,8 @@ function App() {
         {currentPath in ExtractionContextMap && (
           <ExtractModal />
         )}
+        <RecoveryModal />
+        <FileChangedModal />
       </Layout>
     </Layout>
   );
@@ -0,0 +1,79 @@
+import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from "./errors";
+
+export interface BackupEntry {
+    file: string,
+    created_at: number,
+    size: number,
+}
+
+export type ConflictStrategy = 'skip' | 'replace' | 'keep_both';
+
+export type LibraryTable = 'documents' | 'small_molecules' | 'proteins' | 'vessels' | 'creators' | 'laws';
+
+export interface TableImport {
+    table: LibraryTable,
+    added: number,
+    replaced: number,
+    skipped: number,
+}
+
+export interface LibraryConflict {
+    table: LibraryTable,
+    name: string,
+    resolution: ConflictStrategy,
+    renamed_to: string | null,
+}
+
+export interface LibraryImportReport {
+    tables: TableImport[],
+    conflicts: LibraryConflict[],
+}
+
+export async function createBackup(): Promise<BackupEntry> {
+    try {
+        return await invoke<BackupEntry>('create_backup');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function listBackups(): Promise<BackupEntry[]> {
+    try {
+        return await invoke<BackupEntry[]>('list_backups');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function restoreBackup(file: string): Promise<BackupEntry> {
+    try {
+        return await invoke<BackupEntry>('restore_backup', { file: file });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function deleteBackup(file: string): Promise<void> {
+    try {
+        await invoke('delete_backup', { file: file });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function exportLibrary(): Promise<string> {
+    try {
+        return await invoke<string>('export_library');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function importLibrary(strategy?: ConflictStrategy): Promise<LibraryImportReport> {
+    try {
+        return await invoke<LibraryImportReport>('import_library', { strategy: strategy ?? null });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
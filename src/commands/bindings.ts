@@ -0,0 +1,4656 @@
+
+// This file was generated by [tauri-specta](https://github.com/oscartbeaumont/tauri-specta). Do not edit this file manually.
+
+/** user-defined commands **/
+
+
+export const commands = {
+/**
+ * Saves the current EnzymeML document to the database
+ * 
+ * Persists the current document to the local database. If the document already
+ * has an ID (was previously saved), it updates the existing record. If it's a
+ * new document, it creates a new database entry and assigns an ID. After saving,
+ * an update event is emitted to refresh the frontend interface.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing either the document ID or an error message
+ */
+async save(handle: string | null) : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Loads an EnzymeML document from the database
+ * 
+ * Retrieves a previously saved document from the local database using its ID
+ * and loads it into the application state. This replaces any currently loaded
+ * document with the retrieved one, updating the document title and ID accordingly.
+ * 
+ * # Arguments
+ * * `id` - The ID of the document to load
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * Result indicating success or failure
+ */
+async load(id: number, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("load", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists all EnzymeML documents in the database
+ * 
+ * Retrieves a list of all documents stored in the local database, returning
+ * their titles and IDs. This is used to populate document selection interfaces
+ * in the frontend, allowing users to see and choose from their saved documents.
+ * 
+ * # Returns
+ * Result containing either a vector of (title, id) tuples or an error message
+ */
+async listAllEntries() : Promise<Result<[string, number][], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_all_entries") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the stored documents with their metadata
+ * 
+ * Unlike [`list_all_entries`], the documents can be filtered by text, tags and
+ * whether they are in the trash, and sorted by title or time.
+ * 
+ * # Arguments
+ * * `query` - Filter and order of the documents, all documents not in the trash if omitted
+ * 
+ * # Returns
+ * Result containing either the metadata of the matching documents or an error
+ */
+async listDocuments(query: DocumentQuery | null) : Promise<Result<DocumentMetadata[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_documents", { query }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Renames a stored document
+ * 
+ * Open documents that have been saved to it are renamed as well.
+ * 
+ * # Arguments
+ * * `id` - The ID of the document to rename
+ * * `title` - The new title of the document
+ * * `workspace` - The open documents of the application
+ * * `app_handle` - The Tauri application handle notified about the changes
+ * 
+ * # Returns
+ * Result indicating success or failure
+ */
+async renameStoredDocument(id: number, title: string) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rename_stored_document", { id, title }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Duplicates a stored document
+ * 
+ * # Arguments
+ * * `id` - The ID of the document to duplicate
+ * * `title` - The title of the copy, defaults to the title of the document suffixed with "(Copy)"
+ * 
+ * # Returns
+ * Result containing either the ID of the copy or an error
+ */
+async duplicateStoredDocument(id: number, title: string | null) : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("duplicate_stored_document", { id, title }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Moves a stored document to the trash
+ * 
+ * # Arguments
+ * * `id` - The ID of the document to move to the trash
+ * 
+ * # Returns
+ * Result indicating success or failure
+ */
+async trashStoredDocument(id: number) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("trash_stored_document", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Restores a stored document from the trash
+ * 
+ * # Arguments
+ * * `id` - The ID of the document to restore
+ * 
+ * # Returns
+ * Result indicating success or failure
+ */
+async restoreStoredDocument(id: number) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_stored_document", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a stored document permanently
+ * 
+ * Open documents that have been saved to it are detached, so saving them
+ * stores them anew.
+ * 
+ * # Arguments
+ * * `id` - The ID of the document to delete
+ * * `workspace` - The open documents of the application
+ * * `app_handle` - The Tauri application handle notified about the changes
+ * 
+ * # Returns
+ * Result indicating success or failure
+ */
+async deleteStoredDocument(id: number) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_stored_document", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes all stored documents in the trash permanently
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `app_handle` - The Tauri application handle notified about the changes
+ * 
+ * # Returns
+ * Result containing either the number of deleted documents or an error
+ */
+async emptyDocumentTrash() : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("empty_document_trash") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replaces the tags of a stored document
+ * 
+ * # Arguments
+ * * `id` - The ID of the document to tag
+ * * `tags` - The new tags of the document
+ * 
+ * # Returns
+ * Result indicating success or failure
+ */
+async tagStoredDocument(id: number, tags: string[]) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("tag_stored_document", { id, tags }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replaces the description of a stored document
+ * 
+ * # Arguments
+ * * `id` - The ID of the document to describe
+ * * `description` - The new description, `None` to remove it
+ * 
+ * # Returns
+ * Result indicating success or failure
+ */
+async describeStoredDocument(id: number, description: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("describe_stored_document", { id, description }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Searches the stored documents
+ * 
+ * Every term of the query has to match the beginning of a word in any of the
+ * indexed fields, the connective "and" is ignored. Documents in the trash are
+ * left out. Matches in the title weigh most, followed by species and
+ * proteins, reactions and descriptions.
+ * 
+ * # Arguments
+ * * `query` - The terms to search for, e.g. "ABTS laccase"
+ * * `limit` - Maximum number of hits, 50 if omitted
+ * 
+ * # Returns
+ * Result containing the hits, the most relevant first
+ */
+async searchDocuments(query: string, limit: number | null) : Promise<Result<SearchHit[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("search_documents", { query, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Rebuilds the search index from all stored documents
+ * 
+ * # Returns
+ * Result containing the number of indexed documents
+ */
+async rebuildSearchIndex() : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rebuild_search_index") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Returns whether the normalised projection of stored documents is maintained
+ * 
+ * # Returns
+ * Result containing whether the projection is enabled
+ */
+async getProjectionEnabled() : Promise<Result<boolean, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_projection_enabled") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Enables or disables the normalised projection of stored documents
+ * 
+ * Enabling the projection projects all stored documents, disabling it clears
+ * the projection.
+ * 
+ * # Arguments
+ * * `enabled` - Whether the projection is maintained
+ * 
+ * # Returns
+ * Result containing the number of projected documents
+ */
+async setProjectionEnabled(enabled: boolean) : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_projection_enabled", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Rebuilds the normalised projection from all stored documents
+ * 
+ * # Returns
+ * Result containing the number of projected documents, 0 if the projection is disabled
+ */
+async rebuildDocumentProjection() : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rebuild_document_projection") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Looks up parameters across the stored documents
+ * 
+ * Answers questions such as "all Km values of laccase", by combining a
+ * parameter and a protein filter. Documents in the trash are left out.
+ * 
+ * # Arguments
+ * * `query` - The filter of the parameters, all parameters if omitted
+ * 
+ * # Returns
+ * Result containing the matching parameters, ordered by name and document
+ */
+async queryParameters(query: ParameterQuery | null) : Promise<Result<ParameterRecord[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("query_parameters", { query }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Looks up measurements across the stored documents
+ * 
+ * Answers questions such as "all measurements at 30 °C", by filtering the
+ * conditions of the measurements. Documents in the trash are left out.
+ * 
+ * # Arguments
+ * * `query` - The filter of the measurements, all measurements if omitted
+ * 
+ * # Returns
+ * Result containing the matching measurements, ordered by document and name
+ */
+async queryMeasurements(query: MeasurementQuery | null) : Promise<Result<MeasurementRecord[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("query_measurements", { query }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Exports the whole library to an archive
+ * 
+ * Opens a file dialog allowing the user to choose the file the archive is
+ * written to.
+ * 
+ * # Arguments
+ * * `app_handle` - Handle to the Tauri application used to open the dialog
+ * 
+ * # Returns
+ * Result containing the path of the written archive
+ */
+async exportLibrary() : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_library") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Imports an archive into the library
+ * 
+ * Opens a file dialog allowing the user to choose the archive. Entries that
+ * equal existing entries are always skipped.
+ * 
+ * # Arguments
+ * * `strategy` - How conflicting entries are handled, skipped if omitted
+ * * `app_handle` - Handle to the Tauri application used to open the dialog
+ * 
+ * # Returns
+ * Result containing the numbers of imported entries and the conflicts
+ */
+async importLibrary(strategy: ConflictStrategy | null) : Promise<Result<LibraryImportReport, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_library", { strategy }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new empty EnzymeML document
+ * 
+ * Initializes a fresh, empty EnzymeML document in the application state, replacing
+ * any existing document. The new document is given a default title and contains
+ * no experimental data. The document ID is reset to None, indicating it hasn't
+ * been saved to the database yet. An update event is emitted to refresh the frontend.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ */
+async newDocument(handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("new_document", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Exports the EnzymeML document to a JSON file
+ * 
+ * Opens a file dialog allowing the user to save the current EnzymeML document as
+ * a JSON file. The entire document structure including all measurements, vessels,
+ * species, and other data is serialized into a human-readable JSON format that
+ * can be stored, shared, or imported later. Unlike `save_to_file_as`, the
+ * exported file does not become the origin of the document.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * Result containing either the saved file path or an error message
+ */
+async exportToJson(handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_to_json", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Saves the EnzymeML document back to the file it originates from
+ * 
+ * Writes the document to the file it was loaded from or last saved to. The
+ * previous version of the file is kept as backup and the new content is
+ * written atomically. Documents that do not originate from a file are saved
+ * to a file chosen in a dialog, just like with `save_to_file_as`.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing either the path of the written file or an error message
+ */
+async saveToFile(handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_to_file", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Saves the EnzymeML document to a new file
+ * 
+ * Opens a file dialog allowing the user to choose the file to save the
+ * document to. The chosen file becomes the origin of the document, so later
+ * calls of `save_to_file` write back to it.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing either the path of the written file or an error message
+ */
+async saveToFileAs(handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_to_file_as", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets the current application state
+ * 
+ * Retrieves the current state of the EnzymeML document for frontend consumption.
+ * This function converts the internal state representation into a format that
+ * can be safely exposed to the frontend interface.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * An exposed version of the EnzymeML state for frontend use
+ */
+async getState(handle: string | null) : Promise<Result<ExposedEnzymeMLState, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_state", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Exports measurement data to an Excel file
+ * 
+ * Opens a file dialog allowing the user to choose where to save measurement data
+ * as an Excel file. The exported file contains all measurement data from the current
+ * EnzymeML document in a structured format. After successful export, the file is
+ * automatically opened using the system's default Excel application.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * Result containing either the saved file path or an error message
+ */
+async exportMeasurements(handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_measurements", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Imports measurement data from an Excel file
+ * 
+ * Opens a file dialog allowing the user to select an Excel file containing measurement
+ * data to import into the current EnzymeML document. The function parses the Excel
+ * file and adds all valid measurements to the document. After successful import,
+ * an update event is emitted to notify the frontend of the changes.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing either the number of imported measurements or an error message
+ */
+async importExcelMeas(handle: string | null) : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_excel_meas", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Loads an EnzymeML document from a JSON file
+ * 
+ * Opens a file dialog allowing the user to select and load a previously saved
+ * EnzymeML document from a JSON file. The loaded document completely replaces
+ * the current document in memory and the file becomes its origin. After
+ * successful loading, an update event is emitted to refresh the entire
+ * frontend interface.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result indicating success or failure
+ */
+async loadJson(handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("load_json", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Loads a JSON document from a specific file path (for drag and drop)
+ * 
+ * Loads an EnzymeML document from a specified file path without opening a file dialog.
+ * This function is designed for drag-and-drop functionality where the file path
+ * is already known, and for reopening recent files. The document content is read,
+ * deserialized, and loaded into the application state, and the file becomes its
+ * origin. An update event is emitted to refresh the frontend.
+ * 
+ * # Arguments
+ * * `file_path` - The path to the JSON file to load
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result indicating success or failure
+ */
+async loadJsonFromPath(filePath: string, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("load_json_from_path", { filePath, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Imports measurements from an Excel file at a specific path (for drag and drop)
+ * 
+ * Imports measurement data from an Excel file at a specified path without opening
+ * a file dialog. This function is designed for drag-and-drop functionality.
+ * The measurements are added to the current EnzymeML document.
+ * 
+ * # Arguments
+ * * `file_path` - The path to the Excel file to import
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing either the number of imported measurements or an error message
+ */
+async importExcelFromPath(filePath: string, handle: string | null) : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_excel_from_path", { filePath, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Handles file drop events by processing dropped files
+ * 
+ * Processes files dropped into the application window. Supports JSON files (EnzymeML documents)
+ * and Excel files (.xlsx for measurement data). The function validates file extensions,
+ * loads the appropriate content, and emits notifications about the results.
+ * 
+ * # Arguments
+ * * `file_paths` - Array of file paths that were dropped
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing a summary of the processing results
+ */
+async handleFileDrop(filePaths: string[], handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("handle_file_drop", { filePaths, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the recently opened and saved document files
+ * 
+ * Files that no longer exist are left out.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access the store
+ * 
+ * # Returns
+ * Result containing the recent files, the most recent first
+ */
+async listRecentFiles() : Promise<Result<RecentFile[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_recent_files") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Forgets all recently opened and saved document files
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access the store
+ * 
+ * # Returns
+ * Result indicating success or failure
+ */
+async clearRecentFiles() : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("clear_recent_files") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Saves or updates a small molecule in the database
+ * 
+ * This function retrieves a small molecule from the EnzymeML document state
+ * using the provided ID and saves or updates it in the database. The function
+ * uses an upsert operation to handle both new insertions and updates of existing
+ * records based on the molecule's name.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `id` - The unique identifier of the small molecule to save
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * Result containing the number of affected rows on success, or an error on failure
+ */
+async saveMolToDb(id: string, handle: string | null) : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_mol_to_db", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Filters small molecules by name
+ * 
+ * This function queries the database to find small molecules that match the
+ * provided name. It performs a filtered search on the small_molecules table
+ * and returns all matching records.
+ * 
+ * # Arguments
+ * * `name` - The name of the small molecule to search for
+ * 
+ * # Returns
+ * Result containing a vector of matching small molecules or an error
+ */
+async filterSmallMols(name: string) : Promise<Result<DBSmallMolecule[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("filter_small_mols", { name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets all small molecules from the database
+ * 
+ * This function retrieves all small molecule records from the database and
+ * returns them as a vector. This is useful for displaying complete lists
+ * or for performing bulk operations on the entire small molecules collection.
+ * 
+ * # Returns
+ * Result containing a vector of all small molecules or an error on failure
+ */
+async getAllSmallMols() : Promise<Result<DBSmallMolecule[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_all_small_mols") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets a small molecule by ID
+ * 
+ * This function retrieves a specific small molecule from the database using its
+ * unique ID.
+ * 
+ * # Arguments
+ * * `id` - The unique database ID of the small molecule to retrieve
+ * 
+ * # Returns
+ * Result containing the requested small molecule or an error if not found
+ */
+async getSmallMolById(id: number) : Promise<Result<DBSmallMolecule, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_small_mol_by_id", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates a small molecule of the database in place
+ * 
+ * The name is required, blank optional fields are stored as missing. The
+ * name and the InChI Key, if given, have to be unique within the library.
+ * 
+ * # Arguments
+ * * `entry` - The small molecule holding its database ID and the new values
+ * 
+ * # Returns
+ * Result containing the updated small molecule, or an error if it is not
+ * found or conflicts with another small molecule
+ */
+async updateSmallMolInDb(entry: DBSmallMolecule) : Promise<Result<DBSmallMolecule, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_small_mol_in_db", { entry }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a small molecule from the database
+ * 
+ * # Arguments
+ * * `id` - The unique database ID of the small molecule to delete
+ * 
+ * # Returns
+ * Result indicating success, or an error if the small molecule is not found
+ */
+async deleteSmallMolFromDb(id: number) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_small_mol_from_db", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Saves or updates a protein in the database
+ * 
+ * This function retrieves a protein from the EnzymeML document state using
+ * the provided ID and saves or updates it in the database. Existing records
+ * are matched by the protein's name.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `id` - The unique identifier of the protein to save
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * Result containing the number of affected rows on success, or an error on failure
+ */
+async saveProteinToDb(id: string, handle: string | null) : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_protein_to_db", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Searches the proteins of the database
+ * 
+ * All given filters have to match, so e.g. a name and an organism narrow the
+ * search down to proteins of this name from this organism.
+ * 
+ * # Arguments
+ * * `query` - The filters of the search, `None` lists all proteins
+ * 
+ * # Returns
+ * Result containing the matching proteins ordered by name or an error
+ */
+async filterProteins(query: ProteinQuery | null) : Promise<Result<DBProtein[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("filter_proteins", { query }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets a protein by ID
+ * 
+ * # Arguments
+ * * `id` - The unique database ID of the protein to retrieve
+ * 
+ * # Returns
+ * Result containing the requested protein or an error if not found
+ */
+async getProteinById(id: number) : Promise<Result<DBProtein, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_protein_by_id", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets all proteins from the database
+ * 
+ * # Returns
+ * Result containing a vector of all proteins or an error on failure
+ */
+async getAllProteins() : Promise<Result<DBProtein[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_all_proteins") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a protein of the database to an EnzymeML document
+ * 
+ * The protein receives a new ID within the document and is treated as
+ * constant, since proteins are usually not consumed by the reactions.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `id` - The unique database ID of the protein to add
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing the ID of the protein within the document
+ */
+async addProteinFromDb(id: number, handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_protein_from_db", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates a protein of the database in place
+ * 
+ * The name is required, blank optional fields are stored as missing. The
+ * name and the sequence, if given, have to be unique within the library.
+ * 
+ * # Arguments
+ * * `entry` - The protein holding its database ID and the new values
+ * 
+ * # Returns
+ * Result containing the updated protein, or an error if it is not found or
+ * conflicts with another protein
+ */
+async updateProteinInDb(entry: DBProtein) : Promise<Result<DBProtein, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_protein_in_db", { entry }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a protein from the database
+ * 
+ * # Arguments
+ * * `id` - The unique database ID of the protein to delete
+ * 
+ * # Returns
+ * Result indicating success, or an error if the protein is not found
+ */
+async deleteProteinFromDb(id: number) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_protein_from_db", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Saves or updates a vessel in the database
+ * 
+ * This function retrieves a vessel from the EnzymeML document state using
+ * the provided ID and saves or updates it in the database. Existing records
+ * are matched by the vessel's name.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `id` - The unique identifier of the vessel to save
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * Result containing the number of affected rows on success, or an error on failure
+ */
+async saveVesselToDb(id: string, handle: string | null) : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_vessel_to_db", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Filters vessels by name
+ * 
+ * # Arguments
+ * * `name` - The name of the vessel to search for
+ * 
+ * # Returns
+ * Result containing a vector of matching vessels or an error
+ */
+async filterVessels(name: string) : Promise<Result<DBVessel[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("filter_vessels", { name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets a vessel by ID
+ * 
+ * # Arguments
+ * * `id` - The unique database ID of the vessel to retrieve
+ * 
+ * # Returns
+ * Result containing the requested vessel or an error if not found
+ */
+async getVesselById(id: number) : Promise<Result<DBVessel, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_vessel_by_id", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets all vessels from the database
+ * 
+ * # Returns
+ * Result containing a vector of all vessels or an error on failure
+ */
+async getAllVessels() : Promise<Result<DBVessel[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_all_vessels") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a vessel of the database to an EnzymeML document
+ * 
+ * The vessel receives a new ID within the document.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `id` - The unique database ID of the vessel to add
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing the ID of the vessel within the document
+ */
+async addVesselFromDb(id: number, handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_vessel_from_db", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates a vessel of the database in place
+ * 
+ * The name has to be unique within the library, the volume positive and the
+ * unit a known volume unit.
+ * 
+ * # Arguments
+ * * `entry` - The vessel holding its database ID and the new values
+ * 
+ * # Returns
+ * Result containing the updated vessel, or an error if it is not found, is
+ * invalid or conflicts with another vessel
+ */
+async updateVesselInDb(entry: DBVessel) : Promise<Result<DBVessel, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_vessel_in_db", { entry }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a vessel from the database
+ * 
+ * # Arguments
+ * * `id` - The unique database ID of the vessel to delete
+ * 
+ * # Returns
+ * Result indicating success, or an error if the vessel is not found
+ */
+async deleteVesselFromDb(id: number) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_vessel_from_db", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Saves or updates the creators of an EnzymeML document in the database
+ * 
+ * Existing records are matched by the creator's mail address, or by the name
+ * if the creator has no mail address.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * Result containing the number of affected rows on success, or an error on failure
+ */
+async saveCreatorsToDb(handle: string | null) : Promise<Result<number, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_creators_to_db", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Searches the creators of the database
+ * 
+ * Every word of the query has to match a part of the given name, the family
+ * name or the mail address, so e.g. "jane doe" finds Jane Doe. Wildcards such
+ * as `_` are taken literally, so "j_doe" does not find jxdoe@example.org.
+ * 
+ * # Arguments
+ * * `query` - The words to search for, an empty query lists all creators
+ * 
+ * # Returns
+ * Result containing the matching creators ordered by name or an error
+ */
+async filterCreators(query: string) : Promise<Result<DBCreator[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("filter_creators", { query }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets a creator by ID
+ * 
+ * # Arguments
+ * * `id` - The unique database ID of the creator to retrieve
+ * 
+ * # Returns
+ * Result containing the requested creator or an error if not found
+ */
+async getCreatorById(id: number) : Promise<Result<DBCreator, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_creator_by_id", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets all creators from the database
+ * 
+ * # Returns
+ * Result containing a vector of all creators or an error on failure
+ */
+async getAllCreators() : Promise<Result<DBCreator[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_all_creators") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a creator of the database to an EnzymeML document
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `id` - The unique database ID of the creator to add
+ * * `handle` - Handle of the document, `None` for the active document
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing whether the creator has been added, which is not the
+ * case if the document already lists the creator
+ */
+async addCreatorFromDb(id: number, handle: string | null) : Promise<Result<boolean, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_creator_from_db", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates a creator of the database in place
+ * 
+ * The given and family name are required. The mail address, if given, has
+ * to be unique within the library.
+ * 
+ * # Arguments
+ * * `entry` - The creator holding its database ID and the new values
+ * 
+ * # Returns
+ * Result containing the updated creator, or an error if it is not found or
+ * conflicts with another creator
+ */
+async updateCreatorInDb(entry: DBCreator) : Promise<Result<DBCreator, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_creator_in_db", { entry }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a creator from the database
+ * 
+ * # Arguments
+ * * `id` - The unique database ID of the creator to delete
+ * 
+ * # Returns
+ * Result indicating success, or an error if the creator is not found
+ */
+async deleteCreatorFromDb(id: number) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_creator_from_db", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets a kinetic law by ID
+ * 
+ * # Arguments
+ * * `id` - The unique database ID of the kinetic law to retrieve
+ * 
+ * # Returns
+ * Result containing the requested kinetic law or an error if not found
+ */
+async getLawById(id: number) : Promise<Result<LibraryLaw, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_law_by_id", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets all kinetic laws from the database
+ * 
+ * # Returns
+ * Result containing the kinetic laws ordered by name or an error on failure
+ */
+async getAllLaws() : Promise<Result<LibraryLaw[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_all_laws") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates a kinetic law of the database in place
+ * 
+ * The species, assignables and parameters of the law are replaced by the
+ * given ones. The name has to be unique within the library.
+ * 
+ * # Arguments
+ * * `entry` - The kinetic law holding its database ID and the new values
+ * 
+ * # Returns
+ * Result containing the updated kinetic law, or an error if it is not found
+ * or conflicts with another kinetic law
+ */
+async updateLawInDb(entry: LibraryLaw) : Promise<Result<LibraryLaw, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_law_in_db", { entry }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a kinetic law from the database, including its relations
+ * 
+ * # Arguments
+ * * `id` - The unique database ID of the kinetic law to delete
+ * 
+ * # Returns
+ * Result indicating success, or an error if the kinetic law is not found
+ */
+async deleteLawFromDb(id: number) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_law_from_db", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the documents open in the workspace
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * 
+ * # Returns
+ * The open documents in the order they have been opened
+ */
+async listOpenDocuments() : Promise<OpenDocument[]> {
+    return await TAURI_INVOKE("list_open_documents");
+},
+/**
+ * Opens a new, empty document and makes it the active one
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing the handle of the new document
+ */
+async openNewDocument() : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_new_document") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Opens a stored document next to the already open ones
+ * 
+ * If the stored document is open already, it is made the active one instead
+ * of being opened twice.
+ * 
+ * # Arguments
+ * * `id` - The database ID of the document to open
+ * * `workspace` - The open documents of the application
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing the handle of the opened document
+ */
+async openDocument(id: number) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_document", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Makes an open document the active one
+ * 
+ * # Arguments
+ * * `handle` - Handle of the document to activate
+ * * `workspace` - The open documents of the application
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result indicating whether a document with the given handle is open
+ */
+async switchDocument(handle: string) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("switch_document", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Closes an open document without saving it
+ * 
+ * Closing the active document activates its neighbour, closing the last
+ * document opens a new, empty one.
+ * 
+ * # Arguments
+ * * `handle` - Handle of the document to close
+ * * `workspace` - The open documents of the application
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing the handle of the active document after closing
+ */
+async closeDocument(handle: string) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("close_document", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the recovery snapshots left over by previous sessions
+ * 
+ * Leftover snapshots hold the unsaved changes of documents that were open
+ * when the application crashed or was killed. The frontend offers to restore
+ * them on startup.
+ * 
+ * # Arguments
+ * * `recovery` - The recovery snapshots of this session
+ * 
+ * # Returns
+ * Result containing the leftover snapshots, the most recent first
+ */
+async listRecoverySnapshots() : Promise<Result<RecoveryEntry[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_recovery_snapshots") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Restores a leftover recovery snapshot as new open document
+ * 
+ * The restored document becomes the active one and keeps its unsaved state
+ * until it is saved. The snapshot is removed from the recovery directory.
+ * 
+ * # Arguments
+ * * `file` - File name of the snapshot within the recovery directory
+ * * `recovery` - The recovery snapshots of this session
+ * * `workspace` - The open documents of the application
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing the handle of the restored document
+ */
+async restoreRecoverySnapshot(file: string) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_recovery_snapshot", { file }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Discards a leftover recovery snapshot without restoring it
+ * 
+ * # Arguments
+ * * `file` - File name of the snapshot within the recovery directory
+ * * `recovery` - The recovery snapshots of this session
+ * 
+ * # Returns
+ * Result indicating whether the snapshot could be removed
+ */
+async discardRecoverySnapshot(file: string) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("discard_recovery_snapshot", { file }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Takes a backup of the database
+ * 
+ * Only the configured number of most recent backups is kept, so the oldest
+ * backups may be removed.
+ * 
+ * # Arguments
+ * * `backups` - The backups of the database
+ * * `app` - The Tauri application handle used to access the settings
+ * 
+ * # Returns
+ * Result containing the new backup
+ */
+async createBackup() : Promise<Result<BackupEntry, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_backup") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the backups of the database
+ * 
+ * # Arguments
+ * * `backups` - The backups of the database
+ * 
+ * # Returns
+ * Result containing the backups, the most recent first
+ */
+async listBackups() : Promise<Result<BackupEntry[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_backups") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replaces the content of the database with a backup
+ * 
+ * The current content is backed up first. Open documents are detached from
+ * the database, since their entries may differ or be missing in the backup,
+ * so the next save creates a new entry. The search index and the projection
+ * are rebuilt from the restored documents.
+ * 
+ * # Arguments
+ * * `file` - File name of the backup within the backup directory
+ * * `backups` - The backups of the database
+ * * `workspace` - The open documents of the application
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing the backup of the replaced content
+ */
+async restoreBackup(file: string) : Promise<Result<BackupEntry, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_backup", { file }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Removes a backup of the database
+ * 
+ * # Arguments
+ * * `file` - File name of the backup within the backup directory
+ * * `backups` - The backups of the database
+ * 
+ * # Returns
+ * Result indicating whether the backup could be removed
+ */
+async deleteBackup(file: string) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_backup", { file }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves the status of the library the application currently works with
+ * 
+ * # Returns
+ * Result containing the name and path of the library and whether it could be
+ * opened, or an error if no library has been opened yet
+ */
+async getDatabaseStatus() : Promise<Result<DatabaseStatus, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_database_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the registered libraries
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access the store
+ * 
+ * # Returns
+ * Result containing the registered libraries and the active one
+ */
+async listLibraries() : Promise<Result<LibrarySettings, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_libraries") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Registers a library
+ * 
+ * The database file is created when switching to the library, if it
+ * doesn't exist by then.
+ * 
+ * # Arguments
+ * * `name` - Unique name of the library
+ * * `path` - Path to the database file of the library
+ * * `app` - The Tauri application handle used to access the store
+ * 
+ * # Returns
+ * Result containing the updated library settings
+ */
+async addLibrary(name: string, path: string) : Promise<Result<LibrarySettings, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_library", { name, path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Unregisters a library
+ * 
+ * The database file of the library is kept. The active library cannot be
+ * unregistered.
+ * 
+ * # Arguments
+ * * `name` - Name of the library
+ * * `app` - The Tauri application handle used to access the store
+ * 
+ * # Returns
+ * Result containing the updated library settings
+ */
+async removeLibrary(name: string) : Promise<Result<LibrarySettings, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("remove_library", { name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Switches to another registered library
+ * 
+ * The library is opened and migrated to the current schema, and becomes the
+ * library opened when starting. If it cannot be opened, the application keeps
+ * working with the current library. Open documents are detached from the
+ * previous library, so the next save creates an entry in the new one.
+ * 
+ * # Arguments
+ * * `name` - Name of the library
+ * * `workspace` - The open documents of the application
+ * * `app_handle` - Handle to the Tauri application for event emission
+ * 
+ * # Returns
+ * Result containing the status of the opened library
+ */
+async switchLibrary(name: string) : Promise<Result<DatabaseStatus, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("switch_library", { name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replaces the document with the current content of its origin file
+ * 
+ * Tauri adapter of [`document::external::reload`].
+ */
+async reloadFromFile(handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reload_from_file", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Keeps the document as it is and dismisses the changes of its origin file
+ * 
+ * Tauri adapter of [`document::external::keep_local`].
+ */
+async keepLocalChanges(handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("keep_local_changes", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Merges the changes of the origin file into the document
+ * 
+ * Tauri adapter of [`document::external::merge`].
+ */
+async mergeWithFile(handle: string | null) : Promise<Result<MergeReport, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("merge_with_file", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Compares two documents entity by entity
+ * 
+ * Tauri adapter of [`document::diff::diff_documents`], resolving both
+ * documents from their sources first.
+ */
+async diffDocuments(before: DocumentSource, after: DocumentSource, options: DiffOptions | null) : Promise<Result<DocumentDiff, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("diff_documents", { before, after, options }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Imports the entities of another document into the document
+ * 
+ * Tauri adapter of [`document::import::import_document`], resolving the
+ * imported document from its source first.
+ */
+async importDocument(source: DocumentSource, options: ImportOptions | null, handle: string | null) : Promise<Result<ImportReport, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_document", { source, options, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves all species IDs from the EnzymeML document
+ * 
+ * Tauri adapter of [`document::enzmldoc::get_all_species_ids`].
+ */
+async getAllSpeciesIds(handle: string | null) : Promise<Result<string[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_all_species_ids", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves all non-constant species IDs from the EnzymeML document
+ * 
+ * Tauri adapter of [`document::enzmldoc::get_all_non_constant_species_ids`].
+ */
+async getAllNonConstantSpeciesIds(handle: string | null) : Promise<Result<string[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_all_non_constant_species_ids", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves the name of a specific species by its ID
+ * 
+ * Tauri adapter of [`document::enzmldoc::get_species_name`].
+ */
+async getSpeciesName(speciesId: string, handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_species_name", { speciesId, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Sets the title of the EnzymeML document
+ * 
+ * Tauri adapter of [`document::enzmldoc::set_title`].
+ */
+async setTitle(title: string, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_title", { title, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves all species with their IDs and names from the EnzymeML document
+ * 
+ * Tauri adapter of [`document::enzmldoc::get_all_species`].
+ */
+async getAllSpecies(handle: string | null) : Promise<Result<[string, string][], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_all_species", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new EnzymeML document and replaces the current document in state
+ * 
+ * Tauri adapter of [`document::enzmldoc::create_document`].
+ */
+async createDocument(enzmldoc: EnzymeMLDocument, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_document", { enzmldoc, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves statistics about the current EnzymeML document
+ * 
+ * Tauri adapter of [`document::enzmldoc::get_stats`].
+ */
+async getStats(handle: string | null) : Promise<Result<Stats, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_stats", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves a specific unit definition by its name
+ * 
+ * # Arguments
+ * * `unit` - The name of the unit to retrieve
+ * 
+ * # Returns
+ * Result containing the UnitDefinition object or an error if not found
+ */
+async getUnit(unit: string) : Promise<Result<UnitDefinition, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_unit", { unit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves all unit definitions for a specific unit type
+ * 
+ * # Arguments
+ * * `unit_type` - The type of units to retrieve (e.g., concentration, time, volume)
+ * 
+ * # Returns
+ * Result containing a HashMap of unit names to UnitDefinition objects, or an error
+ */
+async getUnitGroup(unitType: UnitGroup) : Promise<Result<Partial<{ [key in string]: UnitDefinition }>, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_unit_group", { unitType }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves unit definitions for multiple unit types
+ * 
+ * # Arguments
+ * * `unit_types` - Vector of unit types to retrieve units for
+ * 
+ * # Returns
+ * Result containing a combined HashMap of all unit names to UnitDefinition objects, or an error
+ */
+async getUnitGroups(unitTypes: UnitGroup[]) : Promise<Result<Partial<{ [key in string]: UnitDefinition }>, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_unit_groups", { unitTypes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new small molecule in the EnzymeML document and adds a corresponding ODE equation
+ * 
+ * Tauri adapter of [`smallmols::create_small_mol`].
+ */
+async createSmallMol(handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_small_mol", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves a specific small molecule from the EnzymeML document
+ * 
+ * Tauri adapter of [`smallmols::get_small_mol`].
+ */
+async getSmallMol(id: string, handle: string | null) : Promise<Result<SmallMolecule, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_small_mol", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates an existing small molecule in the EnzymeML document
+ * 
+ * Tauri adapter of [`smallmols::update_small_mol`].
+ */
+async updateSmallMol(data: SmallMolecule, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_small_mol", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a small molecule and its associated ODE from the EnzymeML document
+ * 
+ * Tauri adapter of [`smallmols::delete_small_mol`].
+ */
+async deleteSmallMol(id: string, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_small_mol", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists all small molecules in the EnzymeML document
+ * 
+ * Tauri adapter of [`smallmols::list_small_mols`].
+ */
+async listSmallMols(handle: string | null) : Promise<Result<[string, string][], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_small_mols", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists all small molecules in the EnzymeML document with their SMILES strings
+ * 
+ * Tauri adapter of [`smallmols::list_small_mol_smiles`].
+ */
+async listSmallMolSmiles(handle: string | null) : Promise<Result<Partial<{ [key in string]: string }>, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_small_mol_smiles", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a small molecule to the EnzymeML document
+ * 
+ * Tauri adapter of [`smallmols::add_small_mol`].
+ */
+async addSmallMol(object: SmallMolecule, handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_small_mol", { object, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds multiple small molecules to the EnzymeML document
+ * 
+ * Tauri adapter of [`smallmols::add_small_mols`].
+ */
+async addSmallMols(data: SmallMolecule[], handle: string | null) : Promise<Result<string[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_small_mols", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new vessel in the EnzymeML document
+ * 
+ * Tauri adapter of [`vessels::create_vessel`].
+ */
+async createVessel(handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_vessel", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves a specific vessel from the EnzymeML document
+ * 
+ * Tauri adapter of [`vessels::get_vessel`].
+ */
+async getVessel(id: string, handle: string | null) : Promise<Result<Vessel, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_vessel", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates an existing vessel in the EnzymeML document
+ * 
+ * Tauri adapter of [`vessels::update_vessel`].
+ */
+async updateVessel(data: Vessel, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_vessel", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a vessel from the EnzymeML document
+ * 
+ * Tauri adapter of [`vessels::delete_vessel`].
+ */
+async deleteVessel(id: string, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_vessel", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves a list of all vessels in the EnzymeML document
+ * 
+ * Tauri adapter of [`vessels::list_vessels`].
+ */
+async listVessels(handle: string | null) : Promise<Result<[string, string][], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_vessels", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a small molecule to the EnzymeML document
+ * 
+ * Tauri adapter of [`vessels::add_vessel`].
+ */
+async addVessel(object: Vessel, handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_vessel", { object, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds multiple vessels to the EnzymeML document
+ * 
+ * Tauri adapter of [`vessels::add_vessels`].
+ */
+async addVessels(data: Vessel[], handle: string | null) : Promise<Result<string[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_vessels", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new protein in the EnzymeML document
+ * 
+ * Tauri adapter of [`proteins::create_protein`].
+ */
+async createProtein(handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_protein", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves a specific protein by its ID
+ * 
+ * Tauri adapter of [`proteins::get_protein`].
+ */
+async getProtein(id: string, handle: string | null) : Promise<Result<Protein, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_protein", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates an existing protein in the EnzymeML document
+ * 
+ * Tauri adapter of [`proteins::update_protein`].
+ */
+async updateProtein(data: Protein, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_protein", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a protein from the EnzymeML document
+ * 
+ * Tauri adapter of [`proteins::delete_protein`].
+ */
+async deleteProtein(id: string, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_protein", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves all proteins from the EnzymeML document
+ * 
+ * Tauri adapter of [`proteins::list_proteins`].
+ */
+async listProteins(handle: string | null) : Promise<Result<[string, string][], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_proteins", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a small molecule to the EnzymeML document
+ * 
+ * Tauri adapter of [`proteins::add_protein`].
+ */
+async addProtein(object: Protein, handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_protein", { object, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds multiple proteins to the EnzymeML document
+ * 
+ * Tauri adapter of [`proteins::add_proteins`].
+ */
+async addProteins(data: Protein[], handle: string | null) : Promise<Result<string[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_proteins", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new reaction in the EnzymeML document
+ * 
+ * Tauri adapter of [`reactions::create_reaction`].
+ */
+async createReaction(handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_reaction", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves a specific reaction by its ID
+ * 
+ * Tauri adapter of [`reactions::get_reaction`].
+ */
+async getReaction(id: string, handle: string | null) : Promise<Result<Reaction, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_reaction", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates an existing reaction in the EnzymeML document
+ * 
+ * Tauri adapter of [`reactions::update_reaction`].
+ */
+async updateReaction(data: Reaction, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_reaction", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a reaction from the EnzymeML document
+ * 
+ * Tauri adapter of [`reactions::delete_reaction`].
+ */
+async deleteReaction(id: string, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_reaction", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves all reactions from the EnzymeML document
+ * 
+ * Tauri adapter of [`reactions::list_reactions`].
+ */
+async listReactions(handle: string | null) : Promise<Result<[string, string][], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_reactions", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a small molecule to the EnzymeML document
+ * 
+ * Tauri adapter of [`reactions::add_reaction`].
+ */
+async addReaction(object: Reaction, handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_reaction", { object, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds multiple reactions to the EnzymeML document
+ * 
+ * Tauri adapter of [`reactions::add_reactions`].
+ */
+async addReactions(data: Reaction[], handle: string | null) : Promise<Result<string[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_reactions", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates an existing equation in the EnzymeML document
+ * 
+ * Tauri adapter of [`equations::update_equation`].
+ */
+async updateEquation(data: Equation, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_equation", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves a specific equation by its species ID
+ * 
+ * Tauri adapter of [`equations::get_equation`].
+ */
+async getEquation(id: string, handle: string | null) : Promise<Result<Equation, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_equation", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes an equation from the EnzymeML document
+ * 
+ * Tauri adapter of [`equations::delete_equation`].
+ */
+async deleteEquation(id: string, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_equation", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new equation in the EnzymeML document
+ * 
+ * Tauri adapter of [`equations::create_equation`].
+ */
+async createEquation(handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_equation", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists all equations in the EnzymeML document
+ * 
+ * Tauri adapter of [`equations::list_equations`].
+ */
+async listEquations(handle: string | null) : Promise<Result<[string, EquationType][], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_equations", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Derives ODE equations from reaction kinetics
+ * 
+ * Tauri adapter of [`equations::derive_from_reactions`].
+ */
+async deriveFromReactions(handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("derive_from_reactions", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves all parameters from the EnzymeML document
+ * 
+ * Tauri adapter of [`parameters::list_parameters`].
+ */
+async listParameters(handle: string | null) : Promise<Result<[string, string][], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_parameters", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new parameter in the EnzymeML document
+ * 
+ * Tauri adapter of [`parameters::create_parameter`].
+ */
+async createParameter(handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_parameter", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves a specific parameter by its ID
+ * 
+ * Tauri adapter of [`parameters::get_parameter`].
+ */
+async getParameter(id: string, handle: string | null) : Promise<Result<Parameter, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_parameter", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates an existing parameter in the EnzymeML document
+ * 
+ * Tauri adapter of [`parameters::update_parameter`].
+ */
+async updateParameter(data: Parameter, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_parameter", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates a single value of a parameter in the EnzymeML document
+ * 
+ * Tauri adapter of [`parameters::partial_update_parameter`].
+ */
+async partialUpdateParameter(pid: string, key: string, value: number, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("partial_update_parameter", { pid, key, value, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a parameter from the EnzymeML document
+ * 
+ * Tauri adapter of [`parameters::delete_parameter`].
+ */
+async deleteParameter(id: string, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_parameter", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new measurement in the EnzymeML document
+ * 
+ * Tauri adapter of [`measurements::create_measurement`].
+ */
+async createMeasurement(handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_measurement", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves a specific measurement by its ID
+ * 
+ * Tauri adapter of [`measurements::get_measurement`].
+ */
+async getMeasurement(id: string, handle: string | null) : Promise<Result<Measurement, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_measurement", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves data points for visualization from a measurement
+ * 
+ * Tauri adapter of [`measurements::get_datapoints`].
+ */
+async getDatapoints(id: string, handle: string | null) : Promise<Result<VisData[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_datapoints", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Updates an existing measurement in the EnzymeML document
+ * 
+ * Tauri adapter of [`measurements::update_measurement`].
+ */
+async updateMeasurement(data: Measurement, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_measurement", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a measurement from the EnzymeML document
+ * 
+ * Tauri adapter of [`measurements::delete_measurement`].
+ */
+async deleteMeasurement(id: string, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_measurement", { id, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves a list of all measurements with their IDs and names
+ * 
+ * Tauri adapter of [`measurements::list_measurements`].
+ */
+async listMeasurements(handle: string | null) : Promise<Result<[string, string][], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_measurements", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a small molecule to the EnzymeML document
+ * 
+ * Tauri adapter of [`measurements::add_measurement`].
+ */
+async addMeasurement(object: Measurement, handle: string | null) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_measurement", { object, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds multiple small molecules to the EnzymeML document
+ * 
+ * Tauri adapter of [`measurements::add_measurements`].
+ */
+async addMeasurements(data: Measurement[], handle: string | null) : Promise<Result<string[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_measurements", { data, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Opens a new visualization window for the EnzymeML application
+ * 
+ * This function creates a new Tauri window dedicated to displaying visualizations
+ * of the EnzymeML data. The window is configured with specific properties including
+ * no decorations, transparency support, and a fixed initial size. The window loads
+ * the visualization HTML file and is set to be resizable for user convenience.
+ * 
+ * # Arguments
+ * * `app` - Handle to the Tauri application for window creation and management
+ * 
+ * # Returns
+ * Result indicating success with empty tuple or failure with error message string
+ */
+async openVisualisation() : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_visualisation") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Installs the MCP server binary to the user's configuration directory
+ * 
+ * This command allows the user to manually trigger the installation of the MCP server
+ * binary, which enables Claude integration. The binary is copied from the application's
+ * resources to the user's config directory and made executable.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access resource paths
+ * 
+ * # Returns
+ * Result containing either a success message with the installation path or an error message
+ */
+async installMcpServer() : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("install_mcp_server") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves the stored OpenAI API token from the secure store
+ * 
+ * This command fetches the OpenAI API token that was previously saved by the user.
+ * The token is stored securely using Tauri's store plugin.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access the store
+ * 
+ * # Returns
+ * Result containing either the token string (or empty string if not set) or an error message
+ */
+async getOpenaiToken() : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_openai_token") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Saves the OpenAI API token to the secure store
+ * 
+ * This command stores the OpenAI API token securely using Tauri's store plugin.
+ * The token will be persisted across application sessions.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access the store
+ * * `token` - The OpenAI API token to store
+ * 
+ * # Returns
+ * Result containing either a success message or an error message
+ */
+async setOpenaiToken(token: string) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_openai_token", { token }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves the settings of the local REST API
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access the store
+ * 
+ * # Returns
+ * Result containing either the API settings or an error message
+ */
+async getApiSettings() : Promise<Result<ApiSettings, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_api_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Saves the settings of the local REST API
+ * 
+ * Enabling or disabling write access takes effect immediately, whereas a
+ * changed host or port is applied after restarting the application.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access the store
+ * * `api_state` - The shared state of the running REST API
+ * * `settings` - The new API settings
+ * 
+ * # Returns
+ * Result containing either a success message or an error message
+ */
+async setApiSettings(settings: ApiSettings) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_api_settings", { settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves the token clients have to provide to access the local REST API
+ * 
+ * # Arguments
+ * * `api_state` - The shared state of the running REST API
+ * 
+ * # Returns
+ * The API token of this install
+ */
+async getApiToken() : Promise<string> {
+    return await TAURI_INVOKE("get_api_token");
+},
+/**
+ * Generates a new token for the local REST API
+ * 
+ * The new token is persisted and applied immediately, so clients using the
+ * previous token lose access to the API. Registered MCP clients read the
+ * token from the config store whenever they launch the stdio bridge and
+ * thus keep their access.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access the store
+ * * `api_state` - The shared state of the running REST API
+ * 
+ * # Returns
+ * Result containing either the new token or an error message
+ */
+async regenerateApiToken() : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("regenerate_api_token") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves the schedule of the backups of the database
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access the store
+ * 
+ * # Returns
+ * Result containing either the backup settings or an error message
+ */
+async getBackupSettings() : Promise<Result<BackupSettings, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_backup_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Saves the schedule of the backups of the database
+ * 
+ * The new schedule applies from the next check of the schedule on.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access the store
+ * * `settings` - The new backup settings
+ * 
+ * # Returns
+ * Result containing either a success message or an error message
+ */
+async setBackupSettings(settings: BackupSettings) : Promise<Result<string, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_backup_settings", { settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves all active Jupyter Lab sessions
+ * 
+ * This function returns a list of all currently running Jupyter Lab sessions
+ * managed by the application. Each session contains information about the
+ * session ID, URL, and port number.
+ * 
+ * # Arguments
+ * * `jupyter_state` - The shared Jupyter state containing session information
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(Vec<JupyterSessionInfo>)` with details of all active sessions
+ * - `Err(SuiteError)` if the operation fails
+ */
+async getJupyterSessions() : Promise<Result<JupyterSessionInfo[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_jupyter_sessions") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Terminates a specific Jupyter Lab session by ID
+ * 
+ * This function kills the child process associated with the given session ID,
+ * effectively stopping the Jupyter Lab server. The session is identified by
+ * its unique string identifier. After successful termination, emits a
+ * `jupyter_update` event to notify the frontend.
+ * 
+ * # Arguments
+ * * `app_handle` - The Tauri application handle for emitting events
+ * * `jupyter_state` - The shared Jupyter state managing active sessions
+ * * `id` - The unique identifier of the session to terminate
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(())` if the session is terminated successfully
+ * - `Err(SuiteError)` if the operation fails, containing the error message
+ * 
+ * # Errors
+ * Returns an error if:
+ * - No session with the given ID exists
+ * - The child process cannot be killed
+ * - The process has already been terminated
+ * - Event emission fails
+ */
+async killJupyter(id: string) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("kill_jupyter", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Starts a Jupyter Lab server with optional template
+ * 
+ * This function spawns a new Jupyter Lab process on an available port.
+ * The server is configured to run in a dedicated directory for the current
+ * EnzymeML document, ensuring each document has its own workspace.
+ * The directory structure follows: `~/enzymeml-suite/projects/{document_name}/`
+ * 
+ * If a template is specified, it will be written to the project directory
+ * before starting the server (only if the file doesn't already exist).
+ * 
+ * # Arguments
+ * * `app_handle` - Handle to the Tauri application for shell command execution
+ * * `template` - Optional template name to create in the project directory
+ * * `jupyter_state` - The shared Jupyter state for managing server information
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(())` if the Jupyter Lab server starts successfully
+ * - `Err(SuiteError)` if the operation fails, containing the error message
+ * 
+ * # Errors
+ * Returns an error if:
+ * - The jupyter command fails to spawn
+ * - Jupyter is not installed on the system
+ * - Directory creation fails
+ * - Template writing fails
+ * - The child process cannot be managed properly
+ */
+async startJupyter(template: string | null, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_jupyter", { template, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Detects all available Python installations on the system
+ * 
+ * This function uses the python_launcher crate to find all Python executables
+ * and ranks them by priority (anaconda > homebrew > others). It stores the
+ * detected installations in the JupyterState and automatically selects the
+ * highest priority one if no selection has been made. Also loads custom Python
+ * environments from the config store.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle for accessing the store
+ * * `jupyter_state` - The shared Jupyter state for storing detected Pythons
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(Vec<PythonInstallation>)` with all detected Python installations
+ * - `Err(SuiteError)` if the operation fails
+ */
+async detectPythonInstallations() : Promise<Result<PythonInstallation[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("detect_python_installations") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists all detected Python installations
+ * 
+ * # Arguments
+ * * `jupyter_state` - The shared Jupyter state containing detected Pythons
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(Vec<PythonInstallation>)` with all detected Python installations
+ * - `Err(SuiteError)` if the operation fails
+ */
+async listDetectedPythons() : Promise<Result<PythonInstallation[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_detected_pythons") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets the currently selected Python installation path
+ * 
+ * # Arguments
+ * * `jupyter_state` - The shared Jupyter state containing the selected Python
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(Option<String>)` with the selected Python path if set
+ * - `Err(SuiteError)` if the operation fails
+ */
+async getSelectedPython() : Promise<Result<string | null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_selected_python") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Sets the preferred Python installation path
+ * 
+ * # Arguments
+ * * `jupyter_state` - The shared Jupyter state to update
+ * * `path` - The path to the Python executable to use
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(())` if the path is set successfully
+ * - `Err(SuiteError)` if the operation fails
+ */
+async setSelectedPython(path: string) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_selected_python", { path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a custom Python environment to the config store
+ * 
+ * This function validates a Python executable path, extracts its version information,
+ * and persists it to the config store's `custom_python_envs` array. The custom
+ * environment will be merged with auto-detected installations on subsequent detections.
+ * The newly added Python environment is automatically selected as the active Python.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle for executing shell commands and accessing the store
+ * * `jupyter_state` - The shared Jupyter state for managing Python installations
+ * * `path` - The path to the Python executable to add
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(())` if the Python environment is added and persisted successfully
+ * - `Err(SuiteError)` if the operation fails, containing the error message
+ * 
+ * # Errors
+ * Returns an error if:
+ * - The provided path is a directory instead of an executable file
+ * - The Python executable cannot be verified (invalid Python installation)
+ * - The version cannot be parsed from the Python output
+ * - The config store cannot be accessed or saved
+ */
+async addPythonEnv() : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_python_env") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves all custom Python environments from the configuration store
+ * 
+ * This function accesses the application's configuration store to retrieve the list
+ * of custom Python environments that have been manually added by the user. These
+ * custom environments are stored separately from auto-detected Python installations
+ * and persist across application restarts. If no custom environments have been
+ * configured yet, the function initializes an empty array in the store for future use.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used for accessing the configuration store
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(Vec<PythonInstallation>)` with all custom Python environments from the store
+ * - `Err(SuiteError)` if the operation fails, containing a descriptive error message
+ * 
+ * # Errors
+ * Returns an error if:
+ * - The configuration store cannot be accessed or opened
+ * - The stored custom Python environments data is corrupted or cannot be parsed
+ * - The store cannot be saved when initializing an empty array
+ */
+async listCustomPythonEnvs() : Promise<Result<PythonInstallation[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_custom_python_envs") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Installs JupyterLab using pip with the selected Python installation
+ * 
+ * This function executes the `python -m pip install jupyterlab jupyter ipywidgets` command
+ * using the selected Python installation to install JupyterLab and its dependencies.
+ * The installation process is streamed and emits events to the frontend for real-time progress updates.
+ * 
+ * # Arguments
+ * * `app_handle` - The Tauri application handle for executing shell commands and emitting events
+ * * `jupyter_state` - The shared Jupyter state containing selected Python path
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(())` if JupyterLab is installed successfully
+ * - `Err(SuiteError)` if the installation fails, containing the error message
+ * 
+ * # Errors
+ * Returns an error if:
+ * - No Python is selected
+ * - The pip command cannot be executed (pip not found in PATH)
+ * - The installation process fails (e.g., network issues, permission problems)
+ * - JupyterLab package cannot be found or installed
+ * - Insufficient disk space or system resources
+ */
+async installJupyterLab() : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("install_jupyter_lab") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks if JupyterLab is installed on the system
+ * 
+ * This function executes the `jupyter lab --version` command using the selected
+ * Python installation to determine if JupyterLab is available.
+ * 
+ * # Arguments
+ * * `app_handle` - The Tauri application handle for executing shell commands
+ * * `jupyter_state` - The shared Jupyter state containing selected Python path
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(true)` if JupyterLab is installed and accessible
+ * - `Ok(false)` if JupyterLab is not found or command fails
+ * - `Err(SuiteError)` if the shell command cannot be executed
+ */
+async isJupyterLabInstalled() : Promise<Result<boolean, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("is_jupyter_lab_installed") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves all Jupyter template metadata
+ * 
+ * This function returns a vector containing all Jupyter template metadata
+ * embedded at compile time. The metadata includes the template name, description,
+ * template path, repository, and category.
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(Vec<JupyterTemplate>)` with all available template metadata
+ * - `Err(SuiteError)` if the operation fails
+ */
+async getJupyterTemplateMetadata() : Promise<Result<JupyterTemplate[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_jupyter_template_metadata") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a template to the current project directory
+ * 
+ * This function writes a specified Jupyter template to the current project's
+ * directory. The template is only written if it doesn't already exist to
+ * avoid overwriting user modifications.
+ * 
+ * # Arguments
+ * * `template_name` - The name of the template to add to the project
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(())` if the template is added successfully
+ * - `Err(SuiteError)` if the operation fails, containing the error message
+ */
+async addTemplateToProject(templateName: string, handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_template_to_project", { templateName, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Opens the project folder in the system file explorer
+ * 
+ * This function opens the project folder in the system file explorer.
+ * If the project folder does not exist, it creates it.
+ * 
+ * # Arguments
+ * * `app_handle` - The Tauri application handle for opening the project folder
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * A `Result` containing:
+ * - `Ok(())` if the project folder is opened successfully
+ * - `Err(SuiteError)` if the operation fails, containing the error message
+ * 
+ * # Errors
+ * Returns an error if:
+ * - The project folder does not exist
+ * - The project folder cannot be created
+ * - The project folder cannot be opened
+ */
+async openProjectFolder(handle: string | null) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_project_folder", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves the current validation report for the EnzymeML document
+ * 
+ * This function returns the cached validation report that contains consistency
+ * checks and validation results for the current EnzymeML document. The report
+ * includes information about data integrity, missing required fields, and
+ * other validation issues that may need attention.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * Result containing either the validation Report object or an error message
+ */
+async getValidationReport(handle: string | null) : Promise<Result<Report, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_validation_report", { handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Retrieves validation results filtered by a specific identifier
+ * 
+ * This function filters the current validation report to return only those
+ * validation results that match the specified identifier. This is particularly
+ * useful when you need to examine validation issues related to a specific
+ * component, measurement, or entity within the EnzymeML document. The function
+ * accesses the cached validation report and applies the identifier filter to
+ * return a subset of validation results that are relevant to the requested
+ * identifier.
+ * 
+ * # Arguments
+ * * `workspace` - The open documents of the application
+ * * `identifier` - The specific identifier string to filter validation results by
+ * * `handle` - Handle of the document, `None` for the active document
+ * 
+ * # Returns
+ * Result containing the ValidationResult objects that match the specified identifier
+ */
+async getValidationReportByIdentifier(identifier: string, handle: string | null) : Promise<Result<ValidationResult[], SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_validation_report_by_identifier", { identifier, handle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Registers the EnzymeML MCP server with the specified AI client
+ * 
+ * This command registers the EnzymeML MCP server binary with AI clients like
+ * Claude Desktop, Cursor or VS Code, enabling them to interact with EnzymeML
+ * documents and data through the Model Context Protocol.
+ * 
+ * The function reads the client's existing MCP configuration, adds the EnzymeML
+ * server to it, and writes the updated configuration back to the client's
+ * configuration file. Other servers and settings of the client are kept and the
+ * previous configuration is backed up next to the file.
+ * 
+ * # Arguments
+ * * `client_type` - The type of AI client to register with
+ * 
+ * # Returns
+ * * `Ok(())` if the registration was successful
+ * * `Err(SuiteError)` if there was an error during registration
+ * 
+ * # Errors
+ * * Returns error if the client configuration directory cannot be created
+ * * Returns error if the existing configuration cannot be read or parsed
+ * * Returns error if the updated configuration cannot be written
+ */
+async registerMcp(clientType: ClientType) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("register_mcp", { clientType }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Removes the EnzymeML MCP server from the specified AI client
+ * 
+ * Only the `EnzymeMLSuite` entry is removed from the client's configuration,
+ * all other servers and settings are kept. The previous configuration is
+ * backed up next to the file. Unregistering a client without registration
+ * succeeds without modifying any file.
+ * 
+ * # Arguments
+ * * `client_type` - The type of AI client to unregister from
+ * 
+ * # Returns
+ * * `Ok(())` if the server is no longer registered with the client
+ * * `Err(SuiteError)` if the configuration cannot be read, parsed or written
+ */
+async unregisterMcp(clientType: ClientType) : Promise<Result<null, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("unregister_mcp", { clientType }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Shows the configuration of a client after registering or unregistering
+ * 
+ * Performs a dry-run of `register_mcp` or `unregister_mcp` without writing
+ * anything, so the user can review the resulting configuration first.
+ * 
+ * # Arguments
+ * * `client_type` - The type of AI client whose configuration to preview
+ * * `action` - Whether to preview registering or unregistering
+ * 
+ * # Returns
+ * * `Ok(McpConfigPreview)` with the current and the resulting configuration
+ * * `Err(SuiteError)` if the existing configuration cannot be read or parsed
+ */
+async previewMcpConfig(clientType: ClientType, action: McpConfigAction) : Promise<Result<McpConfigPreview, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("preview_mcp_config", { clientType, action }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks if the EnzymeML MCP server is already registered with the specified AI client
+ * 
+ * This command checks whether the EnzymeML MCP server is already configured
+ * in the specified AI client's MCP configuration file.
+ * 
+ * # Arguments
+ * * `client_type` - The type of AI client to check
+ * 
+ * # Returns
+ * * `Ok(true)` if the EnzymeML server is already registered
+ * * `Ok(false)` if the EnzymeML server is not registered or config doesn't exist
+ * * `Err(SuiteError)` if there was an error reading the configuration
+ */
+async isMcpRegistered(clientType: ClientType) : Promise<Result<boolean, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("is_mcp_registered", { clientType }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks if the MCP server binary is installed on the system
+ * 
+ * This command checks whether the MCP server binary exists at the expected
+ * installation path in the user's configuration directory.
+ * 
+ * # Returns
+ * * `Ok(true)` if the binary is installed
+ * * `Ok(false)` if the binary is not installed
+ * * `Err(SuiteError)` if there was an error checking the installation
+ */
+async isMcpBinaryInstalled() : Promise<Result<boolean, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("is_mcp_binary_installed") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reports the installed and bundled MCP server versions and all client registrations
+ * 
+ * The installed binary is compared to the binary bundled with the application
+ * by hash, so an outdated install is detected even if both report the same
+ * version. For every client with a registration, the registered command is
+ * checked to exist and to point to the stdio bridge of this application.
+ * Registrations do not use the installed binary, so a repair is only needed
+ * for registrations pointing elsewhere or exposing the API token.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to access resource paths
+ * 
+ * # Returns
+ * * `Ok(McpStatus)` with the state of the binary and all clients
+ * * `Err(SuiteError)` if the resource path cannot be resolved or a binary cannot be read
+ */
+async getMcpStatus() : Promise<Result<McpStatus, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_mcp_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Rewrites all existing registrations of the EnzymeML MCP server
+ * 
+ * Clients without a registration are left untouched. Registrations are
+ * rewritten in place, so they point to the stdio bridge and carry the
+ * current API settings afterwards. A plain text API token written by earlier
+ * versions is removed.
+ * 
+ * # Arguments
+ * * `app` - The Tauri application handle used to read the API settings
+ * 
+ * # Returns
+ * * `Ok(McpStatus)` with the state after the repair
+ * * `Err(SuiteError)` if a configuration cannot be rewritten
+ */
+async repairMcp() : Promise<Result<McpStatus, SuiteError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("repair_mcp") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+}
+}
+
+/** user-defined events **/
+
+
+export const events = __makeEvents__<{
+updateDocument: UpdateDocument,
+updateReport: UpdateReport,
+updateSmallMols: UpdateSmallMols,
+updateProteins: UpdateProteins,
+updateVessels: UpdateVessels,
+updateReactions: UpdateReactions,
+updateMeasurements: UpdateMeasurements,
+updateParameters: UpdateParameters,
+updateEquations: UpdateEquations,
+updateVis: UpdateVis,
+documentChanged: DocumentChanged,
+updateWorkspace: UpdateWorkspace,
+fileChanged: FileChanged,
+navigateTo: NavigateTo,
+jupyterInstallOutput: JupyterInstallOutput,
+jupyterInstallStatus: JupyterInstallStatus,
+jupyterUpdate: JupyterUpdate,
+mcpInstallOutput: McpInstallOutput,
+mcpInstallStatus: McpInstallStatus,
+mcpRegisterOutput: McpRegisterOutput,
+mcpRegisterStatus: McpRegisterStatus
+}>({
+updateDocument: "update_document",
+updateReport: "update_report",
+updateSmallMols: "update_small_mols",
+updateProteins: "update_proteins",
+updateVessels: "update_vessels",
+updateReactions: "update_reactions",
+updateMeasurements: "update_measurements",
+updateParameters: "update_parameters",
+updateEquations: "update_equations",
+updateVis: "update_vis",
+documentChanged: "document_changed",
+updateWorkspace: "update_workspace",
+fileChanged: "file_changed",
+navigateTo: "navigate_to",
+jupyterInstallOutput: "jupyter-install-output",
+jupyterInstallStatus: "jupyter-install-status",
+jupyterUpdate: "jupyter_update",
+mcpInstallOutput: "mcp-install-output",
+mcpInstallStatus: "mcp-install-status",
+mcpRegisterOutput: "mcp-register-output",
+mcpRegisterStatus: "mcp-register-status"
+})
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+/**
+ * User configurable settings of the REST API
+ * 
+ * Changes to `host` and `port` take effect after restarting the application,
+ * whereas `allow_writes` is applied immediately.
+ */
+export type ApiSettings = { 
+/**
+ * Address the REST API binds to
+ */
+host: string; 
+/**
+ * Port the REST API listens on
+ */
+port: number; 
+/**
+ * Whether routes that modify documents are enabled
+ */
+allow_writes: boolean }
+/**
+ * Kinetic law within an archive, including the species, assignables and parameters it involves
+ */
+export type ArchivedLaw = { name: string; equation: string; 
+/**
+ * Species of the law as pairs of name and role
+ */
+species: [string, string][]; 
+/**
+ * Assignables of the law as pairs of name and type
+ */
+assignables: [string, string][]; 
+/**
+ * Names of the parameters of the law
+ */
+parameters: string[] }
+/**
+ * Backup of the database
+ */
+export type BackupEntry = { 
+/**
+ * File name of the backup within the backup directory
+ */
+file: string; 
+/**
+ * Time the backup was taken, in seconds since the Unix epoch
+ */
+created_at: number; 
+/**
+ * Size of the backup in bytes
+ */
+size: number }
+/**
+ * User configurable schedule of the backups
+ */
+export type BackupSettings = { 
+/**
+ * Whether backups are taken automatically
+ */
+enabled: boolean; 
+/**
+ * Hours between two automatic backups
+ */
+interval_hours: number; 
+/**
+ * Number of most recent backups that are kept
+ */
+keep: number }
+export type BaseUnit = { kind: UnitType; exponent: number; multiplier?: number | null; scale?: number | null }
+/**
+ * Operation that changed the document
+ */
+export type ChangeOp = 
+/**
+ * Entities have been created
+ */
+"create" | 
+/**
+ * Entities have been updated
+ */
+"update" | 
+/**
+ * Entities have been deleted
+ */
+"delete" | 
+/**
+ * Many entities changed at once, e.g. by an import or a derivation
+ */
+"bulk"
+/**
+ * Supported AI client types for MCP server registration
+ * 
+ * This enum represents the different AI clients that support the Model Context
+ * Protocol and can be configured to use the EnzymeML MCP server.
+ */
+export type ClientType = 
+/**
+ * Anthropic's Claude Desktop application
+ */
+"ClaudeDesktop" | 
+/**
+ * Cursor AI code editor
+ */
+"Cursor" | 
+/**
+ * Visual Studio Code
+ */
+"VSCode" | 
+/**
+ * Windsurf AI code editor
+ */
+"Windsurf" | 
+/**
+ * Zed code editor
+ */
+"Zed" | 
+/**
+ * Continue coding assistant
+ */
+"Continue" | 
+/**
+ * OpenAI Codex CLI
+ */
+"Codex"
+export type Complex = { id: string; name: string; constant: boolean; vessel_id?: string | null; participants: string[] }
+/**
+ * How entries of an archive that conflict with existing entries are handled
+ */
+export type ConflictStrategy = 
+/**
+ * The existing entry is kept and the archived one left out
+ */
+"skip" | 
+/**
+ * The existing entry is overwritten with the archived one, unless
+ * another entry shares a unique key with the archived one
+ */
+"replace" | 
+/**
+ * The archived entry is added under a new name, unless the entries
+ * share another unique key, such as the mail address of a creator
+ */
+"keep_both"
+export type Creator = { given_name: string; family_name: string; mail: string }
+/**
+ * Represents a creator in the database
+ */
+export type DBCreator = { id: number; given_name: string; family_name: string; mail: string }
+/**
+ * Represents a protein in the database
+ */
+export type DBProtein = { id: number; name: string; sequence: string | null; ecnumber: string | null; organism: string | null; organism_tax_id: string | null }
+/**
+ * Represents a small molecule in the database
+ */
+export type DBSmallMolecule = { id: number; name: string; canonical_smiles: string | null; inchi: string | null; inchikey: string | null; references: StringArray }
+/**
+ * Represents a vessel in the database
+ */
+export type DBVessel = { id: number; name: string; volume: number; unit: string; constant: boolean }
+/**
+ * Represents a single data point with x and y coordinates
+ */
+export type DataPoint = { x: number; y: number }
+export type DataTypes = "absorbance" | "amount" | "concentration" | "conversion" | "fluorescence" | "peakarea" | "transmittance" | "turnover" | "yield"
+/**
+ * Status of the library the application currently works with
+ */
+export type DatabaseStatus = { 
+/**
+ * Name of the library
+ */
+library: string; 
+/**
+ * Path to the database file of the library
+ */
+path: string; 
+/**
+ * Whether the database could be opened
+ */
+available: boolean; 
+/**
+ * Reason the database is unavailable
+ */
+error: string | null }
+/**
+ * Kind of the compared entities
+ */
+export type DiffEntity = "small_molecule" | "protein" | "complex" | "vessel" | "reaction" | "equation" | "parameter" | "measurement"
+/**
+ * Options of the comparison of two documents
+ */
+export type DiffOptions = { 
+/**
+ * Whether entities without a counterpart of the same ID are matched by name
+ */
+match_by_name?: boolean; 
+/**
+ * Absolute difference up to which numbers are considered equal, must not be negative
+ */
+tolerance?: number }
+/**
+ * How an entity differs between the compared documents
+ */
+export type DiffStatus = 
+/**
+ * The entity only exists in the new document
+ */
+"added" | 
+/**
+ * The entity only exists in the old document
+ */
+"removed" | 
+/**
+ * The entity exists in both documents with different fields
+ */
+"modified"
+/**
+ * Event describing a change of the document
+ * 
+ * Carries the kind and IDs of the affected entities, so views can refetch
+ * only what changed. The revision increases with every change of the state
+ * and allows to discard outdated responses.
+ */
+export type DocumentChanged = { 
+/**
+ * Handle of the changed document within the workspace
+ */
+document: string; 
+/**
+ * Kind of the affected entities
+ */
+kind: EntityKind; 
+/**
+ * IDs of the affected entities, empty if the whole collection changed
+ */
+ids: string[]; 
+/**
+ * Operation that changed the entities
+ */
+op: ChangeOp; 
+/**
+ * Revision of the document after the change
+ */
+revision: number }
+/**
+ * Structural difference between two documents
+ */
+export type DocumentDiff = { 
+/**
+ * Changed fields of the document itself, such as its name or creators
+ */
+changes: FieldChange[]; 
+/**
+ * Added, removed and modified entities
+ */
+entities: EntityDiff[] }
+/**
+ * Represents the metadata of a document in the database, leaving out its content
+ */
+export type DocumentMetadata = { id: number; title: string; description: string | null; tags: StringArray; created_at: number; modified_at: number; deleted_at: number | null }
+/**
+ * Filter and order of listed stored documents
+ */
+export type DocumentQuery = { 
+/**
+ * Text the title or description has to contain
+ */
+text?: string | null; 
+/**
+ * Tags the documents must all carry
+ */
+tags?: string[]; 
+/**
+ * Whether the documents in the trash are listed instead of the others
+ */
+trashed?: boolean; 
+/**
+ * Field the documents are sorted by
+ */
+sort?: DocumentSort; 
+/**
+ * Whether the documents are sorted in descending order
+ */
+descending?: boolean }
+/**
+ * Field by which stored documents are sorted
+ */
+export type DocumentSort = 
+/**
+ * Alphabetically by title
+ */
+"title" | 
+/**
+ * By the time the documents have been stored
+ */
+"created" | 
+/**
+ * By the time the documents have last been changed
+ */
+"modified"
+/**
+ * Reference to an EnzymeML document
+ */
+export type DocumentSource = 
+/**
+ * An open document, the active one if no handle is given
+ */
+{ type: "open"; 
+/**
+ * Handle of the open document
+ */
+handle?: string | null } | 
+/**
+ * A document stored in the database
+ */
+{ type: "stored"; 
+/**
+ * Database ID of the document
+ */
+id: number } | 
+/**
+ * An EnzymeML JSON file, not accepted from API clients
+ */
+{ type: "file"; 
+/**
+ * Path of the file
+ */
+path: string } | 
+/**
+ * A document given as is
+ */
+{ type: "inline"; 
+/**
+ * The EnzymeML document
+ */
+content: EnzymeMLDocument }
+/**
+ * Property by which an imported entity has been recognised as duplicate
+ */
+export type DuplicateMatch = 
+/**
+ * Both entities have the same name
+ */
+"name" | 
+/**
+ * Both small molecules have the same InChIKey
+ */
+"inchikey" | 
+/**
+ * Both proteins have the same sequence
+ */
+"sequence" | 
+/**
+ * Both parameters have the same ID and thus the same symbol in equations
+ */
+"id"
+/**
+ * Difference of an entity between the compared documents
+ */
+export type EntityDiff = { 
+/**
+ * Kind of the entity
+ */
+kind: DiffEntity; 
+/**
+ * ID of the entity in the old document, or in the new one if it has been added
+ */
+id: string; 
+/**
+ * ID of the entity in the new document, if it has been matched by name
+ */
+new_id: string | null; 
+/**
+ * Name of the entity, if it has one
+ */
+name: string | null; 
+/**
+ * How the entity differs
+ */
+status: DiffStatus; 
+/**
+ * Changed fields of a modified entity
+ */
+changes: FieldChange[] }
+/**
+ * Kind of the entities affected by a change of the document
+ */
+export type EntityKind = 
+/**
+ * The document itself, e.g. when it has been replaced or renamed
+ */
+"document" | "small_molecule" | "protein" | "vessel" | "reaction" | "measurement" | "parameter" | "equation"
+export type EnzymeMLDocument = { version: string; description?: string | null; name: string; created?: string | null; modified?: string | null; creators: Creator[]; vessels: Vessel[]; proteins: Protein[]; complexes: Complex[]; small_molecules: SmallMolecule[]; reactions: Reaction[]; measurements: Measurement[]; equations: Equation[]; parameters: Parameter[]; references: string[] }
+export type Equation = { species_id: string; equation: string; equation_type: EquationType; variables: Variable[] }
+export type EquationType = "ode" | "assignment" | "initialAssignment" | "rateLaw"
+export type ExposedEnzymeMLState = { handle: string; title: string; doc: EnzymeMLDocument; id: number | null; path: string | null; revision: number; dirty: boolean }
+/**
+ * Changed field of a document or an entity
+ */
+export type FieldChange = { 
+/**
+ * JSON pointer of the field, with nested entities addressed by their ID
+ */
+path: string; 
+/**
+ * Value in the old document as JSON, `None` if the field is missing
+ */
+old: string | null; 
+/**
+ * Value in the new document as JSON, `None` if the field is missing
+ */
+new: string | null }
+/**
+ * Event signalling that the origin file of a document has been changed by another program
+ * 
+ * Emitted once per external version of the file. The frontend offers to
+ * reload the file, keep the document as it is or merge both.
+ */
+export type FileChanged = { 
+/**
+ * Handle of the document within the workspace
+ */
+document: string; 
+/**
+ * Path of the changed file
+ */
+path: string; 
+/**
+ * Whether the document has unsaved changes that a reload would discard
+ */
+dirty: boolean }
+/**
+ * Imported entity that duplicates an entity of the document
+ */
+export type ImportDuplicate = { 
+/**
+ * Kind of the entity
+ */
+kind: DiffEntity; 
+/**
+ * ID of the entity in the imported document
+ */
+id: string; 
+/**
+ * ID of the duplicated entity in the document
+ */
+existing_id: string; 
+/**
+ * Name of the imported entity, if it has one
+ */
+name: string | null; 
+/**
+ * Property by which the duplicate has been recognised
+ */
+matched_by: DuplicateMatch; 
+/**
+ * How the duplicate has been resolved
+ */
+resolution: Resolution }
+/**
+ * Options of importing a document into another
+ */
+export type ImportOptions = { 
+/**
+ * Resolution of duplicates without an individual resolution
+ */
+strategy?: Resolution; 
+/**
+ * Resolutions of individual duplicates, keyed by the ID of the imported entity
+ */
+resolutions?: { [key in string]: Resolution }; 
+/**
+ * Whether only the report is created, leaving the document unchanged
+ */
+dry_run?: boolean }
+/**
+ * Outcome of importing a document into another
+ */
+export type ImportReport = { 
+/**
+ * Imported entities that duplicate entities of the document
+ */
+duplicates: ImportDuplicate[]; 
+/**
+ * Imported entities whose ID changed, including reused and replaced duplicates
+ */
+remapped: RemappedId[]; 
+/**
+ * IDs of the entities added to the document
+ */
+added: string[]; 
+/**
+ * Whether the document has been changed, `false` for dry runs
+ */
+applied: boolean }
+/**
+ * Initial condition of a species in a measurement
+ */
+export type InitialCondition = { 
+/**
+ * ID of the species within the document
+ */
+species_id: string; 
+/**
+ * Name of the species, if it is defined in the document
+ */
+name: string | null; 
+/**
+ * Initial amount of the species
+ */
+initial: number | null; 
+/**
+ * Name of the unit of the measured data
+ */
+unit: string | null }
+export type JupyterInstallOutput = { status: JupyterInstallStatus; output: string }
+export type JupyterInstallStatus = "Success" | "Error" | "Output"
+/**
+ * Lightweight, serializable view of a session (omits the live child handle).
+ */
+export type JupyterSessionInfo = { id: string; url: string; port: number }
+export type JupyterTemplate = { id: string; name: string; description: string; template_path: string; repository: string; category: string }
+/**
+ * Event signalling that a Jupyter Lab session has been started or stopped
+ */
+export type JupyterUpdate = null
+/**
+ * Database holding stored documents and entries saved for reuse
+ */
+export type Library = { 
+/**
+ * Unique name of the library
+ */
+name: string; 
+/**
+ * Path to the database file of the library
+ */
+path: string }
+/**
+ * Entry of an archive that conflicts with an existing entry
+ */
+export type LibraryConflict = { 
+/**
+ * The table holding the entries
+ */
+table: LibraryTable; 
+/**
+ * Name of the conflicting entries
+ */
+name: string; 
+/**
+ * How the conflict was resolved
+ */
+resolution: ConflictStrategy; 
+/**
+ * New name of the archived entry, if it was added under a new name
+ */
+renamed_to: string | null }
+/**
+ * Outcome of importing an archive
+ */
+export type LibraryImportReport = { 
+/**
+ * Numbers of imported entries per table
+ */
+tables: TableImport[]; 
+/**
+ * Entries that differ from existing entries of the same name
+ */
+conflicts: LibraryConflict[] }
+/**
+ * Kinetic law of the library, including the species, assignables and parameters it involves
+ */
+export type LibraryLaw = ({ 
+/**
+ * The unique database ID of the law
+ */
+id: number }) & (ArchivedLaw)
+/**
+ * Registered libraries and the one to work with
+ */
+export type LibrarySettings = { 
+/**
+ * Name of the library opened when starting
+ */
+active: string; 
+/**
+ * The registered libraries
+ */
+libraries: Library[] }
+/**
+ * Table of the library
+ */
+export type LibraryTable = 
+/**
+ * Stored documents
+ */
+"documents" | 
+/**
+ * Saved small molecules
+ */
+"small_molecules" | 
+/**
+ * Saved proteins
+ */
+"proteins" | 
+/**
+ * Saved vessels
+ */
+"vessels" | 
+/**
+ * Saved creators
+ */
+"creators" | 
+/**
+ * Saved kinetic laws
+ */
+"laws"
+/**
+ * Registration state of the EnzymeML MCP server in a single client
+ */
+export type McpClientStatus = { 
+/**
+ * The client the state belongs to
+ */
+client_type: ClientType; 
+/**
+ * Path of the client configuration file
+ */
+config_path: string; 
+/**
+ * Whether the EnzymeML MCP server is registered with the client
+ */
+registered: boolean; 
+/**
+ * Command of the registered server entry, if any
+ */
+command: string | null; 
+/**
+ * Whether the registered command exists on disk
+ */
+command_exists: boolean; 
+/**
+ * Whether the registered command is the stdio bridge of this application
+ */
+command_matches: boolean; 
+/**
+ * Whether the registration holds the API token in plain text, as written
+ * by earlier versions
+ */
+exposes_token: boolean; 
+/**
+ * Error raised while locating or reading the client configuration, if any
+ */
+error: string | null }
+/**
+ * Change to apply to the MCP configuration of a client
+ */
+export type McpConfigAction = 
+/**
+ * Add or update the EnzymeML MCP server entry
+ */
+"Register" | 
+/**
+ * Remove the EnzymeML MCP server entry
+ */
+"Unregister"
+/**
+ * Result of a dry-run of a change to the MCP configuration of a client
+ */
+export type McpConfigPreview = { 
+/**
+ * Path of the client configuration file
+ */
+path: string; 
+/**
+ * Current content of the configuration file, if it exists
+ */
+current: string | null; 
+/**
+ * Content of the configuration file after applying the change
+ */
+updated: string; 
+/**
+ * Whether applying the change modifies the configuration
+ */
+changed: boolean }
+/**
+ * Event payload for MCP installation progress updates
+ */
+export type McpInstallOutput = { status: McpInstallStatus; output: string }
+/**
+ * Status enum for MCP installation events
+ */
+export type McpInstallStatus = "Success" | "Error" | "Output"
+/**
+ * Event payload for MCP registration progress updates
+ */
+export type McpRegisterOutput = { status: McpRegisterStatus; output: string }
+/**
+ * Status enum for MCP registration events
+ */
+export type McpRegisterStatus = "Success" | "Error" | "Output"
+/**
+ * Health of the installed MCP server binary and its client registrations
+ * 
+ * Registrations launch the stdio bridge of this application, so only they
+ * decide whether a repair is needed. The standalone binary is reported for
+ * information.
+ */
+export type McpStatus = { 
+/**
+ * Path where the MCP server binary is installed
+ */
+installed_path: string; 
+/**
+ * Whether the MCP server binary is installed
+ */
+installed: boolean; 
+/**
+ * Version reported by the installed binary, if any
+ */
+installed_version: string | null; 
+/**
+ * Version reported by the binary bundled with the application, if any
+ */
+bundled_version: string | null; 
+/**
+ * Whether the installed binary is identical to the bundled one
+ */
+hash_matches: boolean; 
+/**
+ * Registration state of every supported client
+ */
+clients: McpClientStatus[]; 
+/**
+ * Whether a registration points to a wrong command or exposes the API token
+ */
+needs_repair: boolean }
+export type Measurement = { id: string; name: string; species_data: MeasurementData[]; group_id?: string | null; ph?: number | null; temperature?: number | null; temperature_unit?: UnitDefinition | null }
+export type MeasurementData = { species_id: string; prepared?: number | null; initial?: number | null; data_unit?: UnitDefinition | null; data: number[]; time: number[]; time_unit?: UnitDefinition | null; data_type?: DataTypes | null; is_simulated?: boolean | null }
+/**
+ * Filter of a measurement query
+ * 
+ * Temperatures are given in Kelvin and bounds are inclusive. Measurements
+ * lacking a bounded condition or stating it in an unknown unit do not match.
+ */
+export type MeasurementQuery = { 
+/**
+ * Lowest temperature in Kelvin
+ */
+min_temperature?: number | null; 
+/**
+ * Highest temperature in Kelvin
+ */
+max_temperature?: number | null; 
+/**
+ * Lowest pH
+ */
+min_ph?: number | null; 
+/**
+ * Highest pH
+ */
+max_ph?: number | null; 
+/**
+ * Matches case-insensitively any part of the name or ID of a measured species
+ */
+species?: string | null }
+/**
+ * Measurement of a stored document
+ */
+export type MeasurementRecord = { 
+/**
+ * Database ID of the document
+ */
+document_id: number; 
+/**
+ * Title of the document
+ */
+document_title: string; 
+/**
+ * ID of the measurement within the document
+ */
+measurement_id: string; 
+/**
+ * Name of the measurement
+ */
+name: string; 
+/**
+ * pH of the measurement
+ */
+ph: number | null; 
+/**
+ * Temperature of the measurement as stated in the document
+ */
+temperature: number | null; 
+/**
+ * Name of the unit of the temperature
+ */
+temperature_unit: string | null; 
+/**
+ * Temperature of the measurement in Kelvin, if the unit is known
+ */
+temperature_kelvin: number | null; 
+/**
+ * Initial conditions of the measured species
+ */
+initials: InitialCondition[] }
+/**
+ * Entry of a document that has been changed differently in the document and its file
+ * 
+ * The document keeps its own value of a conflicting entry.
+ */
+export type MergeConflict = { 
+/**
+ * JSON pointer of the entry, with entities addressed by their ID
+ */
+path: string; 
+/**
+ * Value kept from the document as JSON, `null` if it has been removed
+ */
+local: string; 
+/**
+ * Value of the file as JSON, `null` if it has been removed
+ */
+theirs: string }
+/**
+ * Outcome of merging the changes of a file into a document
+ */
+export type MergeReport = { 
+/**
+ * Entries that changed differently in the document and its file
+ */
+conflicts: MergeConflict[] }
+export type ModifierElement = { species_id: string; role: ModifierRole }
+export type ModifierRole = "ACTIVATOR" | "ADDITIVE" | "BIOCATALYST" | "BUFFER" | "CATALYST" | "INHIBITOR" | "SOLVENT"
+/**
+ * Event asking the frontend to navigate to a route, e.g. after a file drop
+ */
+export type NavigateTo = string
+/**
+ * Summary of a document open in the [`Workspace`]
+ */
+export type OpenDocument = { 
+/**
+ * Handle addressing the document while it is open
+ */
+handle: string; 
+/**
+ * Title of the document
+ */
+title: string; 
+/**
+ * Database ID of the document, if it has been saved
+ */
+id: number | null; 
+/**
+ * Whether the document is the active one
+ */
+active: boolean; 
+/**
+ * Whether the document has unsaved changes
+ */
+dirty: boolean }
+export type Parameter = { id: string; name: string; symbol: string; value?: number | null; unit?: UnitDefinition | null; initial_value?: number | null; upper_bound?: number | null; lower_bound?: number | null; stderr?: number | null; fit: boolean; constant?: boolean | null }
+/**
+ * Filter of a parameter query
+ * 
+ * Text filters match case-insensitively any part of the respective fields.
+ * Omitted filters match every parameter.
+ */
+export type ParameterQuery = { 
+/**
+ * Matches the name, symbol or ID of the parameter, e.g. "Km"
+ */
+parameter?: string | null; 
+/**
+ * Matches the name, ID or EC number of a protein the parameter refers to
+ */
+protein?: string | null }
+/**
+ * Parameter of a stored document
+ */
+export type ParameterRecord = { 
+/**
+ * Database ID of the document
+ */
+document_id: number; 
+/**
+ * Title of the document
+ */
+document_title: string; 
+/**
+ * ID of the parameter within the document
+ */
+parameter_id: string; 
+/**
+ * Name of the parameter
+ */
+name: string; 
+/**
+ * Symbol of the parameter in equations
+ */
+symbol: string; 
+/**
+ * Value of the parameter, if estimated or given
+ */
+value: number | null; 
+/**
+ * Name of the unit of the parameter
+ */
+unit: string | null; 
+/**
+ * Standard error of the value
+ */
+stderr: number | null; 
+/**
+ * Names of the proteins the parameter refers to
+ */
+proteins: string[] }
+export type Protein = { id: string; name: string; constant: boolean; sequence?: string | null; vessel_id?: string | null; ecnumber?: string | null; organism?: string | null; organism_tax_id?: string | null; references: string[] }
+/**
+ * Filter of a protein search in the library
+ * 
+ * Text filters match case-insensitively any part of the respective fields,
+ * taking `%` and `_` literally. Omitted filters match every protein.
+ */
+export type ProteinQuery = { 
+/**
+ * Matches the name of the protein
+ */
+name?: string | null; 
+/**
+ * Matches the EC number of the protein, e.g. "1.1.1"
+ */
+ecnumber?: string | null; 
+/**
+ * Matches the organism the protein originates from
+ */
+organism?: string | null; 
+/**
+ * Matches a part of the amino acid sequence, whitespace is ignored
+ */
+sequence?: string | null }
+/**
+ * Represents a detected Python installation
+ */
+export type PythonInstallation = { 
+/**
+ * Path to the Python executable
+ */
+path: string; 
+/**
+ * Version string (e.g., "3.11.5")
+ */
+version: string; 
+/**
+ * Source/type of installation (e.g., "anaconda", "homebrew", "system")
+ */
+source: string; 
+/**
+ * Priority rank (lower is better: anaconda=1, homebrew=2, others=3)
+ */
+priority: number; 
+/**
+ * Is a custom python installation
+ */
+is_custom: boolean }
+export type Reaction = { id: string; name: string; reversible: boolean; kinetic_law?: Equation | null; species: ReactionElement[]; modifiers: ModifierElement[] }
+export type ReactionElement = { species_id: string; stoichiometry: number }
+/**
+ * A document file that has recently been opened or saved
+ */
+export type RecentFile = { 
+/**
+ * Path of the file
+ */
+path: string; 
+/**
+ * Title of the document when the file was last used
+ */
+title: string }
+/**
+ * Summary of a leftover recovery snapshot offered for restoring
+ */
+export type RecoveryEntry = { 
+/**
+ * File name of the snapshot within the recovery directory
+ */
+file: string; 
+/**
+ * Title of the document
+ */
+title: string; 
+/**
+ * Database ID of the document, if it has been saved before
+ */
+id: number | null; 
+/**
+ * Time the snapshot was written, in seconds since the Unix epoch
+ */
+saved_at: number }
+/**
+ * Imported entity that is referred to by a different ID in the document
+ */
+export type RemappedId = { 
+/**
+ * Kind of the entity
+ */
+kind: DiffEntity; 
+/**
+ * ID of the entity in the imported document
+ */
+from: string; 
+/**
+ * ID of the entity in the document
+ */
+to: string }
+/**
+ * Validation report containing all consistency check results
+ */
+export type Report = { 
+/**
+ * Whether the document is valid overall
+ */
+is_valid: boolean; 
+/**
+ * Vector of individual validation results
+ */
+errors: ValidationResult[] }
+/**
+ * How an imported entity duplicating an entity of the document is resolved
+ */
+export type Resolution = 
+/**
+ * The entity of the document is used in place of the imported one
+ */
+"reuse" | 
+/**
+ * The entity of the document is overwritten by the imported one, keeping its ID
+ */
+"replace" | 
+/**
+ * Both entities are kept, the imported one under a new ID if needed
+ */
+"keep_both"
+/**
+ * Indexed field of a stored document
+ */
+export type SearchField = 
+/**
+ * Title of the document
+ */
+"title" | 
+/**
+ * Names and synonyms of the small molecules and complexes
+ */
+"species" | 
+/**
+ * Names, EC numbers and organisms of the proteins
+ */
+"proteins" | 
+/**
+ * Names of the reactions
+ */
+"reactions" | 
+/**
+ * Descriptions of the stored and the EnzymeML document
+ */
+"notes"
+/**
+ * Stored document matching a search
+ */
+export type SearchHit = { 
+/**
+ * Database ID of the document
+ */
+id: number; 
+/**
+ * Title of the document
+ */
+title: string; 
+/**
+ * Relevance of the document, higher is better
+ */
+score: number; 
+/**
+ * Excerpts of the matching fields
+ */
+snippets: SearchSnippet[] }
+/**
+ * Excerpt of a field that matches the search
+ */
+export type SearchSnippet = { 
+/**
+ * The matching field
+ */
+field: SearchField; 
+/**
+ * Excerpt of the field with the matched terms enclosed in [`MATCH_START`] and [`MATCH_END`]
+ */
+text: string }
+/**
+ * Severity levels for validation issues
+ */
+export type Severity = "Error" | "Warning" | "Info"
+export type SmallMolecule = { id: string; name: string; constant: boolean; vessel_id?: string | null; canonical_smiles?: string | null; inchi?: string | null; inchikey?: string | null; synonymous_names: string[]; references: string[] }
+export type Stats = { small_molecules: number; proteins: number; reactions: number; vessels: number; measurements: number }
+/**
+ * A wrapper type for storing arrays of strings in SQLite
+ * 
+ * This type allows storing Vec<String> in SQLite by serializing/deserializing to JSON.
+ * It implements the necessary Diesel traits for database operations.
+ */
+export type StringArray = string[]
+/**
+ * Error of an operation of the EnzymeML Suite
+ * 
+ * Serialized with a `kind` tag, e.g. `{ "kind": "NotFound", "entity": "Small molecule",
+ * "id": "s0", "message": "Small molecule s0 not found" }`.
+ */
+export type SuiteError = 
+/**
+ * The requested entity does not exist
+ */
+{ kind: "NotFound"; 
+/**
+ * Kind of the entity that has been looked up
+ */
+entity: string; 
+/**
+ * ID of the entity that has been looked up
+ */
+id: string; 
+/**
+ * Human readable description of the error
+ */
+message: string } | 
+/**
+ * The input or the document is invalid for the requested operation
+ */
+{ kind: "Validation"; message: string } | 
+/**
+ * A file could not be read or written
+ */
+{ kind: "Io"; 
+/**
+ * Path of the affected file, if known
+ */
+path: string | null; message: string } | 
+/**
+ * A database query failed
+ */
+{ kind: "Database"; message: string } | 
+/**
+ * A document, equation or other input could not be parsed
+ */
+{ kind: "Parse"; message: string } | 
+/**
+ * The operation conflicts with existing data
+ */
+{ kind: "Conflict"; message: string } | 
+/**
+ * The operation has been cancelled by the user, e.g. by closing a dialog
+ */
+{ kind: "Cancelled"; message: string } | 
+/**
+ * Any other failure, such as an external process that could not be run
+ */
+{ kind: "Internal"; message: string }
+/**
+ * Numbers of imported entries of a table
+ */
+export type TableImport = { 
+/**
+ * The table the entries were imported into
+ */
+table: LibraryTable; 
+/**
+ * Number of entries added, including the ones added under a new name
+ */
+added: number; 
+/**
+ * Number of existing entries that were overwritten
+ */
+replaced: number; 
+/**
+ * Number of entries left out, because they exist already
+ */
+skipped: number }
+export type UnitDefinition = { id?: string | null; name?: string | null; base_units: BaseUnit[] }
+/**
+ * Group of units offered for a kind of quantity
+ * 
+ * Exported as `UnitGroup` to the frontend, since the EnzymeML `UnitType` of
+ * base units is exported under its own name.
+ */
+export type UnitGroup = "Volume" | "Mass" | "Time" | "Moles" | "Concentration" | "MassConcentration" | "Temperature"
+export type UnitType = "ampere" | "avogadro" | "becquerel" | "candela" | "celsius" | "coulomb" | "dimensionless" | "farad" | "gram" | "gray" | "henry" | "hertz" | "item" | "joule" | "katal" | "kelvin" | "kilogram" | "litre" | "lumen" | "lux" | "metre" | "mole" | "newton" | "ohm" | "pascal" | "radian" | "second" | "siemens" | "sievert" | "steradian" | "tesla" | "volt" | "watt" | "weber"
+/**
+ * Event signalling that the whole document has been replaced or renamed
+ */
+export type UpdateDocument = null
+/**
+ * Event signalling that the equations of the document changed
+ */
+export type UpdateEquations = null
+/**
+ * Event signalling that the measurements of the document changed
+ */
+export type UpdateMeasurements = null
+/**
+ * Event signalling that the parameters of the document changed
+ */
+export type UpdateParameters = null
+/**
+ * Event signalling that the proteins of the document changed
+ */
+export type UpdateProteins = null
+/**
+ * Event signalling that the reactions of the document changed
+ */
+export type UpdateReactions = null
+/**
+ * Event signalling that the validation report has been refreshed
+ */
+export type UpdateReport = { 
+/**
+ * Whether the document is valid overall
+ */
+is_valid: boolean; 
+/**
+ * Number of issues with severity `Error`
+ */
+errors: number; 
+/**
+ * Number of issues with severity `Warning`
+ */
+warnings: number }
+/**
+ * Event signalling that the small molecules of the document changed
+ */
+export type UpdateSmallMols = null
+/**
+ * Event signalling that the vessels of the document changed
+ */
+export type UpdateVessels = null
+/**
+ * Event signalling that the data shown in the visualisation changed
+ */
+export type UpdateVis = null
+/**
+ * Event signalling that documents have been opened, closed or switched
+ */
+export type UpdateWorkspace = null
+/**
+ * A single validation issue found during checking
+ */
+export type ValidationResult = { 
+/**
+ * JSON pointer path to the location of the validation issue
+ */
+location: string; 
+/**
+ * Human readable description of the validation issue
+ */
+message: string; 
+/**
+ * Severity level of the validation issue
+ */
+severity: Severity; 
+/**
+ * Identifier of the validation issue
+ */
+identifier: string | null }
+export type Variable = { id: string; name: string; symbol: string }
+export type Vessel = { id: string; name: string; volume: number; unit: UnitDefinition; constant: boolean }
+/**
+ * Data structure for visualization containing an ID and data points
+ */
+export type VisData = { id: string; data: DataPoint[] }
+
+/** tauri-specta globals **/
+
+import {
+	invoke as TAURI_INVOKE,
+	Channel as TAURI_CHANNEL,
+} from "@tauri-apps/api/core";
+import * as TAURI_API_EVENT from "@tauri-apps/api/event";
+import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webviewWindow";
+
+type __EventObj__<T> = {
+	listen: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
+	once: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
+	emit: null extends T
+		? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
+		: (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
+};
+
+export type Result<T, E> =
+	| { status: "ok"; data: T }
+	| { status: "error"; error: E };
+
+function __makeEvents__<T extends Record<string, any>>(
+	mappings: Record<keyof T, string>,
+) {
+	return new Proxy(
+		{} as unknown as {
+			[K in keyof T]: __EventObj__<T[K]> & {
+				(handle: __WebviewWindow__): __EventObj__<T[K]>;
+			};
+		},
+		{
+			get: (_, event) => {
+				const name = mappings[event as keyof T];
+
+				return new Proxy((() => {}) as any, {
+					apply: (_, __, [window]: [__WebviewWindow__]) => ({
+						listen: (arg: any) => window.listen(name, arg),
+						once: (arg: any) => window.once(name, arg),
+						emit: (arg: any) => window.emit(name, arg),
+					}),
+					get: (_, command: keyof __EventObj__<any>) => {
+						switch (command) {
+							case "listen":
+								return (arg: any) => TAURI_API_EVENT.listen(name, arg);
+							case "once":
+								return (arg: any) => TAURI_API_EVENT.once(name, arg);
+							case "emit":
+								return (arg: any) => TAURI_API_EVENT.emit(name, arg);
+						}
+					},
+				});
+			},
+		},
+	);
+}
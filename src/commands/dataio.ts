@@ -1,5 +1,6 @@
 import { invoke } from '@tauri-apps/api/core';
 import { EnzymeMLDocument } from "enzymeml";
+import { errorMessage } from "./errors";
 
 export type DBEntries = [string, number];
 
@@ -7,13 +8,113 @@ export interface EnzymeMLState {
     id?: number,
     title: string,
     doc: EnzymeMLDocument,
+    handle: string,
+    path: string | null,
+    dirty: boolean,
+}
+
+export interface RecentFile {
+    path: string,
+    title: string,
+}
+
+export type DocumentSort = 'title' | 'created' | 'modified';
+
+export interface DocumentQuery {
+    text?: string | null,
+    tags?: string[],
+    trashed?: boolean,
+    sort?: DocumentSort,
+    descending?: boolean,
+}
+
+export interface DocumentMetadata {
+    id: number,
+    title: string,
+    description: string | null,
+    tags: string[],
+    created_at: number,
+    modified_at: number,
+    deleted_at: number | null,
 }
 
 export async function listEntries(): Promise<DBEntries[]> {
     try {
         return await invoke<DBEntries[]>('list_all_entries');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function listDocuments(query?: DocumentQuery): Promise<DocumentMetadata[]> {
+    try {
+        return await invoke<DocumentMetadata[]>('list_documents', { query: query ?? null });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function renameStoredDocument(id: number, title: string): Promise<void> {
+    try {
+        await invoke('rename_stored_document', { id: id, title: title });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function duplicateStoredDocument(id: number, title?: string): Promise<number> {
+    try {
+        return await invoke<number>('duplicate_stored_document', { id: id, title: title ?? null });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function trashStoredDocument(id: number): Promise<void> {
+    try {
+        await invoke('trash_stored_document', { id: id });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function restoreStoredDocument(id: number): Promise<void> {
+    try {
+        await invoke('restore_stored_document', { id: id });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function deleteStoredDocument(id: number): Promise<void> {
+    try {
+        await invoke('delete_stored_document', { id: id });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function emptyDocumentTrash(): Promise<number> {
+    try {
+        return await invoke<number>('empty_document_trash');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function tagStoredDocument(id: number, tags: string[]): Promise<void> {
+    try {
+        await invoke('tag_stored_document', { id: id, tags: tags });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function describeStoredDocument(id: number, description: string | null): Promise<void> {
+    try {
+        await invoke('describe_stored_document', { id: id, description: description });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -21,7 +122,7 @@ export async function saveEntry(): Promise<void> {
     try {
         await invoke('save');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -29,7 +130,7 @@ export async function loadEntry(id: number): Promise<void> {
     try {
         await invoke('load', { id: id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -37,7 +138,7 @@ export async function newEntry(): Promise<void> {
     try {
         await invoke('new_document');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -45,7 +146,39 @@ export async function exportToJSON(): Promise<string> {
     try {
         return await invoke<string>('export_to_json');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function saveToFile(): Promise<string> {
+    try {
+        return await invoke<string>('save_to_file');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function saveToFileAs(): Promise<string> {
+    try {
+        return await invoke<string>('save_to_file_as');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function listRecentFiles(): Promise<RecentFile[]> {
+    try {
+        return await invoke<RecentFile[]>('list_recent_files');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function clearRecentFiles(): Promise<void> {
+    try {
+        await invoke('clear_recent_files');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -53,7 +186,7 @@ export async function loadJSON(): Promise<void> {
     try {
         await invoke('load_json', {});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 
 }
@@ -62,7 +195,7 @@ export async function getState(): Promise<EnzymeMLState> {
     try {
         return await invoke<EnzymeMLState>('get_state');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -70,7 +203,7 @@ export async function exportMeasurements(): Promise<string> {
     try {
         return await invoke<string>('export_measurements');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -78,7 +211,7 @@ export async function importMeasurement(): Promise<number> {
     try {
         return await invoke('import_excel_meas');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -86,7 +219,7 @@ export async function loadJSONFromPath(filePath: string): Promise<void> {
     try {
         await invoke('load_json_from_path', { filePath });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -94,7 +227,7 @@ export async function importExcelFromPath(filePath: string): Promise<number> {
     try {
         return await invoke('import_excel_from_path', { filePath });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -102,6 +235,6 @@ export async function handleFileDrop(filePaths: string[]): Promise<string> {
     try {
         return await invoke('handle_file_drop', { filePaths });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 import { invoke } from "@tauri-apps/api/core";
+import { errorMessage } from "./errors";
 
 // Interface for DBSmallMolecule based on the Rust model
 export interface DBSmallMolecule {
@@ -7,7 +8,54 @@ export interface DBSmallMolecule {
     canonical_smiles?: string;
     inchi?: string;
     inchikey?: string;
-    references?: string[];
+    references: string[];
+}
+
+// Interface for DBProtein based on the Rust model
+export interface DBProtein {
+    id: number;
+    name: string;
+    sequence?: string;
+    ecnumber?: string;
+    organism?: string;
+    organism_tax_id?: string;
+}
+
+// Interface for DBVessel based on the Rust model
+export interface DBVessel {
+    id: number;
+    name: string;
+    volume: number;
+    unit: string;
+    constant: boolean;
+}
+
+// Interface for DBCreator based on the Rust model
+export interface DBCreator {
+    id: number;
+    given_name: string;
+    family_name: string;
+    mail: string;
+}
+
+// Interface for LibraryLaw based on the Rust model
+export interface LibraryLaw {
+    id: number;
+    name: string;
+    equation: string;
+    // Pairs of species name and role
+    species: [string, string][];
+    // Pairs of assignable name and type
+    assignables: [string, string][];
+    parameters: string[];
+}
+
+// Filters of a protein search, omitted filters match every protein
+export interface ProteinQuery {
+    name?: string;
+    ecnumber?: string;
+    organism?: string;
+    sequence?: string;
 }
 
 /**
@@ -20,7 +68,7 @@ export async function saveMoleculeToDb(id: string): Promise<void> {
         console.log('Saving molecule to database: ' + id);
         await invoke('save_mol_to_db', { id });
     } catch (error) {
-        throw new Error('Error saving molecule to database: ' + error);
+        throw new Error('Error saving molecule to database: ' + errorMessage(error));
     }
 }
 
@@ -33,7 +81,7 @@ export async function filterSmallMolecules(name: string): Promise<DBSmallMolecul
     try {
         return await invoke('filter_small_mols', { name });
     } catch (error) {
-        throw new Error('Error filtering small molecules: ' + error);
+        throw new Error('Error filtering small molecules: ' + errorMessage(error));
     }
 }
 
@@ -46,7 +94,7 @@ export async function getSmallMoleculeById(id: number): Promise<DBSmallMolecule>
     try {
         return await invoke('get_small_mol_by_id', { id });
     } catch (error) {
-        throw new Error('Error getting small molecule by ID: ' + error);
+        throw new Error('Error getting small molecule by ID: ' + errorMessage(error));
     }
 }
 
@@ -58,6 +106,347 @@ export async function getAllSmallMolecules(): Promise<DBSmallMolecule[]> {
     try {
         return await invoke('get_all_small_mols', {});
     } catch (error) {
-        throw new Error('Error getting all small molecules: ' + error);
+        throw new Error('Error getting all small molecules: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Updates a small molecule of the database in place
+ * @param entry The small molecule with its database ID and the new values
+ * @returns Promise with the updated small molecule
+ */
+export async function updateSmallMoleculeInDb(entry: DBSmallMolecule): Promise<DBSmallMolecule> {
+    try {
+        return await invoke('update_small_mol_in_db', { entry });
+    } catch (error) {
+        throw new Error('Error updating small molecule: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Deletes a small molecule from the database
+ * @param id The ID of the small molecule in the database
+ */
+export async function deleteSmallMoleculeFromDb(id: number): Promise<void> {
+    try {
+        await invoke('delete_small_mol_from_db', { id });
+    } catch (error) {
+        throw new Error('Error deleting small molecule: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Saves or updates a protein in the database
+ * @param id The ID of the protein to save
+ * @returns Promise with the number of affected rows
+ */
+export async function saveProteinToDb(id: string): Promise<number> {
+    try {
+        return await invoke('save_protein_to_db', { id });
+    } catch (error) {
+        throw new Error('Error saving protein to database: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Searches proteins by name, EC number, organism or sequence
+ * @param query The filters of the search
+ * @returns Promise with an array of matching proteins
+ */
+export async function filterProteins(query?: ProteinQuery): Promise<DBProtein[]> {
+    try {
+        return await invoke('filter_proteins', { query: query ?? null });
+    } catch (error) {
+        throw new Error('Error filtering proteins: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Gets a protein by ID
+ * @param id The ID of the protein to get
+ * @returns Promise with the protein
+ */
+export async function getProteinById(id: number): Promise<DBProtein> {
+    try {
+        return await invoke('get_protein_by_id', { id });
+    } catch (error) {
+        throw new Error('Error getting protein by ID: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Gets all proteins from the database
+ * @returns Promise with an array of all proteins
+ */
+export async function getAllProteins(): Promise<DBProtein[]> {
+    try {
+        return await invoke('get_all_proteins', {});
+    } catch (error) {
+        throw new Error('Error getting all proteins: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Updates a protein of the database in place
+ * @param entry The protein with its database ID and the new values
+ * @returns Promise with the updated protein
+ */
+export async function updateProteinInDb(entry: DBProtein): Promise<DBProtein> {
+    try {
+        return await invoke('update_protein_in_db', { entry });
+    } catch (error) {
+        throw new Error('Error updating protein: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Deletes a protein from the database
+ * @param id The ID of the protein in the database
+ */
+export async function deleteProteinFromDb(id: number): Promise<void> {
+    try {
+        await invoke('delete_protein_from_db', { id });
+    } catch (error) {
+        throw new Error('Error deleting protein: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Adds a protein of the database to the active document
+ * @param id The ID of the protein in the database
+ * @returns Promise with the ID of the protein within the document
+ */
+export async function addProteinFromDb(id: number): Promise<string> {
+    try {
+        return await invoke('add_protein_from_db', { id });
+    } catch (error) {
+        throw new Error('Error adding protein to document: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Saves or updates a vessel in the database
+ * @param id The ID of the vessel to save
+ * @returns Promise with the number of affected rows
+ */
+export async function saveVesselToDb(id: string): Promise<number> {
+    try {
+        return await invoke('save_vessel_to_db', { id });
+    } catch (error) {
+        throw new Error('Error saving vessel to database: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Filters vessels by name
+ * @param name The name to filter by
+ * @returns Promise with an array of matching vessels
+ */
+export async function filterVessels(name: string): Promise<DBVessel[]> {
+    try {
+        return await invoke('filter_vessels', { name });
+    } catch (error) {
+        throw new Error('Error filtering vessels: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Gets a vessel by ID
+ * @param id The ID of the vessel to get
+ * @returns Promise with the vessel
+ */
+export async function getVesselById(id: number): Promise<DBVessel> {
+    try {
+        return await invoke('get_vessel_by_id', { id });
+    } catch (error) {
+        throw new Error('Error getting vessel by ID: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Gets all vessels from the database
+ * @returns Promise with an array of all vessels
+ */
+export async function getAllVessels(): Promise<DBVessel[]> {
+    try {
+        return await invoke('get_all_vessels', {});
+    } catch (error) {
+        throw new Error('Error getting all vessels: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Updates a vessel of the database in place
+ * @param entry The vessel with its database ID and the new values
+ * @returns Promise with the updated vessel
+ */
+export async function updateVesselInDb(entry: DBVessel): Promise<DBVessel> {
+    try {
+        return await invoke('update_vessel_in_db', { entry });
+    } catch (error) {
+        throw new Error('Error updating vessel: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Deletes a vessel from the database
+ * @param id The ID of the vessel in the database
+ */
+export async function deleteVesselFromDb(id: number): Promise<void> {
+    try {
+        await invoke('delete_vessel_from_db', { id });
+    } catch (error) {
+        throw new Error('Error deleting vessel: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Adds a vessel of the database to the active document
+ * @param id The ID of the vessel in the database
+ * @returns Promise with the ID of the vessel within the document
+ */
+export async function addVesselFromDb(id: number): Promise<string> {
+    try {
+        return await invoke('add_vessel_from_db', { id });
+    } catch (error) {
+        throw new Error('Error adding vessel to document: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Saves or updates the creators of the active document in the database
+ * @returns Promise with the number of affected rows
+ */
+export async function saveCreatorsToDb(): Promise<number> {
+    try {
+        return await invoke('save_creators_to_db', {});
+    } catch (error) {
+        throw new Error('Error saving creators to database: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Searches creators by name or mail address
+ * @param query The words to search for
+ * @returns Promise with an array of matching creators
+ */
+export async function filterCreators(query: string): Promise<DBCreator[]> {
+    try {
+        return await invoke('filter_creators', { query });
+    } catch (error) {
+        throw new Error('Error filtering creators: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Gets a creator by ID
+ * @param id The ID of the creator to get
+ * @returns Promise with the creator
+ */
+export async function getCreatorById(id: number): Promise<DBCreator> {
+    try {
+        return await invoke('get_creator_by_id', { id });
+    } catch (error) {
+        throw new Error('Error getting creator by ID: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Gets all creators from the database
+ * @returns Promise with an array of all creators
+ */
+export async function getAllCreators(): Promise<DBCreator[]> {
+    try {
+        return await invoke('get_all_creators', {});
+    } catch (error) {
+        throw new Error('Error getting all creators: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Updates a creator of the database in place
+ * @param entry The creator with its database ID and the new values
+ * @returns Promise with the updated creator
+ */
+export async function updateCreatorInDb(entry: DBCreator): Promise<DBCreator> {
+    try {
+        return await invoke('update_creator_in_db', { entry });
+    } catch (error) {
+        throw new Error('Error updating creator: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Deletes a creator from the database
+ * @param id The ID of the creator in the database
+ */
+export async function deleteCreatorFromDb(id: number): Promise<void> {
+    try {
+        await invoke('delete_creator_from_db', { id });
+    } catch (error) {
+        throw new Error('Error deleting creator: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Adds a creator of the database to the active document
+ * @param id The ID of the creator in the database
+ * @returns Promise with whether the creator has been added
+ */
+export async function addCreatorFromDb(id: number): Promise<boolean> {
+    try {
+        return await invoke('add_creator_from_db', { id });
+    } catch (error) {
+        throw new Error('Error adding creator to document: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Gets a kinetic law by ID
+ * @param id The ID of the kinetic law to get
+ * @returns Promise with the kinetic law
+ */
+export async function getLawById(id: number): Promise<LibraryLaw> {
+    try {
+        return await invoke('get_law_by_id', { id });
+    } catch (error) {
+        throw new Error('Error getting kinetic law by ID: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Gets all kinetic laws from the database
+ * @returns Promise with an array of all kinetic laws
+ */
+export async function getAllLaws(): Promise<LibraryLaw[]> {
+    try {
+        return await invoke('get_all_laws', {});
+    } catch (error) {
+        throw new Error('Error getting all kinetic laws: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Updates a kinetic law of the database in place
+ * @param entry The kinetic law with its database ID and the new values
+ * @returns Promise with the updated kinetic law
+ */
+export async function updateLawInDb(entry: LibraryLaw): Promise<LibraryLaw> {
+    try {
+        return await invoke('update_law_in_db', { entry });
+    } catch (error) {
+        throw new Error('Error updating kinetic law: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Deletes a kinetic law from the database
+ * @param id The ID of the kinetic law in the database
+ */
+export async function deleteLawFromDb(id: number): Promise<void> {
+    try {
+        await invoke('delete_law_from_db', { id });
+    } catch (error) {
+        throw new Error('Error deleting kinetic law: ' + errorMessage(error));
     }
 }
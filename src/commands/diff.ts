@@ -0,0 +1,55 @@
+import { invoke } from '@tauri-apps/api/core';
+import { EnzymeMLDocument } from 'enzymeml';
+import { errorMessage } from "./errors";
+
+export type DocumentSource =
+    | { type: 'open', handle?: string | null }
+    | { type: 'stored', id: number }
+    | { type: 'file', path: string }
+    | { type: 'inline', content: EnzymeMLDocument };
+
+export type DiffEntity =
+    'small_molecule' | 'protein' | 'complex' | 'vessel' | 'reaction' | 'equation' | 'parameter' | 'measurement';
+
+export type DiffStatus = 'added' | 'removed' | 'modified';
+
+export interface DiffOptions {
+    match_by_name?: boolean,
+    tolerance?: number,
+}
+
+export interface FieldChange {
+    path: string,
+    old: string | null,
+    new: string | null,
+}
+
+export interface EntityDiff {
+    kind: DiffEntity,
+    id: string,
+    new_id: string | null,
+    name: string | null,
+    status: DiffStatus,
+    changes: FieldChange[],
+}
+
+export interface DocumentDiff {
+    changes: FieldChange[],
+    entities: EntityDiff[],
+}
+
+export async function diffDocuments(
+    before: DocumentSource,
+    after: DocumentSource,
+    options?: DiffOptions,
+): Promise<DocumentDiff> {
+    try {
+        return await invoke<DocumentDiff>('diff_documents', {
+            before: before,
+            after: after,
+            options: options ?? null,
+        });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
@@ -1,5 +1,6 @@
 import { invoke } from "@tauri-apps/api/core";
 import { EnzymeMLDocument } from "enzymeml";
+import { errorMessage } from "./errors";
 
 export interface Stats {
     small_molecules: number;
@@ -18,7 +19,7 @@ export async function setTitle(title?: string): Promise<void> {
     try {
         await invoke('set_title', { title: title });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -26,7 +27,7 @@ export async function createDocument(enzmldoc: EnzymeMLDocument): Promise<void>
     try {
         await invoke('create_document', { enzmldoc: enzmldoc });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -34,7 +35,7 @@ export async function listAllSpeciesIds(): Promise<string[]> {
     try {
         return await invoke('get_all_species_ids');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -42,7 +43,7 @@ export async function listAllSpeciesIdsNames(): Promise<[string, string][]> {
     try {
         return await invoke('get_all_species');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -50,7 +51,7 @@ export async function listAllNonConstantSpeciesIds(): Promise<string[]> {
     try {
         return await invoke('get_all_non_constant_species_ids');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -58,7 +59,7 @@ export async function getSpeciesNameByID(id: string): Promise<string> {
     try {
         return await invoke('get_species_name', { speciesId: id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -66,6 +67,6 @@ export async function getStats(): Promise<Stats> {
     try {
         return await invoke('get_stats');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
\ No newline at end of file
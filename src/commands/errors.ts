@@ -0,0 +1,17 @@
+import { SuiteError } from "./bindings";
+
+/**
+ * Extracts a human readable message from an error raised by a command
+ *
+ * Commands reject with a `SuiteError`, which carries its description in the
+ * `message` field. Other errors are converted to a string as they are.
+ *
+ * @param error - The error the command rejected with
+ * @returns The description of the error
+ */
+export function errorMessage(error: unknown): string {
+    if (typeof error === "object" && error !== null && "message" in error) {
+        return (error as SuiteError).message;
+    }
+    return String(error);
+}
@@ -0,0 +1,42 @@
+import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from "./errors";
+
+export interface FileChanged {
+    document: string,
+    path: string,
+    dirty: boolean,
+}
+
+export interface MergeConflict {
+    path: string,
+    local: string,
+    theirs: string,
+}
+
+export interface MergeReport {
+    conflicts: MergeConflict[],
+}
+
+export async function reloadFromFile(handle: string): Promise<void> {
+    try {
+        await invoke('reload_from_file', { handle: handle });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function keepLocalChanges(handle: string): Promise<void> {
+    try {
+        await invoke('keep_local_changes', { handle: handle });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function mergeWithFile(handle: string): Promise<MergeReport> {
+    try {
+        return await invoke<MergeReport>('merge_with_file', { handle: handle });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
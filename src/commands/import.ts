@@ -0,0 +1,51 @@
+import { invoke } from '@tauri-apps/api/core';
+import { DiffEntity, DocumentSource } from "./diff";
+import { errorMessage } from "./errors";
+
+export type Resolution = 'reuse' | 'replace' | 'keep_both';
+
+export type DuplicateMatch = 'name' | 'inchikey' | 'sequence' | 'id';
+
+export interface ImportOptions {
+    strategy?: Resolution,
+    resolutions?: { [id: string]: Resolution },
+    dry_run?: boolean,
+}
+
+export interface ImportDuplicate {
+    kind: DiffEntity,
+    id: string,
+    existing_id: string,
+    name: string | null,
+    matched_by: DuplicateMatch,
+    resolution: Resolution,
+}
+
+export interface RemappedId {
+    kind: DiffEntity,
+    from: string,
+    to: string,
+}
+
+export interface ImportReport {
+    duplicates: ImportDuplicate[],
+    remapped: RemappedId[],
+    added: string[],
+    applied: boolean,
+}
+
+export async function importDocument(
+    source: DocumentSource,
+    options?: ImportOptions,
+    handle?: string,
+): Promise<ImportReport> {
+    try {
+        return await invoke<ImportReport>('import_document', {
+            source: source,
+            options: options ?? null,
+            handle: handle ?? null,
+        });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
@@ -0,0 +1,59 @@
+import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from "./errors";
+
+export interface DatabaseStatus {
+    library: string,
+    path: string,
+    available: boolean,
+    error: string | null,
+}
+
+export interface Library {
+    name: string,
+    path: string,
+}
+
+export interface LibrarySettings {
+    active: string,
+    libraries: Library[],
+}
+
+export async function getDatabaseStatus(): Promise<DatabaseStatus> {
+    try {
+        return await invoke<DatabaseStatus>('get_database_status');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function listLibraries(): Promise<LibrarySettings> {
+    try {
+        return await invoke<LibrarySettings>('list_libraries');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function addLibrary(name: string, path: string): Promise<LibrarySettings> {
+    try {
+        return await invoke<LibrarySettings>('add_library', { name: name, path: path });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function removeLibrary(name: string): Promise<LibrarySettings> {
+    try {
+        return await invoke<LibrarySettings>('remove_library', { name: name });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function switchLibrary(name: string): Promise<DatabaseStatus> {
+    try {
+        return await invoke<DatabaseStatus>('switch_library', { name: name });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
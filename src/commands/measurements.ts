@@ -1,11 +1,12 @@
 import { invoke } from "@tauri-apps/api/core";
 import { Measurement } from "enzymeml";
+import { errorMessage } from "./errors";
 
 export async function addMeasurement(data: Measurement): Promise<void> {
     try {
         await invoke('add_measurement', { data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -13,7 +14,7 @@ export async function addMeasurements(data: Measurement[]): Promise<void> {
     try {
         await invoke('add_measurements', { data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -21,7 +22,7 @@ export async function createMeasurement(): Promise<string> {
     try {
         return await invoke('create_measurement', {});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -29,7 +30,7 @@ export async function listMeasurements(): Promise<[string, string][]> {
     try {
         return await invoke('list_measurements', {});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 
 }
@@ -38,7 +39,7 @@ export async function getMeasurement(id: string): Promise<Measurement> {
     try {
         return await invoke('get_measurement', { id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -58,7 +59,7 @@ export async function updateMeasurement(id: string, data: Measurement): Promise<
     try {
         await invoke('update_measurement', { id: id, data: data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -66,6 +67,6 @@ export async function deleteMeasurement(id: string): Promise<void> {
     try {
         await invoke('delete_measurement', { id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
\ No newline at end of file
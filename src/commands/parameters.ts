@@ -1,11 +1,12 @@
 import { invoke } from "@tauri-apps/api/core";
 import { Parameter } from "enzymeml";
+import { errorMessage } from "./errors";
 
 export async function listAllParametersIds(): Promise<[string, string][]> {
     try {
         return await invoke('list_parameters');
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -13,7 +14,7 @@ export async function createParameter(name: string): Promise<void> {
     try {
         await invoke('create_parameter', { name: name });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -22,7 +23,7 @@ export async function getParameter(id: string): Promise<Parameter> {
     try {
         return await invoke('get_parameter', { id: id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -30,7 +31,7 @@ export async function updateParameter(id: string, data: Parameter): Promise<void
     try {
         await invoke('update_parameter', { id: id, data: data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -38,7 +39,7 @@ export async function partialUpdateParameter(pid: string, key: string, value: nu
     try {
         await invoke('partial_update_parameter', { pid: pid, key: key, value: value });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -46,6 +47,6 @@ export async function deleteParameter(id: string): Promise<void> {
     try {
         await invoke('delete_parameter', { id: id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
\ No newline at end of file
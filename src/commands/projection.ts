@@ -0,0 +1,86 @@
+import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from "./errors";
+
+export interface ParameterQuery {
+    parameter?: string | null,
+    protein?: string | null,
+}
+
+export interface ParameterRecord {
+    document_id: number,
+    document_title: string,
+    parameter_id: string,
+    name: string,
+    symbol: string,
+    value: number | null,
+    unit: string | null,
+    stderr: number | null,
+    proteins: string[],
+}
+
+export interface MeasurementQuery {
+    min_temperature?: number | null,
+    max_temperature?: number | null,
+    min_ph?: number | null,
+    max_ph?: number | null,
+    species?: string | null,
+}
+
+export interface InitialCondition {
+    species_id: string,
+    name: string | null,
+    initial: number | null,
+    unit: string | null,
+}
+
+export interface MeasurementRecord {
+    document_id: number,
+    document_title: string,
+    measurement_id: string,
+    name: string,
+    ph: number | null,
+    temperature: number | null,
+    temperature_unit: string | null,
+    temperature_kelvin: number | null,
+    initials: InitialCondition[],
+}
+
+export async function getProjectionEnabled(): Promise<boolean> {
+    try {
+        return await invoke<boolean>('get_projection_enabled');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function setProjectionEnabled(enabled: boolean): Promise<number> {
+    try {
+        return await invoke<number>('set_projection_enabled', { enabled: enabled });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function rebuildDocumentProjection(): Promise<number> {
+    try {
+        return await invoke<number>('rebuild_document_projection');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function queryParameters(query?: ParameterQuery): Promise<ParameterRecord[]> {
+    try {
+        return await invoke<ParameterRecord[]>('query_parameters', { query: query ?? null });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function queryMeasurements(query?: MeasurementQuery): Promise<MeasurementRecord[]> {
+    try {
+        return await invoke<MeasurementRecord[]>('query_measurements', { query: query ?? null });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
@@ -1,11 +1,12 @@
 import { invoke } from "@tauri-apps/api/core";
 import { Protein } from "enzymeml";
+import { errorMessage } from "./errors";
 
 export async function addProteins(data: Protein[]): Promise<void> {
     try {
         await invoke('add_proteins', { data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -13,7 +14,7 @@ export async function addProtein(data: Protein): Promise<void> {
     try {
         await invoke('add_protein', { data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -21,7 +22,7 @@ export async function createProtein(): Promise<string> {
     try {
         return await invoke('create_protein', {});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -29,7 +30,7 @@ export async function listProteins(): Promise<[string, string][]> {
     try {
         return await invoke('list_proteins', {});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 
 }
@@ -38,7 +39,7 @@ export async function getProtein(id: string): Promise<Protein> {
     try {
         return await invoke('get_protein', { id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -46,7 +47,7 @@ export async function updateProtein(id: string, data: Protein): Promise<void> {
     try {
         await invoke('update_protein', { id: id, data: data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -54,6 +55,6 @@ export async function deleteProtein(id: string): Promise<void> {
     try {
         await invoke('delete_protein', { id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
\ No newline at end of file
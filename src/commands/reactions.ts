@@ -1,11 +1,12 @@
 import { invoke } from "@tauri-apps/api/core";
 import { Reaction } from "enzymeml";
+import { errorMessage } from "./errors";
 
 export async function addReactions(data: Reaction[]): Promise<void> {
     try {
         await invoke('add_reactions', { data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -13,7 +14,7 @@ export async function addReaction(data: Reaction): Promise<void> {
     try {
         await invoke('add_reaction', { data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -21,7 +22,7 @@ export async function createReaction(): Promise<string> {
     try {
         return await invoke('create_reaction', {});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -29,7 +30,7 @@ export async function listReactions(): Promise<[string, string][]> {
     try {
         return await invoke('list_reactions', {});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 
 }
@@ -38,7 +39,7 @@ export async function getReaction(id: string): Promise<Reaction> {
     try {
         return await invoke('get_reaction', { id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -56,7 +57,7 @@ export async function updateReaction(_id: string, data: Reaction): Promise<void>
 
         await invoke('update_reaction', { data: data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -64,6 +65,6 @@ export async function deleteReaction(id: string): Promise<void> {
     try {
         await invoke('delete_reaction', { id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
\ No newline at end of file
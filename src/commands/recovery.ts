@@ -0,0 +1,33 @@
+import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from "./errors";
+
+export interface RecoveryEntry {
+    file: string,
+    title: string,
+    id: number | null,
+    saved_at: number,
+}
+
+export async function listRecoverySnapshots(): Promise<RecoveryEntry[]> {
+    try {
+        return await invoke<RecoveryEntry[]>('list_recovery_snapshots');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function restoreRecoverySnapshot(file: string): Promise<string> {
+    try {
+        return await invoke<string>('restore_recovery_snapshot', { file: file });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function discardRecoverySnapshot(file: string): Promise<void> {
+    try {
+        await invoke('discard_recovery_snapshot', { file: file });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
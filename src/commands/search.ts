@@ -0,0 +1,35 @@
+import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from "./errors";
+
+export const MATCH_START = '<mark>';
+export const MATCH_END = '</mark>';
+
+export type SearchField = 'title' | 'species' | 'proteins' | 'reactions' | 'notes';
+
+export interface SearchSnippet {
+    field: SearchField,
+    text: string,
+}
+
+export interface SearchHit {
+    id: number,
+    title: string,
+    score: number,
+    snippets: SearchSnippet[],
+}
+
+export async function searchDocuments(query: string, limit?: number): Promise<SearchHit[]> {
+    try {
+        return await invoke<SearchHit[]>('search_documents', { query: query, limit: limit ?? null });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function rebuildSearchIndex(): Promise<number> {
+    try {
+        return await invoke<number>('rebuild_search_index');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
@@ -1,4 +1,5 @@
 import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from './errors';
 
 /**
  * Installs the MCP server binary to the user's configuration directory
@@ -14,7 +15,7 @@ export async function installMcpServer(): Promise<string> {
     try {
         return await invoke<string>('install_mcp_server');
     } catch (error) {
-        throw new Error('Error installing MCP server: ' + error);
+        throw new Error('Error installing MCP server: ' + errorMessage(error));
     }
 }
 
@@ -31,7 +32,7 @@ export async function getOpenAIToken(): Promise<string> {
     try {
         return await invoke<string>('get_openai_token');
     } catch (error) {
-        throw new Error('Error retrieving OpenAI token: ' + error);
+        throw new Error('Error retrieving OpenAI token: ' + errorMessage(error));
     }
 }
 
@@ -49,6 +50,118 @@ export async function setOpenAIToken(token: string): Promise<string> {
     try {
         return await invoke<string>('set_openai_token', { token });
     } catch (error) {
-        throw new Error('Error saving OpenAI token: ' + error);
+        throw new Error('Error saving OpenAI token: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Settings of the local REST API
+ */
+export interface ApiSettings {
+    /** Address the REST API binds to */
+    host: string;
+    /** Port the REST API listens on */
+    port: number;
+    /** Whether routes that modify documents are enabled */
+    allow_writes: boolean;
+}
+
+/**
+ * Retrieves the settings of the local REST API
+ * 
+ * @returns Promise that resolves to the current API settings
+ * @throws Error if the settings cannot be retrieved
+ */
+export async function getApiSettings(): Promise<ApiSettings> {
+    try {
+        return await invoke<ApiSettings>('get_api_settings');
+    } catch (error) {
+        throw new Error('Error retrieving API settings: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Saves the settings of the local REST API
+ * 
+ * Enabling or disabling write access takes effect immediately, whereas a changed
+ * host or port is applied after restarting the application.
+ * 
+ * @param settings - The new API settings
+ * @returns Promise that resolves to a success message
+ * @throws Error if the settings are invalid or cannot be saved
+ */
+export async function setApiSettings(settings: ApiSettings): Promise<string> {
+    try {
+        return await invoke<string>('set_api_settings', { settings });
+    } catch (error) {
+        throw new Error('Error saving API settings: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Retrieves the token clients have to provide to access the local REST API
+ * 
+ * @returns Promise that resolves to the API token
+ * @throws Error if the token retrieval fails
+ */
+export async function getApiToken(): Promise<string> {
+    try {
+        return await invoke<string>('get_api_token');
+    } catch (error) {
+        throw new Error('Error retrieving API token: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Generates a new token for the local REST API
+ * 
+ * Clients using the previous token lose access to the API immediately.
+ * 
+ * @returns Promise that resolves to the new API token
+ * @throws Error if the token cannot be regenerated
+ */
+export async function regenerateApiToken(): Promise<string> {
+    try {
+        return await invoke<string>('regenerate_api_token');
+    } catch (error) {
+        throw new Error('Error regenerating API token: ' + errorMessage(error));
+    }
+}
+
+export interface BackupSettings {
+    /** Whether backups are taken automatically */
+    enabled: boolean;
+    /** Hours between two automatic backups */
+    interval_hours: number;
+    /** Number of most recent backups that are kept */
+    keep: number;
+}
+
+/**
+ * Retrieves the schedule of the backups of the database
+ * 
+ * @returns Promise that resolves to the current backup settings
+ * @throws Error if the settings retrieval fails
+ */
+export async function getBackupSettings(): Promise<BackupSettings> {
+    try {
+        return await invoke<BackupSettings>('get_backup_settings');
+    } catch (error) {
+        throw new Error('Error retrieving backup settings: ' + errorMessage(error));
+    }
+}
+
+/**
+ * Saves the schedule of the backups of the database
+ * 
+ * @param settings - The new backup settings
+ * @returns Promise that resolves to a success message
+ * @throws Error if the settings are invalid or cannot be saved
+ */
+export async function setBackupSettings(settings: BackupSettings): Promise<string> {
+    try {
+        return await invoke<string>('set_backup_settings', { settings });
+    } catch (error) {
+        throw new Error('Error saving backup settings: ' + errorMessage(error));
     }
 }
@@ -1,11 +1,12 @@
 import { invoke } from "@tauri-apps/api/core";
 import { SmallMolecule } from "enzymeml";
+import { errorMessage } from "./errors";
 
 export async function createSmallMolecule(): Promise<string> {
     try {
         return await invoke('create_small_mol', {});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -13,7 +14,7 @@ export async function addSmallMolecules(data: SmallMolecule[]): Promise<void> {
     try {
         await invoke('add_small_mols', { data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -21,7 +22,7 @@ export async function addSmallMolecule(data: SmallMolecule): Promise<void> {
     try {
         await invoke('add_small_mol', { data });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -29,7 +30,7 @@ export async function listSmallMolecules(): Promise<[string, string][]> {
     try {
         return await invoke('list_small_mols', {});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 
 }
@@ -38,7 +39,7 @@ export async function getSmallMolecule(id: string): Promise<SmallMolecule> {
     try {
         return await invoke('get_small_mol', { id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -48,7 +49,7 @@ export async function updateSmallMolecule(id: string, data: SmallMolecule): Prom
         const res = await invoke('update_small_mol', { id: id, data: data });
         console.log(res);
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -56,7 +57,7 @@ export async function deleteSmallMolecule(id: string): Promise<void> {
     try {
         await invoke('delete_small_mol', { id });
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
 
@@ -68,6 +69,6 @@ export async function listSmallMoleculesWithSMILES(): Promise<Record<string, str
     try {
         return await invoke('list_small_mol_smiles', {});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 import { invoke } from "@tauri-apps/api/core";
 import { UnitDefinition } from "enzymeml";
+import { errorMessage } from "./errors";
 
 export enum UnitTypes {
   VOLUME = "Volume",
@@ -17,7 +18,7 @@ export async function getUnitGroups(unitTypes: UnitTypes[]): Promise<UnitMap> {
   try {
     return await invoke("get_unit_groups", { unitTypes: unitTypes });
   } catch (error) {
-    throw new Error("Error invoking command: " + error);
+    throw new Error("Error invoking command: " + errorMessage(error));
   }
 }
 
@@ -25,7 +26,7 @@ export async function getUnitGroup(unitType: UnitTypes): Promise<UnitMap> {
   try {
     return await invoke("get_unit_group", { unitType: unitType });
   } catch (error) {
-    throw new Error("Error invoking command: " + error);
+    throw new Error("Error invoking command: " + errorMessage(error));
   }
 }
 
@@ -35,6 +36,6 @@ export async function getUnitByString(
   try {
     return await invoke("get_unit", { unit: unitString });
   } catch (error) {
-    throw new Error("Error invoking command: " + error);
+    throw new Error("Error invoking command: " + errorMessage(error));
   }
 }
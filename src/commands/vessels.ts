@@ -1,11 +1,12 @@
 import { invoke } from "@tauri-apps/api/core";
 import { Vessel } from "enzymeml";
+import { errorMessage } from "./errors";
 
 export async function addVessels(data: Vessel[]): Promise<void> {
   try {
     await invoke('add_vessels', { data });
   } catch (error) {
-    throw new Error('Error invoking command: ' + error);
+    throw new Error('Error invoking command: ' + errorMessage(error));
   }
 }
 
@@ -13,7 +14,7 @@ export async function addVessel(data: Vessel): Promise<void> {
   try {
     await invoke('add_vessel', { data });
   } catch (error) {
-    throw new Error('Error invoking command: ' + error);
+    throw new Error('Error invoking command: ' + errorMessage(error));
   }
 }
 
@@ -21,7 +22,7 @@ export async function createVessel(): Promise<string> {
   try {
     return await invoke("create_vessel", {});
   } catch (error) {
-    throw new Error("Error invoking command: " + error);
+    throw new Error("Error invoking command: " + errorMessage(error));
   }
 }
 
@@ -29,7 +30,7 @@ export async function listVessels(): Promise<[string, string][]> {
   try {
     return await invoke("list_vessels", {});
   } catch (error) {
-    throw new Error("Error invoking command: " + error);
+    throw new Error("Error invoking command: " + errorMessage(error));
   }
 }
 
@@ -39,7 +40,7 @@ export async function getVessel(id: string): Promise<Vessel> {
     console.log("GETTING VESSEL", vessel);
     return vessel as Vessel;
   } catch (error) {
-    throw new Error("Error invoking command: " + error);
+    throw new Error("Error invoking command: " + errorMessage(error));
   }
 }
 
@@ -47,7 +48,7 @@ export async function updateVessel(id: string, data: Vessel): Promise<void> {
   try {
     await invoke("update_vessel", { id: id, data: data });
   } catch (error) {
-    throw new Error("Error invoking command: " + error);
+    throw new Error("Error invoking command: " + errorMessage(error));
   }
 }
 
@@ -55,6 +56,6 @@ export async function deleteVessel(id: string): Promise<void> {
   try {
     await invoke("delete_vessel", { id });
   } catch (error) {
-    throw new Error("Error invoking command: " + error);
+    throw new Error("Error invoking command: " + errorMessage(error));
   }
 }
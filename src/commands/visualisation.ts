@@ -1,4 +1,5 @@
 import {invoke} from "@tauri-apps/api/core";
+import { errorMessage } from "./errors";
 
 export interface VisData {
     id: string,
@@ -14,7 +15,7 @@ export async function openVisualisation(): Promise<void> {
     try {
         await invoke('open_visualisation', {});
     } catch (error) {
-        throw new Error(error as string);
+        throw new Error(errorMessage(error));
     }
 }
 
@@ -22,6 +23,6 @@ export async function getDataPoints(id: string): Promise<VisData> {
     try {
         return await invoke('get_datapoints', {id: id});
     } catch (error) {
-        throw new Error('Error invoking command: ' + error);
+        throw new Error('Error invoking command: ' + errorMessage(error));
     }
 }
\ No newline at end of file
@@ -0,0 +1,50 @@
+import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from "./errors";
+
+export interface OpenDocument {
+    handle: string,
+    title: string,
+    id: number | null,
+    active: boolean,
+    dirty: boolean,
+}
+
+export async function listOpenDocuments(): Promise<OpenDocument[]> {
+    try {
+        return await invoke<OpenDocument[]>('list_open_documents');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function openNewDocument(): Promise<string> {
+    try {
+        return await invoke<string>('open_new_document');
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function openDocument(id: number): Promise<string> {
+    try {
+        return await invoke<string>('open_document', { id: id });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function switchDocument(handle: string): Promise<void> {
+    try {
+        await invoke('switch_document', { handle: handle });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
+
+export async function closeDocument(handle: string): Promise<string> {
+    try {
+        return await invoke<string>('close_document', { handle: handle });
+    } catch (error) {
+        throw new Error('Error invoking command: ' + errorMessage(error));
+    }
+}
@@ -6,7 +6,7 @@ import { PiSparkle } from "react-icons/pi";
 import { openUrl } from "@tauri-apps/plugin-opener";
 
 import ClaudeIcon from "@icons/claude.svg";
-import { commands as mcpCommands, events as mcpEvents } from "@commands/mcp";
+import { commands as mcpCommands, events as mcpEvents } from "@commands/bindings";
 import McpBinaryInstallation from "@components/McpBinaryInstallation";
 import useLLMStore from "@stores/llmstore";
 import useAppStore from "@stores/appstore";
@@ -2,7 +2,7 @@ import { useEffect, useState } from 'react';
 import { Button, Popconfirm, Tooltip } from 'antd';
 import { DeleteOutlined, SaveOutlined } from '@ant-design/icons';
 import ValidationIndicator from '@suite/validation/components/ValidationIndicator';
-import { ValidationResult } from '@suite/commands/validation';
+import { ValidationResult } from '@suite/commands/bindings';
 import { useRouterTauriListener } from '@suite/hooks/useTauriListener';
 import { getValidationReportById } from '@suite/validation/utils';
 import ValidationModal from '@suite/validation/ValidationModal';
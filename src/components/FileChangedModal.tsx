@@ -0,0 +1,103 @@
+import { useEffect, useState } from "react";
+import { Button, Modal, Typography } from "antd";
+import { listen } from "@tauri-apps/api/event";
+
+import useAppStore from "@stores/appstore";
+import { NotificationType } from "@components/NotificationProvider";
+import { FileChanged, keepLocalChanges, mergeWithFile, reloadFromFile } from "@commands/external";
+
+const { Text, Paragraph } = Typography;
+
+/**
+ * FileChangedModal component that resolves changes of document files made by other programs
+ *
+ * The backend watches the files documents have been loaded from or saved to and emits a
+ * `file_changed` event when another program, such as a notebook, rewrites one of them.
+ * The modal then offers to reload the file, to keep the document as it is, or to merge
+ * the changes of the file into the document. Changes reported while the modal is open
+ * are queued and shown one after another.
+ *
+ * @returns JSX element containing the modal
+ */
+export default function FileChangedModal() {
+    /** Changed files waiting to be resolved, the first one is shown */
+    const [changes, setChanges] = useState<FileChanged[]>([]);
+
+    // Global Actions
+    const openNotification = useAppStore((state) => state.openNotification);
+
+    // Effects
+    /** Queues the changes reported by the backend, replacing older ones of the same document */
+    useEffect(() => {
+        const unlisten = listen<FileChanged>("file_changed", (event) => {
+            setChanges((changes) => [
+                ...changes.filter((change) => change.document !== event.payload.document),
+                event.payload,
+            ]);
+        });
+
+        return () => {
+            unlisten.then((unlisten) => unlisten());
+        };
+    }, []);
+
+    const current = changes[0];
+
+    /** Removes the shown change from the queue */
+    const dismiss = () => {
+        setChanges((changes) => changes.slice(1));
+    };
+
+    /**
+     * Runs a resolution of the shown change and reports failures
+     * @param resolve - The command resolving the change
+     */
+    const resolveWith = (resolve: (handle: string) => Promise<unknown>) => {
+        resolve(current.document).then(dismiss).catch((error) => {
+            openNotification('Error resolving file change', NotificationType.ERROR, error.toString());
+        });
+    };
+
+    const onMerge = () => {
+        mergeWithFile(current.document).then((report) => {
+            dismiss();
+
+            if (report.conflicts.length === 0) {
+                openNotification('Changes merged', NotificationType.SUCCESS, 'The changes of the file have been merged into your document');
+            } else {
+                const paths = report.conflicts.map((conflict) => conflict.path).join(', ');
+                openNotification('Changes merged with conflicts', NotificationType.WARNING, `Your version has been kept for: ${paths}`);
+            }
+        }).catch((error) => {
+            openNotification('Error merging changes', NotificationType.ERROR, error.toString());
+        });
+    };
+
+    return (
+        <Modal
+            title="Document file changed"
+            open={current !== undefined}
+            closable={false}
+            maskClosable={false}
+            footer={[
+                <Button key="keep" onClick={() => resolveWith(keepLocalChanges)}>Keep mine</Button>,
+                <Button key="merge" onClick={onMerge}>Merge</Button>,
+                <Button key="reload" type="primary" danger={current?.dirty} onClick={() => resolveWith(reloadFromFile)}>Reload</Button>,
+            ]}
+        >
+            {current && (
+                <>
+                    <Paragraph>
+                        The file <Text code>{current.path}</Text> has been changed by another program.
+                    </Paragraph>
+                    {current.dirty && (
+                        <Paragraph type="warning">
+                            Your document has unsaved changes, which are discarded when reloading the file.
+                            Merging keeps them and takes over the changes of the file.
+                        </Paragraph>
+                    )}
+                </>
+            )}
+        </Modal>
+    );
+}
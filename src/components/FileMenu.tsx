@@ -6,7 +6,7 @@ import { Button, Dropdown, Input, theme } from 'antd';
 import { useNavigate } from 'react-router-dom';
 
 import { useWindowTauriListener } from '@hooks/useTauriListener';
-import { DBEntries, exportToJSON, getState, listEntries, loadEntry, loadJSON, newEntry, saveEntry } from '@commands/dataio';
+import { DBEntries, exportToJSON, getState, listEntries, listRecentFiles, loadEntry, loadJSON, loadJSONFromPath, newEntry, RecentFile, saveEntry, saveToFile, saveToFileAs } from '@commands/dataio';
 import { EnzymeMLState } from '@commands/dataio';
 import { setTitle as setDBTitle } from '@commands/enzmldoc';
 import Icon from '@ant-design/icons';
@@ -15,7 +15,7 @@ import EnzymeMLLogoCol from '@icons/enzymeml_logo_coloured.svg';
 import useAppStore from '@stores/appstore';
 import { formatKeyboardShortcut } from '@utilities/osutils';
 import { NotificationType } from '@components/NotificationProvider';
-import { commands } from '@commands/validation';
+import { commands } from '@commands/bindings';
 import ValidationIndicator from '@validation/components/ValidationIndicator';
 import ValidationModal from '@validation/ValidationModal';
 
@@ -123,8 +123,12 @@ function FileSearchInput(
 export default function FileMenu() {
     /** Local input value to handle empty state before backend update */
     const [title, setTitle] = useState("");
+    /** Whether the document has changes that have not been saved yet */
+    const [dirty, setDirty] = useState(false);
     /** List of all available database entries for the recent documents submenu */
     const [allEntries, setAllEntries] = useState<DBEntries[]>([]);
+    /** List of recently opened and saved files for the recent documents submenu */
+    const [recentFiles, setRecentFiles] = useState<RecentFile[]>([]);
     /** Search input value for the file search input */
     const [searchInput, setSearchInput] = useState("");
     /** Track whether the search input is focused */
@@ -159,15 +163,20 @@ export default function FileMenu() {
     useWindowTauriListener("update_document", () => {
         getState().then((state: EnzymeMLState) => {
             setTitle(state.title);
+            setDirty(state.dirty);
         });
 
         listEntries().then((data) => {
             setAllEntries(data);
         });
+
+        listRecentFiles().then((data) => {
+            setRecentFiles(data);
+        });
     }, []);
 
     useWindowTauriListener("update_report", () => {
-        commands.getValidationReport().then((data) => {
+        commands.getValidationReport(null).then((data) => {
             if (data.status === "ok") {
                 console.log(data.data);
                 setIsValidationReportValid(true);
@@ -183,13 +192,18 @@ export default function FileMenu() {
     useEffect(() => {
         getState().then((state: EnzymeMLState) => {
             setTitle(state.title);
+            setDirty(state.dirty);
         });
 
         listEntries().then((data) => {
             setAllEntries(data);
         });
 
-        commands.getValidationReport().then((data) => {
+        listRecentFiles().then((data) => {
+            setRecentFiles(data);
+        });
+
+        commands.getValidationReport(null).then((data) => {
             if (data.status === "ok") {
                 setIsValidationReportValid(true);
             }
@@ -250,6 +264,16 @@ export default function FileMenu() {
                 },
             ],
         },
+        {
+            key: 'save-to-file',
+            label: 'Save to File',
+            icon: <SaveOutlined />,
+        },
+        {
+            key: 'save-to-file-as',
+            label: 'Save to File As...',
+            icon: <SaveOutlined />,
+        },
         {
             key: 'export',
             label: 'Export Document',
@@ -306,6 +330,32 @@ export default function FileMenu() {
         }
     });
 
+    /**
+     * Adds the recently opened and saved files to the "Recent" submenu
+     * Files are listed below the database entries, most recent first
+     */
+    const filteredFiles = recentFiles.filter((file) => {
+        if (searchInput.length !== 0 && !file.title.toLowerCase().startsWith(searchInput.toLowerCase())) {
+            return false;
+        }
+        return true;
+    });
+
+    filteredFiles.forEach((file, index) => {
+        const recentMenuItem = items[5];
+        if (recentMenuItem && 'children' in recentMenuItem && recentMenuItem.children) {
+            if (index === 0) {
+                recentMenuItem.children.push({ type: 'divider' });
+            }
+            recentMenuItem.children.push({
+                key: `file-${index}`,
+                label: file.title,
+                title: file.path,
+                icon: <FolderOpenOutlined style={{ fontSize: 14, color: token.colorTextDisabled }} />,
+            });
+        }
+    });
+
     /**
      * Handles menu item clicks and executes corresponding file operations
      * 
@@ -337,6 +387,20 @@ export default function FileMenu() {
                     openNotification('Error saving entry', NotificationType.ERROR, error.toString());
                 });
                 break;
+            case 'save-to-file':
+                saveToFile().then((path) => {
+                    openNotification('Entry saved', NotificationType.SUCCESS, 'Your entry has been saved successfully to ' + path);
+                }).catch((error) => {
+                    openNotification('Error saving entry', NotificationType.ERROR, error.toString());
+                });
+                break;
+            case 'save-to-file-as':
+                saveToFileAs().then((path) => {
+                    openNotification('Entry saved', NotificationType.SUCCESS, 'Your entry has been saved successfully to ' + path);
+                }).catch((error) => {
+                    openNotification('Error saving entry', NotificationType.ERROR, error.toString());
+                });
+                break;
             case 'export':
                 exportToJSON().then((path) => {
                     openNotification('Entry exported', NotificationType.SUCCESS, 'Your entry has been exported successfully to ' + path);
@@ -354,6 +418,13 @@ export default function FileMenu() {
                 // Handle recent document entries
                 if (key.startsWith('entry-')) {
                     loadEntry(parseInt(key.split('-')[1]));
+                } else if (key.startsWith('file-')) {
+                    const file = filteredFiles[parseInt(key.split('-')[1])];
+                    loadJSONFromPath(file.path).then(() => {
+                        navigate('/');
+                    }).catch((error) => {
+                        openNotification('Error loading entry', NotificationType.ERROR, error.toString());
+                    });
                 }
                 break;
         }
@@ -382,7 +453,7 @@ export default function FileMenu() {
                 <Button variant="text" size="small">
                     <div className="flex gap-2 items-center opacity-75 cursor-pointer">
                         <ValidationIndicator verbose={false} />
-                        <span className="text-xs font-semibold">{title || "File"}</span>
+                        <span className="text-xs font-semibold">{title || "File"}{dirty && " •"}</span>
                         <DownOutlined style={{ fontSize: 12 }} />
                     </div>
                 </Button>
@@ -2,7 +2,7 @@ import { Typography, theme, Button } from "antd";
 import { useState, useEffect } from "react";
 
 import { installMcpServer } from "@commands/settings";
-import { events as mcpEvents } from "@commands/mcp";
+import { events as mcpEvents } from "@commands/bindings";
 import useAppStore from "@stores/appstore";
 import { NotificationType } from "@components/NotificationProvider.tsx";
 
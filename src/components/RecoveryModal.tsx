@@ -0,0 +1,100 @@
+import { useEffect, useState } from "react";
+import { Button, List, Modal, Typography } from "antd";
+
+import useAppStore from "@stores/appstore";
+import { NotificationType } from "@components/NotificationProvider";
+import {
+    discardRecoverySnapshot,
+    listRecoverySnapshots,
+    RecoveryEntry,
+    restoreRecoverySnapshot,
+} from "@commands/recovery";
+
+const { Text } = Typography;
+
+/**
+ * RecoveryModal component that offers to restore unsaved documents of a previous session
+ *
+ * On mount, the recovery directory is checked for snapshots left over by a session that
+ * ended without saving, for instance due to a crash. Each leftover can be restored as a
+ * new open document or discarded. The modal closes once all leftovers are handled.
+ *
+ * @returns JSX element containing the recovery modal
+ */
+export default function RecoveryModal() {
+    /** Snapshots left over by previous sessions */
+    const [entries, setEntries] = useState<RecoveryEntry[]>([]);
+
+    // Global Actions
+    const openNotification = useAppStore((state) => state.openNotification);
+
+    // Effects
+    /** Looks for leftover snapshots once on startup */
+    useEffect(() => {
+        listRecoverySnapshots()
+            .then(setEntries)
+            .catch((error) => {
+                openNotification('Error reading recovery snapshots', NotificationType.ERROR, error.toString());
+            });
+    }, []);
+
+    /**
+     * Removes a handled snapshot from the list
+     * @param file - File name of the handled snapshot
+     */
+    const dismiss = (file: string) => {
+        setEntries((entries) => entries.filter((entry) => entry.file !== file));
+    };
+
+    const onRestore = (entry: RecoveryEntry) => {
+        restoreRecoverySnapshot(entry.file).then(() => {
+            dismiss(entry.file);
+            openNotification('Document restored', NotificationType.SUCCESS, `"${entry.title}" has been restored. Save it to keep the changes.`);
+        }).catch((error) => {
+            openNotification('Error restoring document', NotificationType.ERROR, error.toString());
+        });
+    };
+
+    const onDiscard = (entry: RecoveryEntry) => {
+        discardRecoverySnapshot(entry.file).then(() => {
+            dismiss(entry.file);
+        }).catch((error) => {
+            openNotification('Error discarding snapshot', NotificationType.ERROR, error.toString());
+        });
+    };
+
+    const onDiscardAll = () => {
+        entries.forEach(onDiscard);
+    };
+
+    return (
+        <Modal
+            title="Restore unsaved documents"
+            open={entries.length > 0}
+            closable={false}
+            maskClosable={false}
+            footer={<Button onClick={onDiscardAll}>Discard all</Button>}
+        >
+            <Text type="secondary">
+                The previous session ended with unsaved changes. Restore the documents to continue where you left off.
+            </Text>
+            <List
+                className="mt-4"
+                dataSource={entries}
+                renderItem={(entry) => (
+                    <List.Item
+                        actions={[
+                            <Button key="restore" type="primary" size="small" onClick={() => onRestore(entry)}>Restore</Button>,
+                            <Button key="discard" size="small" onClick={() => onDiscard(entry)}>Discard</Button>,
+                        ]}
+                    >
+                        <List.Item.Meta
+                            title={entry.title || "Untitled"}
+                            description={`Last change ${new Date(entry.saved_at * 1000).toLocaleString()}`}
+                        />
+                    </List.Item>
+                )}
+            />
+        </Modal>
+    );
+}
@@ -7,7 +7,7 @@ import { emit } from "@tauri-apps/api/event";
 import useAppStore from "@stores/appstore.ts";
 import { NotificationType } from "@components/NotificationProvider.tsx";
 import { getOpenAIToken, setOpenAIToken as saveOpenAIToken } from "@commands/settings.ts";
-import { commands as mcpCommands, events as mcpEvents, ClientType } from "@commands/mcp";
+import { commands as mcpCommands, events as mcpEvents, ClientType } from "@commands/bindings";
 import McpBinaryInstallation from "@components/McpBinaryInstallation";
 import SmallMoleculeIcon from "@icons/smallmolecule.svg";
 import ProteinIcon from "@icons/protein.svg";
@@ -268,7 +268,7 @@ export default function UserSettings() {
                     openNotification(
                         "Registration Failed",
                         NotificationType.ERROR,
-                        result.error || "Failed to register MCP server."
+                        result.error.message || "Failed to register MCP server."
                     );
                     setRegisteringClient(null);
                 }
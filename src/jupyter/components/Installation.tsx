@@ -1,4 +1,4 @@
-import { commands, events } from '@suite/commands/jupyter';
+import { commands, events, PythonInstallation } from '@suite/commands/bindings';
 import { Typography, theme, Steps, Divider, Button, Tag, Space } from 'antd';
 import { PythonOutlined } from '@ant-design/icons';
 import { useState, useEffect } from 'react';
@@ -6,7 +6,6 @@ import { isPythonInstalled, isJupyterLabInstalled, getSelectedPython, listDetect
 import { CHECK_INTERVAL } from '@jupyter/Jupyter';
 import useAppStore from '@stores/appstore';
 import { NotificationType } from '@components/NotificationProvider';
-import { PythonInstallation } from '@commands/jupyter';
 
 const { Text } = Typography;
 
@@ -274,7 +273,7 @@ export default function Installation() {
                 openNotification(
                     'Installation Command Failed',
                     NotificationType.ERROR,
-                    `Failed to start installation: ${result.error}`
+                    `Failed to start installation: ${result.error.message}`
                 );
                 setIsInstallingJupyter(false);
             }
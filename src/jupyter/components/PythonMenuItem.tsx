@@ -1,6 +1,6 @@
 import { Typography, Tag } from "antd";
 import { getSourceColor, getSourceDisplayName } from "@jupyter/utils";
-import { PythonInstallation } from "@commands/jupyter";
+import { PythonInstallation } from "@commands/bindings";
 
 const { Text } = Typography;
 
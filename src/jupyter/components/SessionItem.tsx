@@ -2,7 +2,7 @@ import { Button, Space, Typography, theme, Tooltip } from 'antd';
 import { CloseOutlined, ExportOutlined } from '@ant-design/icons';
 import { openUrl } from '@tauri-apps/plugin-opener';
 
-import { JupyterSessionInfo } from '@commands/jupyter';
+import { JupyterSessionInfo } from '@commands/bindings';
 import { NotificationType } from '@components/NotificationProvider';
 
 const { Text } = Typography;
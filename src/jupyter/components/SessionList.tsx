@@ -1,6 +1,6 @@
 import { useEffect, useState } from 'react';
 
-import { JupyterSessionInfo } from '@commands/jupyter';
+import { JupyterSessionInfo } from '@commands/bindings';
 import SessionItem from '@jupyter/components/SessionItem';
 import TemplateButton from '@jupyter/components/TemplateButton';
 import useJupyterStore from '@stores/jupyterstore';
@@ -6,7 +6,7 @@ import type { MenuProps } from 'antd';
 import useAppStore from '@stores/appstore';
 import SessionList from '@jupyter/components/SessionList';
 import { NotificationType } from '@components/NotificationProvider';
-import { PythonInstallation } from '@commands/jupyter';
+import { PythonInstallation } from '@commands/bindings';
 import {
     detectPythonInstallations,
     getSelectedPython,
@@ -4,7 +4,7 @@ import type { MenuProps } from 'antd';
 import { DownOutlined } from '@ant-design/icons';
 
 import { getJupyterTemplateMetadata, createJupyterSession, addTemplateToProject } from '@jupyter/utils';
-import { JupyterTemplate } from '@commands/jupyter';
+import { JupyterTemplate } from '@commands/bindings';
 import useAppStore from '@stores/appstore';
 import useJupyterStore from '@stores/jupyterstore';
 import TemplateLabel from '@jupyter/components/TemplateLabel';
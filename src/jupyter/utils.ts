@@ -1,4 +1,4 @@
-import { commands, JupyterSessionInfo, JupyterTemplate, PythonInstallation } from '@commands/jupyter';
+import { commands, JupyterSessionInfo, JupyterTemplate, PythonInstallation } from '@commands/bindings';
 import { NotificationType } from '@components/NotificationProvider';
 
 /**
@@ -9,7 +9,7 @@ export const addPythonEnv = async (): Promise<null> => {
     if (result.status === 'ok') {
         return result.data;
     } else {
-        throw new Error(result.error);
+        throw new Error(result.error.message);
     }
 }
 
@@ -21,7 +21,7 @@ export const detectPythonInstallations = async (): Promise<PythonInstallation[]>
     if (result.status === 'ok') {
         return result.data;
     } else {
-        throw new Error(result.error);
+        throw new Error(result.error.message);
     }
 };
 
@@ -33,7 +33,7 @@ export const listDetectedPythons = async (): Promise<PythonInstallation[]> => {
     if (result.status === 'ok') {
         return result.data;
     } else {
-        throw new Error(result.error);
+        throw new Error(result.error.message);
     }
 };
 
@@ -46,7 +46,7 @@ export const listCustomPythonEnvs = async (): Promise<PythonInstallation[]> => {
     if (result.status === 'ok') {
         return result.data;
     } else {
-        console.error('Failed to list custom Python environments:', result.error);
+        console.error('Failed to list custom Python environments:', result.error.message);
         return [];
     }
 }
@@ -59,7 +59,7 @@ export const getSelectedPython = async (): Promise<string | null> => {
     if (result.status === 'ok') {
         return result.data;
     } else {
-        throw new Error(result.error);
+        throw new Error(result.error.message);
     }
 };
 
@@ -80,7 +80,7 @@ export const setSelectedPython = async (
             );
             return true;
         } else {
-            openNotification('Error', NotificationType.ERROR, result.error);
+            openNotification('Error', NotificationType.ERROR, result.error.message);
             return false;
         }
     } catch (error) {
@@ -109,7 +109,7 @@ export const isJupyterLabInstalled = async (): Promise<boolean> => {
         if (result.status === 'ok') {
             return result.data;
         } else {
-            console.warn('JupyterLab installation check failed:', result.error);
+            console.warn('JupyterLab installation check failed:', result.error.message);
             return false;
         }
     } catch (error) {
@@ -123,7 +123,7 @@ export const getJupyterTemplateMetadata = async (): Promise<JupyterTemplate[]> =
     if (result.status === 'ok') {
         return result.data;
     } else {
-        throw new Error(result.error);
+        throw new Error(result.error.message);
     }
 };
 
@@ -140,7 +140,7 @@ export const fetchJupyterSessions = async (
         if (result.status === 'ok') {
             return result.data;
         } else {
-            openNotification('Error', NotificationType.ERROR, result.error);
+            openNotification('Error', NotificationType.ERROR, result.error.message);
             return null;
         }
     } catch (error) {
@@ -159,7 +159,7 @@ export const createJupyterSession = async (
     template: string | null
 ): Promise<boolean> => {
     try {
-        const result = await commands.startJupyter(template);
+        const result = await commands.startJupyter(template, null);
         if (result.status === 'ok') {
             openNotification(
                 'Session Created',
@@ -168,7 +168,7 @@ export const createJupyterSession = async (
             );
             return true;
         } else {
-            openNotification('Error', NotificationType.ERROR, result.error);
+            openNotification('Error', NotificationType.ERROR, result.error.message);
             return false;
         }
     } catch (error) {
@@ -187,7 +187,7 @@ export const addTemplateToProject = async (
     openNotification: (title: string, type: NotificationType, message: string) => void,
     templateName: string,
 ): Promise<boolean> => {
-    const result = await commands.addTemplateToProject(templateName);
+    const result = await commands.addTemplateToProject(templateName, null);
     if (result.status === 'ok') {
         openNotification('Template Added', NotificationType.SUCCESS, 'Template added to project');
         return true;
@@ -218,7 +218,7 @@ export const terminateJupyterSession = async (
             );
             return true;
         } else {
-            openNotification('Error', NotificationType.ERROR, result.error);
+            openNotification('Error', NotificationType.ERROR, result.error.message);
             return false;
         }
     } catch (error) {
@@ -235,7 +235,7 @@ export const terminateJupyterSession = async (
 export const openProjectFolder = async (
     openNotification: (title: string, type: NotificationType, message: string) => void
 ): Promise<boolean> => {
-    const result = await commands.openProjectFolder();
+    const result = await commands.openProjectFolder(null);
     if (result.status === 'ok') {
         openNotification('Project Folder Opened', NotificationType.SUCCESS, 'Project folder opened');
         return true;
@@ -4,7 +4,7 @@ import { ExclamationCircleOutlined, WarningOutlined, InfoCircleOutlined } from "
 
 import { useRouterTauriListener } from "@suite/hooks/useTauriListener";
 import { getValidationReport, getValidationReportById } from "@validation/utils";
-import { type Report, type ValidationResult, type Severity } from "@commands/validation";
+import { type Report, type ValidationResult, type Severity } from "@commands/bindings";
 
 const { Text } = Typography;
 
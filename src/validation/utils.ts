@@ -1,4 +1,4 @@
-import { commands, type Report, type ValidationResult } from "@commands/validation";
+import { commands, type Report, type ValidationResult } from "@commands/bindings";
 
 /**
  * Enumeration of possible validation statuses
@@ -25,7 +25,7 @@ export interface ValidationReportCounts {
  */
 export default async function getValidationStatus(): Promise<ValidationStatus> {
     // Fetch the validation report from the backend
-    const validationReport = await commands.getValidationReport();
+    const validationReport = await commands.getValidationReport(null);
 
     console.log("validationReport", validationReport);
 
@@ -84,7 +84,7 @@ export async function getReportCounts(): Promise<ValidationReportCounts> {
  * @throws Error if validation report cannot be retrieved
  */
 export async function getValidationReport(): Promise<Report> {
-    const validationReport = await commands.getValidationReport();
+    const validationReport = await commands.getValidationReport(null);
     if (validationReport.status === "error") {
         throw new Error(`Failed to get validation report: ${validationReport.error}`);
     }
@@ -100,7 +100,11 @@ export async function getValidationReport(): Promise<Report> {
  */
 export async function getValidationStatusById(id: string): Promise<ValidationStatus> {
     // Fetch the validation results for the specific identifier from the backend
-    const validationResults = await commands.getValidationReportByIdentifier(id);
+    const result = await commands.getValidationReportByIdentifier(id, null);
+    if (result.status === "error") {
+        throw new Error(`Failed to get validation report: ${result.error.message}`);
+    }
+    const validationResults = result.data;
 
     console.log("validationResults for", id, validationResults);
 
@@ -132,5 +136,9 @@ export async function getValidationStatusById(id: string): Promise<ValidationSta
  * @throws Error if validation report cannot be retrieved
  */
 export async function getValidationReportById(id: string): Promise<ValidationResult[]> {
-    return commands.getValidationReportByIdentifier(id)
+    const result = await commands.getValidationReportByIdentifier(id, null);
+    if (result.status === "error") {
+        throw new Error(`Failed to get validation report: ${result.error.message}`);
+    }
+    return result.data;
 }
\ No newline at end of file